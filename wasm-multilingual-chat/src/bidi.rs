@@ -0,0 +1,99 @@
+//! Bidirectional text helpers
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use serde::Serialize;
+
+/// Strong direction of a character for bidi layout
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Ltr,
+    Rtl,
+    Neutral,
+}
+
+/// Characters from right-to-left scripts (Hebrew, Arabic, Syriac, Thaana, N'Ko
+/// and their presentation forms)
+fn is_rtl_char(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{05FF}'
+        | '\u{0600}'..='\u{06FF}'
+        | '\u{0700}'..='\u{074F}'
+        | '\u{0750}'..='\u{077F}'
+        | '\u{0780}'..='\u{07BF}'
+        | '\u{07C0}'..='\u{07FF}'
+        | '\u{08A0}'..='\u{08FF}'
+        | '\u{FB1D}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}'
+    )
+}
+
+/// Strong direction of a character
+/// Letters are strong; digits (including Arabic-Indic digits), punctuation,
+/// symbols and combining marks are neutral.
+pub fn char_direction(c: char) -> Direction {
+    if !c.is_alphabetic() {
+        Direction::Neutral
+    } else if is_rtl_char(c) {
+        Direction::Rtl
+    } else {
+        Direction::Ltr
+    }
+}
+
+/// Direction of a run of text from its first strong character
+pub fn first_strong_direction(text: &str) -> Direction {
+    text.chars()
+        .map(char_direction)
+        .find(|direction| *direction != Direction::Neutral)
+        .unwrap_or(Direction::Neutral)
+}
+
+/// Direction of one whitespace-separated token
+#[derive(Serialize)]
+struct TokenDirection<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+    direction: Direction,
+}
+
+fn word_directions_report<'a>(text: &'a str) -> Vec<TokenDirection<'a>> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+
+    for piece in text.split_inclusive(char::is_whitespace) {
+        let token = piece.trim_end();
+        if !token.is_empty() {
+            tokens.push(TokenDirection {
+                text: token,
+                start: offset,
+                end: offset + token.len(),
+                direction: first_strong_direction(token),
+            });
+        }
+        offset += piece.len();
+    }
+
+    tokens
+}
+
+/// Detect the writing direction of each token
+/// Returns array: [{"text":"שלום","start":0,"end":8,"direction":"rtl"}]
+///
+/// Tokens are whitespace-separated and take the direction of their first
+/// strong character. Tokens without letters (numbers, punctuation, emoji)
+/// are "neutral" and should inherit direction from their neighbours.
+#[wasm_bindgen]
+pub fn word_directions(text: &str) -> JsValue {
+    to_js(&word_directions_report(text))
+}
+
+/// `word_directions` as a JSON string
+/// @deprecated Use `word_directions`, which returns an object instead of JSON text.
+#[deprecated(note = "use `word_directions`, which returns an object")]
+#[wasm_bindgen]
+pub fn word_directions_json(text: &str) -> String {
+    to_json(&word_directions_report(text), "[]")
+}
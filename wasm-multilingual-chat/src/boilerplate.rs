@@ -0,0 +1,152 @@
+//! Greeting and sign-off detection
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use serde::Serialize;
+use crate::lexicon::{greetings, signoffs};
+use crate::segmentation::words;
+
+/// Words allowed between a greeting and its closing punctuation ("Hi team,")
+const GREETING_MAX_EXTRA_WORDS: usize = 3;
+
+/// Words allowed after a sign-off phrase ("Thanks a lot!", "Cheers, Anna")
+const SIGNOFF_MAX_EXTRA_WORDS: usize = 2;
+
+/// Longest name line accepted below a sign-off ("Best regards\nAnna Schmidt")
+const SIGNATURE_MAX_WORDS: usize = 3;
+
+/// Byte length of the longest phrase that starts `text`, ignoring case
+/// Phrases must end at a word boundary, except in Thai, which has no spaces.
+fn match_phrase(text: &str, phrases: &[&str]) -> Option<usize> {
+    phrases
+        .iter()
+        .filter_map(|phrase| {
+            let mut text_chars = text.char_indices();
+            for expected in phrase.chars() {
+                let (_, c) = text_chars.next()?;
+                if !c.to_lowercase().eq(expected.to_lowercase()) {
+                    return None;
+                }
+            }
+            let end = text_chars.next().map_or(text.len(), |(i, _)| i);
+            let thai = phrase.chars().any(|c| matches!(c, '\u{0E00}'..='\u{0E7F}'));
+            let boundary = text[end..].chars().next().is_none_or(|c| !c.is_alphanumeric());
+            (thai || boundary).then_some(end)
+        })
+        .max()
+}
+
+/// Phrases of the language, followed by English ones (chat often mixes both)
+fn phrase_match(text: &str, table: fn(&str) -> &'static [&'static str], language: &str) -> Option<usize> {
+    match_phrase(text, table(language)).or_else(|| match_phrase(text, table("en")))
+}
+
+/// End offset of the greeting at the start of `text`, if any
+fn greeting_end(text: &str, language: &str) -> Option<usize> {
+    let start = text.len() - text.trim_start().len();
+    let rest = &text[start..];
+    let phrase_len = phrase_match(rest, greetings, language)?;
+
+    let line_end = rest.find('\n').unwrap_or(rest.len());
+    let after = &rest[phrase_len..line_end];
+
+    // "Hi team," / "Bonjour à tous !": the greeting runs to its punctuation
+    if let Some(mark) = after.find([',', '!', '.', ':', ';', '—']) {
+        if words(&after[..mark]).len() <= GREETING_MAX_EXTRA_WORDS {
+            return Some(start + phrase_len + mark + after[mark..].chars().next().map_or(0, char::len_utf8));
+        }
+    }
+    // "Hi Maria" on a line of its own
+    if line_end < rest.len() && words(after).len() <= GREETING_MAX_EXTRA_WORDS {
+        return Some(start + line_end);
+    }
+    Some(start + phrase_len)
+}
+
+fn is_signoff(text: &str, language: &str) -> bool {
+    phrase_match(text, signoffs, language)
+        .is_some_and(|len| words(&text[len..]).len() <= SIGNOFF_MAX_EXTRA_WORDS)
+}
+
+/// Start offset of the sign-off in `text`, if any
+fn signoff_start(text: &str, language: &str) -> Option<usize> {
+    let mut lines: Vec<(usize, &str)> = Vec::new();
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+            lines.push((offset + line.find(trimmed).unwrap_or(0), trimmed));
+        }
+        offset += line.len();
+    }
+
+    let &(last_start, last) = lines.last()?;
+    if lines.len() > 1 {
+        if is_signoff(last, language) {
+            return Some(last_start);
+        }
+        // Sign-off followed by a name line
+        let (previous_start, previous) = lines[lines.len() - 2];
+        if is_signoff(previous, language) && words(last).len() <= SIGNATURE_MAX_WORDS {
+            return Some(previous_start);
+        }
+    }
+
+    // Sign-off as the last sentence of a line: "See you at 5. Cheers!"
+    // Thai separates sentences with a space instead of punctuation.
+    let marks: &[char] = if language == "th" { &['.', '!', '?', ' '] } else { &['.', '!', '?', '。'] };
+    let core = last.trim_end_matches(['.', '!', '?', '。', ' ']);
+    let sentence_start = core
+        .rfind(marks)
+        .map_or(0, |mark| mark + core[mark..].chars().next().map_or(0, char::len_utf8));
+    let sentence = last[sentence_start..].trim_start();
+    let sentence_start = last_start + last.len() - sentence.len();
+    is_signoff(sentence, language).then_some(sentence_start)
+}
+
+/// A message split into its boilerplate and content
+#[derive(Serialize)]
+struct MessageSegments<'a> {
+    greeting: &'a str,
+    body: &'a str,
+    signoff: &'a str,
+}
+
+fn segment_message_report<'a>(text: &'a str, language: &str) -> MessageSegments<'a> {
+    let body_start = greeting_end(text, language).unwrap_or(0);
+    let body_end = signoff_start(&text[body_start..], language).map_or(text.len(), |start| body_start + start);
+
+    MessageSegments {
+        greeting: text[..body_start].trim(),
+        body: text[body_start..body_end].trim(),
+        signoff: text[body_end..].trim(),
+    }
+}
+
+/// Split a message into greeting, body and sign-off
+/// Returns object: {"greeting":"Hi team,","body":"The build is green.","signoff":"Cheers, Anna"}
+///
+/// The greeting is a phrase from the language's greeting list at the very
+/// start, extended to its punctuation (or to the end of its line) when only
+/// a few words, such as a name, follow it. The sign-off is the last line,
+/// the last two lines when a short name line follows a sign-off phrase, or
+/// the last sentence when it is a sign-off phrase plus at most two words.
+/// English phrases are recognized in every language.
+///
+/// Missing parts are empty strings; without either, the body is the whole
+/// (trimmed) message.
+///
+/// @param text - Message text
+/// @param language - Language code selecting the phrase lists
+#[wasm_bindgen]
+pub fn segment_message(text: &str, language: &str) -> JsValue {
+    to_js(&segment_message_report(text, language))
+}
+
+/// `segment_message` as a JSON string
+/// @deprecated Use `segment_message`, which returns an object instead of JSON text.
+#[deprecated(note = "use `segment_message`, which returns an object")]
+#[wasm_bindgen]
+pub fn segment_message_json(text: &str, language: &str) -> String {
+    to_json(&segment_message_report(text, language), "{}")
+}
@@ -0,0 +1,416 @@
+//! Byte-pair encoding tokenizer for vocabularies loaded at runtime
+//!
+//! **Learning Point**: A BPE vocabulary is learned by repeatedly joining the
+//! most frequent pair of adjacent symbols in a corpus, and the list of joins
+//! ("merges") is kept in the order they were learned. Encoding replays it:
+//! a word starts as single characters (or bytes) and the adjacent pair whose
+//! merge was learned earliest is joined until no pair has a merge. GPT-2
+//! style vocabularies work on bytes, each shown as a printable character
+//! ("Ġ" for a space), so any text can be encoded; SentencePiece style ones
+//! mark spaces with "▁" and spell unknown characters as byte tokens
+//! ("<0x0A>").
+
+use wasm_bindgen::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use crate::segmentation::is_extend;
+
+/// Words whose encoding is remembered before the cache is cleared
+const CACHE_LIMIT: usize = 16384;
+
+/// Contractions split off by the GPT-2 pre-tokenizer
+const CONTRACTIONS: &[&str] = &["s", "t", "re", "ve", "m", "ll", "d"];
+
+/// The vocabulary and merges of a BPE model
+#[derive(Deserialize)]
+struct BpeModel {
+    vocab: HashMap<String, u32>,
+    #[serde(default)]
+    merges: Vec<Merge>,
+    #[serde(default)]
+    unk_token: Option<String>,
+    #[serde(default)]
+    byte_fallback: bool,
+    #[serde(default)]
+    end_of_word_suffix: Option<String>,
+}
+
+/// A merge, written "a b" or ["a", "b"]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Merge {
+    Joined(String),
+    Pair(String, String),
+}
+
+impl Merge {
+    fn into_pair(self) -> Option<(String, String)> {
+        match self {
+            Merge::Joined(joined) => joined.split_once(' ').map(|(a, b)| (a.to_string(), b.to_string())),
+            Merge::Pair(a, b) => Some((a, b)),
+        }
+    }
+}
+
+/// A token matched literally before pre-tokenization, such as "<|endoftext|>"
+#[derive(Deserialize)]
+struct AddedToken {
+    id: u32,
+    content: String,
+}
+
+/// How text is cut into words and symbols before merging
+#[derive(Clone, Copy, PartialEq)]
+enum PreTokenizer {
+    /// GPT-2: words split by the GPT-2 rules, symbols are bytes
+    ByteLevel,
+    /// SentencePiece: spaces become "▁", symbols are characters
+    Metaspace,
+    /// Words split at whitespace, symbols are characters
+    Whitespace,
+}
+
+/// Printable character standing for each byte in byte-level vocabularies
+/// Printable Latin-1 bytes stand for themselves; the others are moved to
+/// U+0100 and up, in byte order.
+fn byte_chars() -> [char; 256] {
+    let mut chars = ['\0'; 256];
+    let mut shifted = 0;
+    for (byte, slot) in chars.iter_mut().enumerate() {
+        let printable = matches!(byte, 0x21..=0x7E | 0xA1..=0xAC | 0xAE..=0xFF);
+        let code = if printable {
+            byte as u32
+        } else {
+            shifted += 1;
+            255 + shifted
+        };
+        *slot = char::from_u32(code).unwrap_or('\0');
+    }
+    chars
+}
+
+/// Character classes of the GPT-2 pre-tokenizer: letters, numbers, others
+fn gpt2_class(c: char) -> u8 {
+    if c.is_alphabetic() && !is_extend(c) {
+        0
+    } else if c.is_numeric() {
+        1
+    } else {
+        2
+    }
+}
+
+/// Split text as the GPT-2 pre-tokenizer does
+/// Contractions ("'s", "'ll"), runs of letters, of digits and of other
+/// characters, each taking one preceding space, and whitespace runs.
+fn gpt2_pieces(text: &str) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let offset = |i: usize| chars.get(i).map_or(text.len(), |(start, _)| *start);
+    let mut pieces = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        if c == '\'' {
+            if let Some(ending) = CONTRACTIONS.iter().find(|ending| text[start + 1..].starts_with(**ending)) {
+                pieces.push(&text[start..start + 1 + ending.len()]);
+                i += 1 + ending.len();
+                continue;
+            }
+        }
+
+        let first = if c == ' ' && chars.get(i + 1).is_some_and(|(_, next)| !next.is_whitespace()) { i + 1 } else { i };
+        let mut end = first + 1;
+        if !chars[first].1.is_whitespace() {
+            let class = gpt2_class(chars[first].1);
+            while chars.get(end).is_some_and(|(_, next)| !next.is_whitespace() && gpt2_class(*next) == class) {
+                end += 1;
+            }
+        } else {
+            while chars.get(end).is_some_and(|(_, next)| next.is_whitespace()) {
+                end += 1;
+            }
+            // The last space before a word goes with the word
+            if end < chars.len() && end - i > 1 {
+                end -= 1;
+            }
+        }
+        pieces.push(&text[start..offset(end)]);
+        i = end;
+    }
+    pieces
+}
+
+/// Split text at the start of each run of "▁"
+fn metaspace_pieces(text: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut previous = '▁';
+    for (offset, c) in text.char_indices() {
+        if c == '▁' && previous != '▁' {
+            pieces.push(&text[start..offset]);
+            start = offset;
+        }
+        previous = c;
+    }
+    if start < text.len() {
+        pieces.push(&text[start..]);
+    }
+    pieces
+}
+
+/// Tokenizer for a BPE vocabulary, such as a small on-device model's
+/// Load one from the bytes of a Hugging Face `tokenizer.json`, or of a JSON
+/// object with just the model's fields:
+/// {"vocab":{"h":0,"i":1,"hi":2},"merges":["h i"],"unk_token":"<unk>"}
+///
+/// Byte-level (GPT-2) vocabularies are recognized by a "ByteLevel"
+/// pre-tokenizer or decoder, or "Ġ" in token names, and SentencePiece ones
+/// by "Metaspace" or "▁". Other vocabularies split words at whitespace;
+/// `decode` puts spaces back only if they use an end-of-word suffix such as
+/// "</w>". Added tokens are matched in the text as they are written.
+///
+/// ```js
+/// const tokenizer = BpeTokenizer.load(new Uint8Array(await response.arrayBuffer()));
+/// const ids = tokenizer.encode("Hello world"); // Uint32Array [15496, 995]
+/// tokenizer.decode(ids); // "Hello world"
+/// ```
+#[wasm_bindgen]
+pub struct BpeTokenizer {
+    vocab: HashMap<String, u32>,
+    tokens: HashMap<u32, String>,
+    /// Merges by "a\0b", with their rank
+    ranks: HashMap<String, usize>,
+    /// Added tokens, longest first
+    added: Vec<(String, u32)>,
+    pre_tokenizer: PreTokenizer,
+    unk: Option<u32>,
+    byte_fallback: bool,
+    end_of_word_suffix: Option<String>,
+    byte_chars: [char; 256],
+    /// Encodings of recently seen words
+    cache: RefCell<HashMap<String, Vec<u32>>>,
+}
+
+#[wasm_bindgen]
+impl BpeTokenizer {
+    /// Read a vocabulary from the bytes of its JSON file
+    /// Returns undefined when the bytes are not JSON with a `vocab` object
+    /// (at the top level or under `model`).
+    pub fn load(bytes: &[u8]) -> Option<BpeTokenizer> {
+        let file: Value = serde_json::from_slice(bytes).ok()?;
+        let model: BpeModel = serde_json::from_value(file.get("model").unwrap_or(&file).clone()).ok()?;
+        let added: Vec<AddedToken> = file
+            .get("added_tokens")
+            .and_then(|added| serde_json::from_value(added.clone()).ok())
+            .unwrap_or_default();
+
+        let described = format!("{} {}", file.get("pre_tokenizer").unwrap_or(&Value::Null), file.get("decoder").unwrap_or(&Value::Null));
+        let pre_tokenizer = if described.contains("ByteLevel") {
+            PreTokenizer::ByteLevel
+        } else if described.contains("Metaspace") || described.contains('▁') {
+            PreTokenizer::Metaspace
+        } else if model.vocab.keys().any(|token| token.contains('Ġ')) {
+            PreTokenizer::ByteLevel
+        } else if model.vocab.keys().any(|token| token.starts_with('▁')) {
+            PreTokenizer::Metaspace
+        } else {
+            PreTokenizer::Whitespace
+        };
+
+        let ranks = model
+            .merges
+            .into_iter()
+            .filter_map(Merge::into_pair)
+            .enumerate()
+            .map(|(rank, (a, b))| (format!("{a}\0{b}"), rank))
+            .collect();
+        let mut vocab = model.vocab;
+        let mut added: Vec<(String, u32)> = added.into_iter().map(|token| (token.content, token.id)).collect();
+        added.retain(|(content, _)| !content.is_empty());
+        added.sort_by_key(|(content, _)| std::cmp::Reverse(content.len()));
+        for (content, id) in &added {
+            vocab.entry(content.clone()).or_insert(*id);
+        }
+        let mut tokens: HashMap<u32, String> = vocab.iter().map(|(token, id)| (*id, token.clone())).collect();
+        for (content, id) in &added {
+            tokens.insert(*id, content.clone());
+        }
+
+        Some(BpeTokenizer {
+            unk: model.unk_token.and_then(|unk| vocab.get(&unk).copied()),
+            vocab,
+            tokens,
+            ranks,
+            added,
+            pre_tokenizer,
+            byte_fallback: model.byte_fallback,
+            end_of_word_suffix: model.end_of_word_suffix.filter(|suffix| !suffix.is_empty()),
+            byte_chars: byte_chars(),
+            cache: RefCell::new(HashMap::new()),
+        })
+    }
+
+    /// Token ids of text
+    /// Characters the vocabulary cannot spell become its unknown token, or
+    /// are left out when it has none.
+    pub fn encode(&self, text: &str) -> Vec<u32> {
+        let mut ids = Vec::new();
+        let mut last = 0;
+        let mut offset = 0;
+        while offset < text.len() {
+            match self.added.iter().find(|(content, _)| text[offset..].starts_with(content.as_str())) {
+                Some((content, id)) => {
+                    self.encode_plain(&text[last..offset], last == 0, &mut ids);
+                    ids.push(*id);
+                    offset += content.len();
+                    last = offset;
+                }
+                None => offset += text[offset..].chars().next().map_or(1, char::len_utf8),
+            }
+        }
+        self.encode_plain(&text[last..], last == 0, &mut ids);
+        ids
+    }
+
+    /// Text of token ids
+    /// Unknown ids are skipped, and bytes that do not form UTF-8 become
+    /// "�".
+    pub fn decode(&self, ids: &[u32]) -> String {
+        let mut bytes: Vec<u8> = Vec::new();
+        for id in ids {
+            let Some(token) = self.tokens.get(id) else {
+                continue;
+            };
+            if self.added.iter().any(|(_, added)| added == id) {
+                bytes.extend_from_slice(token.as_bytes());
+            } else if let Some(byte) = byte_token_value(token) {
+                bytes.push(byte);
+            } else {
+                match self.pre_tokenizer {
+                    PreTokenizer::ByteLevel => {
+                        for c in token.chars() {
+                            match self.byte_chars.iter().position(|byte_char| *byte_char == c) {
+                                Some(byte) => bytes.push(byte as u8),
+                                None => bytes.extend_from_slice(c.to_string().as_bytes()),
+                            }
+                        }
+                    }
+                    PreTokenizer::Metaspace => bytes.extend_from_slice(token.replace('▁', " ").as_bytes()),
+                    PreTokenizer::Whitespace => match &self.end_of_word_suffix {
+                        Some(suffix) => bytes.extend_from_slice(token.replace(suffix.as_str(), " ").as_bytes()),
+                        None => bytes.extend_from_slice(token.as_bytes()),
+                    },
+                }
+            }
+        }
+
+        let text = String::from_utf8_lossy(&bytes).into_owned();
+        match self.pre_tokenizer {
+            PreTokenizer::Metaspace => text.strip_prefix(' ').map(String::from).unwrap_or(text),
+            PreTokenizer::Whitespace if self.end_of_word_suffix.is_some() => text.trim_end_matches(' ').to_string(),
+            _ => text,
+        }
+    }
+
+    /// Number of tokens in the vocabulary, including added tokens
+    pub fn vocab_size(&self) -> u32 {
+        self.tokens.len() as u32
+    }
+}
+
+/// Byte of a byte-fallback token such as "<0x0A>"
+fn byte_token_value(token: &str) -> Option<u8> {
+    let hex = token.strip_prefix("<0x")?.strip_suffix('>')?;
+    if hex.len() != 2 {
+        return None;
+    }
+    u8::from_str_radix(hex, 16).ok()
+}
+
+impl BpeTokenizer {
+    /// Encode text without added tokens
+    fn encode_plain(&self, text: &str, at_start: bool, ids: &mut Vec<u32>) {
+        if text.is_empty() {
+            return;
+        }
+        match self.pre_tokenizer {
+            PreTokenizer::ByteLevel => {
+                for piece in gpt2_pieces(text) {
+                    let symbols: String = piece.bytes().map(|byte| self.byte_chars[byte as usize]).collect();
+                    self.encode_word(&symbols, ids);
+                }
+            }
+            PreTokenizer::Metaspace => {
+                let mut spaced = if at_start { String::from("▁") } else { String::new() };
+                spaced.push_str(&text.replace(' ', "▁"));
+                for piece in metaspace_pieces(&spaced) {
+                    self.encode_word(piece, ids);
+                }
+            }
+            PreTokenizer::Whitespace => {
+                for word in text.split_whitespace() {
+                    self.encode_word(word, ids);
+                }
+            }
+        }
+    }
+
+    /// Encode one pre-tokenized word, through the cache
+    fn encode_word(&self, word: &str, ids: &mut Vec<u32>) {
+        if let Some(cached) = self.cache.borrow().get(word) {
+            ids.extend_from_slice(cached);
+            return;
+        }
+
+        let mut symbols: Vec<String> = word.chars().map(String::from).collect();
+        if let (Some(suffix), Some(last)) = (&self.end_of_word_suffix, symbols.last_mut()) {
+            last.push_str(suffix);
+        }
+        let mut key = String::new();
+        loop {
+            let mut best: Option<(usize, usize)> = None;
+            for i in 0..symbols.len().saturating_sub(1) {
+                key.clear();
+                key.push_str(&symbols[i]);
+                key.push('\0');
+                key.push_str(&symbols[i + 1]);
+                if let Some(rank) = self.ranks.get(&key) {
+                    if best.is_none_or(|(_, best_rank)| *rank < best_rank) {
+                        best = Some((i, *rank));
+                    }
+                }
+            }
+            let Some((i, _)) = best else {
+                break;
+            };
+            let next = symbols.remove(i + 1);
+            symbols[i].push_str(&next);
+        }
+
+        let mut encoded = Vec::new();
+        for symbol in &symbols {
+            if let Some(id) = self.vocab.get(symbol) {
+                encoded.push(*id);
+                continue;
+            }
+            let bytes: Option<Vec<u32>> = if self.byte_fallback {
+                symbol.bytes().map(|byte| self.vocab.get(&format!("<0x{byte:02X}>")).copied()).collect()
+            } else {
+                None
+            };
+            match bytes {
+                Some(bytes) => encoded.extend(bytes),
+                None => encoded.extend(self.unk),
+            }
+        }
+
+        ids.extend_from_slice(&encoded);
+        let mut cache = self.cache.borrow_mut();
+        if cache.len() >= CACHE_LIMIT {
+            cache.clear();
+        }
+        cache.insert(word.to_string(), encoded);
+    }
+}
@@ -0,0 +1,109 @@
+//! Confusable (homoglyph) skeletons
+//!
+//! **Learning Point**: UTS #39 compares strings by their "skeleton": each
+//! character is replaced by the prototype it is commonly mistaken for, so
+//! "pаypаl" with Cyrillic "а" and "paypal" share a key. The full Unicode
+//! confusables data has over 6,000 mappings; the crate ships about 250
+//! lookalikes of ASCII letters, digits and punctuation from Cyrillic, Greek,
+//! Armenian, Cherokee, Hebrew, Arabic, small capitals and letterlike
+//! symbols, which covers the impersonation seen in chat. Full-width forms
+//! and mathematical alphanumerics ("𝐩𝐚𝐲𝐩𝐚𝐥") are folded to ASCII
+//! arithmetically before the lookup.
+
+use wasm_bindgen::prelude::*;
+use crate::normalize::is_invisible;
+use crate::unicode::{normalize_form, Form};
+
+/// Lookalike characters and the prototype they are mistaken for
+/// Sorted by character; prototypes map to themselves and are not listed.
+/// Like the Unicode data, skeletons keep case apart except where shapes
+/// collide: "I", "1" and "|" all become "l", "0" becomes "O" and "m"
+/// becomes "rn".
+const CONFUSABLES: &[(char, &str)] = &[
+    ('0', "O"), ('1', "l"), ('I', "l"), ('`', "'"), ('m', "rn"), ('|', "l"), ('´', "'"), ('·', "."),
+    ('×', "x"), ('ı', "i"), ('ĸ', "k"), ('ſ', "f"), ('ƅ', "b"), ('ƍ', "g"), ('Ƨ', "2"), ('ƶ', "z"),
+    ('Ʒ', "3"), ('ƽ', "s"), ('ǀ', "l"), ('ɑ', "a"), ('ɡ', "g"), ('ɣ', "y"), ('ɩ', "i"), ('ʋ', "u"),
+    ('ʏ', "y"), ('ʹ', "'"), ('ʺ', "\""), ('ʻ', "'"), ('ʼ', "'"), ('ˈ', "'"), ('˗', "-"), (';', ";"),
+    ('Ϳ', "J"), ('Α', "A"), ('Β', "B"), ('Ε', "E"), ('Ζ', "Z"), ('Η', "H"), ('Ι', "l"), ('Κ', "K"),
+    ('Μ', "M"), ('Ν', "N"), ('Ο', "O"), ('Ρ', "P"), ('Τ', "T"), ('Υ', "Y"), ('Χ', "X"), ('α', "a"),
+    ('γ', "y"), ('ι', "i"), ('κ', "k"), ('ν', "v"), ('ο', "o"), ('ρ', "p"), ('σ', "o"), ('τ', "t"),
+    ('υ', "u"), ('Ϝ', "F"), ('Ϩ', "2"), ('ϲ', "c"), ('ϳ', "j"), ('Ϲ', "C"), ('Ѕ', "S"), ('І', "l"),
+    ('Ј', "J"), ('А', "A"), ('В', "B"), ('Е', "E"), ('З', "3"), ('К', "K"), ('М', "M"), ('Н', "H"),
+    ('О', "O"), ('Р', "P"), ('С', "C"), ('Т', "T"), ('Х', "X"), ('Ь', "b"), ('а', "a"), ('б', "6"),
+    ('г', "r"), ('е', "e"), ('к', "k"), ('о', "o"), ('р', "p"), ('с', "c"), ('т', "t"), ('у', "y"),
+    ('х', "x"), ('ѕ', "s"), ('і', "i"), ('ј', "j"), ('ѡ', "w"), ('Ѵ', "V"), ('ѵ', "v"), ('Ү', "Y"),
+    ('ү', "y"), ('һ', "h"), ('ҽ', "e"), ('Ӏ', "l"), ('ӏ', "l"), ('Ӡ', "3"), ('ԁ', "d"), ('Ԍ', "G"),
+    ('Ԛ', "Q"), ('ԛ', "q"), ('Ԝ', "W"), ('ԝ', "w"), ('Ս', "U"), ('Տ', "S"), ('Օ', "O"), ('գ', "q"),
+    ('զ', "q"), ('հ', "h"), ('ո', "n"), ('ռ', "n"), ('ս', "u"), ('ց', "g"), ('ք', "f"), ('օ', "o"),
+    ('։', ":"), ('׀', "l"), ('׃', ":"), ('ו', "l"), ('ا', "l"), ('٠', "."), ('١', "l"), ('۰', "."),
+    ('۱', "l"), ('৭', "9"), ('੧', "9"), ('ഠ', "o"), ('ဝ', "o"), ('Ꭰ', "D"), ('Ꭱ', "R"), ('Ꭲ', "T"),
+    ('Ꭹ', "Y"), ('Ꭺ', "A"), ('Ꭻ', "J"), ('Ꭼ', "E"), ('Ꮃ', "W"), ('Ꮇ', "M"), ('Ꮋ', "H"), ('Ꮐ', "G"),
+    ('Ꮒ', "h"), ('Ꮓ', "Z"), ('Ꮟ', "b"), ('Ꮩ', "V"), ('Ꮪ', "S"), ('Ꮯ', "C"), ('Ꮲ', "P"), ('Ꮶ', "K"),
+    ('Ꮷ', "d"), ('Ᏼ', "B"), ('ᑌ', "U"), ('ᑲ', "b"), ('ᖴ', "F"), ('ᗅ', "A"), ('ᗪ', "D"), ('ᗷ', "B"),
+    ('᙮', "x"), ('ᴄ', "c"), ('ᴋ', "k"), ('ᴎ', "n"), ('ᴏ', "o"), ('ᴛ', "t"), ('ᴜ', "u"), ('ᴠ', "v"),
+    ('ᴡ', "w"), ('ᴢ', "z"), ('ᴦ', "r"), ('ẝ', "f"), ('ỿ', "y"), ('ι', "i"), ('‐', "-"), ('‑', "-"),
+    ('‒', "-"), ('–', "-"), ('‘', "'"), ('’', "'"), ('‛', "'"), ('“', "\""), ('”', "\""),
+    ('‟', "\""), ('․', "."), ('′', "'"), ('″', "\""), ('⁃', "-"), ('⁄', "/"), ('ℂ', "C"),
+    ('ℋ', "H"), ('ℌ', "H"), ('ℍ', "H"), ('ℓ', "l"), ('ℕ', "N"), ('ℙ', "P"), ('ℚ', "Q"), ('ℛ', "R"),
+    ('ℜ', "R"), ('ℝ', "R"), ('ℤ', "Z"), ('ℨ', "Z"), ('K', "K"), ('ℬ', "B"), ('ℯ', "e"), ('ℰ', "E"),
+    ('ℱ', "F"), ('ℳ', "M"), ('ℴ', "o"), ('ⅇ', "e"), ('ⅉ', "j"), ('Ⅰ', "l"), ('Ⅴ', "V"), ('Ⅹ', "X"),
+    ('Ⅽ', "C"), ('Ⅾ', "D"), ('Ⅿ', "M"), ('ⅰ', "i"), ('ⅴ', "v"), ('ⅹ', "x"), ('ⅼ', "l"), ('ⅽ', "c"),
+    ('ⅾ', "d"), ('ⅿ', "rn"), ('−', "-"), ('∕', "/"), ('∣', "l"), ('∨', "v"), ('∶', ":"), ('⊤', "T"),
+    ('⍴', "p"), ('⍺', "a"), ('╳', "X"), ('⤫', "x"), ('⧸', "/"), ('ⲅ', "r"), ('Ⲟ', "O"), ('ⲣ', "p"),
+    ('ⲥ', "c"), ('〃', "\""), ('ꜱ', "s"), ('꞉', ":"), ('ꞟ', "u"), ('ꬲ', "e"), ('ꭇ', "r"), ('＇', "'"),
+];
+
+/// Full-width ASCII and mathematical letters and digits as plain ASCII
+fn fold_styled(c: char) -> char {
+    let code = c as u32;
+    let folded = match code {
+        0xFF01..=0xFF5E => code - 0xFF01 + 0x21,
+        0x1D400..=0x1D6A3 => match (code - 0x1D400) % 52 {
+            index @ 0..=25 => 'A' as u32 + index,
+            index => 'a' as u32 + index - 26,
+        },
+        0x1D7CE..=0x1D7FF => '0' as u32 + (code - 0x1D7CE) % 10,
+        _ => code,
+    };
+    char::from_u32(folded).unwrap_or(c)
+}
+
+/// Confusable skeleton of text, for comparison only
+/// Text is decomposed (NFD), invisible characters are dropped, each
+/// character is replaced by its prototype and the result is decomposed
+/// again, as UTS #39 specifies. Accents survive as combining marks, so
+/// "é" and "e" keep different skeletons unless the caller folds them.
+pub fn skeleton_of(text: &str) -> String {
+    let mut mapped = String::with_capacity(text.len());
+    for c in normalize_form(text, Form::Nfd).chars() {
+        if is_invisible(c) || matches!(c, '\u{200C}' | '\u{200D}') {
+            continue;
+        }
+        let c = fold_styled(c);
+        match CONFUSABLES.binary_search_by_key(&c, |(lookalike, _)| *lookalike) {
+            Ok(index) => mapped.push_str(CONFUSABLES[index].1),
+            Err(_) => mapped.push(c),
+        }
+    }
+    normalize_form(&mapped, Form::Nfd)
+}
+
+/// Get the confusable skeleton of text (UTS #39)
+/// Two strings that look alike ("pаypаl" with Cyrillic "а", "paypa1",
+/// "ｐａｙｐａｌ") have the same skeleton, so compare skeletons to catch
+/// impersonating display names or block-listed words spelled with
+/// lookalikes. Skeletons are keys, not text to show: "m" becomes "rn".
+/// Lowercase both sides first for case-insensitive matching.
+///
+/// @param text - Text to map
+#[wasm_bindgen]
+pub fn skeleton(text: &str) -> String {
+    skeleton_of(text)
+}
+
+/// Check whether two strings are confusable with each other
+/// True when their skeletons are equal, even if the strings differ.
+#[wasm_bindgen]
+pub fn are_confusable(a: &str, b: &str) -> bool {
+    skeleton_of(a) == skeleton_of(b)
+}
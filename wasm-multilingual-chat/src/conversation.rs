@@ -0,0 +1,567 @@
+//! Conversation-level analytics over arrays of messages and message streams
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::keywords::{content_words, rank_keywords, KeywordCount};
+use crate::segmentation::{grapheme_count, word_count};
+use crate::detection::{best_language, UNDETERMINED};
+
+/// Number of keywords reported per timeline window
+const TIMELINE_KEYWORDS: usize = 5;
+
+/// Top keywords of one window of messages
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TopicWindow {
+    /// Index of the first message in the window
+    start: u32,
+    /// Index one past the last message in the window
+    end: u32,
+    keywords: Vec<KeywordCount>,
+}
+
+fn topic_timeline_report(messages: JsValue, language: &str, window: u32) -> Vec<TopicWindow> {
+    let messages: Vec<String> = serde_wasm_bindgen::from_value(messages).unwrap_or_default();
+    build_topic_timeline(&messages, language, window.max(1) as usize)
+}
+
+/// Compute the dominant topics of a conversation over time
+/// Returns array: [{"start":0,"end":3,"keywords":[{"word":"deploy","count":4}]}]
+///
+/// The window slides one message at a time, so consecutive entries overlap.
+/// Conversations shorter than the window produce a single entry covering
+/// every message. Keyword counts are updated incrementally as messages enter
+/// and leave the window instead of being recounted per window.
+///
+/// @param messages - Array of message strings, oldest first
+/// @param language - Language code used for stop word filtering
+/// @param window - Messages per window (0 is treated as 1)
+#[wasm_bindgen]
+pub fn topic_timeline(messages: JsValue, language: &str, window: u32) -> JsValue {
+    to_js(&topic_timeline_report(messages, language, window))
+}
+
+/// `topic_timeline` as a JSON string
+/// @deprecated Use `topic_timeline`, which returns an object instead of JSON text.
+#[deprecated(note = "use `topic_timeline`, which returns an object")]
+#[wasm_bindgen]
+pub fn topic_timeline_json(messages: JsValue, language: &str, window: u32) -> String {
+    to_json(&topic_timeline_report(messages, language, window), "[]")
+}
+
+fn build_topic_timeline(messages: &[String], language: &str, window: usize) -> Vec<TopicWindow> {
+    if messages.is_empty() {
+        return Vec::new();
+    }
+
+    let message_words: Vec<Vec<String>> = messages
+        .iter()
+        .map(|message| content_words(message, language))
+        .collect();
+    let window = window.min(messages.len());
+
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    for words in &message_words[..window] {
+        for word in words {
+            *counts.entry(word.clone()).or_insert(0) += 1;
+        }
+    }
+
+    let mut timeline = vec![TopicWindow {
+        start: 0,
+        end: window as u32,
+        keywords: rank_keywords(&counts, TIMELINE_KEYWORDS),
+    }];
+
+    for start in 1..=(messages.len() - window) {
+        for word in &message_words[start - 1] {
+            if let Some(count) = counts.get_mut(word) {
+                *count -= 1;
+            }
+        }
+        for word in &message_words[start + window - 1] {
+            *counts.entry(word.clone()).or_insert(0) += 1;
+        }
+        counts.retain(|_, count| *count > 0);
+
+        timeline.push(TopicWindow {
+            start: start as u32,
+            end: (start + window) as u32,
+            keywords: rank_keywords(&counts, TIMELINE_KEYWORDS),
+        });
+    }
+
+    timeline
+}
+
+/// A chat message with its author
+#[derive(Deserialize)]
+struct AuthoredMessage {
+    author: String,
+    text: String,
+}
+
+/// Participation of one author
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AuthorStats {
+    author: String,
+    messages: u32,
+    /// Runs of consecutive messages by this author
+    turns: u32,
+    words: u32,
+    characters: u32,
+    average_words_per_message: f64,
+    /// Share of all words written by this author (0.0 to 1.0)
+    word_share: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TurnTakingReport {
+    authors: Vec<AuthorStats>,
+    total_messages: u32,
+    total_words: u32,
+    total_turns: u32,
+    balance: f64,
+}
+
+fn turn_taking_stats_report(messages: JsValue) -> TurnTakingReport {
+    let messages: Vec<AuthoredMessage> = serde_wasm_bindgen::from_value(messages).unwrap_or_default();
+    build_turn_taking_report(&messages)
+}
+
+/// Compute turn-taking statistics for a conversation
+/// Returns object: {"authors":[{"author":"ana","messages":3,"turns":2,"words":40,
+/// "characters":180,"averageWordsPerMessage":13.3,"wordShare":0.6}],
+/// "totalMessages":5,"totalWords":66,"totalTurns":4,"balance":0.97}
+///
+/// `balance` is the normalized entropy of the authors' word shares: 1.0 when
+/// every author wrote the same amount, approaching 0.0 as one author
+/// dominates. Conversations with a single author have a balance of 0.0.
+/// Authors are listed in order of their first message.
+///
+/// @param messages - Array of {author, text} objects, oldest first
+#[wasm_bindgen]
+pub fn turn_taking_stats(messages: JsValue) -> JsValue {
+    to_js(&turn_taking_stats_report(messages))
+}
+
+/// `turn_taking_stats` as a JSON string
+/// @deprecated Use `turn_taking_stats`, which returns an object instead of JSON text.
+#[deprecated(note = "use `turn_taking_stats`, which returns an object")]
+#[wasm_bindgen]
+pub fn turn_taking_stats_json(messages: JsValue) -> String {
+    to_json(&turn_taking_stats_report(messages), "{}")
+}
+
+fn build_turn_taking_report(messages: &[AuthoredMessage]) -> TurnTakingReport {
+    let mut authors: Vec<AuthorStats> = Vec::new();
+    let mut previous_author: Option<&str> = None;
+
+    for message in messages {
+        let index = match authors.iter().position(|a| a.author == message.author) {
+            Some(index) => index,
+            None => {
+                authors.push(AuthorStats {
+                    author: message.author.clone(),
+                    messages: 0,
+                    turns: 0,
+                    words: 0,
+                    characters: 0,
+                    average_words_per_message: 0.0,
+                    word_share: 0.0,
+                });
+                authors.len() - 1
+            }
+        };
+
+        let stats = &mut authors[index];
+        stats.messages += 1;
+        stats.words += word_count(&message.text) as u32;
+        stats.characters += grapheme_count(&message.text) as u32;
+        if previous_author != Some(message.author.as_str()) {
+            stats.turns += 1;
+        }
+        previous_author = Some(message.author.as_str());
+    }
+
+    let total_words: u32 = authors.iter().map(|a| a.words).sum();
+    for stats in &mut authors {
+        stats.average_words_per_message = stats.words as f64 / stats.messages as f64;
+        stats.word_share = if total_words > 0 { stats.words as f64 / total_words as f64 } else { 0.0 };
+    }
+
+    let balance = if authors.len() < 2 || total_words == 0 {
+        0.0
+    } else {
+        let entropy: f64 = authors
+            .iter()
+            .filter(|a| a.word_share > 0.0)
+            .map(|a| -a.word_share * a.word_share.ln())
+            .sum();
+        entropy / (authors.len() as f64).ln()
+    };
+
+    TurnTakingReport {
+        total_messages: messages.len() as u32,
+        total_turns: authors.iter().map(|a| a.turns).sum(),
+        total_words,
+        balance,
+        authors,
+    }
+}
+
+/// 64-bit FNV-1a hash of a string, mixed with a seed
+/// The SplitMix64 finalizer spreads FNV's weak low bits so the result can
+/// be compared against a uniform threshold. Identical on every platform.
+fn seeded_hash(text: &str, seed: u64) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325 ^ seed;
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+/// Deterministic sample of the messages, in their original order
+pub fn sample(messages: &[String], fraction: f64, seed: u64) -> Vec<&String> {
+    if fraction.is_nan() || fraction <= 0.0 {
+        return Vec::new();
+    }
+    if fraction >= 1.0 {
+        return messages.iter().collect();
+    }
+
+    let threshold = (fraction * u64::MAX as f64) as u64;
+    messages
+        .iter()
+        .filter(|message| seeded_hash(message, seed) < threshold)
+        .collect()
+}
+
+fn sample_messages_report(messages: JsValue, fraction: f64, seed: u64) -> Vec<String> {
+    let messages: Vec<String> = serde_wasm_bindgen::from_value(messages).unwrap_or_default();
+    sample(&messages, fraction, seed).into_iter().cloned().collect()
+}
+
+/// Take a reproducible pseudo-random sample of messages
+/// Returns array of the kept messages, in their original order
+///
+/// Each message is kept when its seeded hash falls below `fraction` of the
+/// hash range, so the same messages and seed give the same sample on every
+/// machine, without a random number generator. A message's membership
+/// depends only on its own text: adding messages to the corpus never
+/// changes which of the old ones are sampled, and duplicate messages are
+/// either all kept or all dropped. The sample size is `fraction` of the
+/// corpus on average, not exactly.
+///
+/// `fraction` of 0.0 (or less, or NaN) returns an empty array, 1.0 (or
+/// more) returns every message.
+///
+/// @param messages - Array of message strings
+/// @param fraction - Share of messages to keep, 0.0 to 1.0
+/// @param seed - Seed selecting one of many possible samples
+#[wasm_bindgen]
+pub fn sample_messages(messages: JsValue, fraction: f64, seed: u64) -> JsValue {
+    to_js(&sample_messages_report(messages, fraction, seed))
+}
+
+/// `sample_messages` as a JSON string
+/// @deprecated Use `sample_messages`, which returns an object instead of JSON text.
+#[deprecated(note = "use `sample_messages`, which returns an object")]
+#[wasm_bindgen]
+pub fn sample_messages_json(messages: JsValue, fraction: f64, seed: u64) -> String {
+    to_json(&sample_messages_report(messages, fraction, seed), "[]")
+}
+
+/// A message with its send time
+#[derive(Deserialize)]
+struct TimedMessage {
+    #[serde(alias = "timestampMs")]
+    timestamp_ms: f64,
+    text: String,
+}
+
+/// Most buckets a histogram may span
+const MAX_HISTOGRAM_BUCKETS: i64 = 10_000;
+
+/// Activity in one time bucket
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VolumeBucket {
+    /// Bucket start and end (exclusive), in milliseconds since the epoch
+    start: i64,
+    end: i64,
+    messages: u32,
+    words: u32,
+}
+
+fn build_histogram(messages: &[TimedMessage], bucket_ms: u64) -> Vec<VolumeBucket> {
+    let bucket = bucket_ms.min(i64::MAX as u64) as i64;
+    if messages.iter().any(|message| !message.timestamp_ms.is_finite()) {
+        return Vec::new();
+    }
+    let times: Vec<i64> = messages.iter().map(|message| message.timestamp_ms.floor() as i64).collect();
+    let (Some(&min), Some(&max)) = (times.iter().min(), times.iter().max()) else {
+        return Vec::new();
+    };
+    if bucket == 0 {
+        return Vec::new();
+    }
+
+    let first = min.div_euclid(bucket);
+    let last = max.div_euclid(bucket);
+    if last.checked_sub(first).is_none_or(|span| span >= MAX_HISTOGRAM_BUCKETS) {
+        return Vec::new();
+    }
+
+    // Timestamps near the ends of the i64 range saturate rather than wrap
+    let mut buckets: Vec<VolumeBucket> = (first..=last)
+        .map(|index| VolumeBucket {
+            start: index.saturating_mul(bucket),
+            end: index.saturating_add(1).saturating_mul(bucket),
+            messages: 0,
+            words: 0,
+        })
+        .collect();
+    for (message, time) in messages.iter().zip(&times) {
+        let entry = &mut buckets[(time.div_euclid(bucket) - first) as usize];
+        entry.messages += 1;
+        entry.words += word_count(&message.text) as u32;
+    }
+
+    buckets
+}
+
+fn message_volume_histogram_report(messages: JsValue, bucket_ms: u64) -> Vec<VolumeBucket> {
+    let messages: Vec<TimedMessage> = serde_wasm_bindgen::from_value(messages).unwrap_or_default();
+    build_histogram(&messages, bucket_ms)
+}
+
+/// Count messages and words per time bucket for activity graphs
+/// Returns array: [{"start":1700000000000,"end":1700003600000,"messages":4,"words":57}]
+///
+/// Buckets are aligned to multiples of `bucket_ms` since the epoch and run
+/// from the bucket of the earliest message to that of the latest, including
+/// empty buckets in between, so the array can be plotted directly. Messages
+/// may come in any order; words use the canonical word count.
+///
+/// Empty input, a `bucket_ms` of 0, a timestamp that is NaN or infinite,
+/// or a range needing more than 10,000 buckets returns an empty array.
+///
+/// @param messages - Array of {timestamp_ms, text} objects (timestampMs is accepted too)
+/// @param bucket_ms - Bucket width in milliseconds
+#[wasm_bindgen]
+pub fn message_volume_histogram(messages: JsValue, bucket_ms: u64) -> JsValue {
+    to_js(&message_volume_histogram_report(messages, bucket_ms))
+}
+
+/// `message_volume_histogram` as a JSON string
+/// @deprecated Use `message_volume_histogram`, which returns an object instead of JSON text.
+#[deprecated(note = "use `message_volume_histogram`, which returns an object")]
+#[wasm_bindgen]
+pub fn message_volume_histogram_json(messages: JsValue, bucket_ms: u64) -> String {
+    to_json(&message_volume_histogram_report(messages, bucket_ms), "[]")
+}
+
+/// Milliseconds in an hour and in a day
+const HOUR_MS: i64 = 3_600_000;
+const DAY_MS: i64 = 24 * HOUR_MS;
+
+/// Running totals of one sender
+struct SenderTotals {
+    sender: String,
+    messages: u32,
+    words: u32,
+    characters: u32,
+}
+
+/// Activity of one sender
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SenderStats {
+    sender: String,
+    messages: u32,
+    words: u32,
+    average_words: f64,
+    average_characters: f64,
+}
+
+/// Messages detected in one language
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LanguageShare {
+    lang: &'static str,
+    messages: u32,
+    /// Share of all messages (0.0 to 1.0)
+    share: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConversationReport {
+    messages: u32,
+    words: u32,
+    average_words: f64,
+    average_characters: f64,
+    senders: Vec<SenderStats>,
+    languages: Vec<LanguageShare>,
+    /// Messages sent in each hour of the day, from 00:00 to 23:00 local time
+    activity_by_hour: [u32; 24],
+}
+
+/// Aggregate statistics over a conversation, one message at a time
+/// Each message is counted and detected as it is added and then dropped,
+/// so memory stays the same however many thousands of messages are added.
+#[wasm_bindgen]
+pub struct ConversationStats {
+    senders: Vec<SenderTotals>,
+    /// Messages per detected language, in order of first appearance
+    languages: Vec<(&'static str, u32)>,
+    hours: [u32; 24],
+    utc_offset_ms: i64,
+}
+
+#[wasm_bindgen]
+impl ConversationStats {
+    /// @param utc_offset_minutes - Offset added to UTC to get the local
+    /// hours reported in `activityByHour`, such as 120 for UTC+2; use
+    /// `-new Date().getTimezoneOffset()` for the browser's time zone
+    #[wasm_bindgen(constructor)]
+    pub fn new(utc_offset_minutes: i32) -> ConversationStats {
+        ConversationStats {
+            senders: Vec::new(),
+            languages: Vec::new(),
+            hours: [0; 24],
+            utc_offset_ms: utc_offset_minutes as i64 * 60_000,
+        }
+    }
+
+    /// Add the next message
+    /// Messages may be added in any order; words use the canonical word
+    /// count and characters are grapheme clusters.
+    ///
+    /// @param timestamp - Send time in milliseconds since the epoch
+    pub fn add_message(&mut self, text: &str, sender: &str, timestamp: f64) {
+        let index = match self.senders.iter().position(|totals| totals.sender == sender) {
+            Some(index) => index,
+            None => {
+                self.senders.push(SenderTotals { sender: sender.to_string(), messages: 0, words: 0, characters: 0 });
+                self.senders.len() - 1
+            }
+        };
+        let totals = &mut self.senders[index];
+        totals.messages += 1;
+        totals.words += word_count(text) as u32;
+        totals.characters += grapheme_count(text) as u32;
+
+        let (language, confidence) = best_language(text);
+        let language = if confidence > 0.0 { language } else { UNDETERMINED };
+        match self.languages.iter_mut().find(|(lang, _)| *lang == language) {
+            Some(entry) => entry.1 += 1,
+            None => self.languages.push((language, 1)),
+        }
+
+        if timestamp.is_finite() {
+            let local = (timestamp.floor() as i64).saturating_add(self.utc_offset_ms);
+            self.hours[(local.rem_euclid(DAY_MS) / HOUR_MS) as usize] += 1;
+        }
+    }
+
+    /// Statistics of every message added so far
+    /// Returns object: {"messages":120,"words":1530,"averageWords":12.75,
+    /// "averageCharacters":61.2,"senders":[{"sender":"ana","messages":70,
+    /// "words":910,"averageWords":13.0,"averageCharacters":64.1}],
+    /// "languages":[{"lang":"es","messages":90,"share":0.75}],
+    /// "activityByHour":[0,0,...,14,9]}
+    ///
+    /// Senders are listed in order of their first message and languages
+    /// by message count. Languages are detected per message as by
+    /// `detect_language`, with messages that give no evidence (emoji only,
+    /// say) counted as "und".
+    pub fn report(&self) -> JsValue {
+        to_js(&self.build_report())
+    }
+
+    /// Forget all added messages, keeping the UTC offset
+    pub fn reset(&mut self) {
+        self.senders.clear();
+        self.languages.clear();
+        self.hours = [0; 24];
+    }
+}
+
+impl ConversationStats {
+    fn build_report(&self) -> ConversationReport {
+        let per = |part: u32, whole: u32| if whole > 0 { part as f64 / whole as f64 } else { 0.0 };
+        let messages: u32 = self.senders.iter().map(|totals| totals.messages).sum();
+        let words: u32 = self.senders.iter().map(|totals| totals.words).sum();
+        let characters: u32 = self.senders.iter().map(|totals| totals.characters).sum();
+
+        let mut languages: Vec<LanguageShare> = self
+            .languages
+            .iter()
+            .map(|&(lang, count)| LanguageShare { lang, messages: count, share: per(count, messages) })
+            .collect();
+        languages.sort_by_key(|share| std::cmp::Reverse(share.messages));
+
+        ConversationReport {
+            messages,
+            words,
+            average_words: per(words, messages),
+            average_characters: per(characters, messages),
+            senders: self
+                .senders
+                .iter()
+                .map(|totals| SenderStats {
+                    sender: totals.sender.clone(),
+                    messages: totals.messages,
+                    words: totals.words,
+                    average_words: per(totals.words, totals.messages),
+                    average_characters: per(totals.characters, totals.messages),
+                })
+                .collect(),
+            languages,
+            activity_by_hour: self.hours,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timed(timestamps: &[f64]) -> Vec<TimedMessage> {
+        timestamps.iter().map(|&timestamp_ms| TimedMessage { timestamp_ms, text: String::from("hello there") }).collect()
+    }
+
+    fn counts(buckets: &[VolumeBucket]) -> Vec<(i64, i64, u32, u32)> {
+        buckets.iter().map(|bucket| (bucket.start, bucket.end, bucket.messages, bucket.words)).collect()
+    }
+
+    #[test]
+    fn histogram_includes_empty_buckets() {
+        let buckets = build_histogram(&timed(&[2500.0, 100.0, 999.9]), 1000);
+        assert_eq!(counts(&buckets), [(0, 1000, 2, 4), (1000, 2000, 0, 0), (2000, 3000, 1, 2)]);
+        assert!(build_histogram(&timed(&[]), 1000).is_empty());
+        assert!(build_histogram(&timed(&[5.0]), 0).is_empty());
+    }
+
+    #[test]
+    fn extreme_timestamps_do_not_overflow() {
+        assert!(build_histogram(&timed(&[f64::INFINITY]), 1000).is_empty());
+        assert!(build_histogram(&timed(&[0.0, f64::NAN]), 1000).is_empty());
+        assert!(build_histogram(&timed(&[-1e300, 1e300]), 1000).is_empty());
+        let widest = build_histogram(&timed(&[-1e300, 1e300]), u64::MAX);
+        assert_eq!(widest.len(), 4);
+        assert_eq!((widest[0].start, widest[3].end), (i64::MIN, i64::MAX));
+
+        let buckets = build_histogram(&timed(&[1e300]), 1000);
+        assert_eq!(counts(&buckets), [(i64::MAX / 1000 * 1000, i64::MAX, 1, 2)]);
+    }
+}
@@ -0,0 +1,1234 @@
+//! Language detection
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use serde::{Deserialize, Serialize};
+use crate::emoji::is_emoji;
+use crate::entities::{code_spans, hashtag_spans, mentions, url_spans, Span};
+use crate::formatting::split_key_value;
+use crate::lexicon::{common_words, greetings, is_stopword, signoffs, stopwords, LANGUAGES};
+use crate::ngrams::{profile_hits, trigram_profile, trigrams};
+use crate::profiles::{custom_profiles, detectable_languages, LanguageProfile};
+use crate::script::{script_of, Script};
+use crate::segmentation::{paragraphs, sentences, words};
+use std::collections::HashMap;
+
+/// Points for a perfect trigram match, relative to the 2 to 3 points of a
+/// common word
+const TRIGRAM_WEIGHT: f64 = 12.0;
+
+/// Texts with fewer trigrams than this are scored on words only
+const MIN_TRIGRAMS: usize = 4;
+
+/// Letters that rule a language out, and letters specific to it, for
+/// languages sharing a script
+/// - Cyrillic: Russian alone has ы, э and ё; Ukrainian і, ї, є and ґ;
+///   Serbian ђ, ј, љ, њ, ћ and џ (and lacks й, щ, ъ, ь, ю, я); Bulgarian
+///   uses ъ as a vowel
+/// - Arabic: Persian and Urdu add پ, چ, ژ and گ and write ی and ک where
+///   Arabic has ي and ك; only Urdu has ٹ, ڈ, ڑ, ں, ے and ھ, and only
+///   Arabic ة and ى
+const SHARED_SCRIPT_LETTERS: [(Script, &str, &str, &str); 7] = [
+    (Script::Cyrillic, "ru", "іїєґђјљњћџ", "ыэё"),
+    (Script::Cyrillic, "uk", "ыэёъђјљњћџ", "іїєґ"),
+    (Script::Cyrillic, "bg", "ыэёіїєґђјљњћџ", "ъ"),
+    (Script::Cyrillic, "sr", "йщъыьэюяёіїєґ", "ђјљњћџ"),
+    (Script::Arabic, "ar", "پچژگیکٹڈڑںےۓ", "ةىيك"),
+    (Script::Arabic, "fa", "ةىيكٹڈڑںےۓھ", "پچژگ"),
+    (Script::Arabic, "ur", "ةىيكژ", "ٹڈڑںےۓھ"),
+];
+
+/// Accented letters of Latin-script languages: the language, every accented
+/// letter its alphabet uses, the letters among them that hint at it, and
+/// the points for a hint
+/// A language gets no hint points when the text has accented Latin letters
+/// outside its alphabet, so Czech "děkuji" or Vietnamese "chào mọi người"
+/// do not count as French.
+const LATIN_LETTERS: [(&str, &str, &str, f64); 10] = [
+    ("fr", "àâæçéèêëîïôœùûüÿ", "àâéèêëîïôùûüÿç", 3.0),
+    ("es", "áéíñóúü", "áéíóúñü", 3.0),
+    ("pt", "áàâãçéêíóôõúü", "áàâãéêíóôõúüç", 3.0),
+    ("it", "àèéìíîòóùú", "àèéìòù", 3.0),
+    ("de", "äöüßé", "äöüß", 5.0),
+    ("sv", "åäöé", "åäö", 3.0),
+    ("pl", "ąćęłńóśźż", "ąćęłńśźż", 5.0),
+    ("cs", "áčďéěíňóřšťúůýž", "čďěňřšťůž", 5.0),
+    ("tr", "âçğıîöşü", "ğış", 5.0),
+    (
+        "vi",
+        "àáảãạăằắẳẵặâầấẩẫậđèéẻẽẹêềếểễệìíỉĩịòóỏõọôồốổỗộơờớởỡợùúủũụưừứửữựỳýỷỹỵ",
+        "ảạăằắẳẵặầấẩẫậđẻẽẹềếểễệỉĩịỏọồốổỗộơờớởỡợủũụưừứửữựỳỷỹỵ",
+        5.0,
+    ),
+];
+
+/// Points for text in a shared script to every language that could have written it
+const SHARED_SCRIPT_POINTS: f64 = 4.0;
+
+/// Points per language-specific letter, counted up to three times
+const SPECIFIC_LETTER_POINTS: f64 = 3.0;
+
+/// Only the first this many words are looked up in the common-word lists
+const MAX_SCORED_WORDS: usize = 50;
+
+/// Whether a lowercased word is one of a language's common words
+/// Registered languages use their profile's words.
+fn is_common_word(word: &str, language: &str, profile: Option<&LanguageProfile>) -> bool {
+    match profile {
+        Some(profile) => profile.words.iter().any(|common| common == word),
+        None => common_words(language).contains(&word),
+    }
+}
+
+/// Points per common word; Devanagari and Thai words are far less likely
+/// to collide
+fn common_word_points(language: &str) -> f64 {
+    if matches!(language, "hi" | "th") { 3.0 } else { 2.0 }
+}
+
+/// Words of lowercased text looked up in the common-word lists, at most `limit`
+fn scored_words(text_lower: &str, limit: usize) -> Vec<&str> {
+    words(text_lower).into_iter().take(limit).map(|word| word.text).collect()
+}
+
+/// A script or letter heuristic that gave a language points
+#[derive(Serialize)]
+struct RuleHit {
+    rule: &'static str,
+    lang: &'static str,
+    /// Letters of the text that triggered the rule, empty for whole-script rules
+    letters: String,
+    points: f64,
+}
+
+/// Detection evidence collected from text
+/// Evidence is additive: adding text piece by piece, split at whitespace,
+/// scores the same as adding it at once, which lets `LanguageDetector`
+/// score a message while it is typed.
+#[derive(Clone)]
+pub struct Evidence {
+    /// Languages being scored: the built-in ones, then registered profiles
+    languages: Vec<&'static str>,
+    /// Words looked up in the common-word lists so far
+    words: usize,
+    /// Common-word points per language, in `languages` order
+    common: Vec<f64>,
+    /// Occurrences of each non-ASCII character, lowercased
+    letters: HashMap<char, usize>,
+    /// Trigrams seen
+    grams: usize,
+    /// Rank-weighted trigram profile hits per language, in `languages` order
+    hits: Vec<f64>,
+}
+
+impl Evidence {
+    pub fn new() -> Self {
+        Evidence {
+            languages: LANGUAGES.to_vec(),
+            words: 0,
+            common: vec![0.0; LANGUAGES.len()],
+            letters: HashMap::new(),
+            grams: 0,
+            hits: vec![0.0; LANGUAGES.len()],
+        }
+    }
+
+    pub fn from_text(text: &str) -> Self {
+        let mut evidence = Evidence::new();
+        evidence.add(text);
+        evidence
+    }
+
+    /// Add the evidence of more text
+    /// Languages registered since the last call are scored from here on.
+    pub fn add(&mut self, text: &str) {
+        let custom = custom_profiles();
+        for (code, _) in &custom {
+            if !self.languages.contains(code) {
+                self.languages.push(code);
+                self.common.push(0.0);
+                self.hits.push(0.0);
+            }
+        }
+
+        let text_lower = text.to_lowercase();
+        let scored = scored_words(&text_lower, MAX_SCORED_WORDS - self.words);
+        self.words += scored.len();
+
+        for c in text_lower.chars().filter(|c| !c.is_ascii()) {
+            *self.letters.entry(c).or_insert(0) += 1;
+        }
+
+        let grams = trigrams(text);
+        self.grams += grams.len();
+
+        for (i, language) in self.languages.iter().enumerate() {
+            let profile = custom.iter().find(|(code, _)| code == language).map(|(_, profile)| profile.as_ref());
+            let matched = scored.iter().filter(|word| !word.is_empty() && is_common_word(word, language, profile)).count();
+            self.common[i] += common_word_points(language) * matched as f64;
+            self.hits[i] += match profile {
+                Some(profile) => profile_hits(&grams, &profile.trigrams),
+                None => profile_hits(&grams, trigram_profile(language)),
+            };
+        }
+    }
+
+    /// Score every supported language, combining word and trigram evidence
+    /// Returns (language, score) pairs in `LANGUAGES` order, followed by
+    /// registered languages.
+    pub fn scores(&self) -> Vec<(&'static str, f64)> {
+        let mut scores: Vec<(&'static str, f64)> = self.languages.iter().copied().zip(self.common.iter().copied()).collect();
+        for hit in self.rule_hits() {
+            if let Some(entry) = scores.iter_mut().find(|(lang, _)| *lang == hit.lang) {
+                entry.1 += hit.points;
+            }
+        }
+        for ((_, score), points) in scores.iter_mut().zip(self.trigram_points()) {
+            *score += points;
+        }
+        scores
+    }
+
+    /// Script and letter heuristics that fire for the text so far
+    fn rule_hits(&self) -> Vec<RuleHit> {
+        let mut hits: Vec<RuleHit> = Vec::new();
+        let mut add = |rule: &'static str, language: &'static str, letters: String, points: f64| {
+            if self.languages.contains(&language) {
+                hits.push(RuleHit { rule, lang: language, letters, points });
+            }
+        };
+        let count_where = |test: &dyn Fn(char) -> bool| -> usize {
+            self.letters.iter().filter(|(c, _)| test(**c)).map(|(_, count)| count).sum()
+        };
+        let count = |letters: &str| count_where(&|c| letters.contains(c));
+        // Letters of the text among `letters`, in the order listed
+        let found = |letters: &str| -> String { letters.chars().filter(|c| self.letters.contains_key(c)).collect() };
+
+        // Character-based heuristics
+        if count_where(&|c| matches!(c, '\u{0900}'..='\u{097F}')) > 0 {
+            add("devanagariScript", "hi", String::new(), 10.0);
+        }
+        if count_where(&|c| matches!(c, '\u{0E00}'..='\u{0E7F}')) > 0 {
+            add("thaiScript", "th", String::new(), 10.0);
+        }
+
+        // CJK: Hangul is only used for Korean and kana only for Japanese;
+        // Han characters without either are taken as Chinese
+        let hangul = count_where(&|c| script_of(c) == Script::Hangul);
+        let kana = count_where(&|c| matches!(script_of(c), Script::Hiragana | Script::Katakana)) + count("々");
+        let han = count_where(&|c| script_of(c) == Script::Han);
+        if hangul > 0 && hangul >= kana {
+            add("hangulScript", "ko", String::new(), 10.0);
+        } else if kana > 0 {
+            add("kanaScript", "ja", String::new(), 10.0);
+        } else if han > 0 {
+            add("hanScript", "zh", String::new(), 10.0);
+        }
+
+        // Cyrillic and Arabic script: every language of the script is a
+        // candidate unless the text uses a letter it lacks; letters unique to
+        // one language add to it
+        for (script, language, missing, distinctive) in SHARED_SCRIPT_LETTERS {
+            if count_where(&|c| script_of(c) == script) > 0 && found(missing).is_empty() {
+                let rule = if script == Script::Cyrillic { "cyrillicLetters" } else { "arabicLetters" };
+                add(rule, language, found(distinctive), SHARED_SCRIPT_POINTS + SPECIFIC_LETTER_POINTS * count(distinctive).min(3) as f64);
+            }
+        }
+
+        // Diacritics hint at the Latin-script languages using them
+        let accented: Vec<char> = self
+            .letters
+            .keys()
+            .copied()
+            .filter(|c| c.is_alphabetic() && script_of(*c) == Script::Latin)
+            .collect();
+        for (language, alphabet, hints, points) in LATIN_LETTERS {
+            let letters = found(hints);
+            if !letters.is_empty() && accented.iter().all(|c| alphabet.contains(*c)) {
+                add("diacritics", language, letters, points);
+            }
+        }
+
+        // Registered languages: their accented letters count like diacritics
+        for (code, profile) in custom_profiles() {
+            let letters = found(&profile.letters);
+            if !letters.is_empty() {
+                add("profileLetters", code, letters, 3.0);
+            }
+        }
+
+        hits
+    }
+
+    /// Trigram points per language, in `languages` order
+    /// Each language scores `TRIGRAM_WEIGHT` times how much better its
+    /// profile matches than the worst of the matching profiles, so a text
+    /// that looks equally like every language gives no points. Short texts
+    /// score nothing.
+    fn trigram_points(&self) -> Vec<f64> {
+        if self.grams < MIN_TRIGRAMS {
+            return vec![0.0; self.languages.len()];
+        }
+        let similarities: Vec<f64> = self.hits.iter().map(|hits| hits / self.grams as f64).collect();
+
+        // Profiles of another script never match, so only matching profiles
+        // set the floor; a single match is measured against zero
+        let matching: Vec<f64> = similarities.iter().copied().filter(|similarity| *similarity > 0.0).collect();
+        let floor = if matching.len() > 1 { matching.iter().copied().fold(f64::INFINITY, f64::min) } else { 0.0 };
+        similarities.iter().map(|similarity| ((similarity - floor) * TRIGRAM_WEIGHT).max(0.0)).collect()
+    }
+}
+
+/// Spans that say nothing about the language of a text: code, URLs,
+/// @mentions and #hashtags
+fn entity_spans(text: &str) -> Vec<Span> {
+    let mut spans: Vec<Span> = code_spans(text).into_iter().chain(url_spans(text)).collect();
+    spans.extend(mentions(text).into_iter().map(|mention| mention.span));
+    spans.extend(hashtag_spans(text));
+    spans
+}
+
+/// Text with code, URLs, mentions, hashtags and emoji replaced by spaces
+/// Detection scores only what is left, so "@anna https://x.com 😂" has no
+/// evidence instead of being read as English.
+pub fn strip_entities(text: &str) -> String {
+    let spans = entity_spans(text);
+    let mut stripped = String::with_capacity(text.len());
+    for (i, c) in text.char_indices() {
+        if spans.iter().any(|span| span.start <= i && i < span.end) {
+            if spans.iter().any(|span| span.start == i) {
+                stripped.push(' ');
+            }
+        } else if is_emoji(c) {
+            stripped.push(' ');
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+/// Score every supported language, combining word and trigram evidence
+/// Returns (language, score) pairs in `LANGUAGES` order, followed by
+/// languages registered with `register_language_profile`. Code, URLs,
+/// mentions, hashtags and emoji are removed first (see `strip_entities`).
+pub fn language_scores(text: &str) -> Vec<(&'static str, f64)> {
+    Evidence::from_text(&strip_entities(text)).scores()
+}
+
+/// Best language among scores and its share of all points
+fn best_of(scores: &[(&'static str, f64)]) -> (&'static str, f64) {
+    let total: f64 = scores.iter().map(|(_, score)| score).sum();
+    if total == 0.0 {
+        return ("en", 0.0);
+    }
+
+    let (language, top) = scores
+        .iter()
+        .fold(("en", 0.0), |best, &(lang, score)| if score > best.1 { (lang, score) } else { best });
+
+    (language, top / total)
+}
+
+/// Pick the best-scoring language and its confidence
+/// Confidence is the winner's share of all points (0.0 to 1.0). Ties go to
+/// the language listed first in `LANGUAGES`; text without any evidence
+/// falls back to "en" with confidence 0.0.
+pub fn best_language(text: &str) -> (&'static str, f64) {
+    best_of(&language_scores(text))
+}
+
+/// Detect language from text
+/// Returns language code: en, de, fr, it, pt, hi, es, th, zh, ja, ko, ru, uk, bg, sr, ar, fa, ur,
+/// nl, pl, tr, vi, sv, cs, hi-Latn, th-Latn
+///
+/// Hindi and Thai typed in Latin letters ("kya kar rahe ho", "sawasdee
+/// krub") are reported as "hi-Latn" and "th-Latn", recognized by their
+/// common romanized words and trigram profiles rather than by script.
+/// Chinese, Japanese and Korean are told apart by script: any Hangul means
+/// Korean, any kana (or the iteration mark 々) Japanese, and Han characters
+/// alone Chinese, so all-kanji Japanese is reported as "zh".
+/// Cyrillic text is split between Russian, Ukrainian, Bulgarian and
+/// Serbian, and Arabic-script text between Arabic, Persian and Urdu, by the
+/// letters each language does or does not use. Accented Latin letters
+/// likewise only hint at languages whose alphabet has all of them.
+/// Scores combine common words, script and diacritic hints with
+/// character trigram profiles, which keep working on short or misspelled
+/// messages that contain no listed word. Languages added with
+/// `register_language_profile` compete on their own words and trigrams.
+///
+/// Code (fenced blocks and inline), URLs, @mentions, #hashtags and emoji
+/// are removed before scoring, so they cannot outvote a short message;
+/// `detect_language_with_options` can keep them with `keepEntities`.
+#[wasm_bindgen]
+pub fn detect_language(text: &str) -> String {
+    if text.trim().is_empty() {
+        return String::from("en");
+    }
+
+    String::from(best_language(text).0)
+}
+
+/// BCP 47 code for text whose language could not be determined
+pub const UNDETERMINED: &str = "und";
+
+/// Caller settings for detection
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DetectionOptions {
+    /// Candidate languages; empty (or only unsupported codes) allows every
+    /// supported language
+    languages: Vec<String>,
+    /// Weight multiplying a language's score; unlisted languages weigh 1.0
+    priors: HashMap<String, f64>,
+    /// Report `UNDETERMINED` instead of guessing when the best candidate's
+    /// confidence is below this (0.0 only rejects text without evidence)
+    min_confidence: Option<f64>,
+    /// Score code, URLs, mentions, hashtags and emoji as well instead of
+    /// removing them before detection
+    keep_entities: bool,
+}
+
+impl DetectionOptions {
+    /// Options passed from JavaScript; anything unreadable gives the defaults
+    pub fn from_js(options: JsValue) -> Self {
+        serde_wasm_bindgen::from_value(options).unwrap_or_default()
+    }
+
+    /// Candidate languages, in the caller's order
+    fn candidates(&self) -> Vec<&'static str> {
+        let detectable = detectable_languages();
+        let listed: Vec<&'static str> = self
+            .languages
+            .iter()
+            .filter_map(|code| detectable.iter().find(|language| **language == code.as_str()).copied())
+            .collect();
+        if listed.is_empty() { detectable } else { listed }
+    }
+
+    /// Text as it is scored: without entities unless `keep_entities` is set
+    fn prepare(&self, text: &str) -> String {
+        if self.keep_entities { text.to_string() } else { strip_entities(text) }
+    }
+
+    fn prior(&self, language: &str) -> f64 {
+        self.priors.get(language).copied().unwrap_or(1.0).max(0.0)
+    }
+
+    /// Keep the scores of candidate languages, weighted by their priors
+    /// Scores stay in `LANGUAGES` order.
+    pub fn apply(&self, scores: Vec<(&'static str, f64)>) -> Vec<(&'static str, f64)> {
+        let candidates = self.candidates();
+        scores
+            .into_iter()
+            .filter(|(language, _)| candidates.contains(language))
+            .map(|(language, score)| (language, score * self.prior(language)))
+            .collect()
+    }
+
+    /// Best candidate and its share of the weighted scores
+    /// Without any evidence the candidate with the highest prior wins, with
+    /// ties going to the first listed, and the confidence is 0.0; the result
+    /// is always one of the candidates.
+    /// With `min_confidence` set, text without evidence and guesses below
+    /// the threshold give `UNDETERMINED` (with the guess's confidence).
+    pub fn pick(&self, scores: Vec<(&'static str, f64)>) -> (&'static str, f64) {
+        let scores = self.apply(scores);
+        let total: f64 = scores.iter().map(|(_, score)| score).sum();
+        if total == 0.0 {
+            if self.min_confidence.is_some() {
+                return (UNDETERMINED, 0.0);
+            }
+            let candidates = self.candidates();
+            let fallback = candidates.iter().fold(candidates[0], |best, &language| if self.prior(language) > self.prior(best) { language } else { best });
+            return (fallback, 0.0);
+        }
+
+        let (language, confidence) = best_of(&scores);
+        match self.min_confidence {
+            Some(threshold) if confidence < threshold => (UNDETERMINED, confidence),
+            _ => (language, confidence),
+        }
+    }
+}
+
+/// Detect language among the languages a deployment supports
+/// Returns language code, always one of the allowed languages or "und"
+///
+/// Scores are the same as `detect_language`, multiplied by each language's
+/// prior weight, and only allowed languages compete. Text without any
+/// evidence for them (including empty text) returns the allowed language
+/// with the highest prior, or the first one listed.
+///
+/// Setting `minConfidence` switches to reporting "und" (undetermined)
+/// instead of guessing: for text without evidence, and whenever the best
+/// language's confidence (its share of the weighted scores) is below the
+/// threshold. Confidences spread over many candidates, so thresholds
+/// around 0.3 to 0.5 suit an unrestricted candidate list.
+///
+/// @param text - Message text
+/// Like `detect_language`, code, URLs, mentions, hashtags and emoji are
+/// removed before scoring unless `keepEntities` is true.
+///
+/// @param options - {languages: ["en", "es"], priors: {"es": 1.5}, minConfidence: 0.4, keepEntities: false}; all optional
+#[wasm_bindgen]
+pub fn detect_language_with_options(text: &str, options: JsValue) -> String {
+    let options = DetectionOptions::from_js(options);
+    String::from(options.pick(Evidence::from_text(&options.prepare(text)).scores()).0)
+}
+
+/// Candidate languages ordered by confidence
+/// Confidence is each language's share of all points, so the values sum to
+/// 1.0. Languages without any points are left out; ties keep `LANGUAGES`
+/// order.
+pub fn ranked_languages(text: &str) -> Vec<(&'static str, f64)> {
+    let scores = language_scores(text);
+    let total: f64 = scores.iter().map(|(_, score)| score).sum();
+    if total == 0.0 {
+        return Vec::new();
+    }
+
+    let mut ranked: Vec<(&'static str, f64)> = scores
+        .into_iter()
+        .filter(|(_, score)| *score > 0.0)
+        .map(|(language, score)| (language, score / total))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+}
+
+/// Incremental language detection for a message being typed
+/// Complete words are folded into the running evidence once, and only the
+/// unfinished last word is rescored on each guess, so a keystroke costs
+/// the same however long the buffer has grown. Entities are removed from
+/// each folded piece, so a fenced code block typed over several lines is
+/// only recognized when its fences arrive in one piece.
+#[wasm_bindgen]
+pub struct LanguageDetector {
+    evidence: Evidence,
+    /// Text after the last whitespace, which may still grow
+    pending: String,
+    options: DetectionOptions,
+}
+
+impl Default for LanguageDetector {
+    fn default() -> Self {
+        LanguageDetector::new()
+    }
+}
+
+#[wasm_bindgen]
+impl LanguageDetector {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> LanguageDetector {
+        LanguageDetector { evidence: Evidence::new(), pending: String::new(), options: DetectionOptions::default() }
+    }
+
+    /// Append the next piece of typed text
+    pub fn feed(&mut self, chunk: &str) {
+        self.pending.push_str(chunk);
+        if let Some((boundary, space)) = self.pending.char_indices().rev().find(|(_, c)| c.is_whitespace()) {
+            let complete: String = self.pending.drain(..boundary + space.len_utf8()).collect();
+            self.evidence.add(&self.options.prepare(&complete));
+        }
+    }
+
+    /// Language of everything fed so far
+    /// Same codes as `detect_language`, including "en" before any evidence.
+    pub fn current_guess(&self) -> String {
+        String::from(self.current().0)
+    }
+
+    /// Confidence of `current_guess` (0.0 to 1.0)
+    pub fn confidence(&self) -> f64 {
+        self.current().1
+    }
+
+    /// Restrict and weight candidate languages, set a minimum confidence or
+    /// keep entities, as in `detect_language_with_options`
+    /// Guesses then fall back to the preferred allowed language (or "und")
+    /// instead of "en". Options apply to text fed afterwards.
+    pub fn set_options(&mut self, options: JsValue) {
+        self.options = DetectionOptions::from_js(options);
+    }
+
+    /// Forget all fed text, keeping the options
+    pub fn reset(&mut self) {
+        self.evidence = Evidence::new();
+        self.pending.clear();
+    }
+}
+
+impl LanguageDetector {
+    fn current(&self) -> (&'static str, f64) {
+        let mut evidence = self.evidence.clone();
+        evidence.add(&self.options.prepare(&self.pending));
+        self.options.pick(evidence.scores())
+    }
+}
+
+/// A candidate language with its confidence
+#[derive(Serialize)]
+struct RankedLanguage {
+    lang: &'static str,
+    confidence: f64,
+}
+
+fn detect_language_ranked_report(text: &str) -> Vec<RankedLanguage> {
+    ranked_languages(text)
+        .into_iter()
+        .map(|(lang, confidence)| RankedLanguage { lang, confidence })
+        .collect()
+}
+
+/// Detect language with a confidence for every candidate
+/// Returns array, most likely first: [{"lang":"es","confidence":0.6},{"lang":"pt","confidence":0.4}]
+///
+/// Confidences are normalized to 0.0–1.0 and sum to 1.0. Only languages
+/// with some evidence are listed, so empty or unrecognized text returns an
+/// empty array; callers can ask the user when the first confidence is low.
+#[wasm_bindgen]
+pub fn detect_language_ranked(text: &str) -> JsValue {
+    to_js(&detect_language_ranked_report(text))
+}
+
+/// `detect_language_ranked` as a JSON string
+/// @deprecated Use `detect_language_ranked`, which returns an object instead of JSON text.
+#[deprecated(note = "use `detect_language_ranked`, which returns an object")]
+#[wasm_bindgen]
+pub fn detect_language_ranked_json(text: &str) -> String {
+    to_json(&detect_language_ranked_report(text), "[]")
+}
+
+/// Detect the language of many messages in one call
+/// Returns array of {lang, confidence}, one per text in input order:
+/// [{"lang":"es","confidence":0.6},{"lang":"en","confidence":0.0}]
+///
+/// Each entry matches `detect_language` for that text, so empty text gives
+/// "en" with confidence 0.0. Loading a history this way crosses the wasm
+/// boundary once instead of per message. Null entries count as empty
+/// text; anything other than an array of strings returns an empty array.
+///
+/// @param texts - Array of message strings
+#[wasm_bindgen]
+pub fn detect_languages(texts: JsValue) -> JsValue {
+    let texts: Vec<Option<String>> = serde_wasm_bindgen::from_value(texts).unwrap_or_default();
+    let results: Vec<RankedLanguage> = texts
+        .iter()
+        .map(|text| {
+            let (lang, confidence) = best_language(text.as_deref().unwrap_or(""));
+            RankedLanguage { lang, confidence }
+        })
+        .collect();
+
+    to_js(&results)
+}
+
+/// One language's score, broken down by source
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CandidateExplanation {
+    lang: &'static str,
+    score: f64,
+    confidence: f64,
+    /// Words of the text found in the language's common-word list
+    words: Vec<String>,
+    word_points: f64,
+    rule_points: f64,
+    /// Points for matching the trigram profile better than the worst match
+    trigram_points: f64,
+}
+
+#[derive(Serialize)]
+struct Explanation {
+    lang: &'static str,
+    confidence: f64,
+    /// Trigrams in the text; fewer than 4 are not scored
+    trigrams: usize,
+    rules: Vec<RuleHit>,
+    candidates: Vec<CandidateExplanation>,
+}
+
+fn explain_detection_report(text: &str) -> Explanation {
+    let text = &strip_entities(text);
+    let evidence = Evidence::from_text(text);
+    let scores = evidence.scores();
+    let (lang, confidence) = best_of(&scores);
+    let total: f64 = scores.iter().map(|(_, score)| score).sum();
+    let rules = evidence.rule_hits();
+    let trigram_points = evidence.trigram_points();
+
+    let text_lower = text.to_lowercase();
+    let scored = scored_words(&text_lower, MAX_SCORED_WORDS);
+    let custom = custom_profiles();
+
+    let mut candidates: Vec<CandidateExplanation> = scores
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, score))| *score > 0.0)
+        .map(|(i, &(language, score))| {
+            let profile = custom.iter().find(|(code, _)| *code == language).map(|(_, profile)| profile.as_ref());
+            let words: Vec<String> = scored
+                .iter()
+                .filter(|word| !word.is_empty() && is_common_word(word, language, profile))
+                .map(|word| word.to_string())
+                .collect();
+            CandidateExplanation {
+                lang: language,
+                score,
+                confidence: score / total,
+                word_points: evidence.common[i],
+                rule_points: rules.iter().filter(|hit| hit.lang == language).fold(0.0, |sum, hit| sum + hit.points),
+                trigram_points: trigram_points[i],
+                words,
+            }
+        })
+        .collect();
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    Explanation { lang, confidence, trigrams: evidence.grams, rules, candidates }
+}
+
+/// Explain how `detect_language` reached its answer
+/// Returns object: {"lang":"fr","confidence":0.5,"trigrams":21,
+/// "rules":[{"rule":"diacritics","lang":"fr","letters":"é","points":3.0}],
+/// "candidates":[{"lang":"fr","score":9.2,"confidence":0.5,"words":["le"],
+/// "wordPoints":2.0,"rulePoints":3.0,"trigramPoints":4.2}]}
+///
+/// `rules` lists the script and letter heuristics that fired, with the
+/// letters that triggered them: devanagariScript, thaiScript, hangulScript,
+/// kanaScript, hanScript, cyrillicLetters, arabicLetters, diacritics and
+/// profileLetters. Each candidate's score is the sum of its word, rule and
+/// trigram points; only candidates with points are listed, highest first.
+/// Only the first 50 words are looked up in the common-word lists.
+///
+/// @param text - Message text
+#[wasm_bindgen]
+pub fn explain_detection(text: &str) -> JsValue {
+    to_js(&explain_detection_report(text))
+}
+
+/// `explain_detection` as a JSON string
+/// @deprecated Use `explain_detection`, which returns an object instead of JSON text.
+#[deprecated(note = "use `explain_detection`, which returns an object")]
+#[wasm_bindgen]
+pub fn explain_detection_json(text: &str) -> String {
+    to_json(&explain_detection_report(text), "{}")
+}
+
+/// Check a lowercased word against a built-in language's stop words
+/// Registered languages have no stop word list (the lexicon would fall
+/// back to English for them).
+fn is_builtin_stopword(word: &str, language: &str) -> bool {
+    LANGUAGES.contains(&language) && is_stopword(word, language)
+}
+
+/// A run of text in one language
+#[derive(Serialize)]
+struct LanguageSpan<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+    lang: &'static str,
+    confidence: f64,
+}
+
+/// Share of a language in the scores of a text (0.0 to 1.0)
+fn language_share(text: &str, language: &str) -> f64 {
+    let scores = language_scores(text);
+    let total: f64 = scores.iter().map(|(_, score)| score).sum();
+    let own = scores.iter().find(|(lang, _)| *lang == language).map_or(0.0, |(_, score)| *score);
+    if total > 0.0 { own / total } else { 0.0 }
+}
+
+/// Cost of switching language between two words in `detect_language_spans`
+/// A word has at most 1.0 of evidence and a span inside a sentence pays
+/// twice, to enter and to leave, so one word only becomes a span of its
+/// own when it is clearly in another language than its neighbours.
+const SWITCH_PENALTY: f64 = 0.25;
+
+/// Chat words that mark their language on their own, enough to open a
+/// span in `detect_language_spans` ("gracias" in an English message); a
+/// word listed for several languages is shared between them
+const CHAT_WORDS: &[(&str, &[&str])] = &[
+    ("en", &[
+        "ok", "okay", "yes", "yeah", "thanks", "thank", "please", "see", "meet", "tomorrow", "today", "tonight", "later",
+        "let's", "what", "how", "why", "where", "when", "sorry", "sure", "great", "cool", "good", "bye", "hello",
+        "morning", "night", "just", "really", "will", "can", "we", "my", "me", "is", "are", "was", "lol",
+    ]),
+    ("es", &[
+        "gracias", "mañana", "hola", "hoy", "adiós", "sí", "bueno", "buena", "muy", "pero", "porque", "qué", "cómo",
+        "dónde", "luego", "hasta", "nos", "vemos", "también", "tengo", "estoy", "vamos", "ahora", "nada", "claro",
+        "perdón", "amigo",
+    ]),
+    ("fr", &[
+        "je", "suis", "très", "aujourd'hui", "merci", "oui", "bonjour", "salut", "demain", "c'est", "mais", "avec",
+        "pourquoi", "comment", "bien", "beaucoup", "fatigué", "toujours", "rien", "vous", "nous", "j'ai",
+    ]),
+    ("de", &[
+        "ich", "bin", "danke", "bitte", "morgen", "heute", "ja", "nein", "nicht", "gut", "sehr", "auch", "aber",
+        "warum", "tschüss", "genau", "schon", "wir", "du",
+    ]),
+    ("it", &["grazie", "ciao", "domani", "oggi", "sì", "molto", "perché", "sono", "bene", "anche", "ancora", "prego", "allora", "adesso"]),
+    ("pt", &["obrigado", "obrigada", "amanhã", "hoje", "sim", "não", "muito", "também", "tudo", "você", "bom", "agora", "então"]),
+];
+
+/// Evidence of a chat word for each language, if it is one
+fn chat_word_evidence(word: &str, languages: &[&'static str]) -> Option<Vec<f64>> {
+    let word = word.to_lowercase().replace('’', "'");
+    let listed: Vec<&str> = CHAT_WORDS.iter().filter(|(_, words)| words.contains(&word.as_str())).map(|(language, _)| *language).collect();
+    if listed.is_empty() {
+        return None;
+    }
+    Some(languages.iter().map(|language| if listed.contains(language) { 1.0 / listed.len() as f64 } else { 0.0 }).collect())
+}
+
+fn detect_language_spans_report<'a>(text: &'a str) -> Vec<LanguageSpan<'a>> {
+    let tokens = words(text);
+    let languages = detectable_languages();
+    let entities = entity_spans(text);
+    let evidence: Vec<Vec<f64>> = tokens
+        .iter()
+        .map(|word| {
+            if entities.iter().any(|span| span.overlaps(&Span { start: word.start, end: word.end })) {
+                return vec![0.0; languages.len()];
+            }
+            if let Some(evidence) = chat_word_evidence(word.text, &languages) {
+                return evidence;
+            }
+            let lower = word.text.to_lowercase();
+            let scores: Vec<f64> = language_scores(word.text)
+                .into_iter()
+                .map(|(language, score)| score + if is_builtin_stopword(&lower, language) { 1.0 } else { 0.0 })
+                .collect();
+            let total: f64 = scores.iter().sum();
+            scores.iter().map(|score| if total > 0.0 { score / total } else { 0.0 }).collect()
+        })
+        .collect();
+    if evidence.iter().flatten().all(|share| *share == 0.0) {
+        return Vec::new();
+    }
+
+    // Best total evidence of a labelling ending in each language, with the
+    // language of the previous word on that labelling
+    let mut best: Vec<f64> = evidence[0].clone();
+    let mut previous: Vec<Vec<usize>> = vec![(0..languages.len()).collect()];
+    for shares in &evidence[1..] {
+        let leader = (0..best.len()).fold(0, |top, i| if best[i] > best[top] { i } else { top });
+        let mut next = Vec::with_capacity(best.len());
+        let mut from = Vec::with_capacity(best.len());
+        for (i, share) in shares.iter().enumerate() {
+            let (origin, total) = if best[i] >= best[leader] - SWITCH_PENALTY { (i, best[i]) } else { (leader, best[leader] - SWITCH_PENALTY) };
+            next.push(total + share);
+            from.push(origin);
+        }
+        best = next;
+        previous.push(from);
+    }
+
+    let mut label = (0..best.len()).fold(0, |top, i| if best[i] > best[top] { i } else { top });
+    let mut labels = vec![0; tokens.len()];
+    for i in (0..tokens.len()).rev() {
+        labels[i] = label;
+        label = previous[i][label];
+    }
+
+    let mut runs: Vec<(usize, usize, &'static str)> = Vec::new();
+    for (word, label) in tokens.iter().zip(labels) {
+        match runs.last_mut() {
+            Some(run) if run.2 == languages[label] => run.1 = word.end,
+            _ => runs.push((word.start, word.end, languages[label])),
+        }
+    }
+
+    runs
+        .into_iter()
+        .map(|(start, end, lang)| LanguageSpan { text: &text[start..end], start, end, lang, confidence: language_share(&text[start..end], lang) })
+        .collect()
+}
+
+/// Split a code-switching message into single-language spans
+/// Returns array: [{"text":"gracias","start":3,"end":10,"lang":"es","confidence":0.7}]
+///
+/// Every word gets each language's share of its own scores (plus a point
+/// for each stop word list it is on) as evidence; common chat words that
+/// mark a language on their own ("gracias", "mañana", "see", "merci") give
+/// all of theirs to it, so a single one can open a span. The labelling
+/// with the most total evidence wins, where each change of language costs
+/// `SWITCH_PENALTY`. Words without any evidence (names, numbers, and words
+/// of code, URLs, mentions and hashtags) therefore join a neighbouring
+/// span, and short ambiguous words ("a", "la") follow their context.
+/// Spans run from the first word's start to the last word's end (byte
+/// offsets); the confidence is the language's share of the scores of the
+/// whole span. Text without any evidence returns an empty array.
+#[wasm_bindgen]
+pub fn detect_language_spans(text: &str) -> JsValue {
+    to_js(&detect_language_spans_report(text))
+}
+
+/// `detect_language_spans` as a JSON string
+/// @deprecated Use `detect_language_spans`, which returns an object instead of JSON text.
+#[deprecated(note = "use `detect_language_spans`, which returns an object")]
+#[wasm_bindgen]
+pub fn detect_language_spans_json(text: &str) -> String {
+    to_json(&detect_language_spans_report(text), "[]")
+}
+
+/// Language of one block of text
+#[derive(Serialize)]
+struct BlockLanguage<'a> {
+    text: &'a str,
+    language: &'static str,
+    confidence: f64,
+}
+
+fn detect_language_per_paragraph_report<'a>(text: &'a str) -> Vec<BlockLanguage<'a>> {
+    paragraphs(text)
+        .into_iter()
+        .map(|paragraph| {
+            let (language, confidence) = best_language(paragraph.text);
+            BlockLanguage { text: paragraph.text, language, confidence }
+        })
+        .collect()
+}
+
+/// Detect the primary language of each paragraph
+/// Returns array: [{"text":"Hello there","language":"en","confidence":0.8}]
+///
+/// Paragraphs are separated by blank lines. Input without blank lines
+/// returns a single entry; empty input returns an empty array.
+#[wasm_bindgen]
+pub fn detect_language_per_paragraph(text: &str) -> JsValue {
+    to_js(&detect_language_per_paragraph_report(text))
+}
+
+/// `detect_language_per_paragraph` as a JSON string
+/// @deprecated Use `detect_language_per_paragraph`, which returns an object instead of JSON text.
+#[deprecated(note = "use `detect_language_per_paragraph`, which returns an object")]
+#[wasm_bindgen]
+pub fn detect_language_per_paragraph_json(text: &str) -> String {
+    to_json(&detect_language_per_paragraph_report(text), "[]")
+}
+
+/// Best language for a short phrase, or `None` without any evidence
+/// Single words rarely hit the common-word lists, so stopwords, greetings
+/// and sign-offs count as evidence too; a whole-phrase greeting or sign-off
+/// outweighs the trigram profiles.
+pub fn phrase_language(text: &str) -> Option<&'static str> {
+    let mut scores = language_scores(text);
+    let lowered: Vec<String> = words(text).iter().map(|word| word.text.to_lowercase()).collect();
+    let phrase = lowered.join(" ");
+
+    for (language, score) in scores.iter_mut() {
+        if !LANGUAGES.contains(language) {
+            continue;
+        }
+        let in_list = |list: &[&str]| lowered.iter().any(|word| list.contains(&word.as_str()));
+        if in_list(stopwords(language)) {
+            *score += 1.0;
+        }
+        if greetings(language).contains(&phrase.as_str()) || signoffs(language).contains(&phrase.as_str()) {
+            *score += 5.0;
+        }
+    }
+
+    scores
+        .iter()
+        .fold(None, |best: Option<(&'static str, f64)>, &(language, score)| match best {
+            Some((_, top)) if top >= score => best,
+            _ if score > 0.0 => Some((language, score)),
+            _ => best,
+        })
+        .map(|(language, _)| language)
+}
+
+/// A word followed by its translation in parentheses
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Gloss<'a> {
+    term: &'a str,
+    gloss: &'a str,
+    term_language: &'static str,
+    gloss_language: &'static str,
+    span: Span,
+}
+
+/// Offset of the ')' matching the '(' at `open`, on the same line
+fn matching_paren(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 1 => return Some(open + i),
+            ')' => depth -= 1,
+            '\n' => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn extract_glosses_report<'a>(text: &'a str) -> Vec<Gloss<'a>> {
+    let excluded: Vec<Span> = code_spans(text).into_iter().chain(url_spans(text)).collect();
+    let tokens = words(text);
+    let mut glosses = Vec::new();
+
+    for (open, _) in text.match_indices('(') {
+        if excluded.iter().any(|span| span.start <= open && open < span.end) {
+            continue;
+        }
+        let Some(term) = tokens.iter().rev().find(|word| word.end <= open) else {
+            continue;
+        };
+        let between = &text[term.end..open];
+        if between.len() > 1 || !between.chars().all(|c| c == ' ') {
+            continue;
+        }
+        let Some(close) = matching_paren(text, open) else {
+            continue;
+        };
+        let gloss = text[open + 1..close].trim();
+        if !gloss.chars().any(char::is_alphabetic) {
+            continue;
+        }
+
+        let (Some(term_language), Some(gloss_language)) = (phrase_language(term.text), phrase_language(gloss)) else {
+            continue;
+        };
+        if term_language != gloss_language {
+            glosses.push(Gloss {
+                term: term.text,
+                gloss,
+                term_language,
+                gloss_language,
+                span: Span { start: term.start, end: close + 1 },
+            });
+        }
+    }
+
+    glosses
+}
+
+/// Extract inline translations such as "hola (hello)"
+/// Returns array: [{"term":"hola","gloss":"hello","termLanguage":"es","glossLanguage":"en","span":{"start":0,"end":12}}]
+///
+/// A gloss is a parenthetical directly after a word (at most one space in
+/// between) whose content is detected as a different language than the
+/// word. Same-language parentheticals are clarifications and are skipped,
+/// as are pairs where either side gives no language evidence, and
+/// parentheses inside code or URLs.
+#[wasm_bindgen]
+pub fn extract_glosses(text: &str) -> JsValue {
+    to_js(&extract_glosses_report(text))
+}
+
+/// `extract_glosses` as a JSON string
+/// @deprecated Use `extract_glosses`, which returns an object instead of JSON text.
+#[deprecated(note = "use `extract_glosses`, which returns an object")]
+#[wasm_bindgen]
+pub fn extract_glosses_json(text: &str) -> String {
+    to_json(&extract_glosses_report(text), "[]")
+}
+
+/// Sentences shorter than this many words may take the message's language
+const SHORT_SENTENCE_WORDS: usize = 3;
+
+/// Language of one sentence
+#[derive(Serialize)]
+struct SentenceLanguage<'a> {
+    sentence: &'a str,
+    start: usize,
+    end: usize,
+    lang: &'static str,
+    confidence: f64,
+    /// The language was taken from the whole message
+    fallback: bool,
+}
+
+fn detect_language_per_sentence_report<'a>(text: &'a str, fallback_to_message: bool) -> Vec<SentenceLanguage<'a>> {
+    let message = best_language(text);
+
+    sentences(text)
+        .into_iter()
+        .map(|sentence| {
+            let (language, confidence) = best_language(sentence.text);
+            let short = words(sentence.text).len() < SHORT_SENTENCE_WORDS || confidence == 0.0;
+            let fallback = fallback_to_message && short && message.1 > 0.0;
+            let (lang, confidence) = if fallback { message } else { (language, confidence) };
+            SentenceLanguage { sentence: sentence.text, start: sentence.start, end: sentence.end, lang, confidence, fallback }
+        })
+        .collect()
+}
+
+/// Detect the language of each sentence
+/// Returns array: [{"sentence":"Hola.","start":0,"end":5,"lang":"es","confidence":1.0,"fallback":false}]
+///
+/// Sentences come from the shared sentence splitter (abbreviations and
+/// decimals do not split), with byte offsets into `text`. Each sentence
+/// gets its own best guess, which for very short sentences is often weak.
+/// With `fallback_to_message`, sentences of fewer than three words, or
+/// without any language evidence, take the language of the whole message
+/// instead; their confidence is then the message's and `fallback` is true.
+///
+/// @param text - Message text
+/// @param fallback_to_message - Use the message language for short sentences (off when omitted)
+#[wasm_bindgen]
+pub fn detect_language_per_sentence(text: &str, fallback_to_message: bool) -> JsValue {
+    to_js(&detect_language_per_sentence_report(text, fallback_to_message))
+}
+
+/// `detect_language_per_sentence` as a JSON string
+/// @deprecated Use `detect_language_per_sentence`, which returns an object instead of JSON text.
+#[deprecated(note = "use `detect_language_per_sentence`, which returns an object")]
+#[wasm_bindgen]
+pub fn detect_language_per_sentence_json(text: &str, fallback_to_message: bool) -> String {
+    to_json(&detect_language_per_sentence_report(text, fallback_to_message), "[]")
+}
+
+/// Longest side of a bilingual pair, in words
+const PAIR_MAX_WORDS: usize = 4;
+
+/// A vocabulary pair with the language of each side
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BilingualPair<'a> {
+    source: &'a str,
+    target: &'a str,
+    source_language: &'static str,
+    target_language: &'static str,
+}
+
+/// Check whether a pair side is a short phrase rather than prose
+fn is_pair_side(side: &str) -> bool {
+    let count = words(side).len();
+    count > 0 && count <= PAIR_MAX_WORDS && !side.ends_with(['.', '!', '?'])
+}
+
+fn parse_bilingual_pairs_report<'a>(text: &'a str) -> Vec<BilingualPair<'a>> {
+    let mut candidates: Vec<(&str, &str)> = Vec::new();
+    for line in text.lines() {
+        let parts: Vec<&str> = line.split([',', ';']).collect();
+        let entries = if parts.iter().all(|part| split_key_value(part).is_some()) { parts } else { vec![line] };
+        candidates.extend(
+            entries
+                .into_iter()
+                .filter_map(split_key_value)
+                .filter(|(source, target)| is_pair_side(source) && is_pair_side(target)),
+        );
+    }
+
+    let (sources, targets): (Vec<&str>, Vec<&str>) = candidates.iter().copied().unzip();
+    let source_column = phrase_language(&sources.join(" "));
+    let target_column = phrase_language(&targets.join(" "));
+
+    candidates
+        .iter()
+        .filter_map(|&(source, target)| {
+            let source_language = phrase_language(source).or(source_column)?;
+            let target_language = phrase_language(target).or(target_column)?;
+            (source_language != target_language).then_some(BilingualPair { source, target, source_language, target_language })
+        })
+        .collect()
+}
+
+/// Parse vocabulary pairs from bilingual notes ("dog = Hund, cat = Katze")
+/// Returns array: [{"source":"dog","target":"Hund","sourceLanguage":"en","targetLanguage":"de"}]
+///
+/// Lines are split into entries at ',' and ';' when every part contains a
+/// delimiter, and each entry at its first '=', ':', tab or spaced " - ".
+/// An entry is a pair when both sides are short phrases (up to four words,
+/// no closing sentence punctuation) in different languages. A side without
+/// language evidence of its own takes the language of its whole column,
+/// detected from all left (or right) sides together; pairs whose languages
+/// are still unknown or equal are skipped, which filters out prose like
+/// "Note: this is fine".
+#[wasm_bindgen]
+pub fn parse_bilingual_pairs(text: &str) -> JsValue {
+    to_js(&parse_bilingual_pairs_report(text))
+}
+
+/// `parse_bilingual_pairs` as a JSON string
+/// @deprecated Use `parse_bilingual_pairs`, which returns an object instead of JSON text.
+#[deprecated(note = "use `parse_bilingual_pairs`, which returns an object")]
+#[wasm_bindgen]
+pub fn parse_bilingual_pairs_json(text: &str) -> String {
+    to_json(&parse_bilingual_pairs_report(text), "[]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trigrams_recognize_unlisted_words() {
+        let samples = [
+            ("Schmetterlinge fliegen", "de"),
+            ("Programmazione funzionale", "it"),
+            ("Développement durable", "fr"),
+            ("Informação importante", "pt"),
+            ("thanks everyone for coming tonight", "en"),
+            ("Muchas gracias por todo", "es"),
+        ];
+        for (text, language) in samples {
+            assert_eq!(detect_language(text), language, "{text}");
+        }
+    }
+
+    #[test]
+    fn short_cyrillic_chat_lines() {
+        let samples = [
+            ("Привет, как дела?", "ru"),
+            ("Как тебя зовут?", "ru"),
+            ("Спасибо, всё хорошо", "ru"),
+            ("Какво правиш?", "bg"),
+            ("Как си?", "bg"),
+            ("Аз съм добре", "bg"),
+            ("Привіт, як справи?", "uk"),
+            ("Дякую, все добре", "uk"),
+            ("Како си?", "sr"),
+            ("Где си?", "sr"),
+            ("Хвала, добро сам", "sr"),
+        ];
+        for (text, language) in samples {
+            assert_eq!(detect_language(text), language, "{text}");
+        }
+    }
+
+    #[test]
+    fn texts_without_evidence_fall_back_to_english() {
+        assert_eq!(best_language("ok"), ("en", 0.0));
+        assert!(ranked_languages("ok").is_empty());
+        assert_eq!(detect_language("   "), "en");
+    }
+
+    #[test]
+    fn scores_keep_language_order() {
+        let scores = language_scores("Gestern habe ich eine Wohnung gefunden");
+        assert_eq!(scores.iter().map(|(language, _)| *language).collect::<Vec<_>>(), LANGUAGES);
+        let ranked = ranked_languages("Gestern habe ich eine Wohnung gefunden");
+        assert_eq!(ranked[0].0, "de");
+        assert!((ranked.iter().map(|(_, confidence)| confidence).sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    fn spans(text: &str) -> Vec<(&'static str, usize, usize)> {
+        detect_language_spans_report(text).iter().map(|span| (span.lang, span.start, span.end)).collect()
+    }
+
+    #[test]
+    fn spans_of_a_code_switching_message() {
+        assert_eq!(spans("ok gracias, see you mañana"), [("en", 0, 2), ("es", 3, 10), ("en", 12, 19), ("es", 20, 27)]);
+    }
+
+    #[test]
+    fn spans_switch_between_sentences() {
+        let text = "Let's meet tomorrow. Je suis très fatigué aujourd'hui.";
+        assert_eq!(spans(text), [("en", 0, 19), ("fr", 21, 55)]);
+    }
+
+    #[test]
+    fn single_language_text_is_one_span() {
+        let text = "I really love this restaurant, the food is great and the service was friendly";
+        assert_eq!(spans(text), [("en", 0, text.len())]);
+        assert!(spans("12 34").is_empty());
+    }
+}
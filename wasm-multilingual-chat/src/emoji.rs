@@ -0,0 +1,120 @@
+//! Emoji and emoticon helpers
+
+use wasm_bindgen::prelude::*;
+use crate::entities::{code_spans, url_spans, Span};
+
+/// ASCII emoticons and their emoji
+/// Ambiguous forms that also occur in plain text ("8)", ":3", "o_O") are
+/// deliberately left out.
+const EMOTICONS: &[(&str, &str)] = &[
+    (":-)", "🙂"), (":)", "🙂"), ("=)", "🙂"), ("(:", "🙂"),
+    (":-D", "😀"), (":D", "😀"), ("=D", "😀"), ("xD", "😆"), ("XD", "😆"),
+    (";-)", "😉"), (";)", "😉"),
+    (":-(", "🙁"), (":(", "🙁"), ("=(", "🙁"),
+    (":'-(", "😢"), (":'(", "😢"), ("T_T", "😭"),
+    (">:-(", "😠"), (">:(", "😠"),
+    (":-P", "😛"), (":P", "😛"), (":-p", "😛"), (":p", "😛"), (";-P", "😜"), (";P", "😜"), (";p", "😜"),
+    (":-O", "😮"), (":O", "😮"), (":-o", "😮"), (":o", "😮"),
+    (":-|", "😐"), (":|", "😐"), ("-_-", "😑"),
+    (":-/", "😕"), (":/", "😕"),
+    (":-*", "😘"), (":*", "😘"),
+    ("B-)", "😎"), ("8-)", "😎"),
+    ("^_^", "😊"), ("^^", "😊"),
+    ("</3", "💔"), ("<3", "❤️"),
+];
+
+/// Emoji and the characters that build emoji sequences (zero width
+/// joiner, variation selectors, keycaps, skin tones, flags and tags)
+pub fn is_emoji(c: char) -> bool {
+    matches!(c,
+        '\u{1F000}'..='\u{1FAFF}' // Pictographs, emoticons, flags and skin tones
+        | '\u{2300}'..='\u{23FF}' | '\u{2600}'..='\u{27BF}' | '\u{2B00}'..='\u{2BFF}' // Symbols and dingbats
+        | '\u{200D}' | '\u{20E3}' | '\u{FE0E}'..='\u{FE0F}'
+        | '\u{3030}' | '\u{303D}' | '\u{3297}' | '\u{3299}'
+        | '\u{E0020}'..='\u{E007F}'
+    )
+}
+
+/// Characters that may directly follow an emoticon
+fn ends_token(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '.' | ',' | '!' | '?' | ';')
+}
+
+/// Emoticons starting `text`, with their emoji, when they form a whole
+/// token on their own or written back to back (":D:D")
+/// Longer emoticons are tried first.
+fn match_emoticons(text: &str) -> Option<Vec<(&'static str, &'static str)>> {
+    let mut candidates: Vec<&(&str, &str)> = EMOTICONS.iter().filter(|(emoticon, _)| text.starts_with(emoticon)).collect();
+    candidates.sort_by_key(|(emoticon, _)| std::cmp::Reverse(emoticon.len()));
+    candidates.into_iter().find_map(|&(emoticon, emoji)| {
+        let rest = &text[emoticon.len()..];
+        // ';' may end a token or start ";)"
+        if let Some(mut following) = match_emoticons(rest) {
+            following.insert(0, (emoticon, emoji));
+            return Some(following);
+        }
+        rest.chars().next().is_none_or(ends_token).then(|| vec![(emoticon, emoji)])
+    })
+}
+
+/// Replace ASCII emoticons with emoji
+///
+/// An emoticon is only replaced when it forms a whole token: at the start
+/// of the text or after whitespace, and before whitespace, the end of the
+/// text or sentence punctuation (":)." or ":D!"). Emoticons written back
+/// to back count as one token (":):)" → "🙂🙂", ":D:D"). The longest match
+/// wins, so ":-)" is not read as ":-" plus ")". Unknown or extended forms
+/// (":)))", "8)", "route 8)") are left untouched, as are code and URLs.
+#[wasm_bindgen]
+pub fn emoticons_to_emoji(text: &str) -> String {
+    let excluded: Vec<Span> = code_spans(text).into_iter().chain(url_spans(text)).collect();
+    let mut result = String::with_capacity(text.len());
+    let mut at_boundary = true;
+    let mut skip_until = 0;
+
+    for (i, c) in text.char_indices() {
+        if i < skip_until {
+            continue;
+        }
+
+        let in_excluded = excluded.iter().any(|span| span.start <= i && i < span.end);
+        if at_boundary && !in_excluded {
+            if let Some(emoticons) = match_emoticons(&text[i..]) {
+                skip_until = i;
+                for (emoticon, emoji) in emoticons {
+                    result.push_str(emoji);
+                    skip_until += emoticon.len();
+                }
+                at_boundary = false;
+                continue;
+            }
+        }
+
+        result.push(c);
+        at_boundary = c.is_whitespace();
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjacent_emoticons() {
+        assert_eq!(emoticons_to_emoji(":):)"), "🙂🙂");
+        assert_eq!(emoticons_to_emoji("ok :D:D!"), "ok 😀😀!");
+        assert_eq!(emoticons_to_emoji(":-);)<3"), "🙂😉❤️");
+        assert_eq!(emoticons_to_emoji("^^^^ yay"), "😊😊 yay");
+    }
+
+    #[test]
+    fn emoticons_must_be_whole_tokens() {
+        assert_eq!(emoticons_to_emoji(":)))"), ":)))");
+        assert_eq!(emoticons_to_emoji(":):"), ":):");
+        assert_eq!(emoticons_to_emoji("a:)"), "a:)");
+        assert_eq!(emoticons_to_emoji(":D:Dx"), ":D:Dx");
+        assert_eq!(emoticons_to_emoji("see `:):)`"), "see `:):)`");
+    }
+}
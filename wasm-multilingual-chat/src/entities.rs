@@ -0,0 +1,632 @@
+//! Detection of inline chat entities (URLs, code, mentions, email
+//! addresses, phone numbers)
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use crate::unicode::{normalize_form, Form};
+use serde::Serialize;
+
+/// Byte range of an entity in the source text
+#[derive(Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn overlaps(&self, other: &Span) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+/// A fenced code block
+pub struct FencedBlock<'a> {
+    /// Whole block, both fences included
+    pub span: Span,
+    /// Text after the opening fence ("python" in ```python)
+    pub info: &'a str,
+    /// Lines between the fences
+    pub code: &'a str,
+    /// False when the text ends before a closing fence
+    pub closed: bool,
+}
+
+/// Parse a fence line: up to three spaces, then three or more '`' or '~'
+/// Returns the fence character, its length and the info string.
+fn parse_fence(line: &str) -> Option<(char, usize, &str)> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    let rest = &line[indent..];
+    let fence = rest.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let length = rest.chars().take_while(|c| *c == fence).count();
+    let info = rest[length..].trim();
+    // A backtick fence's info string may not contain backticks (that is inline code)
+    if indent > 3 || length < 3 || (fence == '`' && info.contains('`')) {
+        return None;
+    }
+    Some((fence, length, info))
+}
+
+/// Find fenced code blocks delimited by ``` or ~~~ lines
+/// A block closes at a line of the same fence character, at least as long
+/// as the opening fence, with nothing else on it. An unclosed block runs to
+/// the end of the text.
+pub fn fenced_blocks(text: &str) -> Vec<FencedBlock<'_>> {
+    let mut blocks = Vec::new();
+    let mut open: Option<(usize, usize, char, usize, &str)> = None;
+    let mut line_start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        let fence = parse_fence(line.trim_end_matches(['\n', '\r']));
+        match (open, fence) {
+            (Some((start, code_start, fence, length, info)), Some((closing, closing_length, ""))) if closing == fence && closing_length >= length => {
+                blocks.push(FencedBlock {
+                    span: Span { start, end: line_end },
+                    info,
+                    code: &text[code_start..line_start],
+                    closed: true,
+                });
+                open = None;
+            }
+            (None, Some((fence, length, info))) => open = Some((line_start, line_end, fence, length, info)),
+            _ => {}
+        }
+        line_start = line_end;
+    }
+
+    if let Some((start, code_start, _, _, info)) = open {
+        blocks.push(FencedBlock {
+            span: Span { start, end: text.len() },
+            info,
+            code: &text[code_start.min(text.len())..],
+            closed: false,
+        });
+    }
+
+    blocks
+}
+
+/// Spans of the fenced code blocks (see `fenced_blocks`)
+pub fn fenced_code_spans(text: &str) -> Vec<Span> {
+    fenced_blocks(text).into_iter().map(|block| block.span).collect()
+}
+
+/// Find inline `code` spans outside of fenced blocks
+/// Inline code never crosses a line break; a lone backtick is left alone.
+fn inline_code_spans(text: &str, fenced: &[Span]) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut open: Option<usize> = None;
+
+    for (i, c) in text.char_indices() {
+        if fenced.iter().any(|span| span.start <= i && i < span.end) {
+            open = None;
+            continue;
+        }
+        match c {
+            '`' => match open.take() {
+                Some(start) => spans.push(Span { start, end: i + 1 }),
+                None => open = Some(i),
+            },
+            '\n' => open = None,
+            _ => {}
+        }
+    }
+
+    spans
+}
+
+/// Find all code spans (fenced blocks and inline code), in text order
+pub fn code_spans(text: &str) -> Vec<Span> {
+    let fenced = fenced_code_spans(text);
+    let mut spans = inline_code_spans(text, &fenced);
+    spans.extend(fenced);
+    spans.sort_by_key(|span| span.start);
+    spans
+}
+
+/// Find URLs starting with http://, https:// or www.
+/// Trailing sentence punctuation is not part of the URL, and a closing
+/// parenthesis is only kept when the URL contains a matching opening one.
+pub fn url_spans(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut offset = 0;
+
+    for token in text.split_inclusive(char::is_whitespace) {
+        let word = token.trim_end();
+        let lower = word.to_ascii_lowercase();
+        let prefix = ["https://", "http://", "www."]
+            .iter()
+            .filter_map(|p| lower.find(p).map(|at| (at, p.len())))
+            .min_by_key(|(at, _)| *at);
+
+        if let Some((at, prefix_len)) = prefix {
+            let url = trim_url(&word[at..]);
+            if url.len() > prefix_len {
+                spans.push(Span { start: offset + at, end: offset + at + url.len() });
+            }
+        }
+        offset += token.len();
+    }
+
+    spans
+}
+
+fn trim_url(url: &str) -> &str {
+    let mut url = url;
+    loop {
+        let Some(last) = url.chars().last() else {
+            return url;
+        };
+        let unbalanced_paren = last == ')' && url.matches('(').count() < url.matches(')').count();
+        if matches!(last, '.' | ',' | '!' | '?' | ';' | ':' | '\'' | '"' | '>' | ']') || unbalanced_paren {
+            url = &url[..url.len() - last.len_utf8()];
+        } else {
+            return url;
+        }
+    }
+}
+
+/// A parsed @mention
+#[derive(Serialize)]
+pub struct Mention {
+    /// "simple" for `@username`, "rich" for `@[Display Name](id)`
+    pub kind: &'static str,
+    /// Username or unescaped display name
+    pub name: String,
+    /// Target id of a rich mention
+    pub id: Option<String>,
+    pub span: Span,
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '.' | '-')
+}
+
+/// Parse `[Display Name](id)` starting at `start` (the '[' after '@')
+/// Display names may contain spaces, balanced brackets and `\]` / `\[` escapes.
+/// Returns the unescaped name, the id and the end offset.
+fn parse_rich_mention(text: &str, start: usize) -> Option<(String, String, usize)> {
+    let mut name = String::new();
+    let mut depth = 0;
+    let mut chars = text[start + 1..].char_indices();
+    let mut name_end = None;
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                let (_, escaped) = chars.next()?;
+                name.push(escaped);
+            }
+            '[' => {
+                depth += 1;
+                name.push(c);
+            }
+            ']' if depth > 0 => {
+                depth -= 1;
+                name.push(c);
+            }
+            ']' => {
+                name_end = Some(start + 1 + i + 1);
+                break;
+            }
+            '\n' => return None,
+            _ => name.push(c),
+        }
+    }
+
+    let name_end = name_end?;
+    let rest = text[name_end..].strip_prefix('(')?;
+    let id_len = rest.find(')')?;
+    let id = &rest[..id_len];
+    if name.trim().is_empty() || id.is_empty() || id.contains(|c: char| c.is_whitespace() || c == '(') {
+        return None;
+    }
+
+    Some((name.trim().to_string(), id.to_string(), name_end + 1 + id_len + 1))
+}
+
+/// Find all well-formed mentions, in text order
+/// `@` only starts a mention at the beginning of the text or after a
+/// non-word character, so email addresses are not mistaken for mentions.
+pub fn mentions(text: &str) -> Vec<Mention> {
+    let mut found = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(found_at) = text[search_from..].find('@') {
+        let at = search_from + found_at;
+        search_from = at + 1;
+
+        let preceded_by_word = text[..at].chars().last().is_some_and(is_username_char);
+        if preceded_by_word {
+            continue;
+        }
+
+        let after = &text[at + 1..];
+        if after.starts_with('[') {
+            if let Some((name, id, end)) = parse_rich_mention(text, at + 1) {
+                found.push(Mention { kind: "rich", name, id: Some(id), span: Span { start: at, end } });
+                search_from = end;
+            }
+            continue;
+        }
+
+        let name_len: usize = after
+            .chars()
+            .take_while(|c| is_username_char(*c))
+            .map(char::len_utf8)
+            .sum();
+        let name = after[..name_len].trim_end_matches(['.', '-']);
+        if !name.is_empty() {
+            let end = at + 1 + name.len();
+            found.push(Mention { kind: "simple", name: name.to_string(), id: None, span: Span { start: at, end } });
+            search_from = end;
+        }
+    }
+
+    found
+}
+
+/// Find #hashtags, in text order
+/// Like mentions, `#` only starts a hashtag at the beginning of the text or
+/// after a non-word character, and the tag runs over letters, digits and
+/// underscores; tags without a letter ("#1") are not hashtags.
+pub fn hashtag_spans(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    for (at, _) in text.match_indices('#') {
+        if text[..at].chars().last().is_some_and(is_username_char) {
+            continue;
+        }
+        let tag_len: usize = text[at + 1..]
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .map(char::len_utf8)
+            .sum();
+        if text[at + 1..at + 1 + tag_len].chars().any(char::is_alphabetic) {
+            spans.push(Span { start: at, end: at + 1 + tag_len });
+        }
+    }
+    spans
+}
+
+fn is_email_local_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-')
+}
+
+fn is_domain_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '-')
+}
+
+/// Find email addresses: a local part, '@' and a domain with a dot
+/// The last label of the domain needs two letters or more ("a@b.io", not
+/// "a@b.1"), and dots at either end are sentence punctuation.
+pub fn email_spans(text: &str) -> Vec<Span> {
+    let mut spans: Vec<Span> = Vec::new();
+    for (at, _) in text.match_indices('@') {
+        if spans.last().is_some_and(|last| last.end > at) {
+            continue;
+        }
+        let local_len: usize = text[..at].chars().rev().take_while(|c| is_email_local_char(*c)).map(char::len_utf8).sum();
+        let local = text[at - local_len..at].trim_start_matches('.');
+        let domain_len: usize = text[at + 1..].chars().take_while(|c| is_domain_char(*c)).map(char::len_utf8).sum();
+        let domain = text[at + 1..at + 1 + domain_len].trim_end_matches(['.', '-']);
+        let labels: Vec<&str> = domain.split('.').collect();
+        let valid_domain = labels.len() > 1
+            && labels.iter().all(|label| !label.is_empty() && !label.starts_with('-') && !label.ends_with('-'))
+            && labels.last().is_some_and(|tld| tld.chars().count() > 1 && tld.chars().all(char::is_alphabetic));
+        if !local.is_empty() && valid_domain {
+            spans.push(Span { start: at - local.len(), end: at + 1 + domain.len() });
+        }
+    }
+    spans
+}
+
+/// Find phone numbers: 7 to 15 digits, with an optional leading '+'
+/// Digits may be grouped by spaces, '-', '.' and parentheses. Without a
+/// '+', a number needs a separator or parentheses, and dates ("2024-01-15")
+/// numbers grouped by thousands ("1 234 567") and IPv4 addresses are not
+/// phone numbers.
+pub fn phone_spans(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        let previous = i.checked_sub(1).map(|p| chars[p].1);
+        let boundary = !previous.is_some_and(|p| p.is_alphanumeric() || matches!(p, '+' | '-' | '.' | '/' | '_'));
+        if !(boundary && (c.is_ascii_digit() || matches!(c, '+' | '('))) {
+            i += 1;
+            continue;
+        }
+
+        // Digits and separators, with single spaces between groups
+        let mut j = i + 1;
+        while let Some(&(_, c)) = chars.get(j) {
+            let spaced = c == ' ' && chars.get(j + 1).is_some_and(|(_, n)| n.is_ascii_digit() || *n == '(');
+            if c.is_ascii_digit() || matches!(c, '-' | '.' | '(' | ')') || spaced {
+                j += 1;
+            } else {
+                break;
+            }
+        }
+        while j > i && !(chars[j - 1].1.is_ascii_digit() || (chars[j - 1].1 == ')' && chars[i..j].iter().any(|(_, c)| *c == '('))) {
+            j -= 1;
+        }
+        let end = chars.get(j).map_or(text.len(), |(offset, _)| *offset);
+        let candidate = &text[start..end];
+        let glued = chars.get(j).is_some_and(|(_, c)| c.is_alphanumeric());
+        if !glued && candidate.len() > 1 && is_phone_number(candidate) {
+            spans.push(Span { start, end });
+        }
+        i = j.max(i + 1);
+    }
+
+    spans
+}
+
+fn is_phone_number(candidate: &str) -> bool {
+    let digits = candidate.chars().filter(char::is_ascii_digit).count();
+    let opened = candidate.matches('(').count();
+    if !(7..=15).contains(&digits) || opened != candidate.matches(')').count() || opened > 1 {
+        return false;
+    }
+    if let Some(rest) = candidate.strip_prefix('+') {
+        return !rest.contains('+');
+    }
+    if candidate.contains('+') {
+        return false;
+    }
+    let groups: Vec<usize> = candidate.split(|c: char| !c.is_ascii_digit()).filter(|group| !group.is_empty()).map(str::len).collect();
+    let date = matches!(groups.as_slice(), [4, 2, 2] | [2, 2, 4] | [1 | 2, 1 | 2, 4]) && !candidate.contains(' ');
+    let thousands = groups.len() > 1 && groups[0] <= 3 && groups[1..].iter().all(|len| *len == 3) && !candidate.contains(['(', '-']);
+    let address = groups.len() == 4 && groups.iter().all(|len| *len <= 3) && candidate.chars().all(|c| c.is_ascii_digit() || c == '.');
+    groups.len() > 1 && !date && !thousands && !address
+}
+
+fn parse_mentions_report(text: &str) -> Vec<Mention> {
+    mentions(text)
+}
+
+/// Parse @mentions in both `@username` and `@[Display Name](id)` forms
+/// Returns array: [{"kind":"rich","name":"Jane Doe","id":"u42","span":{"start":0,"end":16}}]
+///
+/// Malformed rich mentions (missing id, unbalanced brackets) are not
+/// reported and stay literal text.
+#[wasm_bindgen]
+pub fn parse_mentions(text: &str) -> JsValue {
+    to_js(&parse_mentions_report(text))
+}
+
+/// `parse_mentions` as a JSON string
+/// @deprecated Use `parse_mentions`, which returns an object instead of JSON text.
+#[deprecated(note = "use `parse_mentions`, which returns an object")]
+#[wasm_bindgen]
+pub fn parse_mentions_json(text: &str) -> String {
+    to_json(&parse_mentions_report(text), "[]")
+}
+
+/// Replace rich mentions with plain `@Display Name` for notifications
+/// Simple mentions and malformed mention syntax are left unchanged.
+#[wasm_bindgen]
+pub fn render_mentions(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last_end = 0;
+
+    for mention in mentions(text).into_iter().filter(|m| m.kind == "rich") {
+        result.push_str(&text[last_end..mention.span.start]);
+        result.push('@');
+        result.push_str(&mention.name);
+        last_end = mention.span.end;
+    }
+
+    result.push_str(&text[last_end..]);
+    result
+}
+
+/// An entity found by `extract_entities`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entity<'a> {
+    /// "mention", "hashtag", "url", "email" or "phone"
+    pub kind: &'static str,
+    pub text: &'a str,
+    /// Normalized form for linking and comparing
+    pub value: String,
+    pub start: usize,
+    pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+}
+
+/// Add "https://" to "www." links and lowercase the scheme and host
+fn normalize_url(url: &str) -> String {
+    let url = if url.to_ascii_lowercase().starts_with("www.") { format!("https://{url}") } else { url.to_string() };
+    let host_start = url.find("://").map_or(0, |at| at + 3);
+    let host_end = url[host_start..].find(['/', '?', '#']).map_or(url.len(), |at| host_start + at);
+    format!("{}{}", url[..host_end].to_lowercase(), &url[host_end..])
+}
+
+/// Digits of a phone number, after a '+' when it has one
+fn normalize_phone(phone: &str) -> String {
+    let digits = phone.chars().filter(char::is_ascii_digit);
+    if phone.starts_with('+') { std::iter::once('+').chain(digits).collect() } else { digits.collect() }
+}
+
+/// All entities of a message in text order, without overlaps
+/// Code spans hide the entities in them ("`a@b.io`" is code); otherwise
+/// URLs win over email addresses in them, emails over mentions and
+/// hashtags, and all of these over phone numbers.
+pub fn entities(text: &str) -> Vec<Entity<'_>> {
+    let mut found: Vec<(Span, &'static str, String)> = Vec::new();
+    let code = code_spans(text);
+    let candidates = url_spans(text)
+        .into_iter()
+        .map(|span| (span, "url", normalize_url(&text[span.start..span.end])))
+        .chain(email_spans(text).into_iter().map(|span| {
+            let (local, domain) = text[span.start..span.end].split_once('@').unwrap_or_default();
+            (span, "email", format!("{local}@{}", domain.to_lowercase()))
+        }))
+        .chain(mentions(text).into_iter().map(|mention| {
+            let value = mention.id.unwrap_or_else(|| mention.name.to_lowercase());
+            (mention.span, "mention", value)
+        }))
+        .chain(hashtag_spans(text).into_iter().map(|span| {
+            let tag = normalize_form(&text[span.start + 1..span.end], Form::Nfc).to_lowercase();
+            (span, "hashtag", tag)
+        }))
+        .chain(phone_spans(text).into_iter().map(|span| (span, "phone", normalize_phone(&text[span.start..span.end]))));
+    for (span, kind, value) in candidates {
+        if !code.iter().chain(found.iter().map(|(other, _, _)| other)).any(|other| other.overlaps(&span)) {
+            found.push((span, kind, value));
+        }
+    }
+    found.sort_by_key(|(span, _, _)| span.start);
+
+    let (mut byte, mut char) = (0, 0);
+    let mut char_at = |offset: usize| {
+        char += text[byte..offset].chars().count();
+        byte = offset;
+        char
+    };
+    found
+        .into_iter()
+        .map(|(span, kind, value)| {
+            let char_start = char_at(span.start);
+            let char_end = char_at(span.end);
+            Entity { kind, text: &text[span.start..span.end], value, start: span.start, end: span.end, char_start, char_end }
+        })
+        .collect()
+}
+
+/// Find the mentions, hashtags, URLs, email addresses and phone numbers
+/// of a message, for rendering it as links
+/// Returns array: [{"kind":"url","text":"www.Example.com/Docs","value":"https://www.example.com/Docs","start":6,"end":26,"charStart":6,"charEnd":26}]
+///
+/// Offsets are given in bytes and in characters (code points); slicing a
+/// JavaScript string needs UTF-16 units, which differ only for characters
+/// outside the BMP such as emoji. Each entity has a normalized `value`:
+/// - mention: the id of a rich mention (`@[Jane](u42)` → "u42"), or the
+///   lowercase username
+/// - hashtag: the tag without "#", lowercase ("#RustLang" → "rustlang")
+/// - url: with "https://" added to "www." links and a lowercase host
+/// - email: with a lowercase domain
+/// - phone: its digits, after a "+" if it has one ("+1 (555) 123-4567"
+///   → "+15551234567")
+///
+/// Entities inside code spans and fenced code blocks are not reported.
+///
+/// @param text - Message text
+#[wasm_bindgen]
+pub fn extract_entities(text: &str) -> JsValue {
+    to_js(&entities(text))
+}
+
+/// Whether a line is indented enough to be code (four spaces or a tab)
+fn is_indented_code(line: &str) -> bool {
+    line.starts_with("    ") || line.starts_with('\t')
+}
+
+/// A code block found in a message
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CodeBlock {
+    /// "fenced" or "indented"
+    kind: &'static str,
+    /// First word of the fence's info string
+    language_hint: Option<String>,
+    code: String,
+    span: Span,
+    /// False for a fenced block missing its closing fence
+    closed: bool,
+}
+
+/// Find indented code blocks outside of fenced ones
+/// A block is a run of lines indented by four spaces or a tab that starts
+/// after a blank line (or at the start of the text); blank lines inside
+/// the run are kept. One level of indentation is removed from the code.
+fn indented_blocks(text: &str, fenced: &[Span]) -> Vec<CodeBlock> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(usize, usize, Vec<&str>)> = None;
+    let mut previous_blank = true;
+    let mut line_start = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        let content = line.trim_end_matches(['\n', '\r']);
+        let in_fence = fenced.iter().any(|span| span.start <= line_start && line_start < span.end);
+        let blank = content.trim().is_empty();
+
+        if !in_fence && is_indented_code(content) && !blank && (previous_blank || current.is_some()) {
+            let code = content.strip_prefix("    ").or_else(|| content.strip_prefix('\t')).unwrap_or(content);
+            let entry = current.get_or_insert((line_start, line_start, Vec::new()));
+            entry.1 = line_start + content.len();
+            entry.2.push(code);
+        } else if blank && current.is_some() {
+            if let Some(entry) = current.as_mut() {
+                entry.2.push("");
+            }
+        } else if let Some((start, end, mut lines)) = current.take() {
+            while lines.last() == Some(&"") {
+                lines.pop();
+            }
+            blocks.push(CodeBlock { kind: "indented", language_hint: None, code: lines.join("\n"), span: Span { start, end }, closed: true });
+        }
+
+        previous_blank = blank;
+        line_start = line_end;
+    }
+
+    if let Some((start, end, mut lines)) = current {
+        while lines.last() == Some(&"") {
+            lines.pop();
+        }
+        blocks.push(CodeBlock { kind: "indented", language_hint: None, code: lines.join("\n"), span: Span { start, end }, closed: true });
+    }
+
+    blocks
+}
+
+fn extract_code_blocks_report(text: &str) -> Vec<CodeBlock> {
+    let fenced = fenced_blocks(text);
+    let spans: Vec<Span> = fenced.iter().map(|block| block.span).collect();
+
+    let mut blocks: Vec<CodeBlock> = fenced
+        .into_iter()
+        .map(|block| CodeBlock {
+            kind: "fenced",
+            language_hint: block.info.split_whitespace().next().map(String::from),
+            code: block.code.strip_suffix('\n').map_or(block.code, |code| code.strip_suffix('\r').unwrap_or(code)).to_string(),
+            span: block.span,
+            closed: block.closed,
+        })
+        .collect();
+    blocks.extend(indented_blocks(text, &spans));
+    blocks.sort_by_key(|block| block.span.start);
+
+    blocks
+}
+
+/// Extract code blocks and their declared languages
+/// Returns array: [{"kind":"fenced","languageHint":"python","code":"print(1)","span":{"start":0,"end":22},"closed":true}]
+///
+/// - Fenced blocks use ``` or ~~~ (three or more, indented at most three
+///   spaces) and close at a line of the same character that is at least as
+///   long. The language hint is the first word of the text after the
+///   opening fence, or null. A block without a closing fence runs to the
+///   end of the text with `closed: false`.
+/// - Indented blocks are runs of lines indented by four spaces or a tab,
+///   starting after a blank line; they have no language hint.
+///
+/// Code excludes the fences and the final line break. Blocks are in text order.
+#[wasm_bindgen]
+pub fn extract_code_blocks(text: &str) -> JsValue {
+    to_js(&extract_code_blocks_report(text))
+}
+
+/// `extract_code_blocks` as a JSON string
+/// @deprecated Use `extract_code_blocks`, which returns an object instead of JSON text.
+#[deprecated(note = "use `extract_code_blocks`, which returns an object")]
+#[wasm_bindgen]
+pub fn extract_code_blocks_json(text: &str) -> String {
+    to_json(&extract_code_blocks_report(text), "[]")
+}
@@ -0,0 +1,280 @@
+//! Line-oriented message formatting helpers
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use serde::Serialize;
+use crate::lexicon::{is_stopword, LANGUAGES};
+use crate::segmentation::words;
+
+/// Characters recognized as unordered bullet markers
+const BULLET_CHARS: [char; 16] = [
+    '-', '*', '+', '•', '·', '‣', '◦', '▪', '▫', '●', '○', '■', '□', '–', '—', '⁃',
+];
+
+/// A line that starts with a list marker
+struct ListLine<'a> {
+    /// Leading whitespace, kept verbatim to preserve nesting
+    indent: &'a str,
+    /// Marker pattern: the bullet character, or "1." / "1)" / "a)" for ordered items
+    marker: String,
+    /// Item text after the marker and its separating whitespace
+    content: &'a str,
+}
+
+/// Parse a list marker at the start of a line
+/// A marker only counts when followed by whitespace, so "-5°" or "*bold*"
+/// are not mistaken for bullets.
+fn parse_list_line(line: &str) -> Option<ListLine<'_>> {
+    let content_start = line.len() - line.trim_start().len();
+    let indent = &line[..content_start];
+    let rest = &line[content_start..];
+    let first = rest.chars().next()?;
+
+    let (marker, marker_len) = if BULLET_CHARS.contains(&first) {
+        (first.to_string(), first.len_utf8())
+    } else {
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        let (label_len, label) = if (1..=3).contains(&digits) {
+            (digits, "1")
+        } else if first.is_ascii_lowercase() {
+            (1, "a")
+        } else {
+            return None;
+        };
+        match rest[label_len..].chars().next() {
+            Some(delimiter @ ('.' | ')')) if !(label == "a" && delimiter == '.') => {
+                (format!("{}{}", label, delimiter), label_len + 1)
+            }
+            _ => return None,
+        }
+    };
+
+    let after = &rest[marker_len..];
+    if !after.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    Some(ListLine { indent, marker, content: after.trim_start() })
+}
+
+/// Rewrite every list line to use a consistent marker
+/// Indentation is preserved, so nested lists keep their structure.
+///
+/// @param text - Multi-line message
+/// @param style - Target marker: "-", "*", "•" or "numbered" (unknown styles use "-")
+/// @returns Text with normalized markers; numbered items restart per nesting level
+#[wasm_bindgen]
+pub fn normalize_bullets(text: &str, style: &str) -> String {
+    let bullet = match style {
+        "*" | "•" | "numbered" => style,
+        _ => "-",
+    };
+
+    // Counters per indentation width for numbered style
+    let mut counters: Vec<(usize, u32)> = Vec::new();
+    let mut lines = Vec::new();
+
+    for line in text.split('\n') {
+        let Some(item) = parse_list_line(line) else {
+            if !line.trim().is_empty() {
+                counters.clear();
+            }
+            lines.push(line.to_string());
+            continue;
+        };
+
+        let marker = if bullet == "numbered" {
+            let depth = item.indent.chars().count();
+            counters.retain(|(indent, _)| *indent <= depth);
+            let number = match counters.last_mut() {
+                Some((indent, count)) if *indent == depth => {
+                    *count += 1;
+                    *count
+                }
+                _ => {
+                    counters.push((depth, 1));
+                    1
+                }
+            };
+            format!("{}.", number)
+        } else {
+            bullet.to_string()
+        };
+
+        lines.push(format!("{}{} {}", item.indent, marker, item.content));
+    }
+
+    lines.join("\n")
+}
+
+/// Summary of the list structure of a message
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListReport {
+    is_list: bool,
+    item_count: u32,
+    /// Distinct markers in order of first use
+    markers: Vec<String>,
+    mixed_markers: bool,
+    ordered: bool,
+    /// Number of distinct indentation levels
+    depth: u32,
+}
+
+fn detect_list_report(text: &str) -> ListReport {
+    let mut markers: Vec<String> = Vec::new();
+    let mut indents: Vec<usize> = Vec::new();
+    let mut item_count = 0u32;
+
+    for item in text.split('\n').filter_map(parse_list_line) {
+        item_count += 1;
+        if !markers.contains(&item.marker) {
+            markers.push(item.marker);
+        }
+        let indent = item.indent.chars().count();
+        if !indents.contains(&indent) {
+            indents.push(indent);
+        }
+    }
+
+    ListReport {
+        is_list: item_count >= 2,
+        item_count,
+        mixed_markers: markers.len() > 1,
+        ordered: !markers.is_empty() && markers.iter().all(|m| m.ends_with(['.', ')'])),
+        markers,
+        depth: indents.len() as u32,
+    }
+}
+
+/// Detect whether a message is a list and which markers it uses
+/// Returns object: {"isList":true,"itemCount":3,"markers":["-","•"],"mixedMarkers":true,"ordered":false,"depth":1}
+///
+/// A message counts as a list once two or more lines start with a marker.
+#[wasm_bindgen]
+pub fn detect_list(text: &str) -> JsValue {
+    to_js(&detect_list_report(text))
+}
+
+/// `detect_list` as a JSON string
+/// @deprecated Use `detect_list`, which returns an object instead of JSON text.
+#[deprecated(note = "use `detect_list`, which returns an object")]
+#[wasm_bindgen]
+pub fn detect_list_json(text: &str) -> String {
+    to_json(&detect_list_report(text), "{}")
+}
+
+/// Longest key accepted in a key-value line, in words
+const KEY_MAX_WORDS: usize = 3;
+
+/// Longest key accepted in a key-value line, in characters
+const KEY_MAX_CHARS: usize = 32;
+
+/// Split a line at its key-value delimiter: ':', '=', a tab or a spaced " - "
+/// The earliest delimiter wins; the value may contain further delimiters
+/// ("Link: https://…", "Time: 10:30").
+pub fn split_key_value(line: &str) -> Option<(&str, &str)> {
+    let (at, len) = [
+        line.find(':').map(|at| (at, 1)),
+        line.find('=').map(|at| (at, 1)),
+        line.find('\t').map(|at| (at, 1)),
+        line.find(" - ").map(|at| (at, 3)),
+    ]
+        .into_iter()
+        .flatten()
+        .min_by_key(|(at, _)| *at)?;
+    Some((line[..at].trim(), line[at + len..].trim()))
+}
+
+/// Check whether text before a delimiter looks like a field name
+/// Keys are short (up to three words and 32 characters), start with a
+/// letter, contain no sentence punctuation and do not end in a function
+/// word, which rules out prose like "The problem is: …" or "Here is what
+/// I found: …".
+fn is_key(key: &str) -> bool {
+    let key_words = words(key);
+    let Some(last) = key_words.last() else {
+        return false;
+    };
+    let last = last.text.to_lowercase();
+
+    key.chars().next().is_some_and(char::is_alphabetic)
+        && key_words.len() <= KEY_MAX_WORDS
+        && key.chars().count() <= KEY_MAX_CHARS
+        && !key.contains(['.', ',', '!', '?', ';', '(', '"'])
+        && (key_words.len() == 1 || !LANGUAGES.iter().any(|language| is_stopword(&last, language)))
+}
+
+/// Value of a key: a single string, or all values of a repeated key
+#[derive(Serialize)]
+#[serde(untagged)]
+enum FieldValue<'a> {
+    Single(&'a str),
+    Repeated(Vec<&'a str>),
+}
+
+#[derive(Serialize)]
+struct Field<'a> {
+    key: &'a str,
+    value: FieldValue<'a>,
+}
+
+fn parse_key_values_report<'a>(text: &'a str) -> Vec<Field<'a>> {
+    let mut fields: Vec<Field> = Vec::new();
+    let mut seen: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        let line = parse_list_line(line).map_or(line.trim(), |item| item.content.trim());
+        let Some((key, value)) = split_key_value(line) else {
+            continue;
+        };
+        if value.is_empty() || value.starts_with("//") || !is_key(key) {
+            continue;
+        }
+
+        let folded = key.to_lowercase();
+        match seen.iter().position(|existing| *existing == folded) {
+            Some(index) => {
+                let field = &mut fields[index];
+                field.value = match std::mem::replace(&mut field.value, FieldValue::Repeated(Vec::new())) {
+                    FieldValue::Single(first) => FieldValue::Repeated(vec![first, value]),
+                    FieldValue::Repeated(mut values) => {
+                        values.push(value);
+                        FieldValue::Repeated(values)
+                    }
+                };
+            }
+            None => {
+                seen.push(folded);
+                fields.push(Field { key, value: FieldValue::Single(value) });
+            }
+        }
+    }
+
+    fields
+}
+
+/// Parse structured "key: value" lines out of a message
+/// Returns array: [{"key":"Order ID","value":"12345"},{"key":"Status","value":"shipped"}]
+///
+/// Each line (after an optional list marker) is split at its first ':',
+/// '=', tab or spaced " - ". It counts as a field when the key looks like a
+/// field name (see `is_key`: short, no sentence punctuation) and the value
+/// is not empty. Other lines, including prose with a colon, are ignored,
+/// as are URLs ("https://…") where the colon opens "//".
+///
+/// Keys are matched case-insensitively. A key that occurs more than once
+/// is reported once, at its first position and with its first spelling,
+/// and its value becomes an array of all values in order.
+#[wasm_bindgen]
+pub fn parse_key_values(text: &str) -> JsValue {
+    to_js(&parse_key_values_report(text))
+}
+
+/// `parse_key_values` as a JSON string
+/// @deprecated Use `parse_key_values`, which returns an object instead of JSON text.
+#[deprecated(note = "use `parse_key_values`, which returns an object")]
+#[wasm_bindgen]
+pub fn parse_key_values_json(text: &str) -> String {
+    to_json(&parse_key_values_report(text), "[]")
+}
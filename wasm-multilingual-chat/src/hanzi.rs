@@ -0,0 +1,258 @@
+//! Simplified and Traditional Chinese character mapping
+//!
+//! **Learning Point**: Full conversion needs word-level dictionaries (发 is
+//! 發 in 发展 but 髮 in 头发). The crate ships one-to-one mappings for about
+//! 570 everyday characters instead, picking the most common counterpart
+//! where it depends on the word; characters written the same way in both
+//! are not listed. Both tables are sorted by their first column so lookups
+//! are a binary search.
+
+use wasm_bindgen::prelude::*;
+
+/// Simplified characters and their usual Traditional form
+const SIMPLIFIED_TO_TRADITIONAL: &[(char, char)] = &[
+    ('万', '萬'), ('与', '與'), ('业', '業'), ('东', '東'), ('两', '兩'), ('严', '嚴'), ('个', '個'), ('为', '為'),
+    ('丽', '麗'), ('举', '舉'), ('么', '麼'), ('义', '義'), ('乌', '烏'), ('乐', '樂'), ('习', '習'), ('乡', '鄉'),
+    ('书', '書'), ('买', '買'), ('云', '雲'), ('产', '產'), ('亲', '親'), ('亿', '億'), ('仅', '僅'), ('从', '從'),
+    ('们', '們'), ('价', '價'), ('众', '眾'), ('优', '優'), ('会', '會'), ('传', '傳'), ('伤', '傷'), ('体', '體'),
+    ('关', '關'), ('写', '寫'), ('农', '農'), ('冲', '衝'), ('决', '決'), ('况', '況'), ('净', '淨'), ('减', '減'),
+    ('几', '幾'), ('凤', '鳳'), ('击', '擊'), ('刘', '劉'), ('则', '則'), ('刚', '剛'), ('创', '創'), ('删', '刪'),
+    ('别', '別'), ('剧', '劇'), ('办', '辦'), ('务', '務'), ('动', '動'), ('劳', '勞'), ('势', '勢'), ('区', '區'),
+    ('医', '醫'), ('华', '華'), ('协', '協'), ('单', '單'), ('卖', '賣'), ('卢', '盧'), ('卫', '衛'), ('却', '卻'),
+    ('厂', '廠'), ('厅', '廳'), ('历', '歷'), ('厉', '厲'), ('压', '壓'), ('厕', '廁'), ('县', '縣'), ('参', '參'),
+    ('双', '雙'), ('发', '發'), ('变', '變'), ('叶', '葉'), ('号', '號'), ('叹', '嘆'), ('后', '後'), ('吓', '嚇'),
+    ('吗', '嗎'), ('听', '聽'), ('启', '啟'), ('吴', '吳'), ('员', '員'), ('呜', '嗚'), ('响', '響'), ('哑', '啞'),
+    ('团', '團'), ('园', '園'), ('围', '圍'), ('国', '國'), ('图', '圖'), ('圣', '聖'), ('场', '場'), ('坏', '壞'),
+    ('块', '塊'), ('坚', '堅'), ('坛', '壇'), ('墙', '牆'), ('壮', '壯'), ('声', '聲'), ('处', '處'), ('备', '備'),
+    ('头', '頭'), ('夸', '誇'), ('夺', '奪'), ('奋', '奮'), ('奖', '獎'), ('妇', '婦'), ('妈', '媽'), ('娱', '娛'),
+    ('婴', '嬰'), ('孙', '孫'), ('学', '學'), ('宁', '寧'), ('宝', '寶'), ('实', '實'), ('宪', '憲'), ('宫', '宮'),
+    ('寝', '寢'), ('对', '對'), ('导', '導'), ('将', '將'), ('尔', '爾'), ('尘', '塵'), ('尝', '嘗'), ('尽', '盡'),
+    ('层', '層'), ('属', '屬'), ('岁', '歲'), ('岛', '島'), ('峡', '峽'), ('币', '幣'), ('帅', '帥'), ('师', '師'),
+    ('带', '帶'), ('帮', '幫'), ('广', '廣'), ('庄', '莊'), ('庆', '慶'), ('库', '庫'), ('应', '應'), ('庙', '廟'),
+    ('开', '開'), ('异', '異'), ('弃', '棄'), ('张', '張'), ('弹', '彈'), ('强', '強'), ('归', '歸'), ('录', '錄'),
+    ('彻', '徹'), ('径', '徑'), ('忆', '憶'), ('忧', '憂'), ('怀', '懷'), ('态', '態'), ('怜', '憐'), ('总', '總'),
+    ('恋', '戀'), ('恶', '惡'), ('恼', '惱'), ('惊', '驚'), ('惯', '慣'), ('愿', '願'), ('戏', '戲'), ('战', '戰'),
+    ('户', '戶'), ('扑', '撲'), ('执', '執'), ('扩', '擴'), ('扰', '擾'), ('抢', '搶'), ('护', '護'), ('报', '報'),
+    ('担', '擔'), ('拟', '擬'), ('拥', '擁'), ('择', '擇'), ('挂', '掛'), ('挡', '擋'), ('挤', '擠'), ('挥', '揮'),
+    ('损', '損'), ('换', '換'), ('据', '據'), ('摄', '攝'), ('摆', '擺'), ('敌', '敵'), ('数', '數'), ('断', '斷'),
+    ('无', '無'), ('旧', '舊'), ('时', '時'), ('昼', '晝'), ('显', '顯'), ('晓', '曉'), ('暂', '暫'), ('术', '術'),
+    ('机', '機'), ('杀', '殺'), ('杂', '雜'), ('权', '權'), ('条', '條'), ('来', '來'), ('杰', '傑'), ('极', '極'),
+    ('构', '構'), ('标', '標'), ('树', '樹'), ('样', '樣'), ('档', '檔'), ('桥', '橋'), ('梦', '夢'), ('检', '檢'),
+    ('楼', '樓'), ('横', '橫'), ('欢', '歡'), ('欧', '歐'), ('残', '殘'), ('毁', '毀'), ('毕', '畢'), ('气', '氣'),
+    ('汇', '匯'), ('汉', '漢'), ('汤', '湯'), ('沟', '溝'), ('没', '沒'), ('沪', '滬'), ('泪', '淚'), ('泽', '澤'),
+    ('洁', '潔'), ('浅', '淺'), ('浊', '濁'), ('测', '測'), ('济', '濟'), ('涛', '濤'), ('润', '潤'), ('涨', '漲'),
+    ('渊', '淵'), ('渔', '漁'), ('温', '溫'), ('湾', '灣'), ('湿', '濕'), ('滚', '滾'), ('满', '滿'), ('灭', '滅'),
+    ('灯', '燈'), ('灵', '靈'), ('灾', '災'), ('炉', '爐'), ('点', '點'), ('烟', '煙'), ('烦', '煩'), ('烧', '燒'),
+    ('热', '熱'), ('爱', '愛'), ('爷', '爺'), ('牵', '牽'), ('牺', '犧'), ('状', '狀'), ('犹', '猶'), ('独', '獨'),
+    ('狮', '獅'), ('猎', '獵'), ('玛', '瑪'), ('环', '環'), ('现', '現'), ('琐', '瑣'), ('电', '電'), ('画', '畫'),
+    ('畅', '暢'), ('疗', '療'), ('疯', '瘋'), ('痒', '癢'), ('监', '監'), ('盖', '蓋'), ('盘', '盤'), ('矿', '礦'),
+    ('码', '碼'), ('砖', '磚'), ('础', '礎'), ('确', '確'), ('礼', '禮'), ('祸', '禍'), ('离', '離'), ('秃', '禿'),
+    ('种', '種'), ('积', '積'), ('称', '稱'), ('稳', '穩'), ('穷', '窮'), ('窃', '竊'), ('竖', '豎'), ('竞', '競'),
+    ('笔', '筆'), ('笼', '籠'), ('筑', '築'), ('签', '簽'), ('简', '簡'), ('类', '類'), ('粮', '糧'), ('紧', '緊'),
+    ('纠', '糾'), ('红', '紅'), ('约', '約'), ('级', '級'), ('纪', '紀'), ('纯', '純'), ('纷', '紛'), ('纸', '紙'),
+    ('纹', '紋'), ('纺', '紡'), ('线', '線'), ('练', '練'), ('组', '組'), ('细', '細'), ('织', '織'), ('终', '終'),
+    ('绍', '紹'), ('经', '經'), ('结', '結'), ('绕', '繞'), ('绘', '繪'), ('给', '給'), ('绝', '絕'), ('统', '統'),
+    ('继', '繼'), ('续', '續'), ('绿', '綠'), ('缓', '緩'), ('编', '編'), ('缘', '緣'), ('缩', '縮'), ('网', '網'),
+    ('罗', '羅'), ('罚', '罰'), ('罢', '罷'), ('翘', '翹'), ('耻', '恥'), ('职', '職'), ('联', '聯'), ('聪', '聰'),
+    ('肃', '肅'), ('肠', '腸'), ('胁', '脅'), ('胜', '勝'), ('脑', '腦'), ('脸', '臉'), ('舰', '艦'), ('艺', '藝'),
+    ('节', '節'), ('苏', '蘇'), ('苹', '蘋'), ('荣', '榮'), ('药', '藥'), ('莲', '蓮'), ('获', '獲'), ('萝', '蘿'),
+    ('蓝', '藍'), ('虑', '慮'), ('虫', '蟲'), ('虽', '雖'), ('蚁', '蟻'), ('蛮', '蠻'), ('衬', '襯'), ('袜', '襪'),
+    ('装', '裝'), ('见', '見'), ('观', '觀'), ('规', '規'), ('视', '視'), ('览', '覽'), ('觉', '覺'), ('触', '觸'),
+    ('誉', '譽'), ('计', '計'), ('订', '訂'), ('认', '認'), ('讨', '討'), ('让', '讓'), ('训', '訓'), ('议', '議'),
+    ('讯', '訊'), ('记', '記'), ('讲', '講'), ('许', '許'), ('论', '論'), ('设', '設'), ('访', '訪'), ('证', '證'),
+    ('评', '評'), ('识', '識'), ('诉', '訴'), ('词', '詞'), ('译', '譯'), ('试', '試'), ('诗', '詩'), ('诚', '誠'),
+    ('话', '話'), ('诞', '誕'), ('询', '詢'), ('该', '該'), ('详', '詳'), ('语', '語'), ('误', '誤'), ('说', '說'),
+    ('请', '請'), ('读', '讀'), ('课', '課'), ('谁', '誰'), ('调', '調'), ('谈', '談'), ('谋', '謀'), ('谓', '謂'),
+    ('谜', '謎'), ('谢', '謝'), ('谱', '譜'), ('贝', '貝'), ('负', '負'), ('贡', '貢'), ('财', '財'), ('责', '責'),
+    ('败', '敗'), ('货', '貨'), ('质', '質'), ('贩', '販'), ('贫', '貧'), ('购', '購'), ('贯', '貫'), ('贵', '貴'),
+    ('贷', '貸'), ('贸', '貿'), ('费', '費'), ('资', '資'), ('赏', '賞'), ('赔', '賠'), ('赛', '賽'), ('赞', '贊'),
+    ('赠', '贈'), ('赵', '趙'), ('赶', '趕'), ('跃', '躍'), ('车', '車'), ('轨', '軌'), ('转', '轉'), ('轮', '輪'),
+    ('软', '軟'), ('轰', '轟'), ('轻', '輕'), ('较', '較'), ('辆', '輛'), ('辈', '輩'), ('边', '邊'), ('辽', '遼'),
+    ('达', '達'), ('迁', '遷'), ('过', '過'), ('运', '運'), ('还', '還'), ('这', '這'), ('进', '進'), ('远', '遠'),
+    ('连', '連'), ('迟', '遲'), ('适', '適'), ('选', '選'), ('递', '遞'), ('逻', '邏'), ('遗', '遺'), ('邓', '鄧'),
+    ('邮', '郵'), ('邻', '鄰'), ('郑', '鄭'), ('酱', '醬'), ('释', '釋'), ('里', '裡'), ('针', '針'), ('钓', '釣'),
+    ('钟', '鐘'), ('钢', '鋼'), ('钥', '鑰'), ('钱', '錢'), ('钻', '鑽'), ('铁', '鐵'), ('铃', '鈴'), ('铅', '鉛'),
+    ('银', '銀'), ('链', '鏈'), ('销', '銷'), ('锁', '鎖'), ('锅', '鍋'), ('锋', '鋒'), ('错', '錯'), ('锦', '錦'),
+    ('键', '鍵'), ('镜', '鏡'), ('长', '長'), ('门', '門'), ('闪', '閃'), ('闭', '閉'), ('问', '問'), ('闯', '闖'),
+    ('闲', '閒'), ('间', '間'), ('闹', '鬧'), ('闻', '聞'), ('阅', '閱'), ('队', '隊'), ('阳', '陽'), ('阴', '陰'),
+    ('阶', '階'), ('际', '際'), ('陆', '陸'), ('陈', '陳'), ('险', '險'), ('随', '隨'), ('隐', '隱'), ('难', '難'),
+    ('静', '靜'), ('韩', '韓'), ('页', '頁'), ('顶', '頂'), ('项', '項'), ('顺', '順'), ('须', '須'), ('顾', '顧'),
+    ('顿', '頓'), ('颁', '頒'), ('预', '預'), ('领', '領'), ('频', '頻'), ('颗', '顆'), ('题', '題'), ('颜', '顏'),
+    ('额', '額'), ('风', '風'), ('飘', '飄'), ('飞', '飛'), ('饭', '飯'), ('饮', '飲'), ('饰', '飾'), ('饱', '飽'),
+    ('饼', '餅'), ('饿', '餓'), ('馆', '館'), ('馈', '饋'), ('马', '馬'), ('驱', '驅'), ('驶', '駛'), ('驻', '駐'),
+    ('驾', '駕'), ('骂', '罵'), ('验', '驗'), ('骑', '騎'), ('骗', '騙'), ('鱼', '魚'), ('鲜', '鮮'), ('鸟', '鳥'),
+    ('鸡', '雞'), ('鸭', '鴨'), ('鹅', '鵝'), ('麦', '麥'), ('黄', '黃'), ('齐', '齊'), ('齿', '齒'), ('龄', '齡'),
+    ('龙', '龍'), ('龟', '龜'),
+];
+
+/// Traditional characters and their Simplified form
+const TRADITIONAL_TO_SIMPLIFIED: &[(char, char)] = &[
+    ('來', '来'), ('個', '个'), ('們', '们'), ('傑', '杰'), ('備', '备'), ('傳', '传'), ('傷', '伤'), ('僅', '仅'),
+    ('價', '价'), ('億', '亿'), ('優', '优'), ('兩', '两'), ('別', '别'), ('刪', '删'), ('則', '则'), ('剛', '刚'),
+    ('創', '创'), ('劇', '剧'), ('劉', '刘'), ('動', '动'), ('務', '务'), ('勝', '胜'), ('勞', '劳'), ('勢', '势'),
+    ('匯', '汇'), ('區', '区'), ('協', '协'), ('卻', '却'), ('厲', '厉'), ('參', '参'), ('吳', '吴'), ('員', '员'),
+    ('問', '问'), ('啞', '哑'), ('啟', '启'), ('單', '单'), ('嗎', '吗'), ('嗚', '呜'), ('嘆', '叹'), ('嘗', '尝'),
+    ('嚇', '吓'), ('嚴', '严'), ('國', '国'), ('圍', '围'), ('園', '园'), ('圖', '图'), ('團', '团'), ('執', '执'),
+    ('堅', '坚'), ('報', '报'), ('場', '场'), ('塊', '块'), ('塵', '尘'), ('壇', '坛'), ('壓', '压'), ('壞', '坏'),
+    ('壯', '壮'), ('夢', '梦'), ('奪', '夺'), ('奮', '奋'), ('娛', '娱'), ('婦', '妇'), ('媽', '妈'), ('嬰', '婴'),
+    ('孫', '孙'), ('學', '学'), ('宮', '宫'), ('寢', '寝'), ('實', '实'), ('寧', '宁'), ('寫', '写'), ('寶', '宝'),
+    ('將', '将'), ('對', '对'), ('導', '导'), ('層', '层'), ('屬', '属'), ('島', '岛'), ('峽', '峡'), ('帥', '帅'),
+    ('師', '师'), ('帶', '带'), ('幣', '币'), ('幫', '帮'), ('幾', '几'), ('庫', '库'), ('廁', '厕'), ('廟', '庙'),
+    ('廠', '厂'), ('廣', '广'), ('廳', '厅'), ('張', '张'), ('強', '强'), ('彈', '弹'), ('後', '后'), ('徑', '径'),
+    ('從', '从'), ('徹', '彻'), ('恥', '耻'), ('惡', '恶'), ('惱', '恼'), ('愛', '爱'), ('態', '态'), ('慣', '惯'),
+    ('慮', '虑'), ('慶', '庆'), ('憂', '忧'), ('憐', '怜'), ('憲', '宪'), ('憶', '忆'), ('應', '应'), ('懷', '怀'),
+    ('戀', '恋'), ('戰', '战'), ('戲', '戏'), ('戶', '户'), ('掛', '挂'), ('換', '换'), ('揮', '挥'), ('損', '损'),
+    ('搶', '抢'), ('撲', '扑'), ('擁', '拥'), ('擇', '择'), ('擊', '击'), ('擋', '挡'), ('擔', '担'), ('據', '据'),
+    ('擠', '挤'), ('擬', '拟'), ('擴', '扩'), ('擺', '摆'), ('擾', '扰'), ('攝', '摄'), ('敗', '败'), ('敵', '敌'),
+    ('數', '数'), ('斷', '断'), ('時', '时'), ('晝', '昼'), ('暢', '畅'), ('暫', '暂'), ('曉', '晓'), ('書', '书'),
+    ('會', '会'), ('東', '东'), ('條', '条'), ('棄', '弃'), ('業', '业'), ('極', '极'), ('榮', '荣'), ('構', '构'),
+    ('樂', '乐'), ('樓', '楼'), ('標', '标'), ('樣', '样'), ('樹', '树'), ('橋', '桥'), ('機', '机'), ('橫', '横'),
+    ('檔', '档'), ('檢', '检'), ('權', '权'), ('歐', '欧'), ('歡', '欢'), ('歲', '岁'), ('歷', '历'), ('歸', '归'),
+    ('殘', '残'), ('殺', '杀'), ('毀', '毁'), ('氣', '气'), ('決', '决'), ('沒', '没'), ('況', '况'), ('淚', '泪'),
+    ('淨', '净'), ('淵', '渊'), ('淺', '浅'), ('減', '减'), ('測', '测'), ('湯', '汤'), ('溝', '沟'), ('溫', '温'),
+    ('滅', '灭'), ('滬', '沪'), ('滾', '滚'), ('滿', '满'), ('漁', '渔'), ('漢', '汉'), ('漲', '涨'), ('潔', '洁'),
+    ('潤', '润'), ('澤', '泽'), ('濁', '浊'), ('濕', '湿'), ('濟', '济'), ('濤', '涛'), ('灣', '湾'), ('災', '灾'),
+    ('為', '为'), ('烏', '乌'), ('無', '无'), ('煙', '烟'), ('煩', '烦'), ('熱', '热'), ('燈', '灯'), ('燒', '烧'),
+    ('爐', '炉'), ('爺', '爷'), ('爾', '尔'), ('牆', '墙'), ('牽', '牵'), ('犧', '牺'), ('狀', '状'), ('猶', '犹'),
+    ('獅', '狮'), ('獎', '奖'), ('獨', '独'), ('獲', '获'), ('獵', '猎'), ('現', '现'), ('瑣', '琐'), ('瑪', '玛'),
+    ('環', '环'), ('產', '产'), ('畢', '毕'), ('畫', '画'), ('異', '异'), ('瘋', '疯'), ('療', '疗'), ('癢', '痒'),
+    ('發', '发'), ('盡', '尽'), ('監', '监'), ('盤', '盘'), ('盧', '卢'), ('眾', '众'), ('確', '确'), ('碼', '码'),
+    ('磚', '砖'), ('礎', '础'), ('礦', '矿'), ('禍', '祸'), ('禮', '礼'), ('禿', '秃'), ('種', '种'), ('稱', '称'),
+    ('積', '积'), ('穩', '稳'), ('窮', '穷'), ('竊', '窃'), ('競', '竞'), ('筆', '笔'), ('節', '节'), ('築', '筑'),
+    ('簡', '简'), ('簽', '签'), ('籠', '笼'), ('糧', '粮'), ('糾', '纠'), ('紀', '纪'), ('約', '约'), ('紅', '红'),
+    ('紋', '纹'), ('純', '纯'), ('紙', '纸'), ('級', '级'), ('紛', '纷'), ('紡', '纺'), ('細', '细'), ('紹', '绍'),
+    ('終', '终'), ('組', '组'), ('結', '结'), ('絕', '绝'), ('給', '给'), ('統', '统'), ('經', '经'), ('綠', '绿'),
+    ('網', '网'), ('緊', '紧'), ('線', '线'), ('緣', '缘'), ('編', '编'), ('緩', '缓'), ('練', '练'), ('縣', '县'),
+    ('縮', '缩'), ('總', '总'), ('織', '织'), ('繞', '绕'), ('繪', '绘'), ('繼', '继'), ('續', '续'), ('罰', '罚'),
+    ('罵', '骂'), ('罷', '罢'), ('羅', '罗'), ('義', '义'), ('習', '习'), ('翹', '翘'), ('聖', '圣'), ('聞', '闻'),
+    ('聯', '联'), ('聰', '聪'), ('聲', '声'), ('職', '职'), ('聽', '听'), ('肅', '肃'), ('脅', '胁'), ('腦', '脑'),
+    ('腸', '肠'), ('臉', '脸'), ('與', '与'), ('舉', '举'), ('舊', '旧'), ('艦', '舰'), ('莊', '庄'), ('華', '华'),
+    ('萬', '万'), ('葉', '叶'), ('蓋', '盖'), ('蓮', '莲'), ('藍', '蓝'), ('藝', '艺'), ('藥', '药'), ('蘇', '苏'),
+    ('蘋', '苹'), ('蘿', '萝'), ('處', '处'), ('號', '号'), ('蟲', '虫'), ('蟻', '蚁'), ('蠻', '蛮'), ('術', '术'),
+    ('衛', '卫'), ('衝', '冲'), ('裝', '装'), ('裡', '里'), ('襪', '袜'), ('襯', '衬'), ('見', '见'), ('規', '规'),
+    ('視', '视'), ('親', '亲'), ('覺', '觉'), ('覽', '览'), ('觀', '观'), ('觸', '触'), ('訂', '订'), ('計', '计'),
+    ('訊', '讯'), ('討', '讨'), ('訓', '训'), ('記', '记'), ('訪', '访'), ('設', '设'), ('許', '许'), ('訴', '诉'),
+    ('評', '评'), ('詞', '词'), ('詢', '询'), ('試', '试'), ('詩', '诗'), ('話', '话'), ('該', '该'), ('詳', '详'),
+    ('誇', '夸'), ('認', '认'), ('誕', '诞'), ('語', '语'), ('誠', '诚'), ('誤', '误'), ('說', '说'), ('誰', '谁'),
+    ('課', '课'), ('調', '调'), ('談', '谈'), ('請', '请'), ('論', '论'), ('謀', '谋'), ('謂', '谓'), ('謎', '谜'),
+    ('講', '讲'), ('謝', '谢'), ('證', '证'), ('識', '识'), ('譜', '谱'), ('譯', '译'), ('議', '议'), ('護', '护'),
+    ('譽', '誉'), ('讀', '读'), ('變', '变'), ('讓', '让'), ('豎', '竖'), ('貝', '贝'), ('負', '负'), ('財', '财'),
+    ('貢', '贡'), ('貧', '贫'), ('貨', '货'), ('販', '贩'), ('貫', '贯'), ('責', '责'), ('貴', '贵'), ('買', '买'),
+    ('貸', '贷'), ('費', '费'), ('貿', '贸'), ('資', '资'), ('賞', '赏'), ('賠', '赔'), ('賣', '卖'), ('質', '质'),
+    ('購', '购'), ('賽', '赛'), ('贈', '赠'), ('贊', '赞'), ('趕', '赶'), ('趙', '赵'), ('躍', '跃'), ('車', '车'),
+    ('軌', '轨'), ('軟', '软'), ('較', '较'), ('輕', '轻'), ('輛', '辆'), ('輩', '辈'), ('輪', '轮'), ('轉', '转'),
+    ('轟', '轰'), ('辦', '办'), ('農', '农'), ('這', '这'), ('連', '连'), ('進', '进'), ('運', '运'), ('過', '过'),
+    ('達', '达'), ('遞', '递'), ('遠', '远'), ('適', '适'), ('遲', '迟'), ('遷', '迁'), ('選', '选'), ('遺', '遗'),
+    ('遼', '辽'), ('還', '还'), ('邊', '边'), ('邏', '逻'), ('郵', '邮'), ('鄉', '乡'), ('鄧', '邓'), ('鄭', '郑'),
+    ('鄰', '邻'), ('醫', '医'), ('醬', '酱'), ('釋', '释'), ('針', '针'), ('釣', '钓'), ('鈴', '铃'), ('鉛', '铅'),
+    ('銀', '银'), ('銷', '销'), ('鋒', '锋'), ('鋼', '钢'), ('錄', '录'), ('錢', '钱'), ('錦', '锦'), ('錯', '错'),
+    ('鍋', '锅'), ('鍵', '键'), ('鎖', '锁'), ('鏈', '链'), ('鏡', '镜'), ('鐘', '钟'), ('鐵', '铁'), ('鑰', '钥'),
+    ('鑽', '钻'), ('長', '长'), ('門', '门'), ('閃', '闪'), ('閉', '闭'), ('開', '开'), ('閒', '闲'), ('間', '间'),
+    ('閱', '阅'), ('闖', '闯'), ('關', '关'), ('陰', '阴'), ('陳', '陈'), ('陸', '陆'), ('陽', '阳'), ('隊', '队'),
+    ('階', '阶'), ('際', '际'), ('隨', '随'), ('險', '险'), ('隱', '隐'), ('雖', '虽'), ('雙', '双'), ('雜', '杂'),
+    ('雞', '鸡'), ('離', '离'), ('難', '难'), ('雲', '云'), ('電', '电'), ('靈', '灵'), ('靜', '静'), ('韓', '韩'),
+    ('響', '响'), ('頁', '页'), ('頂', '顶'), ('項', '项'), ('順', '顺'), ('須', '须'), ('預', '预'), ('頒', '颁'),
+    ('頓', '顿'), ('領', '领'), ('頭', '头'), ('頻', '频'), ('顆', '颗'), ('題', '题'), ('額', '额'), ('顏', '颜'),
+    ('願', '愿'), ('類', '类'), ('顧', '顾'), ('顯', '显'), ('風', '风'), ('飄', '飘'), ('飛', '飞'), ('飯', '饭'),
+    ('飲', '饮'), ('飽', '饱'), ('飾', '饰'), ('餅', '饼'), ('餓', '饿'), ('館', '馆'), ('饋', '馈'), ('馬', '马'),
+    ('駐', '驻'), ('駕', '驾'), ('駛', '驶'), ('騎', '骑'), ('騙', '骗'), ('驅', '驱'), ('驗', '验'), ('驚', '惊'),
+    ('體', '体'), ('鬧', '闹'), ('魚', '鱼'), ('鮮', '鲜'), ('鳥', '鸟'), ('鳳', '凤'), ('鴨', '鸭'), ('鵝', '鹅'),
+    ('麗', '丽'), ('麥', '麦'), ('麼', '么'), ('黃', '黄'), ('點', '点'), ('齊', '齐'), ('齒', '齿'), ('齡', '龄'),
+    ('龍', '龙'), ('龜', '龟'),
+];
+
+/// Mandarin readings of common characters, without tones, and the
+/// Simplified characters read that way
+/// A character with several readings is listed under its most common one
+/// (了 "le", 都 "dou"); the table is sorted by reading.
+const PINYIN: &[(&str, &str)] = &[
+    ("a", "啊阿"), ("ai", "爱哎矮艾碍"), ("an", "安按暗岸案"), ("ba", "八把吧爸巴拔"), ("bai", "白百摆败拜"), ("ban", "半办班般板版搬"),
+    ("bang", "帮棒"), ("bao", "包报保宝抱饱"), ("bei", "被北备背杯悲倍"), ("ben", "本笨"), ("bi", "比笔必毕鼻闭币避"), ("bian", "边变便遍编"),
+    ("biao", "表标"), ("bie", "别"), ("bin", "宾"), ("bing", "病并冰兵饼"), ("bo", "波播博伯"), ("bu", "不部步布补"), ("cai", "才菜采彩财猜"),
+    ("can", "参餐残"), ("cao", "草"), ("ce", "测册策"), ("ceng", "层曾"), ("cha", "查茶差察"), ("chan", "产"), ("chang", "长常场唱厂尝"),
+    ("chao", "超朝吵"), ("che", "车"), ("chen", "陈晨沉"), ("cheng", "成城程称乘"), ("chi", "吃持迟池尺"), ("chong", "冲虫"),
+    ("chu", "出处初除楚"), ("chuan", "穿传船川"), ("chuang", "窗床创"), ("chui", "吹"), ("chun", "春"), ("ci", "次词此"), ("cong", "从聪"),
+    ("cu", "粗"), ("cun", "村存"), ("cuo", "错"), ("da", "大打达答"), ("dai", "带代待戴袋"), ("dan", "但单蛋担"), ("dang", "当党"),
+    ("dao", "到道倒刀导岛"), ("de", "的得德"), ("deng", "等灯登"), ("di", "地第低弟底敌帝"), ("dian", "点电店典"), ("diao", "掉"),
+    ("ding", "定订顶"), ("dong", "东动懂冬"), ("dou", "都斗豆"), ("du", "读度独"), ("duan", "段短断"), ("dui", "对队"), ("dun", "顿"),
+    ("duo", "多朵"), ("e", "饿恶"), ("er", "二儿而耳"), ("fa", "发法"), ("fan", "饭反翻犯"), ("fang", "方放房访"), ("fei", "非飞费"),
+    ("fen", "分份"), ("feng", "风封丰"), ("fu", "服父夫复福富"), ("gai", "该改"), ("gan", "干感敢"), ("gang", "刚港"), ("gao", "高告"),
+    ("ge", "个哥歌各格"), ("gei", "给"), ("gen", "跟根"), ("geng", "更"), ("gong", "工公功共"), ("gou", "够狗"), ("gu", "古故顾"),
+    ("gua", "挂"), ("guan", "关观管"), ("guang", "光广"), ("gui", "贵"), ("guo", "国过果"), ("hai", "还孩海害"), ("han", "汉喊韩"),
+    ("hao", "好号"), ("he", "和喝合河"), ("hei", "黑"), ("hen", "很"), ("hong", "红"), ("hou", "后候"), ("hu", "湖护"),
+    ("hua", "话花化画华"), ("huai", "坏"), ("huan", "欢换"), ("huang", "黄"), ("hui", "会回"), ("huo", "活火或"), ("ji", "几机级记鸡急计技"),
+    ("jia", "家加价假"), ("jian", "见间件简健"), ("jiang", "将讲江"), ("jiao", "叫教交角"), ("jie", "姐接节结界"), ("jin", "进今近金"),
+    ("jing", "经京静"), ("jiu", "就九久酒旧"), ("ju", "句举"), ("jue", "觉决"), ("kai", "开"), ("kan", "看"), ("kao", "考"),
+    ("ke", "可课客"), ("kong", "空"), ("kou", "口"), ("kuai", "快块"), ("lai", "来"), ("lao", "老"), ("le", "了乐"), ("lei", "累"),
+    ("leng", "冷"), ("li", "里理力李利"), ("lian", "连脸练"), ("liang", "两亮"), ("liao", "聊"), ("lin", "林"), ("ling", "零"),
+    ("liu", "六留"), ("long", "龙"), ("lu", "路"), ("lü", "绿"), ("ma", "吗妈马"), ("mai", "买卖"), ("man", "慢满"), ("mang", "忙"),
+    ("mao", "猫毛"), ("mei", "没每美妹"), ("men", "们门"), ("mi", "米"), ("mian", "面"), ("min", "民"), ("ming", "名明"),
+    ("mu", "母木目"), ("na", "那拿哪"), ("nan", "男南难"), ("nao", "脑"), ("ne", "呢"), ("neng", "能"), ("ni", "你"), ("nian", "年"),
+    ("niang", "娘"), ("niao", "鸟"), ("nin", "您"), ("niu", "牛"), ("nü", "女"), ("pa", "怕"), ("pai", "排"), ("pang", "旁"),
+    ("pao", "跑"), ("peng", "朋"), ("pi", "皮"), ("pian", "片"), ("piao", "票漂"), ("pin", "品频"), ("ping", "平苹"),
+    ("qi", "七起气期其"), ("qian", "前钱千"), ("qiang", "强"), ("qiao", "桥"), ("qie", "且"), ("qin", "亲"), ("qing", "请情清青"),
+    ("qiu", "秋球"), ("qu", "去区取"), ("quan", "全"), ("que", "却"), ("qun", "群"), ("ran", "然"), ("rang", "让"), ("re", "热"),
+    ("ren", "人认"), ("ri", "日"), ("rong", "容"), ("rou", "肉"), ("ru", "如"), ("san", "三"), ("se", "色"), ("shan", "山"),
+    ("shang", "上商"), ("shao", "少"), ("she", "社"), ("shei", "谁"), ("shen", "什身深神"), ("sheng", "生声"),
+    ("shi", "是时十事市世实识师视试室"), ("shou", "手收首"), ("shu", "书数树术"), ("shui", "水睡"), ("shuo", "说"), ("si", "四死思"),
+    ("song", "送"), ("su", "诉"), ("suan", "算"), ("sui", "岁"), ("suo", "所"), ("ta", "他她它"), ("tai", "太台"), ("tan", "谈"),
+    ("tang", "汤"), ("tao", "套"), ("te", "特"), ("ti", "题体"), ("tian", "天"), ("tiao", "条"), ("ting", "听"), ("tong", "同"),
+    ("tou", "头"), ("tu", "图"), ("tuo", "脱"), ("wai", "外"), ("wan", "完晚万玩湾"), ("wang", "王往网忘"), ("wei", "为位"),
+    ("wen", "问文"), ("wo", "我"), ("wu", "五无"), ("xi", "系西洗喜戏"), ("xia", "下"), ("xian", "先现"), ("xiang", "想向香项"),
+    ("xiao", "小笑"), ("xie", "些写谢"), ("xin", "新心信"), ("xing", "行星姓"), ("xiu", "休"), ("xu", "需"), ("xue", "学雪"),
+    ("yan", "眼言"), ("yang", "样"), ("yao", "要"), ("ye", "也夜"), ("yi", "一以已"), ("yin", "因音"), ("ying", "应英迎"),
+    ("yong", "用"), ("you", "有又友游"), ("yu", "语鱼"), ("yuan", "元远"), ("yue", "月"), ("yun", "运"), ("zai", "在再"),
+    ("zan", "咱"), ("zao", "早"), ("ze", "则"), ("zen", "怎"), ("zhan", "站"), ("zhang", "张"), ("zhao", "找"), ("zhe", "这者"),
+    ("zhen", "真圳"), ("zheng", "正"), ("zhi", "只知"), ("zhong", "中重"), ("zhou", "州"), ("zhu", "住主"), ("zhuan", "专"),
+    ("zhun", "准"), ("zi", "子字自"), ("zong", "总"), ("zou", "走"), ("zu", "组"), ("zui", "最"), ("zuo", "做作坐"),
+];
+
+fn lookup(table: &[(char, char)], c: char) -> Option<char> {
+    table.binary_search_by_key(&c, |(from, _)| *from).ok().map(|index| table[index].1)
+}
+
+/// Traditional form of a Simplified character, if it differs
+pub fn to_traditional(c: char) -> Option<char> {
+    lookup(SIMPLIFIED_TO_TRADITIONAL, c)
+}
+
+/// Simplified form of a Traditional character, if it differs
+pub fn to_simplified(c: char) -> Option<char> {
+    lookup(TRADITIONAL_TO_SIMPLIFIED, c)
+}
+
+/// Toneless pinyin of a Simplified or Traditional character, if it is listed
+pub fn pinyin(c: char) -> Option<&'static str> {
+    let c = to_simplified(c).unwrap_or(c);
+    PINYIN.iter().find(|(_, characters)| characters.contains(c)).map(|(reading, _)| *reading)
+}
+
+/// Characters written only in Simplified and only in Traditional text
+pub fn variant_counts(text: &str) -> (usize, usize) {
+    let simplified = text.chars().filter(|c| to_traditional(*c).is_some()).count();
+    let traditional = text.chars().filter(|c| to_simplified(*c).is_some()).count();
+    (simplified, traditional)
+}
+
+/// Convert Chinese text to Simplified or Traditional characters
+/// Returns the converted text; characters outside the mapping tables
+/// (including all non-Chinese text) are kept as they are
+///
+/// Conversion is character by character, so characters whose Traditional
+/// form depends on the word get the most common one (后 becomes 後, even in
+/// 皇后). That is enough to normalize room content for search, but not for
+/// publishing converted text.
+///
+/// @param text - Text to convert
+/// @param target_variant - "zh-Hans" (also "zh-CN", "zh-SG", "simplified") or
+/// "zh-Hant" (also "zh-TW", "zh-HK", "zh-MO", "traditional"); other values
+/// return the text unchanged
+#[wasm_bindgen]
+pub fn convert_chinese(text: &str, target_variant: &str) -> String {
+    let convert: fn(char) -> Option<char> = match target_variant.to_lowercase().as_str() {
+        "zh-hans" | "zh-cn" | "zh-sg" | "hans" | "simplified" => to_simplified,
+        "zh-hant" | "zh-tw" | "zh-hk" | "zh-mo" | "hant" | "traditional" => to_traditional,
+        _ => return text.to_string(),
+    };
+    text.chars().map(|c| convert(c).unwrap_or(c)).collect()
+}
@@ -0,0 +1,369 @@
+//! Hyphenation with Liang patterns
+//!
+//! **Learning Point**: Frank Liang's algorithm (used by TeX and by browsers)
+//! stores hyphenation as short patterns of letters with digits between
+//! them, such as "1ba" or "b2l". Every pattern found in a word votes at its
+//! digit positions, the highest vote wins, and odd winners are hyphenation
+//! points. Full TeX pattern sets run to tens of thousands of entries; the
+//! built-in sets are generated from each language's syllable rules (a
+//! consonant before a vowel starts a syllable, "bl" and "tr" stay
+//! together, Spanish "a-e" splits) and give a few hundred patterns that
+//! hyphenate long compounds well. TeX patterns can be loaded to replace
+//! them.
+
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+use crate::js::to_js;
+use crate::segmentation::is_extend;
+
+/// Soft hyphen, which browsers show only where a line wraps
+const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// Consonant clusters that start a syllable in every supported language
+const COMMON_ONSETS: &[&str] = &["bl", "br", "cl", "cr", "dr", "fl", "fr", "gl", "gr", "pl", "pr", "tr"];
+
+/// Syllable rules a language's patterns are generated from
+struct SyllableRules {
+    language: &'static str,
+    vowels: &'static str,
+    consonants: &'static str,
+    /// Clusters besides `COMMON_ONSETS` kept together before a vowel
+    onsets: &'static [&'static str],
+    /// Whether two strong vowels, or a vowel and an accented "í" or "ú",
+    /// are split ("le-er", "dí-a")
+    hiatus: bool,
+    /// Further patterns, in Liang notation
+    patterns: &'static str,
+    left_min: usize,
+    right_min: usize,
+}
+
+const RULES: &[SyllableRules] = &[
+    SyllableRules {
+        language: "en",
+        vowels: "aeiouy",
+        consonants: "bcdfghjklmnpqrstvwxyz",
+        onsets: &["ch", "ph", "sh", "th", "wh"],
+        hiatus: false,
+        // "ck" and "x" end a syllable; a final silent "e" keeps its consonant
+        patterns: "c2k ck1 2x x1 2be. 2ce. 2de. 2fe. 2ge. 2ke. 2le. 2me. 2ne. 2pe. 2re. 2se. 2te. 2ve. 2ze. \
+                   2bes. 2ces. 2des. 2ges. 2kes. 2les. 2mes. 2nes. 2pes. 2res. 2ses. 2tes. 2ves. 2zes. \
+                   2bed. 2ced. 2ged. 2ked. 2led. 2med. 2ned. 2ped. 2red. 2sed. 2ved. 2zed.",
+        left_min: 2,
+        right_min: 3,
+    },
+    SyllableRules {
+        language: "de",
+        vowels: "aeiouyäöü",
+        consonants: "bcdfghjklmnpqrstvwxzß",
+        onsets: &["ch", "ck", "ph", "qu", "sch", "th"],
+        hiatus: false,
+        patterns: "",
+        left_min: 2,
+        right_min: 2,
+    },
+    SyllableRules {
+        language: "fr",
+        vowels: "aeiouyàâäéèêëîïôöùûüÿœæ",
+        consonants: "bcçdfghjklmnpqrstvwxz",
+        onsets: &["ch", "gn", "ph", "th", "vr"],
+        hiatus: false,
+        patterns: "",
+        left_min: 2,
+        right_min: 3,
+    },
+    SyllableRules {
+        language: "es",
+        vowels: "aeiouáéíóúü",
+        consonants: "bcdfghjklmnñpqrstvwxyz",
+        onsets: &["ch", "ll", "rr"],
+        hiatus: true,
+        patterns: "",
+        left_min: 2,
+        right_min: 2,
+    },
+    SyllableRules {
+        language: "pt",
+        vowels: "aeiouáéíóúâêôãõàü",
+        consonants: "bcçdfghjklmnpqrstvwxyz",
+        onsets: &["ch", "lh", "nh", "vr"],
+        hiatus: true,
+        patterns: "",
+        left_min: 2,
+        right_min: 3,
+    },
+    SyllableRules {
+        language: "it",
+        vowels: "aeiouàèéìíòóùú",
+        consonants: "bcdfghjklmnpqrstvwxyz",
+        // An "s" before a consonant joins the next syllable ("pa-sta")
+        onsets: &["ch", "gh", "gn", "vr", "sb", "sc", "sd", "sf", "sg", "sl", "sm", "sn", "sp", "sq", "st", "sv", "sch", "scr", "spr", "str"],
+        hiatus: true,
+        patterns: "",
+        left_min: 2,
+        right_min: 2,
+    },
+];
+
+/// Hyphenation patterns of one language
+#[derive(Clone)]
+struct Patterns {
+    /// Votes by pattern letters, one more vote than letters
+    votes: HashMap<String, Vec<u8>>,
+    /// Letters in the longest pattern
+    longest: usize,
+    /// Whole words with their hyphenation points
+    exceptions: HashMap<String, Vec<usize>>,
+    left_min: usize,
+    right_min: usize,
+    /// Vowels of generated patterns; each part of a hyphenated word keeps one
+    vowels: Option<&'static str>,
+}
+
+impl Patterns {
+    fn new(left_min: usize, right_min: usize, vowels: Option<&'static str>) -> Patterns {
+        Patterns { votes: HashMap::new(), longest: 0, exceptions: HashMap::new(), left_min, right_min, vowels }
+    }
+
+    /// Add a pattern ("1ba", ".ab3s") or, with hyphens, an exception ("ta-ble")
+    fn add(&mut self, pattern: &str) {
+        if pattern.contains('-') {
+            let mut word = String::new();
+            let mut points = Vec::new();
+            for c in pattern.chars() {
+                if c == '-' {
+                    points.push(word.chars().count());
+                } else {
+                    word.push(c);
+                }
+            }
+            self.exceptions.insert(word.to_lowercase(), points);
+            return;
+        }
+
+        let mut letters = String::new();
+        let mut votes = vec![0];
+        for c in pattern.chars() {
+            match c.to_digit(10) {
+                Some(vote) => *votes.last_mut().unwrap() = vote as u8,
+                None => {
+                    letters.push(c);
+                    votes.push(0);
+                }
+            }
+        }
+        if letters.is_empty() {
+            return;
+        }
+        self.longest = self.longest.max(votes.len() - 1);
+        let entry = self.votes.entry(letters).or_insert_with(|| vec![0; votes.len()]);
+        for (existing, vote) in entry.iter_mut().zip(votes) {
+            *existing = (*existing).max(vote);
+        }
+    }
+
+    /// Generate the patterns of a language's syllable rules
+    fn from_rules(rules: &SyllableRules) -> Patterns {
+        let mut patterns = Patterns::new(rules.left_min, rules.right_min, Some(rules.vowels));
+        for vowel in rules.vowels.chars() {
+            for consonant in rules.consonants.chars() {
+                patterns.add(&format!("1{consonant}{vowel}"));
+            }
+            for onset in COMMON_ONSETS.iter().chain(rules.onsets) {
+                patterns.add(&format!("1{onset}{vowel}"));
+            }
+        }
+        for onset in COMMON_ONSETS.iter().chain(rules.onsets) {
+            let letters: Vec<char> = onset.chars().collect();
+            for pair in letters.windows(2) {
+                patterns.add(&format!("{}2{}", pair[0], pair[1]));
+            }
+        }
+        if rules.hiatus {
+            let strong = |c: char| "aeoáéóàèòâêôãõ".contains(c);
+            let accented = |c: char| "íúìù".contains(c);
+            for first in rules.vowels.chars() {
+                for second in rules.vowels.chars() {
+                    if (strong(first) && strong(second)) || accented(first) || accented(second) {
+                        patterns.add(&format!("{first}1{second}"));
+                    }
+                }
+            }
+        }
+        for pattern in rules.patterns.split_whitespace() {
+            patterns.add(pattern);
+        }
+        patterns
+    }
+
+    /// Character positions a lowercase word may be hyphenated before
+    fn points(&self, word: &[char]) -> Vec<usize> {
+        let length = word.len();
+        if length < self.left_min + self.right_min {
+            return Vec::new();
+        }
+        let allowed = |point: &usize| *point >= self.left_min && length - *point >= self.right_min;
+        let key: String = word.iter().collect();
+        if let Some(points) = self.exceptions.get(&key) {
+            return points.iter().copied().filter(allowed).collect();
+        }
+
+        let padded: Vec<char> = std::iter::once('.').chain(word.iter().copied()).chain(std::iter::once('.')).collect();
+        let mut votes = vec![0u8; padded.len() + 1];
+        let mut letters = String::new();
+        for start in 0..padded.len() {
+            letters.clear();
+            for c in padded[start..].iter().take(self.longest) {
+                letters.push(*c);
+                if let Some(pattern) = self.votes.get(&letters) {
+                    for (i, vote) in pattern.iter().enumerate() {
+                        let slot = &mut votes[start + i];
+                        *slot = (*slot).max(*vote);
+                    }
+                }
+            }
+        }
+
+        let has_vowel = |part: &[char]| self.vowels.is_none_or(|vowels| part.iter().any(|c| vowels.contains(*c)));
+        // votes[i] is the vote before padded[i], so before word[i - 1]
+        (1..length)
+            .filter(|point| votes[point + 1] % 2 == 1)
+            .filter(allowed)
+            .filter(|point| has_vowel(&word[..*point]) && has_vowel(&word[*point..]))
+            .collect()
+    }
+}
+
+/// Built-in patterns per language, generated on first use
+static BUILTIN_PATTERNS: LazyLock<HashMap<&'static str, Arc<Patterns>>> =
+    LazyLock::new(|| RULES.iter().map(|rules| (rules.language, Arc::new(Patterns::from_rules(rules)))).collect());
+
+/// Patterns loaded at runtime per language
+static LOADED_PATTERNS: LazyLock<RwLock<HashMap<String, Arc<Patterns>>>> = LazyLock::new(|| RwLock::new(HashMap::new()));
+
+fn patterns_for(language: &str) -> Option<Arc<Patterns>> {
+    let loaded = LOADED_PATTERNS.read().ok().and_then(|loaded| loaded.get(language).cloned());
+    loaded.or_else(|| BUILTIN_PATTERNS.get(language).cloned())
+}
+
+/// A word with its hyphenation points
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hyphenated {
+    /// The text with soft hyphens at each point
+    pub hyphenated: String,
+    /// Byte offsets in the original text that may be hyphenated before
+    pub positions: Vec<usize>,
+    pub char_positions: Vec<usize>,
+}
+
+pub fn hyphenation_points(text: &str, language: &str) -> Hyphenated {
+    let mut result = Hyphenated { hyphenated: String::with_capacity(text.len()), positions: Vec::new(), char_positions: Vec::new() };
+    let Some(patterns) = patterns_for(language) else {
+        text.clone_into(&mut result.hyphenated);
+        return result;
+    };
+
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let is_letter = |c: char| c.is_alphabetic() || is_extend(c);
+    let mut points: Vec<usize> = Vec::new();
+    let mut start = 0;
+    while start < chars.len() {
+        if !is_letter(chars[start].1) {
+            start += 1;
+            continue;
+        }
+        let mut end = start;
+        while end < chars.len() && is_letter(chars[end].1) {
+            end += 1;
+        }
+        let word: Vec<char> = chars[start..end].iter().map(|(_, c)| c.to_lowercase().next().unwrap_or(*c)).collect();
+        points.extend(patterns.points(&word).into_iter().map(|point| start + point));
+        start = end;
+    }
+
+    let mut next = points.iter().peekable();
+    for (index, (offset, c)) in chars.iter().enumerate() {
+        if next.peek() == Some(&&index) {
+            next.next();
+            result.hyphenated.push(SOFT_HYPHEN);
+            result.positions.push(*offset);
+            result.char_positions.push(index);
+        }
+        result.hyphenated.push(*c);
+    }
+    result
+}
+
+/// Hyphenation points of a word, for wrapping long words in narrow bubbles
+/// Returns object: {"hyphenated":"Do\u00ADnau\u00ADdampf\u00ADschiff\u00ADfahrt","positions":[2,5,10,16],
+/// "charPositions":[2,5,10,16]}
+///
+/// `hyphenated` is the text with a soft hyphen (U+00AD) at each point, which
+/// browsers show only when a line wraps there; render it as is. Several
+/// words can be passed at once; each run of letters is hyphenated on its
+/// own, so existing hyphens and apostrophes are kept.
+///
+/// English ("en"), German ("de"), French ("fr"), Spanish ("es"),
+/// Portuguese ("pt") and Italian ("it") have built-in patterns, which
+/// split between syllables but do not know exceptions ("Haus-tür" is right,
+/// English "pro-ject" cannot tell the noun from the verb). Load TeX patterns
+/// with `load_hyphenation_patterns` for dictionary quality. Other languages
+/// are returned unchanged.
+///
+/// @param word - Word or text to hyphenate
+/// @param language - Language code
+#[wasm_bindgen]
+pub fn hyphenate(word: &str, language: &str) -> JsValue {
+    to_js(&hyphenation_points(word, language))
+}
+
+/// Replace a language's hyphenation patterns with TeX patterns
+/// Returns the number of patterns and exceptions read.
+///
+/// `patterns` holds patterns in Liang notation ("1ba", ".ab3s", "4m1p"),
+/// separated by whitespace, as in the `hyph-*.pat.txt` files of the
+/// hyph-utf8 project; words with hyphens ("ta-ble") are exceptions, as in
+/// its `hyph-*.hyp.txt` files. Lines starting with '%' or '#' are skipped.
+/// Calling again for the same language adds to the loaded patterns. Any
+/// language code can be loaded; built-in patterns of the language are no
+/// longer used.
+///
+/// @param language - Language code
+/// @param patterns - Whitespace-separated patterns and exceptions
+/// @param left_min - Letters kept before the first hyphen (default 2)
+/// @param right_min - Letters kept after the last hyphen (default 3)
+#[wasm_bindgen]
+pub fn load_hyphenation_patterns(language: &str, patterns: &str, left_min: Option<u32>, right_min: Option<u32>) -> u32 {
+    let mut loaded = LOADED_PATTERNS.write().unwrap();
+    let mut updated = match loaded.remove(language) {
+        Some(current) => current.as_ref().clone(),
+        None => Patterns::new(2, 3, None),
+    };
+    if let Some(left_min) = left_min {
+        updated.left_min = (left_min as usize).max(1);
+    }
+    if let Some(right_min) = right_min {
+        updated.right_min = (right_min as usize).max(1);
+    }
+
+    let mut count = 0;
+    for line in patterns.lines().map(str::trim).filter(|line| !line.starts_with('%') && !line.starts_with('#')) {
+        for pattern in line.split_whitespace() {
+            updated.add(pattern);
+            count += 1;
+        }
+    }
+    loaded.insert(language.to_string(), Arc::new(updated));
+    count
+}
+
+/// Go back to the built-in hyphenation patterns of a language
+#[wasm_bindgen]
+pub fn clear_hyphenation_patterns(language: &str) {
+    if let Ok(mut loaded) = LOADED_PATTERNS.write() {
+        loaded.remove(language);
+    }
+}
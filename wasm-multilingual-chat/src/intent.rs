@@ -0,0 +1,366 @@
+//! Message intent heuristics (questions)
+
+use wasm_bindgen::prelude::*;
+use crate::segmentation::words;
+
+/// Interrogative words and the question type they introduce
+/// Multi-word interrogatives ("por qué") are matched as word pairs.
+fn interrogatives(language: &str) -> &'static [(&'static str, &'static str)] {
+    match language {
+        "de" => &[
+            ("wer", "who"), ("wen", "who"), ("wem", "who"), ("wessen", "who"),
+            ("was", "what"), ("welche", "what"), ("welcher", "what"), ("welches", "what"),
+            ("wo", "where"), ("wohin", "where"), ("woher", "where"),
+            ("wann", "when"),
+            ("warum", "why"), ("weshalb", "why"), ("wieso", "why"),
+            ("wie", "how"),
+        ],
+        "fr" => &[
+            ("qui", "who"),
+            ("que", "what"), ("quoi", "what"), ("quel", "what"), ("quelle", "what"), ("quels", "what"), ("quelles", "what"), ("qu'est", "what"),
+            ("où", "where"),
+            ("quand", "when"),
+            ("pourquoi", "why"),
+            ("comment", "how"), ("combien", "how"),
+        ],
+        "es" => &[
+            ("quién", "who"), ("quiénes", "who"),
+            ("qué", "what"), ("cuál", "what"), ("cuáles", "what"),
+            ("dónde", "where"), ("adónde", "where"),
+            ("cuándo", "when"),
+            ("por qué", "why"),
+            ("cómo", "how"), ("cuánto", "how"), ("cuánta", "how"),
+        ],
+        "it" => &[
+            ("chi", "who"),
+            ("che", "what"), ("cosa", "what"), ("quale", "what"), ("quali", "what"),
+            ("dove", "where"),
+            ("quando", "when"),
+            ("perché", "why"),
+            ("come", "how"), ("quanto", "how"), ("quanti", "how"),
+        ],
+        "pt" => &[
+            ("quem", "who"),
+            ("que", "what"), ("qual", "what"), ("quais", "what"),
+            ("onde", "where"),
+            ("quando", "when"),
+            ("por que", "why"), ("por quê", "why"), ("porque", "why"),
+            ("como", "how"), ("quanto", "how"),
+        ],
+        "hi" => &[
+            ("कौन", "who"), ("किसने", "who"), ("किसको", "who"),
+            ("क्या", "what"),
+            ("कहाँ", "where"), ("कहां", "where"),
+            ("कब", "when"),
+            ("क्यों", "why"),
+            ("कैसे", "how"), ("कितना", "how"), ("कितने", "how"),
+        ],
+        "th" => &[
+            ("ใคร", "who"),
+            ("อะไร", "what"),
+            ("ที่ไหน", "where"), ("ไหน", "where"),
+            ("เมื่อไร", "when"), ("เมื่อไหร่", "when"),
+            ("ทำไม", "why"),
+            ("อย่างไร", "how"), ("ยังไง", "how"),
+        ],
+        _ => &[
+            ("who", "who"), ("whom", "who"), ("whose", "who"),
+            ("what", "what"), ("which", "what"),
+            ("where", "where"),
+            ("when", "when"),
+            ("why", "why"),
+            ("how", "how"),
+        ],
+    }
+}
+
+/// English auxiliaries that start an inverted yes-no question ("Are you coming")
+const EN_AUXILIARIES: [&str; 24] = [
+    "am", "is", "are", "was", "were", "do", "does", "did", "have", "has", "had", "can",
+    "could", "will", "would", "shall", "should", "may", "might", "must", "isn't", "aren't",
+    "don't", "didn't",
+];
+
+/// Auxiliaries that are also imperatives ("Do it now", "Have a nice day")
+const EN_IMPERATIVE_AUXILIARIES: [&str; 3] = ["do", "have", "don't"];
+
+/// Personal pronouns that can be the subject of an inverted auxiliary
+const EN_SUBJECT_PRONOUNS: [&str; 7] = ["i", "you", "he", "she", "we", "they", "there"];
+
+/// Words that start a subject noun phrase ("Is it", "Was the movie good")
+const EN_SUBJECT_STARTS: [&str; 13] = [
+    "it", "this", "that", "these", "those", "the", "my", "your", "his", "her", "its", "our",
+    "their",
+];
+
+/// Whether English words open with an auxiliary followed by its subject
+/// ("Are you coming", "Is the shop open"). An imperative auxiliary only
+/// counts before a personal pronoun, since "Do it" and "Have the rest"
+/// are orders rather than questions.
+fn is_english_inversion(words: &[String]) -> bool {
+    let [auxiliary, subject, ..] = words else {
+        return false;
+    };
+    if !EN_AUXILIARIES.contains(&auxiliary.as_str()) {
+        return false;
+    }
+    EN_SUBJECT_PRONOUNS.contains(&subject.as_str())
+        || (!EN_IMPERATIVE_AUXILIARIES.contains(&auxiliary.as_str()) && EN_SUBJECT_STARTS.contains(&subject.as_str()))
+}
+
+/// Subject pronouns that follow the verb in a verb-first yes-no question
+/// ("Kommst du morgen", "Kom je morgen", "Kommer du i morgon"), and words
+/// that can stand before a subject without being its verb ("aber er
+/// kommt", "weil du")
+fn inverted_subjects(language: &str) -> (&'static [&'static str], &'static [&'static str]) {
+    match language {
+        "de" => (
+            &["ich", "du", "er", "sie", "es", "wir", "ihr", "man"],
+            &["und", "aber", "oder", "denn", "doch", "sondern", "weil", "dass", "da", "wenn", "als", "ob", "obwohl", "falls"],
+        ),
+        "nl" => (
+            &["ik", "je", "jij", "u", "hij", "zij", "ze", "het", "we", "wij", "jullie", "men"],
+            &["en", "maar", "of", "want", "dus", "omdat", "dat", "als", "toen"],
+        ),
+        "sv" => (
+            &["jag", "du", "han", "hon", "den", "det", "vi", "ni", "de", "man"],
+            &["och", "men", "eller", "för", "så", "att", "om", "när", "eftersom"],
+        ),
+        _ => (&[], &[]),
+    }
+}
+
+/// Interjections and greetings that can open a sentence without taking
+/// part in its word order ("Ja, ich komme", "Hallo, kommst du")
+fn discourse_particles(language: &str) -> &'static [&'static str] {
+    match language {
+        "de" => &["ja", "nein", "nee", "jo", "na", "also", "tja", "ach", "oh", "ok", "okay", "hallo", "hi", "hey", "danke"],
+        "nl" => &["ja", "nee", "nou", "tja", "oh", "ok", "oké", "hoi", "hallo", "hey", "dank", "bedankt"],
+        "sv" => &["ja", "nej", "jo", "nä", "nja", "oj", "ok", "okej", "hej", "hallå", "tack"],
+        _ => &[],
+    }
+}
+
+/// German, Dutch and Swedish put the finite verb first in yes-no
+/// questions, right before a subject pronoun; statements put the subject
+/// or another word first ("Ich komme", "Morgen kommst du"). Leading
+/// particles are skipped, so "Ja, ich komme morgen" stays a statement.
+fn is_verb_first(words: &[String], language: &str) -> bool {
+    let (subjects, openers) = inverted_subjects(language);
+    let particles = discourse_particles(language);
+    let start = words.iter().take_while(|word| particles.contains(&word.as_str())).count();
+    let [verb, subject, ..] = &words[start..] else {
+        return false;
+    };
+    verb.chars().all(char::is_alphabetic)
+        && !subjects.contains(&verb.as_str())
+        && !openers.contains(&verb.as_str())
+        && subjects.contains(&subject.as_str())
+}
+
+/// French subject pronouns joined to the verb in an inverted question
+/// ("Viens-tu", "Va-t-il", "Est-ce vrai")
+const FR_INVERTED_SUBJECTS: [&str; 10] = ["je", "tu", "il", "elle", "on", "nous", "vous", "ils", "elles", "ce"];
+
+/// Whether a French sentence opens with a hyphenated inversion
+fn is_french_inversion(sentence: &str) -> bool {
+    let first = sentence.split_whitespace().next().unwrap_or("").to_lowercase();
+    let first = first.trim_end_matches(|c: char| !c.is_alphabetic());
+    match first.rsplit_once(['-', '‑']) {
+        Some((verb, subject)) => !verb.is_empty() && FR_INVERTED_SUBJECTS.contains(&subject),
+        None => false,
+    }
+}
+
+/// Thai sentence-final particles that turn a statement into a yes-no question
+const TH_QUESTION_PARTICLES: [&str; 4] = ["ไหม", "มั้ย", "หรือเปล่า", "หรือยัง"];
+
+fn is_question_mark(c: char) -> bool {
+    matches!(c, '?' | '？' | '¿' | '؟')
+}
+
+/// Pick the sentence to classify: the first one containing a question mark,
+/// or the whole text when there is none
+fn question_sentence(text: &str) -> (&str, bool) {
+    let Some(mark) = text.find(is_question_mark) else {
+        return (text.trim(), false);
+    };
+
+    // '¿' opens the question, other marks close it
+    let end = if text[mark..].starts_with('¿') {
+        text[mark..].find(['?', '？']).map_or(text.len(), |close| mark + close + 1)
+    } else {
+        mark + text[mark..].chars().next().map_or(0, char::len_utf8)
+    };
+    let start = text[..mark]
+        .rfind(['.', '!', '。', '।', '\n'])
+        .map_or(0, |boundary| boundary + 1);
+
+    (text[start..end].trim(), true)
+}
+
+/// Interrogatives only count within the first few words of a sentence,
+/// so conjunctions like "que" or "che" later on are not mistaken for them
+const INTERROGATIVE_WINDOW: usize = 3;
+
+/// First interrogative near the start of the words, with its word index
+fn find_interrogative(words: &[String], language: &str) -> Option<(usize, &'static str)> {
+    let table = interrogatives(language);
+    for (index, word) in words.iter().enumerate().take(INTERROGATIVE_WINDOW) {
+        // Contractions like "what's" or "how'd" keep their interrogative
+        let stem = word.split(['\'', '’']).next().unwrap_or(word);
+        let pair = words.get(index + 1).map(|next| format!("{} {}", word, next));
+        for (interrogative, kind) in table {
+            if *interrogative == stem || *interrogative == word || pair.as_deref() == Some(interrogative) {
+                return Some((index, kind));
+            }
+        }
+    }
+    None
+}
+
+/// Classify a message as a question
+/// Returns one of: who, what, where, when, why, how, yes-no, none
+///
+/// Messages with a question mark ('?', '？', '¿', '؟') are questions:
+/// wh-questions when one of their first three words is an interrogative,
+/// yes-no otherwise.
+/// Without a mark, a message still counts when it starts with an
+/// interrogative or an inverted verb and does not end in '.' or '!': an
+/// English auxiliary before its subject ("Are you coming"), a German, Dutch or Swedish verb
+/// before its subject pronoun ("Kommst du morgen") or a French hyphenated
+/// inversion ("Viens-tu"). Thai questions are also recognized by their question words
+/// and final particles, since Thai rarely uses '?'.
+///
+/// This is a heuristic: relative clauses at the start of a sentence
+/// ("Cuando llegue, te llamo") can be misread as questions.
+#[wasm_bindgen]
+pub fn question_type(text: &str, language: &str) -> String {
+    let (sentence, has_mark) = question_sentence(text);
+    if sentence.is_empty() {
+        return String::from("none");
+    }
+
+    if language == "th" {
+        let stripped = sentence.trim_end_matches(|c: char| is_question_mark(c) || c.is_whitespace());
+        if let Some((_, kind)) = interrogatives("th").iter().find(|(word, _)| stripped.contains(word)) {
+            return String::from(*kind);
+        }
+        if has_mark || TH_QUESTION_PARTICLES.iter().any(|particle| stripped.ends_with(particle)) {
+            return String::from("yes-no");
+        }
+        return String::from("none");
+    }
+
+    let lowered: Vec<String> = words(sentence).iter().map(|w| w.text.to_lowercase()).collect();
+    let Some(first_word) = lowered.first() else {
+        return String::from(if has_mark { "yes-no" } else { "none" });
+    };
+    let mut interrogative = find_interrogative(&lowered, language);
+
+    // French "est-ce que" introduces a yes-no question unless a wh-word follows
+    if language == "fr" && lowered.starts_with(&[String::from("est"), String::from("ce"), String::from("que")]) {
+        interrogative = find_interrogative(&lowered[3..], language).map(|(index, kind)| (index + 3, kind));
+        if interrogative.is_none() {
+            return String::from("yes-no");
+        }
+    }
+
+    // Hindi "क्या" at the start marks a yes-no question ("क्या आप आ रहे हैं?")
+    if language == "hi" && first_word == "क्या" && lowered.len() > 1 {
+        let later = find_interrogative(&lowered[1..], language);
+        if has_mark || later.is_none() {
+            return String::from(later.map_or("yes-no", |(_, kind)| kind));
+        }
+    }
+
+    if has_mark {
+        return String::from(interrogative.map_or("yes-no", |(_, kind)| kind));
+    }
+
+    if sentence.ends_with(['.', '!', '।', '。']) {
+        return String::from("none");
+    }
+    match interrogative {
+        Some((0, kind)) => String::from(kind),
+        _ if language == "en" && is_english_inversion(&lowered) => String::from("yes-no"),
+        _ if is_verb_first(&lowered, language) => String::from("yes-no"),
+        _ if language == "fr" && is_french_inversion(sentence) => String::from("yes-no"),
+        _ => String::from("none"),
+    }
+}
+
+/// Check whether a message is a question
+/// Uses the same rules as `question_type`.
+#[wasm_bindgen]
+pub fn is_question(text: &str, language: &str) -> bool {
+    question_type(text, language) != "none"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verb_first_questions() {
+        assert!(is_question("Kommst du morgen", "de"));
+        assert!(is_question("Hast du Zeit", "de"));
+        assert!(is_question("Kom je morgen", "nl"));
+        assert!(is_question("Is het koud", "nl"));
+        assert!(is_question("Kommer du i morgon", "sv"));
+        assert!(is_question("Finns det kaffe", "sv"));
+        assert_eq!(question_type("Kommst du morgen", "de"), "yes-no");
+    }
+
+    #[test]
+    fn verb_second_statements_are_not_questions() {
+        assert!(!is_question("Morgen kommst du", "de"));
+        assert!(!is_question("Aber er kommt", "de"));
+        assert!(!is_question("Ich komme morgen", "de"));
+        assert!(!is_question("Kommst du morgen.", "de"));
+        assert!(!is_question("Maar hij komt", "nl"));
+        assert!(!is_question("Hej du", "sv"));
+        assert!(!is_question("Kommst du morgen", "en"));
+    }
+
+    #[test]
+    fn english_auxiliary_needs_a_subject() {
+        assert!(is_question("Are you coming", "en"));
+        assert!(is_question("Is it raining", "en"));
+        assert!(is_question("Was the movie good", "en"));
+        assert!(is_question("Do you like it", "en"));
+        assert!(is_question("Have they left", "en"));
+        assert!(!is_question("Have a nice day", "en"));
+        assert!(!is_question("Do it now", "en"));
+        assert!(!is_question("Will do", "en"));
+        assert!(!is_question("Can't wait", "en"));
+        assert!(!is_question("Must be nice", "en"));
+        assert!(is_question("Do it now?", "en"));
+    }
+
+    #[test]
+    fn declaratives_after_a_particle_are_not_questions() {
+        assert!(!is_question("Ja, ich komme morgen", "de"));
+        assert!(!is_question("Nein, wir gehen nicht", "de"));
+        assert!(!is_question("Hallo du", "de"));
+        assert!(!is_question("Ja, ik kom morgen", "nl"));
+        assert!(!is_question("Nee, het is koud", "nl"));
+        assert!(!is_question("Ja, jag kommer i morgon", "sv"));
+        assert!(!is_question("Nej, vi har ingen tid", "sv"));
+        assert!(!is_question("Oui, je viens demain", "fr"));
+        assert!(!is_question("Je viens demain", "fr"));
+        assert!(is_question("Ja, kommst du morgen", "de"));
+        assert!(is_question("Hallo, kom je morgen", "nl"));
+    }
+
+    #[test]
+    fn french_hyphenated_inversion() {
+        assert!(is_question("Viens-tu ce soir", "fr"));
+        assert!(is_question("Va-t-il pleuvoir", "fr"));
+        assert!(is_question("Avez-vous faim", "fr"));
+        assert_eq!(question_type("Où vas-tu", "fr"), "where");
+        assert!(!is_question("Dis-moi demain", "fr"));
+        assert!(!is_question("Peut-être demain", "fr"));
+        assert!(!is_question("Viens-tu ce soir.", "fr"));
+    }
+}
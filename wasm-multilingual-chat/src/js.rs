@@ -0,0 +1,21 @@
+//! Conversion of results to JavaScript values
+//!
+//! **Learning Point**: Returning a `JsValue` built by `serde_wasm_bindgen`
+//! hands JavaScript a ready object, saving the caller a `JSON.parse` and the
+//! intermediate string. The json_compatible serializer turns maps into plain
+//! objects and 64-bit integers into numbers, so the objects look exactly like
+//! the parsed JSON the string versions return.
+
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+
+/// Plain JavaScript object or array for a value, null if it cannot be converted
+pub fn to_js<T: Serialize + ?Sized>(value: &T) -> JsValue {
+    value.serialize(&serde_wasm_bindgen::Serializer::json_compatible()).unwrap_or(JsValue::NULL)
+}
+
+/// JSON text for a value, `fallback` if it cannot be serialized
+/// Used by the deprecated string-returning aliases.
+pub fn to_json<T: Serialize + ?Sized>(value: &T, fallback: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| String::from(fallback))
+}
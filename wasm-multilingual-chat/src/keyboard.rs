@@ -0,0 +1,175 @@
+//! Keyboard and input-method helpers
+
+use std::collections::BTreeSet;
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use serde::Serialize;
+use crate::unicode::decompose_char;
+
+/// Letters on each phone key, indexed by digit
+/// Key 1 cycles through common punctuation; key 0 is the pause key.
+const KEYPAD: [&str; 10] = ["", ".,?!", "abc", "def", "ghi", "jkl", "mno", "pqrs", "tuv", "wxyz"];
+
+/// Decode multi-tap phone keypad input into text
+///
+/// Consecutive presses of the same key cycle through its letters
+/// ("44" → "h"). A run longer than the key's letter count starts over on
+/// the next letter group, so "555555" → "ll". A space or '0' is a pause
+/// that separates letters on the same key ("44 444" → "hi").
+/// Any other character ends the current run and is passed through unchanged.
+///
+/// Examples: "4433555555666" → "hello", "9666777555 3" → "world",
+/// "8 44 33" → "the" with pauses, "1 11" → ".,"
+#[wasm_bindgen]
+pub fn t9_decode(digits: &str) -> String {
+    let mut result = String::new();
+    let mut chars = digits.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let Some(key) = c.to_digit(10).filter(|d| *d != 0) else {
+            if c != ' ' && c != '0' {
+                result.push(c);
+            }
+            continue;
+        };
+
+        let mut presses = 1;
+        while chars.peek() == Some(&c) {
+            presses += 1;
+            chars.next();
+        }
+
+        let letters: Vec<char> = KEYPAD[key as usize].chars().collect();
+        let full_cycles = presses / letters.len();
+        for _ in 0..full_cycles {
+            result.push(letters[letters.len() - 1]);
+        }
+        let remainder = presses % letters.len();
+        if remainder > 0 {
+            result.push(letters[remainder - 1]);
+        }
+    }
+
+    result
+}
+
+/// Spacing form of a combining diacritic, as printed on its dead key
+fn dead_key(mark: char) -> char {
+    match mark {
+        '\u{0300}' => '`',
+        '\u{0301}' => '´',
+        '\u{0302}' => '^',
+        '\u{0303}' => '~',
+        '\u{0304}' => '¯',
+        '\u{0306}' => '˘',
+        '\u{0307}' => '˙',
+        '\u{0308}' => '¨',
+        '\u{030A}' => '˚',
+        '\u{030B}' => '˝',
+        '\u{030C}' => 'ˇ',
+        '\u{0327}' => '¸',
+        '\u{0328}' => '˛',
+        '\u{0313}' => '᾿',
+        '\u{0314}' => '῾',
+        '\u{0342}' => '῀',
+        '\u{0345}' => 'ͺ',
+        _ => mark,
+    }
+}
+
+/// Latin and Greek letters are typed as base letter plus dead key; other
+/// scripts have dedicated keys for their precomposed letters (Cyrillic й,
+/// Devanagari nukta letters) or use an input method (kana)
+fn typed_with_dead_keys(c: char) -> bool {
+    c.is_ascii_alphabetic() || matches!(c, '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' | '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}')
+}
+
+/// Add the keys needed for one character, decomposing recursively
+fn collect_keys(c: char, keys: &mut BTreeSet<char>, dead_keys: &mut BTreeSet<char>) {
+    if let Some(decomposed) = decompose_char(c).filter(|_| typed_with_dead_keys(c)) {
+        let mut parts = decomposed.chars();
+        if let Some(base) = parts.next() {
+            collect_keys(base, keys, dead_keys);
+        }
+        dead_keys.extend(parts.map(dead_key));
+        return;
+    }
+    keys.extend(c.to_lowercase());
+}
+
+/// Keys needed to type a message
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequiredKeys {
+    keys: Vec<char>,
+    dead_keys: Vec<char>,
+}
+
+fn required_keys_report(text: &str) -> RequiredKeys {
+    let mut keys = BTreeSet::new();
+    let mut dead_keys = BTreeSet::new();
+
+    for c in text.chars().filter(|c| !c.is_whitespace() && !c.is_control()) {
+        collect_keys(c, &mut keys, &mut dead_keys);
+    }
+
+    RequiredKeys {
+        keys: keys.into_iter().collect(),
+        dead_keys: dead_keys.into_iter().collect(),
+    }
+}
+
+/// Compute the minimal set of keys needed to type a message
+/// Returns object: {"keys":["a","c","e","f"],"deadKeys":["´"]} for "Café"
+///
+/// Keys are case-folded and sorted by code point. Accented Latin and Greek
+/// letters are split into their base letter and the dead keys for their
+/// diacritics ("ǘ" → "u" plus "¨" and "´"). Letters of other scripts,
+/// including Thai vowels and tone marks, Devanagari matras and Cyrillic
+/// "й", are keys of their own. Whitespace and control characters need no
+/// key. Empty input returns empty lists.
+#[wasm_bindgen]
+pub fn required_keys(text: &str) -> JsValue {
+    to_js(&required_keys_report(text))
+}
+
+/// `required_keys` as a JSON string
+/// @deprecated Use `required_keys`, which returns an object instead of JSON text.
+#[deprecated(note = "use `required_keys`, which returns an object")]
+#[wasm_bindgen]
+pub fn required_keys_json(text: &str) -> String {
+    to_json(&required_keys_report(text), "{}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn t9_decodes_classic_sequences() {
+        assert_eq!(t9_decode("4433555555666"), "hello");
+        assert_eq!(t9_decode("9666777555 3"), "world");
+    }
+
+    #[test]
+    fn t9_pauses_separate_letters_on_one_key() {
+        assert_eq!(t9_decode("44 444"), "hi");
+        assert_eq!(t9_decode("440444"), "hi");
+        assert_eq!(t9_decode("8 44 33"), "the");
+        assert_eq!(t9_decode("1 11"), ".,");
+    }
+
+    #[test]
+    fn t9_wraps_past_the_last_letter() {
+        assert_eq!(t9_decode("2222"), "ca");
+        assert_eq!(t9_decode("555555"), "ll");
+        assert_eq!(t9_decode("77777"), "sp");
+    }
+
+    #[test]
+    fn t9_passes_other_characters_through() {
+        assert_eq!(t9_decode("44#444"), "h#i");
+        assert_eq!(t9_decode("2x2"), "axa");
+        assert_eq!(t9_decode(""), "");
+    }
+}
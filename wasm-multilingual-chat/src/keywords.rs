@@ -0,0 +1,41 @@
+//! Keyword extraction and word frequency
+
+use std::collections::HashMap;
+use serde::Serialize;
+use crate::lexicon::is_stopword;
+use crate::segmentation::words;
+
+/// A keyword and how often it occurred
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct KeywordCount {
+    pub word: String,
+    pub count: u32,
+}
+
+/// Check whether a lowercased word carries content
+/// Stop words, pure numbers and single letters do not.
+pub fn is_content_word(word: &str, language: &str) -> bool {
+    !is_stopword(word, language) && word.chars().count() > 1 && !word.chars().all(|c| c.is_numeric())
+}
+
+/// Lowercased content words of a text, in order of appearance
+pub fn content_words(text: &str, language: &str) -> Vec<String> {
+    words(text)
+        .into_iter()
+        .map(|token| token.text.to_lowercase())
+        .filter(|word| is_content_word(word, language))
+        .collect()
+}
+
+/// Rank keyword counts, highest first
+/// Ties are broken alphabetically so the ranking is stable across runs.
+pub fn rank_keywords(counts: &HashMap<String, u32>, limit: usize) -> Vec<KeywordCount> {
+    let mut ranked: Vec<KeywordCount> = counts
+        .iter()
+        .filter(|(_, count)| **count > 0)
+        .map(|(word, count)| KeywordCount { word: word.clone(), count: *count })
+        .collect();
+    ranked.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+    ranked.truncate(limit);
+    ranked
+}
@@ -0,0 +1,327 @@
+//! Per-language word lists shared by detection and analysis
+
+/// Supported language codes, in detection priority order
+/// "hi-Latn" and "th-Latn" are Hindi and Thai typed in Latin letters.
+pub const LANGUAGES: [&str; 26] = [
+    "en", "de", "fr", "it", "pt", "hi", "es", "th", "zh", "ja", "ko", "ru", "uk", "bg", "sr", "ar", "fa", "ur",
+    "nl", "pl", "tr", "vi", "sv", "cs", "hi-Latn", "th-Latn",
+];
+
+// Common words used as detection evidence
+// Languages sharing a script list words the others lack where they can
+// (Russian "что", Bulgarian "какво"), so short messages are not decided
+// by words they all use.
+
+/// English common words
+const EN_COMMON: [&str; 20] = ["the", "be", "to", "of", "and", "a", "in", "that", "have", "i", "it", "for", "not", "on", "with", "he", "as", "you", "do", "at"];
+/// German common words
+const DE_COMMON: [&str; 20] = ["der", "die", "und", "in", "den", "von", "zu", "das", "mit", "sich", "des", "auf", "für", "ist", "im", "dem", "nicht", "ein", "eine", "als"];
+/// French common words
+const FR_COMMON: [&str; 20] = ["le", "de", "et", "à", "un", "il", "être", "et", "en", "avoir", "que", "pour", "dans", "ce", "son", "une", "sur", "avec", "ne", "se"];
+/// Italian common words
+const IT_COMMON: [&str; 20] = ["il", "di", "e", "la", "a", "un", "per", "è", "in", "una", "sono", "che", "si", "con", "non", "le", "da", "al", "i", "come"];
+/// Portuguese common words
+const PT_COMMON: [&str; 20] = ["o", "de", "e", "do", "da", "em", "um", "para", "é", "com", "não", "uma", "os", "no", "se", "na", "por", "mais", "as", "como"];
+/// Hindi common words
+const HI_COMMON: [&str; 20] = ["है", "और", "के", "में", "को", "से", "का", "की", "यह", "वह", "हो", "नहीं", "तो", "भी", "या", "पर", "इस", "उस", "जो", "कि"];
+/// Spanish common words
+const ES_COMMON: [&str; 20] = ["el", "la", "de", "que", "y", "a", "en", "un", "ser", "se", "no", "haber", "por", "con", "su", "para", "como", "estar", "tener", "le"];
+/// Thai common words
+const TH_COMMON: [&str; 20] = ["ที่", "เป็น", "และ", "ใน", "ของ", "จะ", "ได้", "ไม่", "มี", "ก็", "แล้ว", "กับ", "ให้", "ไป", "มา", "นี้", "นั้น", "เขา", "เธอ", "เรา"];
+/// Chinese common words
+const ZH_COMMON: [&str; 20] = ["的", "了", "是", "我", "你", "他", "她", "在", "有", "不", "这", "那", "们", "吗", "呢", "吧", "和", "也", "就", "都"];
+/// Japanese common words
+const JA_COMMON: [&str; 20] = ["の", "に", "は", "を", "た", "が", "で", "て", "と", "も", "です", "ます", "する", "いる", "ある", "こと", "から", "まで", "これ", "それ"];
+/// Korean common words
+const KO_COMMON: [&str; 20] = ["이", "그", "저", "것", "수", "나", "너", "우리", "저는", "나는", "있어요", "없어요", "그리고", "하지만", "그런데", "정말", "진짜", "너무", "오늘", "내일"];
+/// Russian common words
+const RU_COMMON: [&str; 20] = ["я", "что", "это", "ты", "вы", "мы", "его", "как", "привет", "тебя", "меня", "дела", "всё", "так", "только", "спасибо", "хорошо", "нет", "она", "сегодня"];
+/// Ukrainian common words
+const UK_COMMON: [&str; 20] = ["і", "й", "я", "що", "він", "з", "це", "як", "та", "але", "ти", "привіт", "дякую", "добре", "справи", "ви", "ми", "для", "мене", "який"];
+/// Bulgarian common words
+const BG_COMMON: [&str; 20] = ["какво", "съм", "ще", "нали", "аз", "ние", "това", "че", "със", "във", "са", "тук", "как", "сега", "мерси", "здравей", "къде", "кога", "защо", "нещо"];
+/// Serbian common words
+const SR_COMMON: [&str; 20] = ["је", "сам", "си", "смо", "јер", "како", "што", "шта", "ли", "где", "хвала", "ово", "али", "ја", "овде", "код", "нисам", "ће", "сутра", "баш"];
+/// Arabic common words
+const AR_COMMON: [&str; 20] = ["في", "من", "على", "أن", "إلى", "هذا", "هذه", "ما", "لا", "هو", "هي", "كان", "مع", "عن", "قد", "التي", "الذي", "أنا", "أنت", "نحن"];
+/// Persian common words
+const FA_COMMON: [&str; 20] = ["و", "در", "به", "از", "که", "این", "را", "با", "است", "آن", "برای", "من", "تو", "ما", "شما", "هم", "یک", "نه", "بود", "می"];
+/// Urdu common words
+const UR_COMMON: [&str; 20] = ["کے", "میں", "کی", "ہے", "اور", "کہ", "کو", "سے", "نے", "یہ", "وہ", "پر", "ہیں", "بھی", "تھا", "کیا", "نہیں", "ہم", "آپ", "میرا"];
+/// Dutch common words
+const NL_COMMON: [&str; 20] = ["het", "een", "van", "ik", "je", "dat", "niet", "op", "met", "voor", "zijn", "er", "maar", "om", "aan", "ook", "wat", "wel", "nog", "naar"];
+/// Polish common words
+const PL_COMMON: [&str; 20] = ["nie", "się", "że", "jest", "na", "to", "do", "jak", "ale", "po", "co", "tak", "za", "od", "mnie", "już", "czy", "jestem", "ten", "w"];
+/// Turkish common words
+const TR_COMMON: [&str; 20] = ["bir", "ve", "bu", "için", "çok", "ne", "mi", "ben", "sen", "var", "gibi", "daha", "ama", "değil", "ile", "her", "şey", "olarak", "kadar", "ya"];
+/// Vietnamese common words
+const VI_COMMON: [&str; 20] = ["và", "của", "là", "có", "không", "được", "một", "cho", "này", "trong", "với", "người", "những", "các", "để", "đã", "tôi", "bạn", "em", "anh"];
+/// Swedish common words
+const SV_COMMON: [&str; 20] = ["och", "att", "det", "i", "en", "som", "är", "på", "för", "med", "inte", "jag", "till", "har", "den", "av", "om", "du", "så", "men"];
+/// Czech common words
+const CS_COMMON: [&str; 20] = ["je", "se", "na", "to", "že", "do", "jsem", "jsou", "ale", "tak", "jak", "by", "pro", "už", "ten", "jako", "když", "mi", "co", "není"];
+/// Romanized Hindi common words
+const HI_LATN_COMMON: [&str; 20] = ["kya", "hai", "nahi", "hoon", "kar", "raha", "rahe", "rahi", "tha", "bhi", "ko", "ki", "ke", "kaise", "aap", "tum", "mujhe", "yaar", "accha", "kuch"];
+/// Romanized Thai common words
+const TH_LATN_COMMON: [&str; 20] = ["krub", "krab", "khrap", "ka", "kha", "khun", "mai", "sabai", "dee", "arai", "pai", "nai", "kin", "khao", "laew", "chai", "pen", "rai", "mak", "jing"];
+
+// Stop words: function words that carry no topic on their own
+
+const EN_STOPWORDS: &[&str] = &[
+    "the", "a", "an", "and", "or", "but", "if", "of", "to", "in", "on", "at", "by", "for", "with", "from",
+    "as", "is", "are", "was", "were", "be", "been", "being", "am", "have", "has", "had", "do", "does", "did",
+    "i", "you", "he", "she", "it", "we", "they", "me", "him", "her", "us", "them", "my", "your", "his", "its",
+    "our", "their", "this", "that", "these", "those", "not", "no", "so", "than", "too", "very", "can", "will",
+    "just", "there", "then", "what", "which", "who", "would", "could", "should",
+];
+const DE_STOPWORDS: &[&str] = &[
+    "der", "die", "das", "den", "dem", "des", "ein", "eine", "einen", "einem", "einer", "und", "oder", "aber",
+    "in", "im", "von", "zu", "zum", "zur", "mit", "auf", "für", "an", "am", "aus", "bei", "nach", "über",
+    "ist", "sind", "war", "bin", "bist", "hat", "haben", "wird", "werden", "ich", "du", "er", "sie", "es",
+    "wir", "ihr", "mich", "dich", "sich", "nicht", "kein", "als", "auch", "so", "wie", "dass", "noch", "nur",
+    "schon", "sehr", "mein",
+];
+const FR_STOPWORDS: &[&str] = &[
+    "le", "la", "les", "l", "un", "une", "des", "du", "de", "d", "et", "ou", "mais", "à", "au", "aux", "en",
+    "dans", "sur", "pour", "par", "avec", "sans", "ce", "cet", "cette", "ces", "que", "qui", "ne", "pas",
+    "est", "sont", "être", "avoir", "ai", "as", "a", "ont", "je", "j", "tu", "il", "elle", "nous", "vous",
+    "ils", "elles", "se", "son", "sa", "ses", "mon", "ma", "mes", "plus", "très", "y", "c", "qu",
+];
+const IT_STOPWORDS: &[&str] = &[
+    "il", "lo", "la", "i", "gli", "le", "un", "uno", "una", "di", "del", "della", "dei", "e", "o", "ma",
+    "a", "al", "alla", "da", "dal", "in", "nel", "nella", "per", "con", "su", "che", "chi", "non", "è",
+    "sono", "essere", "ho", "hai", "ha", "abbiamo", "hanno", "io", "tu", "lui", "lei", "noi", "voi", "loro",
+    "si", "mi", "ti", "ci", "come", "più", "molto", "anche", "questo", "quello", "mio", "suo", "se",
+    "ne", "l",
+];
+const PT_STOPWORDS: &[&str] = &[
+    "o", "a", "os", "as", "um", "uma", "uns", "umas", "de", "do", "da", "dos", "das", "e", "ou", "mas",
+    "em", "no", "na", "nos", "nas", "para", "por", "com", "sem", "que", "quem", "não", "é", "são", "ser",
+    "estar", "está", "foi", "tem", "ter", "eu", "tu", "você", "ele", "ela", "nós", "eles", "elas", "se",
+    "me", "te", "lhe", "como", "mais", "muito", "também", "este", "esse", "isso", "isto", "meu", "seu",
+    "ao", "pelo",
+];
+const HI_STOPWORDS: &[&str] = &[
+    "है", "हैं", "था", "थे", "थी", "और", "के", "में", "को", "से", "का", "की", "यह", "वह", "ये", "वे", "हो",
+    "नहीं", "तो", "भी", "या", "पर", "इस", "उस", "जो", "कि", "मैं", "तुम", "आप", "हम", "वो", "ने", "एक",
+    "कर", "रहा", "रही", "गया", "लिए", "साथ", "बहुत",
+];
+const ES_STOPWORDS: &[&str] = &[
+    "el", "la", "los", "las", "un", "una", "unos", "unas", "de", "del", "y", "o", "pero", "a", "al", "en",
+    "con", "sin", "por", "para", "que", "quien", "no", "es", "son", "ser", "estar", "está", "fue", "ha",
+    "he", "haber", "tener", "tiene", "yo", "tú", "él", "ella", "nosotros", "ellos", "ellas", "usted", "se",
+    "me", "te", "le", "lo", "su", "sus", "mi", "como", "más", "muy", "también", "este", "esta", "eso",
+    "esto", "si", "ya",
+];
+const TH_STOPWORDS: &[&str] = &[
+    "ที่", "เป็น", "และ", "ใน", "ของ", "จะ", "ได้", "ไม่", "มี", "ก็", "แล้ว", "กับ", "ให้", "ไป", "มา", "นี้",
+    "นั้น", "เขา", "เธอ", "เรา", "ผม", "ฉัน", "คุณ", "ว่า", "แต่", "หรือ", "ถ้า", "อยู่", "คือ", "ครับ",
+];
+const ZH_STOPWORDS: &[&str] = &[
+    "的", "了", "是", "我", "你", "他", "她", "它", "我们", "你们", "他们", "在", "有", "不", "没", "这", "那",
+    "这个", "那个", "吗", "呢", "吧", "啊", "和", "与", "也", "就", "都", "还", "很", "太", "被", "把", "给",
+    "对", "从", "到", "但是", "因为", "所以",
+];
+const JA_STOPWORDS: &[&str] = &[
+    "の", "に", "は", "を", "が", "で", "と", "も", "へ", "や", "か", "ね", "よ", "な", "です", "ます", "でした",
+    "ました", "する", "した", "いる", "ある", "こと", "もの", "から", "まで", "より", "これ", "それ", "あれ",
+    "この", "その", "あの", "私", "僕", "あなた", "彼", "彼女", "でも", "そして",
+];
+const KO_STOPWORDS: &[&str] = &[
+    "이", "그", "저", "것", "수", "등", "및", "나", "너", "우리", "저는", "나는", "제가", "내가", "너는",
+    "그리고", "하지만", "그런데", "그래서", "또", "더", "안", "못", "잘", "너무", "정말", "진짜", "좀", "다",
+    "있다", "없다", "있어요", "없어요", "이다", "하다", "해요", "입니다",
+];
+const RU_STOPWORDS: &[&str] = &[
+    "и", "в", "во", "не", "на", "с", "со", "что", "это", "а", "как", "по", "но", "к", "у", "же", "за", "из",
+    "от", "о", "об", "для", "до", "или", "бы", "ли", "я", "ты", "он", "она", "оно", "мы", "вы", "они", "меня",
+    "тебя", "его", "её", "нас", "вас", "их", "мой", "твой", "был", "была", "было", "быть", "есть", "так",
+    "уже", "ещё", "очень", "тоже",
+];
+const UK_STOPWORDS: &[&str] = &[
+    "і", "й", "та", "в", "у", "не", "на", "з", "із", "що", "це", "а", "як", "по", "але", "до", "за", "від",
+    "о", "про", "для", "або", "б", "чи", "я", "ти", "він", "вона", "воно", "ми", "ви", "вони", "мене", "тебе",
+    "його", "її", "нас", "вас", "їх", "мій", "твій", "був", "була", "було", "бути", "є", "так", "вже", "ще",
+    "дуже", "теж", "також",
+];
+const BG_STOPWORDS: &[&str] = &[
+    "и", "в", "във", "не", "на", "с", "със", "че", "да", "е", "са", "съм", "си", "сме", "сте", "бях", "за",
+    "от", "по", "към", "до", "при", "или", "но", "а", "това", "този", "тази", "тези", "как", "ще", "се",
+    "аз", "ти", "той", "тя", "то", "ние", "вие", "те", "ме", "ви", "го", "я", "нас", "вас", "ги", "мой",
+    "твой", "много", "също", "вече", "още",
+];
+const SR_STOPWORDS: &[&str] = &[
+    "и", "у", "не", "на", "са", "се", "да", "је", "су", "сам", "си", "смо", "сте", "био", "била", "било",
+    "за", "од", "по", "ка", "до", "из", "при", "или", "али", "а", "то", "овај", "ова", "ово", "како", "ће",
+    "ја", "ти", "он", "она", "оно", "ми", "ви", "они", "ме", "те", "га", "њу", "нас", "вас", "их", "мој",
+    "твој", "много", "такође", "већ", "још", "јер", "што",
+];
+const AR_STOPWORDS: &[&str] = &[
+    "في", "من", "على", "أن", "إن", "إلى", "عن", "مع", "هذا", "هذه", "ذلك", "تلك", "ما", "لا", "لم", "لن", "هو",
+    "هي", "هم", "أنا", "أنت", "نحن", "كان", "كانت", "قد", "التي", "الذي", "الذين", "أو", "ثم", "بل", "لكن",
+    "كل", "بعد", "قبل", "عند", "حتى", "و", "ف",
+];
+const FA_STOPWORDS: &[&str] = &[
+    "و", "در", "به", "از", "که", "این", "آن", "را", "با", "است", "هست", "بود", "شد", "برای", "تا", "یا",
+    "اما", "ولی", "اگر", "من", "تو", "او", "ما", "شما", "آنها", "هم", "نیز", "یک", "نه", "می", "خیلی",
+    "چه", "هر", "همه", "بر", "پس",
+];
+const UR_STOPWORDS: &[&str] = &[
+    "کے", "کا", "کی", "میں", "ہے", "ہیں", "تھا", "تھی", "تھے", "اور", "کہ", "کو", "سے", "نے", "پر", "یہ",
+    "وہ", "یا", "لیکن", "اگر", "بھی", "تو", "ہم", "آپ", "مجھے", "تم", "میرا", "میری", "نہیں", "کیا", "ہو",
+    "بہت", "گیا", "رہا", "کر",
+];
+const NL_STOPWORDS: &[&str] = &[
+    "de", "het", "een", "en", "van", "ik", "je", "jij", "u", "hij", "zij", "ze", "wij", "we", "jullie", "dat",
+    "die", "dit", "deze", "is", "zijn", "was", "waren", "niet", "op", "te", "met", "voor", "er", "maar", "om", "aan",
+    "ook", "als", "bij", "of", "in", "uit", "naar", "dan", "nog", "wel", "al", "wat", "hoe",
+];
+const PL_STOPWORDS: &[&str] = &[
+    "i", "w", "z", "na", "do", "nie", "się", "że", "to", "jest", "są", "był", "była", "było", "ale", "a",
+    "o", "po", "za", "od", "jak", "co", "czy", "tak", "ten", "ta", "te", "tego", "jej", "jego", "ich", "ja",
+    "ty", "on", "ona", "my", "wy", "oni", "mnie", "mi", "już", "też", "tylko", "bardzo", "przez", "dla",
+];
+const TR_STOPWORDS: &[&str] = &[
+    "ve", "bir", "bu", "şu", "o", "da", "de", "ile", "için", "çok", "ne", "mi", "mı", "mu", "mü", "ben",
+    "sen", "biz", "siz", "onlar", "var", "yok", "gibi", "daha", "ama", "değil", "her", "şey", "olarak", "kadar", "ya", "ki",
+    "en",
+];
+const VI_STOPWORDS: &[&str] = &[
+    "và", "của", "là", "có", "không", "được", "một", "cho", "này", "trong", "với", "người", "những", "các", "để", "đã",
+    "tôi", "bạn", "em", "anh", "chị", "nó", "họ", "chúng", "ta", "mình", "thì", "mà", "cũng", "rất", "đó", "khi",
+    "sẽ", "đang", "bị", "ở",
+];
+const SV_STOPWORDS: &[&str] = &[
+    "och", "att", "det", "i", "en", "ett", "som", "är", "på", "för", "med", "inte", "jag", "till", "har", "den",
+    "av", "om", "du", "så", "men", "han", "hon", "vi", "ni", "de", "var", "vad", "när", "från", "kan", "ska",
+    "eller", "också", "hade", "mig", "dig",
+];
+const CS_STOPWORDS: &[&str] = &[
+    "a", "je", "se", "na", "v", "to", "že", "s", "z", "o", "i", "do", "jsem", "jsi", "jsou", "byl",
+    "byla", "bylo", "ale", "tak", "jak", "by", "pro", "už", "ten", "ta", "jako", "když", "mi", "co", "není", "ani",
+    "nebo", "já", "ty", "on", "ona", "my", "vy", "oni",
+];
+const HI_LATN_STOPWORDS: &[&str] = &[
+    "hai", "hain", "tha", "thi", "aur", "ke", "ka", "ki", "mein", "ko", "se", "ye", "yeh", "woh", "wo", "ho",
+    "nahi", "nahin", "toh", "bhi", "ya", "par", "jo", "mai", "tum", "aap", "hum", "ne", "ek", "kar", "raha",
+    "rahi", "rahe", "gaya", "liye", "saath", "bahut", "kya", "hoon", "mera", "meri", "mujhe",
+];
+const TH_LATN_STOPWORDS: &[&str] = &[
+    "krub", "krab", "khrap", "ka", "kha", "na", "mai", "pen", "tee", "nai", "gap", "kap", "laew", "ja",
+    "dai", "mee", "kor", "tae", "reu", "kao", "chan", "pom", "khun", "rao", "ter", "nee", "nan", "arai",
+    "yoo", "pai", "ma",
+];
+
+/// Common words used as detection evidence for a language
+pub fn common_words(language: &str) -> &'static [&'static str] {
+    match language {
+        "de" => &DE_COMMON,
+        "fr" => &FR_COMMON,
+        "it" => &IT_COMMON,
+        "pt" => &PT_COMMON,
+        "hi" => &HI_COMMON,
+        "es" => &ES_COMMON,
+        "th" => &TH_COMMON,
+        "zh" => &ZH_COMMON,
+        "ja" => &JA_COMMON,
+        "ko" => &KO_COMMON,
+        "ru" => &RU_COMMON,
+        "uk" => &UK_COMMON,
+        "bg" => &BG_COMMON,
+        "sr" => &SR_COMMON,
+        "ar" => &AR_COMMON,
+        "fa" => &FA_COMMON,
+        "ur" => &UR_COMMON,
+        "nl" => &NL_COMMON,
+        "pl" => &PL_COMMON,
+        "tr" => &TR_COMMON,
+        "vi" => &VI_COMMON,
+        "sv" => &SV_COMMON,
+        "cs" => &CS_COMMON,
+        "hi-Latn" => &HI_LATN_COMMON,
+        "th-Latn" => &TH_LATN_COMMON,
+        _ => &EN_COMMON,
+    }
+}
+
+/// Function words for a language (articles, pronouns, auxiliaries, prepositions)
+/// Unknown languages fall back to English.
+pub fn stopwords(language: &str) -> &'static [&'static str] {
+    match language {
+        "de" => DE_STOPWORDS,
+        "fr" => FR_STOPWORDS,
+        "it" => IT_STOPWORDS,
+        "pt" => PT_STOPWORDS,
+        "hi" => HI_STOPWORDS,
+        "es" => ES_STOPWORDS,
+        "th" => TH_STOPWORDS,
+        "zh" => ZH_STOPWORDS,
+        "ja" => JA_STOPWORDS,
+        "ko" => KO_STOPWORDS,
+        "ru" => RU_STOPWORDS,
+        "uk" => UK_STOPWORDS,
+        "bg" => BG_STOPWORDS,
+        "sr" => SR_STOPWORDS,
+        "ar" => AR_STOPWORDS,
+        "fa" => FA_STOPWORDS,
+        "ur" => UR_STOPWORDS,
+        "nl" => NL_STOPWORDS,
+        "pl" => PL_STOPWORDS,
+        "tr" => TR_STOPWORDS,
+        "vi" => VI_STOPWORDS,
+        "sv" => SV_STOPWORDS,
+        "cs" => CS_STOPWORDS,
+        "hi-Latn" => HI_LATN_STOPWORDS,
+        "th-Latn" => TH_LATN_STOPWORDS,
+        _ => EN_STOPWORDS,
+    }
+}
+
+/// Check whether a lowercased word is a stop word in the language
+pub fn is_stopword(word: &str, language: &str) -> bool {
+    stopwords(language).contains(&word)
+}
+
+/// Opening phrases of a message ("Hi", "Bonjour", "Dear")
+pub fn greetings(language: &str) -> &'static [&'static str] {
+    match language {
+        "de" => &["hallo", "hi", "hey", "guten morgen", "guten tag", "guten abend", "servus", "moin", "liebe", "lieber", "sehr geehrte", "sehr geehrter"],
+        "fr" => &["bonjour", "bonsoir", "salut", "coucou", "cher", "chère", "chers", "madame", "monsieur"],
+        "it" => &["ciao", "buongiorno", "buonasera", "salve", "caro", "cara", "gentile"],
+        "pt" => &["olá", "ola", "oi", "bom dia", "boa tarde", "boa noite", "prezado", "prezada", "querido", "querida"],
+        "hi" => &["नमस्ते", "नमस्कार", "प्रिय", "हैलो", "हेलो"],
+        "es" => &["hola", "buenos días", "buenas tardes", "buenas noches", "buenas", "querido", "querida", "estimado", "estimada"],
+        "th" => &["สวัสดีครับ", "สวัสดีค่ะ", "สวัสดี", "เรียน"],
+        "nl" => &["hallo", "hoi", "hey", "goedemorgen", "goedemiddag", "goedenavond", "beste", "lieve", "dag"],
+        "pl" => &["cześć", "dzień dobry", "dobry wieczór", "witam", "witaj", "hej", "szanowni państwo", "drogi", "droga"],
+        "tr" => &["merhaba", "selam", "günaydın", "iyi günler", "iyi akşamlar", "sevgili", "sayın"],
+        "vi" => &["xin chào", "chào", "chào bạn", "chào anh", "chào chị", "chào em", "kính gửi", "thân gửi"],
+        "sv" => &["hej", "hejsan", "hallå", "tjena", "god morgon", "god kväll", "kära"],
+        "cs" => &["ahoj", "čau", "dobrý den", "dobré ráno", "dobrý večer", "milý", "milá", "vážený", "vážená"],
+        "hi-Latn" => &["namaste", "namaskar", "pranam"],
+        "th-Latn" => &["sawasdee", "sawatdee", "sawasdee krub", "sawasdee ka", "sawatdee khrap", "sawatdee kha"],
+        _ => &["hi", "hello", "hey", "hiya", "howdy", "dear", "greetings", "good morning", "good afternoon", "good evening"],
+    }
+}
+
+/// Closing phrases of a message ("Thanks", "Cheers", "Saludos")
+pub fn signoffs(language: &str) -> &'static [&'static str] {
+    match language {
+        "de" => &["danke", "vielen dank", "danke schön", "viele grüße", "liebe grüße", "beste grüße", "mit freundlichen grüßen", "grüße", "gruß", "lg", "mfg", "bis bald", "tschüss"],
+        "fr" => &["merci", "merci beaucoup", "cordialement", "bien à vous", "amicalement", "bisous", "à bientôt", "à plus", "bonne journée"],
+        "it" => &["grazie", "grazie mille", "saluti", "cordiali saluti", "distinti saluti", "un abbraccio", "a presto", "ci vediamo"],
+        "pt" => &["obrigado", "obrigada", "abraço", "abraços", "um abraço", "atenciosamente", "cumprimentos", "beijos", "até logo", "até mais"],
+        "hi" => &["धन्यवाद", "शुक्रिया", "आपका", "आपकी", "फिर मिलेंगे"],
+        "es" => &["gracias", "muchas gracias", "saludos", "un saludo", "atentamente", "un abrazo", "besos", "hasta luego", "nos vemos"],
+        "th" => &["ขอบคุณครับ", "ขอบคุณค่ะ", "ขอบคุณ", "ด้วยความเคารพ", "แล้วพบกันใหม่"],
+        "nl" => &["bedankt", "dank je", "dank je wel", "dank u wel", "groetjes", "groeten", "met vriendelijke groet", "tot ziens", "tot straks", "doei"],
+        "pl" => &["dzięki", "dziękuję", "pozdrawiam", "pozdrowienia", "z poważaniem", "do zobaczenia", "na razie", "trzymaj się"],
+        "tr" => &["teşekkürler", "teşekkür ederim", "sağ ol", "saygılarımla", "sevgiler", "görüşürüz", "hoşça kal", "kolay gelsin"],
+        "vi" => &["cảm ơn", "cám ơn", "cảm ơn bạn", "trân trọng", "thân ái", "hẹn gặp lại", "tạm biệt"],
+        "sv" => &["tack", "tack så mycket", "hälsningar", "med vänliga hälsningar", "mvh", "vi ses", "hej då", "ha det bra"],
+        "cs" => &["díky", "děkuji", "děkuju", "s pozdravem", "zdravím", "na shledanou", "měj se", "mějte se hezky"],
+        "hi-Latn" => &["dhanyavaad", "dhanyavad", "shukriya", "phir milenge"],
+        "th-Latn" => &["khop khun", "khob khun", "kob kun", "khop khun krub", "khop khun ka", "khop khun khrap", "khop khun kha"],
+        _ => &["thanks", "thank you", "thx", "cheers", "regards", "best regards", "kind regards", "best", "sincerely", "take care", "talk soon", "see you", "bye"],
+    }
+}
@@ -1,198 +1,426 @@
+//! Main library entry point for wasm-multilingual-chat
+//!
+//! Text statistics and normalization live here; the remaining features are
+//! organized into sub-modules:
+//! - segmentation: Grapheme and word tokenization shared by all modules
+//! - lexicon: Per-language word lists
+//! - keywords: Keyword extraction and word frequency
+//! - privacy: Privacy-preserving text previews
+//! - style: Character flooding, emphasis and shouting detection
+//! - conversation: Analytics over arrays of messages
+//! - formatting: Line-oriented message formatting
+//! - entities: Inline entity detection (URLs, code spans, mentions, emails, phone numbers)
+//! - reading: Reading time estimates
+//! - detection: Language detection
+//! - writing: Writing-assist checks
+//! - unicode: Unicode decomposition data
+//! - names: Display name helpers
+//! - intent: Message intent heuristics
+//! - keyboard: Keyboard and input-method helpers
+//! - bidi: Bidirectional text helpers
+//! - normalize: Character-level normalization helpers
+//! - metrics: Writing style and complexity metrics
+//! - boilerplate: Greeting and sign-off detection
+//! - numbers: Number format detection
+//! - script: Writing system (script) classification
+//! - emoji: Emoji and emoticon helpers
+//! - moderation: Moderation heuristics
+//! - transliterate: Transliteration and pronunciation respelling
+//! - ngrams: Character n-gram language profiles
+//! - profiles: Language profiles registered at runtime
+//! - variants: Regional variants of detected languages
+//! - hanzi: Simplified and Traditional Chinese character mapping
+//! - wordbreak: Word segmentation for Thai, Chinese and Japanese
+//! - js: Conversion of results to JavaScript values
+//! - stats: Text statistics, at once or streamed
+//! - confusables: Confusable (homoglyph) skeletons
+//! - bpe: Byte-pair encoding tokenizer for loaded vocabularies
+//! - linebreak: Line-break opportunities for wrapping
+//! - hyphenation: Hyphenation with Liang patterns
+
+// The deprecated `_json` aliases are called by the glue wasm-bindgen generates
+#![allow(deprecated)]
+
+// Module declarations
+mod segmentation;
+mod lexicon;
+mod keywords;
+mod privacy;
+mod style;
+mod conversation;
+mod formatting;
+mod entities;
+mod reading;
+mod detection;
+mod writing;
+mod unicode;
+mod names;
+mod intent;
+mod keyboard;
+mod bidi;
+mod normalize;
+mod metrics;
+mod boilerplate;
+mod numbers;
+mod script;
+mod emoji;
+mod moderation;
+mod transliterate;
+mod ngrams;
+mod profiles;
+mod variants;
+mod hanzi;
+mod wordbreak;
+mod js;
+mod stats;
+mod confusables;
+mod bpe;
+mod linebreak;
+mod hyphenation;
+
+// Re-export all public functions from sub-modules
+
+// From privacy module
+pub use privacy::{redact_words, shape_signature};
+
+// From style module
+pub use style::{emphasis_from_elongation, get_style_flags, collapse_elongation};
+
+// From conversation module
+pub use conversation::{topic_timeline, topic_timeline_json, turn_taking_stats, turn_taking_stats_json, sample_messages, sample_messages_json, message_volume_histogram, message_volume_histogram_json, ConversationStats};
+
+// From formatting module
+pub use formatting::{normalize_bullets, detect_list, detect_list_json, parse_key_values, parse_key_values_json};
+
+// From reading module
+pub use reading::{reading_time_smart, speaking_time_seconds, estimate_reading_time};
+
+// From entities module
+pub use entities::{parse_mentions, parse_mentions_json, render_mentions, extract_code_blocks, extract_code_blocks_json, extract_entities};
+
+// From detection module
+pub use detection::{detect_language, detect_language_per_paragraph, detect_language_per_paragraph_json, extract_glosses, extract_glosses_json, detect_language_per_sentence, detect_language_per_sentence_json, parse_bilingual_pairs, parse_bilingual_pairs_json, detect_language_ranked, detect_language_ranked_json, detect_language_spans, detect_language_spans_json, LanguageDetector, detect_language_with_options, detect_languages, explain_detection, explain_detection_json};
+
+// From writing module
+pub use writing::{find_doubled_words, find_doubled_words_json, find_passive_voice, find_passive_voice_json, suggest_correction, suggest_correction_json, overused_words, overused_words_json};
+
+// From names module
+pub use names::initials;
+
+// From intent module
+pub use intent::{is_question, question_type};
+
+// From keyboard module
+pub use keyboard::{t9_decode, required_keys, required_keys_json};
+
+// From bidi module
+pub use bidi::{word_directions, word_directions_json};
+
+// From normalize module
+pub use normalize::{normalize_apostrophes, normalize_punctuation_spacing, fold_diacritics, clean_whitespace, Normalizer, plain_punctuation, smart_punctuation, strip_vowel_marks, unify_arabic_letters};
+
+// From metrics module
+pub use metrics::{content_function_ratio, effort_score, machine_translation_likelihood, stylometric_similarity, mtld, get_readability, get_readability_json};
+
+// From boilerplate module
+pub use boilerplate::{segment_message, segment_message_json};
+
+// From numbers module
+pub use numbers::{number_ambiguities, number_ambiguities_json};
+
+// From emoji module
+pub use emoji::emoticons_to_emoji;
+
+// From moderation module
+pub use moderation::{toxicity_score, fold_obfuscation, detect_zalgo, strip_excess_marks};
+
+// From transliterate module
+pub use transliterate::{pronunciation_hint, transliterate, slugify, load_kanji_readings, clear_kanji_readings};
+
+// From profiles module
+pub use profiles::{register_language_profile, remove_language_profile, export_detector_model, load_detector_model};
+
+// From script module
+pub use script::{detect_scripts, detect_scripts_json};
+
+// From variants module
+pub use variants::{detect_language_variant, regional_language_variant};
+
+// From hanzi module
+pub use hanzi::convert_chinese;
+
+// From stats module
+pub use stats::{TextStats, StatsAccumulator};
+
+// From confusables module
+pub use confusables::{skeleton, are_confusable};
+
+// From wordbreak module
+pub use wordbreak::{segment_words, add_dictionary_words, clear_dictionary_words};
+
+// From segmentation module
+pub use segmentation::{segment_text, split_sentences, tokenize_with_spans};
+
+// From bpe module
+pub use bpe::BpeTokenizer;
+
+// From linebreak module
+pub use linebreak::line_break_opportunities;
+
+// From hyphenation module
+pub use hyphenation::{hyphenate, load_hyphenation_patterns, clear_hyphenation_patterns};
+
 use wasm_bindgen::prelude::*;
-use serde::{Deserialize, Serialize};
+use crate::js::{to_js, to_json};
+use serde::Serialize;
+use std::collections::HashMap;
 
 #[wasm_bindgen(start)]
 pub fn init() {
     console_error_panic_hook::set_once();
 }
 
-#[derive(Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TextStats {
-    pub word_count: u32,
-    pub character_count: u32,
-    pub character_count_no_spaces: u32,
-    pub sentence_count: u32,
-    pub average_word_length: f64,
+fn get_text_stats_report(text: &str) -> TextStats {
+    stats::text_stats(text)
 }
 
-/// Detect language from text using simple heuristics
-/// Returns language code: en, de, fr, it, pt, hi, es, th
+/// Get text statistics
+/// Returns object with word count, character count, etc.
+///
+/// `characterCount` counts Unicode scalar values, as JavaScript's
+/// `[...text].length` does; `graphemeCount` counts grapheme clusters, so an
+/// emoji sequence, an accented letter or a Devanagari syllable with its
+/// matras is one character. Use the grapheme counts for limits shown to users.
+/// `byteLength` (UTF-8) and `utf16Length` are for limits set by storage and
+/// transports; see `fits_limit`.
+///
+/// Graphemes, words and sentences are those of `segment_text` for the
+/// detected language. Words are runs of letters, digits and marks, so
+/// punctuation, symbols and emoji standing alone are not counted, and text
+/// detected as Thai, Chinese or Japanese is split into dictionary words.
+/// Sentences end at '.', '!', '?', '…', '।', '。' and line breaks, but not
+/// after abbreviations ("Dr.", "z.B.") or inside numbers.
+///
+/// Entity counts use the same rules as the rest of the crate: URLs and
+/// mentions as in `parse_mentions`, hashtags with at least one letter, and
+/// numbers as standalone digit runs ("1,234" but not "v1.2" or "3rd").
+/// Mentions, hashtags and numbers inside URLs or code are not counted.
+///
+/// Paragraphs are separated by blank lines. The longest word excludes
+/// punctuation ("world" in "hello, world!"), and the punctuation ratio counts
+/// punctuation marks (not symbols or emoji) per letter, so text without
+/// letters scores 0.
 #[wasm_bindgen]
-pub fn detect_language(text: &str) -> String {
-    if text.trim().is_empty() {
-        return String::from("en");
-    }
+pub fn get_text_stats(text: &str) -> JsValue {
+    to_js(&get_text_stats_report(text))
+}
 
-    let text_lower = text.to_lowercase();
-    let words: Vec<&str> = text_lower.split_whitespace().collect();
-    
-    if words.is_empty() {
-        return String::from("en");
-    }
+/// `get_text_stats` as a JSON string
+/// @deprecated Use `get_text_stats`, which returns an object instead of JSON text.
+#[deprecated(note = "use `get_text_stats`, which returns an object")]
+#[wasm_bindgen]
+pub fn get_text_stats_json(text: &str) -> String {
+    to_json(&get_text_stats_report(text), "{}")
+}
 
-    let mut scores: std::collections::HashMap<String, u32> = std::collections::HashMap::new();
-    
-    // English common words
-    let en_words = ["the", "be", "to", "of", "and", "a", "in", "that", "have", "i", "it", "for", "not", "on", "with", "he", "as", "you", "do", "at"];
-    // German common words
-    let de_words = ["der", "die", "und", "in", "den", "von", "zu", "das", "mit", "sich", "des", "auf", "für", "ist", "im", "dem", "nicht", "ein", "eine", "als"];
-    // French common words
-    let fr_words = ["le", "de", "et", "à", "un", "il", "être", "et", "en", "avoir", "que", "pour", "dans", "ce", "son", "une", "sur", "avec", "ne", "se"];
-    // Italian common words
-    let it_words = ["il", "di", "e", "la", "a", "un", "per", "è", "in", "una", "sono", "che", "si", "con", "non", "le", "da", "al", "i", "come"];
-    // Portuguese common words
-    let pt_words = ["o", "de", "e", "do", "da", "em", "um", "para", "é", "com", "não", "uma", "os", "no", "se", "na", "por", "mais", "as", "como"];
-    // Hindi common words (transliterated)
-    let hi_words = ["है", "और", "के", "में", "को", "से", "का", "की", "यह", "वह", "हो", "नहीं", "तो", "भी", "या", "पर", "इस", "उस", "जो", "कि"];
-    // Spanish common words
-    let es_words = ["el", "la", "de", "que", "y", "a", "en", "un", "ser", "se", "no", "haber", "por", "con", "su", "para", "como", "estar", "tener", "le"];
-    // Thai common words
-    let th_words = ["ที่", "เป็น", "และ", "ใน", "ของ", "จะ", "ได้", "ไม่", "มี", "ก็", "แล้ว", "กับ", "ให้", "ไป", "มา", "นี้", "นั้น", "เขา", "เธอ", "เรา"];
-
-    // Score based on common words
-    for word in words.iter().take(50) {
-        let word_trimmed = word.trim_matches(|c: char| !c.is_alphanumeric());
-        if word_trimmed.is_empty() {
-            continue;
-        }
+/// Get the writing-system make-up of Chinese or Japanese text
+/// Returns object: {"hanCount":5,"uniqueHanCount":4,"hiraganaCount":9,
+/// "katakanaCount":3,"latinCount":0,"otherLetterCount":0,
+/// "kanjiRatio":0.29,"kanaRatio":0.71,"latinRatio":0.0}
+///
+/// Ratios are shares of all letters, so punctuation, digits, spaces and
+/// emoji do not dilute them; text without letters has ratios of 0. A high
+/// kanji ratio with many distinct kanji marks harder Japanese, while
+/// learner messages lean on kana. Works on any text, but the counts are
+/// meant for Japanese and Chinese.
+#[wasm_bindgen]
+pub fn get_cjk_stats(text: &str) -> JsValue {
+    to_js(&stats::cjk_stats(text))
+}
 
-        if en_words.contains(&word_trimmed) {
-            *scores.entry(String::from("en")).or_insert(0) += 2;
-        }
-        if de_words.contains(&word_trimmed) {
-            *scores.entry(String::from("de")).or_insert(0) += 2;
-        }
-        if fr_words.contains(&word_trimmed) {
-            *scores.entry(String::from("fr")).or_insert(0) += 2;
-        }
-        if it_words.contains(&word_trimmed) {
-            *scores.entry(String::from("it")).or_insert(0) += 2;
-        }
-        if pt_words.contains(&word_trimmed) {
-            *scores.entry(String::from("pt")).or_insert(0) += 2;
-        }
-        if hi_words.contains(&word_trimmed) {
-            *scores.entry(String::from("hi")).or_insert(0) += 3;
-        }
-        if es_words.contains(&word_trimmed) {
-            *scores.entry(String::from("es")).or_insert(0) += 2;
-        }
-        if th_words.contains(&word_trimmed) {
-            *scores.entry(String::from("th")).or_insert(0) += 3;
-        }
+/// Length of text in a unit, for `fits_limit`
+fn length_in(text: &str, unit: &str) -> usize {
+    match unit {
+        "bytes" | "utf8" => text.len(),
+        "utf16" => text.encode_utf16().count(),
+        "characters" | "chars" => text.chars().count(),
+        _ => segmentation::grapheme_count(text),
     }
+}
+
+/// Check whether text fits a length limit
+/// Returns true when the text is at most `limit` units long
+///
+/// Units: "bytes" (or "utf8") for UTF-8 bytes, "utf16" for UTF-16 code units
+/// as counted by SMS gateways and the DOM, "characters" (or "chars") for
+/// Unicode scalar values, and "graphemes" for user-perceived characters.
+/// Any other unit counts graphemes.
+///
+/// @param text - Text to measure
+/// @param limit - Largest allowed length
+/// @param unit - "bytes", "utf16", "characters" or "graphemes"
+#[wasm_bindgen]
+pub fn fits_limit(text: &str, limit: u32, unit: &str) -> bool {
+    length_in(text, unit) <= limit as usize
+}
 
-    // Character-based heuristics
-    let _has_cyrillic = text.chars().any(|c| matches!(c, '\u{0400}'..='\u{04FF}'));
-    let _has_arabic = text.chars().any(|c| matches!(c, '\u{0600}'..='\u{06FF}'));
-    let has_devanagari = text.chars().any(|c| matches!(c, '\u{0900}'..='\u{097F}'));
-    let has_thai = text.chars().any(|c| matches!(c, '\u{0E00}'..='\u{0E7F}'));
-    
-    if has_devanagari {
-        *scores.entry(String::from("hi")).or_insert(0) += 10;
+/// Cut text to a length limit without splitting a character
+/// Returns the longest prefix, ending on a grapheme cluster boundary, that
+/// is at most `limit` units long (the whole text when it fits), so a cut
+/// never leaves half an emoji sequence, a letter without its accent or a
+/// lone surrogate. With `ellipsis`, text that had to be cut ends in "…",
+/// counted within the limit. Units are those of `fits_limit`.
+///
+/// @param text - Text to cut
+/// @param limit - Largest allowed length
+/// @param unit - "bytes", "utf16", "characters" or "graphemes"
+/// @param ellipsis - End cut text with "…" (optional, default false)
+#[wasm_bindgen]
+pub fn truncate_to_limit(text: &str, limit: u32, unit: &str, ellipsis: Option<bool>) -> String {
+    let limit = limit as usize;
+    if length_in(text, unit) <= limit {
+        return text.to_string();
     }
-    if has_thai {
-        *scores.entry(String::from("th")).or_insert(0) += 10;
+    let marker = if ellipsis.unwrap_or(false) { "…" } else { "" };
+    let Some(room) = limit.checked_sub(length_in(marker, unit)) else {
+        return String::new();
+    };
+
+    let mut end = 0;
+    let mut length = 0;
+    for cluster in segmentation::graphemes(text) {
+        length += length_in(cluster.text, unit);
+        if length > room {
+            break;
+        }
+        end = cluster.end;
     }
+    format!("{}{}", &text[..end], marker)
+}
 
-    // Diacritics hint at Romance languages
-    let has_french_diacritics = text.chars().any(|c| matches!(c, 'à' | 'â' | 'é' | 'è' | 'ê' | 'ë' | 'î' | 'ï' | 'ô' | 'ù' | 'û' | 'ü' | 'ÿ' | 'ç'));
-    let has_spanish_diacritics = text.chars().any(|c| matches!(c, 'á' | 'é' | 'í' | 'ó' | 'ú' | 'ñ' | 'ü'));
-    let has_portuguese_diacritics = text.chars().any(|c| matches!(c, 'á' | 'à' | 'â' | 'ã' | 'é' | 'ê' | 'í' | 'ó' | 'ô' | 'õ' | 'ú' | 'ü' | 'ç'));
-    let has_italian_diacritics = text.chars().any(|c| matches!(c, 'à' | 'è' | 'é' | 'ì' | 'ò' | 'ù'));
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VocabularyStats {
+    pub word_count: u32,
+    pub unique_word_count: u32,
+    pub type_token_ratio: f64,
+    pub top_words: Vec<keywords::KeywordCount>,
+}
 
-    if has_french_diacritics {
-        *scores.entry(String::from("fr")).or_insert(0) += 3;
-    }
-    if has_spanish_diacritics {
-        *scores.entry(String::from("es")).or_insert(0) += 3;
-    }
-    if has_portuguese_diacritics {
-        *scores.entry(String::from("pt")).or_insert(0) += 3;
-    }
-    if has_italian_diacritics {
-        *scores.entry(String::from("it")).or_insert(0) += 3;
-    }
+fn get_vocabulary_stats_report(text: &str, language: &str, top_n: u32) -> VocabularyStats {
+    let language = if language.is_empty() { detection::best_language(text).0 } else { language };
+    let tokens: Vec<String> = wordbreak::segment(text, language)
+        .iter()
+        .map(|word| word.text.to_lowercase())
+        .filter(|word| word.chars().any(char::is_alphabetic))
+        .collect();
 
-    // German-specific characters
-    let has_german_chars = text.chars().any(|c| matches!(c, 'ä' | 'ö' | 'ü' | 'ß'));
-    if has_german_chars {
-        *scores.entry(String::from("de")).or_insert(0) += 5;
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    for token in &tokens {
+        *counts.entry(token.clone()).or_insert(0) += 1;
     }
+    let unique_word_count = counts.len() as u32;
+    counts.retain(|word, _| keywords::is_content_word(word, language));
 
-    // Find language with highest score
-    let detected = scores.iter()
-        .max_by_key(|(_, score)| *score)
-        .map(|(lang, _)| lang.clone())
-        .unwrap_or_else(|| String::from("en"));
-
-    detected
+    VocabularyStats {
+        word_count: tokens.len() as u32,
+        unique_word_count,
+        type_token_ratio: if tokens.is_empty() { 0.0 } else { unique_word_count as f64 / tokens.len() as f64 },
+        top_words: keywords::rank_keywords(&counts, top_n as usize),
+    }
 }
 
-/// Get text statistics
-/// Returns JSON string with word count, character count, etc.
+/// Get vocabulary richness statistics
+/// Returns object: {"wordCount":12,"uniqueWordCount":9,"typeTokenRatio":0.75,
+/// "topWords":[{"word":"deploy","count":2}]}
+///
+/// Words are lowercased and must contain a letter; Thai, Chinese and
+/// Japanese are segmented into dictionary words. The type-token ratio is
+/// unique words divided by words and counts stop words. It falls as texts
+/// get longer, so compare it between texts of similar length (or use
+/// `mtld`). `topWords` are the most frequent content words, leaving out the
+/// language's stop words, numbers and single letters, ties alphabetically.
+///
+/// @param text - Text to analyze
+/// @param language - Language code; empty to detect it
+/// @param top_n - Number of most frequent words to return
 #[wasm_bindgen]
-pub fn get_text_stats(text: &str) -> String {
-    let words: Vec<&str> = text.split_whitespace().collect();
-    let word_count = words.len() as u32;
-    
-    let character_count = text.chars().count() as u32;
-    let character_count_no_spaces = text.chars().filter(|c| !c.is_whitespace()).count() as u32;
-    
-    let sentence_count = text.split(|c: char| c == '.' || c == '!' || c == '?')
-        .filter(|s| !s.trim().is_empty())
-        .count() as u32;
-    
-    let total_word_length: u32 = words.iter()
-        .map(|w| w.chars().count() as u32)
-        .sum();
-    
-    let average_word_length = if word_count > 0 {
-        total_word_length as f64 / word_count as f64
-    } else {
-        0.0
-    };
-
-    let stats = TextStats {
-        word_count,
-        character_count,
-        character_count_no_spaces,
-        sentence_count,
-        average_word_length,
-    };
+pub fn get_vocabulary_stats(text: &str, language: &str, top_n: u32) -> JsValue {
+    to_js(&get_vocabulary_stats_report(text, language, top_n))
+}
 
-    serde_json::to_string(&stats).unwrap_or_else(|_| String::from("{}"))
+/// `get_vocabulary_stats` as a JSON string
+/// @deprecated Use `get_vocabulary_stats`, which returns an object instead of JSON text.
+#[deprecated(note = "use `get_vocabulary_stats`, which returns an object")]
+#[wasm_bindgen]
+pub fn get_vocabulary_stats_json(text: &str, language: &str, top_n: u32) -> String {
+    to_json(&get_vocabulary_stats_report(text, language, top_n), "{}")
 }
 
 /// Normalize text for a specific language
+/// A shorthand for the common `Normalizer` pipelines; build a `Normalizer`
+/// for other steps or orders. Text is lowercased for matching, except Hindi and Thai, which have no
+/// case and are trimmed instead. Lowercasing follows the language: Turkish
+/// and Azerbaijani map "I" to dotless "ı" and "İ" to "i", and Greek sigma
+/// becomes "ς" at the end of a word.
+///
+/// `full_folding` applies full case folding on top, for search keys where
+/// spelling variants must meet: "ß" → "ss" (so "Straße" matches
+/// "STRASSE"), "ﬁ" → "fi" and final "ς" → "σ". German keeps "ß" without it.
+///
+/// `form` additionally puts the result in a Unicode normalization form, so
+/// that "é" typed as one character and as "e" plus a combining accent
+/// compare equal:
+/// - "NFC": composed, the form to store and compare
+/// - "NFD": decomposed into base letters and combining marks
+/// - "NFKC" / "NFKD": also folds compatibility variants (full-width "Ａ",
+///   "ﬁ", "²", half-width katakana) into their plain equivalents, for search
+///
+/// Forms follow the Unicode 14.0 character database. Omitting `form`, or
+/// an unknown name, leaves the form as typed.
+///
+/// `width` converts mixed-width input from Japanese input methods:
+/// - "narrow": full-width letters and digits ("ＡＢＣ１２３") become ASCII and
+///   half-width katakana ("ｶﾀｶﾅ") full-width, the form to match keywords in
+/// - "wide": ASCII and half-width katakana become full-width
+/// - "half": full-width letters, digits and katakana become half-width
+///
+/// @param form - "NFC", "NFD", "NFKC" or "NFKD" (optional)
+/// @param full_folding - Fold "ß" to "ss" and similar (optional, default false)
+/// @param width - "narrow", "wide" or "half" (optional)
 #[wasm_bindgen]
-pub fn normalize_text(text: &str, language: &str) -> String {
-    let mut normalized = text.to_string();
-    
-    match language {
-        "de" => {
-            // German: lowercase but preserve ß
-            normalized = normalized.to_lowercase();
-        }
-        "fr" | "es" | "it" | "pt" => {
-            // Romance languages: lowercase
-            normalized = normalized.to_lowercase();
-        }
-        "hi" => {
-            // Hindi: trim whitespace
-            normalized = normalized.trim().to_string();
-        }
-        "th" => {
-            // Thai: trim whitespace
-            normalized = normalized.trim().to_string();
-        }
-        _ => {
-            // English: lowercase
-            normalized = normalized.to_lowercase();
-        }
+pub fn normalize_text(text: &str, language: &str, form: Option<String>, full_folding: Option<bool>, width: Option<String>) -> String {
+    text_normalizer(language, form, full_folding, width).normalize(text)
+}
+
+/// `normalize_text`, also listing every edit it made
+/// Returns object: {"text":"strasse","edits":[{"step":"case_fold","kind":"replaced",
+/// "original":"S","replacement":"s","start":0,"end":1},{"step":"case_fold",
+/// "kind":"replaced","original":"ß","replacement":"ss","start":4,"end":6}]}
+///
+/// For an audit trail of automated changes: each edit names the step
+/// ("width", "case_fold", "trim" or "unicode_form"), what it removed,
+/// inserted or replaced, and the byte range in `text`, as
+/// `Normalizer.normalize_with_edits`. Parameters are those of
+/// `normalize_text`.
+#[wasm_bindgen]
+pub fn normalize_text_with_edits(text: &str, language: &str, form: Option<String>, full_folding: Option<bool>, width: Option<String>) -> JsValue {
+    text_normalizer(language, form, full_folding, width).normalize_with_edits(text)
+}
+
+/// The pipeline behind `normalize_text`
+fn text_normalizer(language: &str, form: Option<String>, full_folding: Option<bool>, width: Option<String>) -> normalize::Normalizer {
+    let mut normalizer = normalize::Normalizer::new();
+    if let Some(width) = width {
+        normalizer = normalizer.width(&width);
+    }
+    normalizer = match language {
+        "hi" | "th" => normalizer.trim(),
+        _ => normalizer.case_fold(language, full_folding.unwrap_or(false)),
+    };
+    if let Some(form) = form {
+        normalizer = normalizer.unicode_form(&form);
     }
-    
-    normalized
+    normalizer
 }
 
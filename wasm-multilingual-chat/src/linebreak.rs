@@ -0,0 +1,196 @@
+//! Line-break opportunities for wrapping messages
+//!
+//! **Learning Point**: UAX #14 gives every character a line-breaking class
+//! and decides, for each pair of neighbours, whether a line may end between
+//! them: never before a closing bracket or "!", never after an opening one,
+//! anywhere between two ideographs, after spaces and hyphens otherwise.
+//! Thai, Lao, Khmer and Burmese (class SA) are written without spaces, so
+//! the spec leaves their breaks to a dictionary. This module implements the
+//! pair rules over grapheme clusters, which keeps combining marks, emoji
+//! sequences and CRLF together (LB9), and breaks Thai at the words found by
+//! `segment_words`.
+
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+use std::collections::HashSet;
+use crate::emoji::is_emoji;
+use crate::js::to_js;
+use crate::script::{script_of, Script};
+use crate::segmentation::{graphemes, is_extend};
+use crate::wordbreak;
+
+/// Line-breaking classes of UAX #14 that change break decisions here
+#[derive(Clone, Copy, PartialEq)]
+enum BreakClass {
+    /// Mandatory break after: line feeds, carriage returns, paragraph separators
+    Bk,
+    Sp,
+    /// Zero width space
+    Zw,
+    /// Word joiner and byte order mark
+    Wj,
+    /// No-break spaces and hyphens
+    Gl,
+    /// Opening brackets and quotes ("(", "「", "„", "¿")
+    Op,
+    /// Closing parentheses ")", "]", "}"
+    Cp,
+    /// Other closing punctuation ("」", "。", "、")
+    Cl,
+    /// Ambiguous quotes
+    Qu,
+    /// "!" and "?"
+    Ex,
+    /// Infix separators: "," "." ":" ";"
+    Is,
+    Sy,
+    /// Characters that may not start a line ("ー", "々", "・")
+    Ns,
+    Hy,
+    /// Break after: tabs, soft hyphens, en dashes, dandas
+    Ba,
+    /// Em dash
+    B2,
+    /// Ellipsis
+    In,
+    /// Currency and "+" before numbers
+    Pr,
+    /// "%" and units after numbers
+    Po,
+    Nu,
+    /// Ideographs, kana, Hangul syllables and emoji
+    Id,
+    /// Thai, Lao, Khmer and Burmese
+    Sa,
+    /// Letters and everything else
+    Al,
+}
+
+use BreakClass::*;
+
+fn break_class(c: char, language: &str) -> BreakClass {
+    match c {
+        '\n' | '\r' | '\u{0B}' | '\u{0C}' | '\u{85}' | '\u{2028}' | '\u{2029}' => Bk,
+        ' ' => Sp,
+        '\u{200B}' => Zw,
+        '\u{2060}' | '\u{FEFF}' => Wj,
+        '\u{00A0}' | '\u{202F}' | '\u{2007}' | '\u{2011}' | '\u{034F}' => Gl,
+        '(' | '[' | '{' | '¡' | '¿' | '‚' | '„' | '⁅' | '〈' | '《' | '「' | '『' | '【' | '〔' | '〖' | '〘'
+        | '〚' | '〝' | '（' | '［' | '｛' | '｟' | '｢' => Op,
+        ')' | ']' | '}' | '）' | '］' => Cp,
+        '⁆' | '〉' | '》' | '」' | '』' | '】' | '〕' | '〗' | '〙' | '〛' | '〞' | '〟' | '、' | '。' | '，'
+        | '．' | '｝' | '｠' | '｣' | '､' | '｡' => Cl,
+        '"' | '\'' | '«' | '»' | '‘' | '’' | '‛' | '“' | '”' | '‟' | '‹' | '›' => Qu,
+        '!' | '?' | '！' | '？' | '؟' => Ex,
+        ',' | '.' | ':' | ';' | '։' | '،' | '؛' | '\u{037E}' => Is,
+        '/' => Sy,
+        '々' | '〻' | 'ゝ' | 'ゞ' | '゠' | '・' | 'ー' | 'ヽ' | 'ヾ' | '〜' | '‼' | '‽' | '⁇' | '⁈' | '⁉' | '：'
+        | '；' | '･' | 'ｰ' => Ns,
+        '-' => Hy,
+        '\t' | '\u{00AD}' | '\u{2010}' | '\u{2012}' | '\u{2013}' | '|' | '\u{0964}' | '\u{0965}' | '\u{1680}'
+        | '\u{2000}'..='\u{2006}' | '\u{2008}'..='\u{200A}' | '\u{3000}' => Ba,
+        '\u{2014}' => B2,
+        '…' | '‥' => In,
+        '$' | '+' | '\\' | '£' | '¥' | '€' | '₹' | '₩' | '₽' | '₺' | '₱' | '₪' => Pr,
+        '%' | '‰' | '‱' | '¢' | '°' | '′' | '″' | '℃' | '℉' => Po,
+        '\u{0E00}'..='\u{0E4F}' | '\u{0E5A}'..='\u{0EFF}' | '\u{1000}'..='\u{103F}' | '\u{104A}'..='\u{109F}'
+        | '\u{1780}'..='\u{17FF}' => if c.is_numeric() { Nu } else { Sa },
+        '\u{FF01}'..='\u{FF60}' | '\u{FE30}'..='\u{FE4F}' | '\u{3000}'..='\u{303F}' | '\u{3100}'..='\u{312F}' => Id,
+        _ if c.is_numeric() => Nu,
+        _ => match script_of(c) {
+            Script::Han | Script::Hiragana | Script::Katakana => Id,
+            // Korean is written with spaces; keep its words whole
+            Script::Hangul if language == "ko" => Al,
+            Script::Hangul if !is_extend(c) => Id,
+            _ if is_emoji(c) => Id,
+            _ => Al,
+        },
+    }
+}
+
+/// Whether a line may end between two clusters, and whether it must
+/// `before` is the class ahead of any run of spaces that `previous` ends.
+fn pair_break(previous: BreakClass, before: BreakClass, next: BreakClass, word_start: bool) -> Option<bool> {
+    // Line breaking classes of SA characters outside a dictionary run (LB1)
+    let resolve = |class: BreakClass| if class == Sa { Al } else { class };
+    match (previous, next) {
+        (Bk, _) => return Some(true),
+        (_, Bk | Sp | Zw) => return None,
+        _ if before == Zw => return Some(false),
+        (Wj | Gl, _) | (_, Wj) => return None,
+        (previous, Gl) if !matches!(previous, Sp | Ba | Hy) => return None,
+        (_, Cl | Cp | Ex | Is | Sy) => return None,
+        _ if before == Op => return None,
+        _ if before == Qu && next == Op => return None,
+        _ if matches!(before, Cl | Cp) && next == Ns => return None,
+        _ if before == B2 && next == B2 => return None,
+        (Sp, _) => return Some(false),
+        (Qu, _) | (_, Qu) => return None,
+        (_, Ba | Hy | Ns | In) => return None,
+        (Sa, Sa) => return if word_start { Some(false) } else { None },
+        _ => {}
+    }
+    match (resolve(previous), resolve(next)) {
+        (Al, Nu) | (Nu, Al) => None,
+        (Pr | Po, Al) | (Al, Pr | Po) | (Pr, Id) | (Id, Po) => None,
+        (Cl | Cp | Nu, Po | Pr) | (Po | Pr, Op | Nu) | (Hy | Is | Nu | Sy, Nu) => None,
+        (Al, Al) | (Is, Al) => None,
+        (Al | Nu, Op) | (Cp, Al | Nu) => None,
+        _ => Some(false),
+    }
+}
+
+/// A position where a line may end
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakOpportunity {
+    /// Byte offset of the first character of the next line
+    pub offset: usize,
+    pub char_offset: usize,
+    /// After a line break character, where the line must end
+    pub required: bool,
+}
+
+pub fn break_opportunities(text: &str, language: &str) -> Vec<BreakOpportunity> {
+    let thai_words: HashSet<usize> = wordbreak::segment(text, "th").iter().map(|word| word.start).collect();
+    let mut opportunities = Vec::new();
+    let mut previous: Option<BreakClass> = None;
+    let mut before = Al;
+    let mut char_offset = 0;
+    for cluster in graphemes(text) {
+        let first = cluster.text.chars().next().unwrap_or(' ');
+        // A combining mark with nothing to attach to is a letter (LB10)
+        let class = if is_extend(first) { Al } else { break_class(first, language) };
+        if let Some(previous) = previous {
+            if previous != Sp {
+                before = previous;
+            }
+            if let Some(required) = pair_break(previous, before, class, thai_words.contains(&cluster.start)) {
+                opportunities.push(BreakOpportunity { offset: cluster.start, char_offset, required });
+            }
+        }
+        previous = Some(class);
+        char_offset += cluster.text.chars().count();
+    }
+    opportunities
+}
+
+/// Positions where a message may wrap onto a new line
+/// Returns array: [{"offset":6,"charOffset":6,"required":false}]
+///
+/// Each position is where the next line would start: after spaces, hyphens
+/// and dashes, between ideographs and kana (but not before "。", "ー" or
+/// small punctuation that may not start a line), and between Thai words,
+/// using the same dictionary as `segment_words`. Positions after a line
+/// feed are `required`. The end of the text is not listed.
+///
+/// Lao, Khmer and Burmese are kept whole between spaces and punctuation,
+/// as there is no dictionary for them.
+///
+/// @param text - Text to wrap
+/// @param language - Language code; "ko" keeps Korean words whole instead
+/// of breaking between Hangul syllables
+#[wasm_bindgen]
+pub fn line_break_opportunities(text: &str, language: &str) -> JsValue {
+    to_js(&break_opportunities(text, language))
+}
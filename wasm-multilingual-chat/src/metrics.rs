@@ -0,0 +1,549 @@
+//! Writing style and complexity metrics
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use serde::Serialize;
+use crate::lexicon::{is_stopword, stopwords};
+use crate::script::{expected_script, script_counts};
+use std::collections::{HashMap, HashSet};
+use crate::segmentation::{grapheme_count, sentences, sentences_in, words};
+use crate::reading::estimate_syllables;
+
+/// Count content words and function words in a text
+/// Function words are the language's stop words; tokens without any
+/// letter (numbers) count as neither.
+pub fn content_function_counts(text: &str, language: &str) -> (usize, usize) {
+    let mut content = 0;
+    let mut function = 0;
+    for word in words(text) {
+        let lower = word.text.to_lowercase();
+        if is_stopword(&lower, language) {
+            function += 1;
+        } else if lower.chars().any(char::is_alphabetic) {
+            content += 1;
+        }
+    }
+    (content, function)
+}
+
+/// Ratio of content words to function words
+/// High values suggest dense or terse writing ("Deploy fixed, tests green"),
+/// low values verbose or casual writing.
+///
+/// When the text has no function words the ratio is undefined; the content
+/// word count is returned instead, so "ok thanks" scores 2.0.
+///
+/// @param text - Message text
+/// @param language - Language code selecting the function word list
+/// @returns Content words per function word, 0.0 for empty input
+#[wasm_bindgen]
+pub fn content_function_ratio(text: &str, language: &str) -> f64 {
+    let (content, function) = content_function_counts(text, language);
+    if function == 0 {
+        return content as f64;
+    }
+    content as f64 / function as f64
+}
+
+/// Characters per word for scripts written without spaces
+fn characters_per_word(language: &str) -> Option<f64> {
+    match language {
+        "th" => Some(4.0),
+        "zh" | "ja" => Some(1.5),
+        _ => None,
+    }
+}
+
+/// Typical word length in graphemes, used to judge vocabulary complexity
+fn typical_word_length(language: &str) -> f64 {
+    match language {
+        "de" => 6.0,
+        "fr" | "es" => 4.9,
+        "it" => 5.0,
+        "pt" => 4.8,
+        "hi" => 3.7,
+        _ => 4.7,
+    }
+}
+
+/// Length in word equivalents
+/// Scripts without spaces count letters divided by their average word length.
+fn length_units(text: &str, language: &str) -> f64 {
+    match characters_per_word(language) {
+        Some(per_word) => text.chars().filter(|c| c.is_alphabetic()).count() as f64 / per_word,
+        None => words(text).len() as f64,
+    }
+}
+
+/// Word equivalents at which the length factor reaches 63% of its maximum
+const EFFORT_LENGTH_SCALE: f64 = 15.0;
+
+/// Distinct words beyond this count no longer raise the diversity component
+const EFFORT_DIVERSITY_CAP: usize = 50;
+
+/// Sentences shorter than this (in word equivalents) count as fragments
+const EFFORT_MIN_SENTENCE_UNITS: f64 = 3.0;
+
+/// Score how much effort went into a message
+/// Returns a score from 0.0 to 1.0: "ok" scores about 0.05, a short
+/// paragraph of complete sentences about 0.8.
+///
+/// score = length × (0.4 + 0.25 × diversity + 0.2 × completeness + 0.15 × complexity)
+///
+/// - length: 1 − e^(−units / 15), where units are words (or, for Thai,
+///   Chinese and Japanese, letters divided by the average word length).
+///   It saturates, so very long messages cannot score above 1.0.
+/// - diversity: distinct lowercased words divided by the word count, with
+///   the word count capped at 50 so long texts are not penalized for
+///   repeating function words
+/// - completeness: share of sentences with at least three word equivalents
+/// - complexity: average word length relative to the language's typical
+///   length, capped at 1.0 (0.5 for scripts without spaces)
+///
+/// @param text - Message text
+/// @param language - Language code used for calibration
+/// @returns Effort score, 0.0 for empty input
+#[wasm_bindgen]
+pub fn effort_score(text: &str, language: &str) -> f64 {
+    let units = length_units(text, language);
+    if units == 0.0 {
+        return 0.0;
+    }
+    let length = 1.0 - (-units / EFFORT_LENGTH_SCALE).exp();
+
+    let tokens = words(text);
+    let distinct: HashSet<String> = tokens.iter().map(|word| word.text.to_lowercase()).collect();
+    let diversity = (distinct.len() as f64 / tokens.len().min(EFFORT_DIVERSITY_CAP) as f64).min(1.0);
+
+    let sentences: Vec<&str> = text
+        .split(['.', '!', '?', '。', '！', '？', '।'])
+        .filter(|sentence| !sentence.trim().is_empty())
+        .collect();
+    let complete = sentences
+        .iter()
+        .filter(|sentence| length_units(sentence, language) >= EFFORT_MIN_SENTENCE_UNITS)
+        .count();
+    let completeness = complete as f64 / sentences.len().max(1) as f64;
+
+    let complexity = if characters_per_word(language).is_some() {
+        0.5
+    } else {
+        let letters: usize = tokens.iter().map(|word| grapheme_count(word.text)).sum();
+        (letters as f64 / tokens.len() as f64 / typical_word_length(language)).min(1.0)
+    };
+
+    length * (0.4 + 0.25 * diversity + 0.2 * completeness + 0.15 * complexity)
+}
+
+/// Stock phrases and literal calques typical of machine translation
+fn machine_translation_phrases(language: &str) -> &'static [&'static str] {
+    match language {
+        "de" => &["bitte beachten sie", "es ist wichtig zu beachten", "in der reihenfolge", "am ende des tages", "zögern sie nicht"],
+        "fr" => &["veuillez noter que", "il est important de noter", "faire du sens", "à la fin de la journée", "en ordre de", "n'hésitez pas à"],
+        "it" => &["si prega di notare", "è importante notare", "fa senso", "alla fine del giorno", "in ordine di", "non esitate a"],
+        "pt" => &["por favor note que", "é importante notar", "no final do dia", "em ordem de", "aplicar para", "não hesite em"],
+        "hi" => &["कृपया ध्यान दें", "यह ध्यान रखना महत्वपूर्ण है", "दिन के अंत में", "संकोच न करें"],
+        "es" => &["tenga en cuenta que", "es importante tener en cuenta", "hace sentido", "al final del día", "en orden de", "aplicar para", "no dude en"],
+        "th" => &["โปรดทราบว่า", "สิ่งสำคัญคือต้องทราบ", "ในตอนท้ายของวัน", "อย่าลังเลที่จะ"],
+        _ => &["please note that", "it should be noted that", "it is important to note", "do the needful", "kindly", "with regards to", "as per", "do not hesitate to"],
+    }
+}
+
+/// Weights of the machine translation signals (they sum to 1.0)
+const MT_WEIGHT_FOREIGN_SCRIPT: f64 = 0.3;
+const MT_WEIGHT_LEFTOVER_ENGLISH: f64 = 0.15;
+const MT_WEIGHT_PHRASES: f64 = 0.3;
+const MT_WEIGHT_REPETITION: f64 = 0.15;
+const MT_WEIGHT_DIVERSITY: f64 = 0.1;
+
+/// Words needed before low lexical diversity counts as a signal
+const MT_MIN_WORDS_FOR_DIVERSITY: usize = 20;
+
+/// Score how likely a message is machine-translated
+/// Returns a rough likelihood from 0.0 to 1.0; it flags text for review
+/// and is not proof either way.
+///
+/// Weighted signals, each scaled to 0.0–1.0:
+/// - foreign script (0.3): letters outside the language's script, when they
+///   are a minority (below half) of the letters; 15% or more saturates
+/// - leftover English (0.15): English function words in text of another
+///   language, saturating at 5% of the words
+/// - stock phrases (0.3): phrases and calques typical of MT output ("please
+///   note that", "hace sentido"); two hits saturate
+/// - repetition (0.15): share of word trigrams that occur more than once
+/// - low diversity (0.1): Guiraud's index (distinct words / √words) below
+///   5, reaching 1.0 at 2; only for messages of 20 words or more
+///
+/// Genuine code-switching chat (Hinglish, English terms in German) also
+/// raises the first two signals, so scores below 0.3 are unremarkable.
+///
+/// @param text - Message text
+/// @param language - Language the text is supposed to be in
+/// @returns Likelihood, 0.0 for empty input
+#[wasm_bindgen]
+pub fn machine_translation_likelihood(text: &str, language: &str) -> f64 {
+    let tokens: Vec<String> = words(text).iter().map(|word| word.text.to_lowercase()).collect();
+    if tokens.is_empty() {
+        return 0.0;
+    }
+
+    let expected = expected_script(language);
+    let scripts = script_counts(text);
+    let letters: usize = scripts.iter().map(|(_, count)| count).sum();
+    let foreign: usize = scripts.iter().filter(|(script, _)| *script != expected).map(|(_, count)| count).sum();
+    let foreign = foreign as f64 / letters.max(1) as f64;
+    let foreign_script = if foreign < 0.5 { (foreign / 0.15).min(1.0) } else { 0.0 };
+
+    let leftover_english = if language == "en" {
+        0.0
+    } else {
+        let own = stopwords(language);
+        let english = tokens
+            .iter()
+            .filter(|word| is_stopword(word, "en") && !own.contains(&word.as_str()))
+            .count();
+        (english as f64 / tokens.len() as f64 / 0.05).min(1.0)
+    };
+
+    let lowered = text.to_lowercase();
+    let phrase_hits: usize = machine_translation_phrases(language)
+        .iter()
+        .map(|phrase| lowered.matches(phrase).count())
+        .sum();
+    let phrases = (phrase_hits as f64 / 2.0).min(1.0);
+
+    let mut trigrams: HashMap<&[String], usize> = HashMap::new();
+    for trigram in tokens.windows(3) {
+        *trigrams.entry(trigram).or_default() += 1;
+    }
+    let repeated: usize = trigrams.values().filter(|count| **count > 1).sum();
+    let repetition = repeated as f64 / tokens.len().saturating_sub(2).max(1) as f64;
+
+    let diversity = if tokens.len() >= MT_MIN_WORDS_FOR_DIVERSITY {
+        let distinct: HashSet<&String> = tokens.iter().collect();
+        let guiraud = distinct.len() as f64 / (tokens.len() as f64).sqrt();
+        ((5.0 - guiraud) / 3.0).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    MT_WEIGHT_FOREIGN_SCRIPT * foreign_script
+        + MT_WEIGHT_LEFTOVER_ENGLISH * leftover_english
+        + MT_WEIGHT_PHRASES * phrases
+        + MT_WEIGHT_REPETITION * repetition
+        + MT_WEIGHT_DIVERSITY * diversity
+}
+
+/// Punctuation habits compared by `stylometric_similarity`
+const STYLE_PUNCTUATION: [&str; 10] = [",", ".", "!", "?", ";", ":", "...", "-", "(", "\""];
+
+/// Style features of a text, independent of its topic
+struct StyleFingerprint {
+    /// Relative frequency of each function word of the language
+    function_words: Vec<f64>,
+    /// Uses of each `STYLE_PUNCTUATION` mark per word
+    punctuation: Vec<f64>,
+    /// Average word length, average sentence length, share of ALL-CAPS
+    /// words and share of sentences starting in lowercase
+    shape: [f64; 4],
+}
+
+fn style_fingerprint(text: &str, language: &str) -> Option<StyleFingerprint> {
+    let tokens = words(text);
+    if tokens.is_empty() {
+        return None;
+    }
+    let total = tokens.len() as f64;
+    let lowered: Vec<String> = tokens.iter().map(|word| word.text.to_lowercase()).collect();
+
+    let function_words = stopwords(language)
+        .iter()
+        .map(|stopword| lowered.iter().filter(|word| word == stopword).count() as f64 / total)
+        .collect();
+    let punctuation = STYLE_PUNCTUATION
+        .iter()
+        .map(|mark| {
+            let count = match *mark {
+                "." => text.matches('.').count().saturating_sub(3 * text.matches("...").count()),
+                _ => text.matches(mark).count(),
+            };
+            count as f64 / total
+        })
+        .collect();
+
+    let split = sentences(text);
+    let letters: usize = tokens.iter().map(|word| grapheme_count(word.text)).sum();
+    let shouting = tokens
+        .iter()
+        .filter(|word| word.text.chars().count() > 1 && word.text.chars().all(|c| !c.is_lowercase()) && word.text.chars().any(char::is_uppercase))
+        .count();
+    let lowercase_starts = split
+        .iter()
+        .filter(|sentence| sentence.text.chars().find(|c| c.is_alphabetic()).is_some_and(char::is_lowercase))
+        .count();
+
+    Some(StyleFingerprint {
+        function_words,
+        punctuation,
+        shape: [
+            letters as f64 / total,
+            total / split.len().max(1) as f64,
+            shouting as f64 / total,
+            lowercase_starts as f64 / split.len().max(1) as f64,
+        ],
+    })
+}
+
+/// Cosine similarity, or `None` when either vector is all zeros
+fn cosine(a: &[f64], b: &[f64]) -> Option<f64> {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+    (norm_a > 0.0 && norm_b > 0.0).then(|| dot / (norm_a * norm_b))
+}
+
+/// Similarity of two non-negative numbers: 1.0 when equal, 0.0 when either is 0
+fn ratio_similarity(a: f64, b: f64) -> f64 {
+    if a == b {
+        1.0
+    } else {
+        a.min(b) / a.max(b)
+    }
+}
+
+/// Weights of the fingerprint parts
+const STYLE_WEIGHT_FUNCTION_WORDS: f64 = 0.5;
+const STYLE_WEIGHT_PUNCTUATION: f64 = 0.3;
+const STYLE_WEIGHT_SHAPE: f64 = 0.2;
+
+/// Compare the writing style of two texts
+/// Returns a similarity from 0.0 to 1.0, where higher means the texts are
+/// more likely written by the same person.
+///
+/// Each text is reduced to a fingerprint that ignores its topic:
+/// - function words (weight 0.5): cosine similarity of the relative
+///   frequencies of the language's function words
+/// - punctuation (0.3): cosine similarity of the rates of , . ! ? ; : ...
+///   - ( and " per word
+/// - shape (0.2): mean ratio similarity of average word length, average
+///   sentence length, share of ALL-CAPS words and share of sentences that
+///   start in lowercase
+///
+/// A part that is empty in either text (no function words, no
+/// punctuation) is left out and the remaining weights are rescaled.
+/// Empty input returns 0.0.
+///
+/// Limitations: this is a weak moderation signal, not attribution. Short
+/// messages (under ~50 words) give noisy fingerprints, people adapt their
+/// style to the conversation, and a deliberate change of punctuation or
+/// casing defeats it. Both texts are assumed to be in `language`.
+///
+/// @param a - First text
+/// @param b - Second text
+/// @param language - Language code selecting the function words
+#[wasm_bindgen]
+pub fn stylometric_similarity(a: &str, b: &str, language: &str) -> f64 {
+    let (Some(a), Some(b)) = (style_fingerprint(a, language), style_fingerprint(b, language)) else {
+        return 0.0;
+    };
+
+    let shape = a.shape.iter().zip(&b.shape).map(|(x, y)| ratio_similarity(*x, *y)).sum::<f64>() / a.shape.len() as f64;
+    let parts = [
+        (cosine(&a.function_words, &b.function_words), STYLE_WEIGHT_FUNCTION_WORDS),
+        (cosine(&a.punctuation, &b.punctuation), STYLE_WEIGHT_PUNCTUATION),
+        (Some(shape), STYLE_WEIGHT_SHAPE),
+    ];
+
+    let weight: f64 = parts.iter().filter(|(score, _)| score.is_some()).map(|(_, weight)| weight).sum();
+    let score: f64 = parts.iter().filter_map(|(score, weight)| score.map(|score| score * weight)).sum();
+    score / weight
+}
+
+/// Type-token ratio at which MTLD closes a factor (McCarthy & Jarvis, 2010)
+const MTLD_THRESHOLD: f64 = 0.72;
+
+/// Fewest words for which MTLD is reported
+const MTLD_MIN_WORDS: usize = 50;
+
+/// Returned by `mtld` for texts below `MTLD_MIN_WORDS`
+const MTLD_TOO_SHORT: f64 = -1.0;
+
+/// Lowercased word types for lexical diversity
+/// Numbers are skipped, and elided function words are split off their
+/// word ("l'homme" → "l" + "homme") when the prefix is a stop word of the
+/// language, so the noun counts as the same type with or without article.
+fn diversity_tokens(text: &str, language: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for word in words(text) {
+        let lower = word.text.to_lowercase();
+        if lower.chars().all(char::is_numeric) {
+            continue;
+        }
+        match lower.split_once(['\'', '’']) {
+            Some((prefix, rest)) if !rest.is_empty() && is_stopword(prefix, language) => {
+                tokens.push(prefix.to_string());
+                tokens.push(rest.to_string());
+            }
+            _ => tokens.push(lower),
+        }
+    }
+    tokens
+}
+
+/// MTLD factor count in one direction, including the partial last factor
+fn mtld_factors<'a>(tokens: impl Iterator<Item = &'a String>) -> f64 {
+    let mut factors = 0.0;
+    let mut types: HashSet<&String> = HashSet::new();
+    let mut count = 0;
+
+    for token in tokens {
+        types.insert(token);
+        count += 1;
+        if (types.len() as f64 / count as f64) <= MTLD_THRESHOLD {
+            factors += 1.0;
+            types.clear();
+            count = 0;
+        }
+    }
+
+    if count > 0 {
+        let ratio = types.len() as f64 / count as f64;
+        factors += (1.0 - ratio) / (1.0 - MTLD_THRESHOLD);
+    }
+    factors
+}
+
+/// Measure of Textual Lexical Diversity (MTLD)
+/// Returns the mean length of word runs that keep a type-token ratio
+/// above 0.72; higher means a richer vocabulary. Unlike the plain
+/// type-token ratio it does not fall as texts get longer.
+///
+/// The text is read forwards and backwards. In each direction, every time
+/// the running ratio drops to 0.72 one factor is counted and counting
+/// restarts; the leftover run adds a partial factor of
+/// (1 − ratio) / (1 − 0.72). Each direction's score is words / factors,
+/// and the result is their mean. Tokens are lowercased words without pure
+/// numbers; function words are kept, as the 0.72 calibration assumes them.
+///
+/// Texts with fewer than 50 words return -1.0, since MTLD is unreliable
+/// on short texts. A direction with less than one full factor scores the
+/// word count, so the result never exceeds the number of words.
+///
+/// @param text - Text to measure
+/// @param language - Language code, used to split elided articles ("l'")
+#[wasm_bindgen]
+pub fn mtld(text: &str, language: &str) -> f64 {
+    let tokens = diversity_tokens(text, language);
+    if tokens.len() < MTLD_MIN_WORDS {
+        return MTLD_TOO_SHORT;
+    }
+
+    let total = tokens.len() as f64;
+    let score = |factors: f64| if factors > 1.0 { total / factors } else { total };
+    (score(mtld_factors(tokens.iter())) + score(mtld_factors(tokens.iter().rev()))) / 2.0
+}
+
+/// Languages whose syllables `estimate_syllables` counts with their own rules
+fn has_syllable_rules(language: &str) -> bool {
+    matches!(language, "en" | "de" | "fr" | "it" | "pt" | "es" | "nl" | "pl" | "tr" | "vi" | "sv" | "cs" | "hi" | "hi-Latn" | "ru" | "uk" | "bg" | "sr")
+}
+
+/// Flesch reading ease as calibrated for a language
+/// Returns the formula name and the score from average sentence length
+/// (words) and average syllables per word.
+fn flesch_reading_ease(language: &str, sentence_length: f64, syllables_per_word: f64) -> (&'static str, f64) {
+    match language {
+        "de" => ("amstad", 180.0 - sentence_length - 58.5 * syllables_per_word),
+        "fr" => ("kandelMoles", 207.0 - 1.015 * sentence_length - 73.6 * syllables_per_word),
+        "es" => ("fernandezHuerta", 206.84 - 0.60 * 100.0 * syllables_per_word - 1.02 * sentence_length),
+        "it" => ("franchinaVacca", 217.0 - 1.3 * sentence_length - 0.6 * 100.0 * syllables_per_word),
+        "pt" => ("martins", 248.835 - 1.015 * sentence_length - 84.6 * syllables_per_word),
+        "nl" => ("douma", 206.835 - 0.93 * sentence_length - 77.0 * syllables_per_word),
+        "ru" => ("oborneva", 206.835 - 1.3 * sentence_length - 60.1 * syllables_per_word),
+        _ => ("flesch", 206.835 - 1.015 * sentence_length - 84.6 * syllables_per_word),
+    }
+}
+
+/// Words with more letters than this are long words for LIX
+const LIX_LONG_WORD: usize = 6;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Readability {
+    words: usize,
+    sentences: usize,
+    syllables: Option<usize>,
+    long_words: usize,
+    formula: Option<&'static str>,
+    flesch_reading_ease: Option<f64>,
+    flesch_kincaid_grade: Option<f64>,
+    lix: Option<f64>,
+}
+
+fn get_readability_report(text: &str, language: &str) -> Readability {
+    let tokens: Vec<&str> = words(text)
+        .iter()
+        .map(|word| word.text)
+        .filter(|word| word.chars().any(char::is_alphabetic))
+        .collect();
+    let sentence_count = sentences_in(text, language).iter().filter(|sentence| sentence.text.chars().any(char::is_alphabetic)).count();
+    let long_words = tokens.iter().filter(|word| grapheme_count(word) > LIX_LONG_WORD).count();
+    let syllables = has_syllable_rules(language).then(|| tokens.iter().map(|word| estimate_syllables(word, language)).sum::<usize>());
+
+    let spaced = characters_per_word(language).is_none() && !tokens.is_empty();
+    let word_total = tokens.len() as f64;
+    let sentence_length = word_total / sentence_count.max(1) as f64;
+    let flesch = syllables.filter(|_| spaced).map(|syllables| {
+        let per_word = syllables as f64 / word_total;
+        let (formula, ease) = flesch_reading_ease(language, sentence_length, per_word);
+        (formula, ease, 0.39 * sentence_length + 11.8 * per_word - 15.59)
+    });
+
+    Readability {
+        words: tokens.len(),
+        sentences: sentence_count,
+        syllables,
+        long_words,
+        formula: flesch.map(|(formula, _, _)| formula),
+        flesch_reading_ease: flesch.map(|(_, ease, _)| ease),
+        flesch_kincaid_grade: flesch.map(|(_, _, grade)| grade),
+        lix: spaced.then(|| sentence_length + 100.0 * long_words as f64 / word_total),
+    }
+}
+
+/// Score how hard a text is to read
+/// Returns object: {"words":42,"sentences":3,"syllables":61,"longWords":9,
+/// "formula":"flesch","fleschReadingEase":71.2,"fleschKincaidGrade":6.9,"lix":35.4}
+///
+/// - fleschReadingEase: 0–100, higher is easier (60–70 is plain
+///   language). German, French, Spanish, Italian, Portuguese, Dutch and
+///   Russian use their own calibration (Amstad, Kandel & Moles, Fernández
+///   Huerta, Franchina & Vacca, Martins, Douma, Oborneva), named in
+///   `formula`; other languages use the English formula.
+/// - fleschKincaidGrade: US school grade, 0.39 × words/sentence +
+///   11.8 × syllables/word − 15.59. It is calibrated on English, so compare
+///   it only between texts of the same language.
+/// - lix: words/sentence + 100 × long words/words, where long words have
+///   more than 6 letters; under 30 is very easy, over 50 hard, and the
+///   scale works across Latin and Cyrillic languages.
+///
+/// Syllables are estimated per word by `estimate_syllables`, which uses
+/// per-language rules (Devanagari akshara for Hindi). Languages without them
+/// (Arabic script, Korean) give null Flesch scores, and Thai, Chinese and
+/// Japanese, written without spaces, give null for all three scores.
+/// Words without letters are not counted.
+///
+/// @param text - Text to score
+/// @param language - Language code selecting the syllable rules and formula
+#[wasm_bindgen]
+pub fn get_readability(text: &str, language: &str) -> JsValue {
+    to_js(&get_readability_report(text, language))
+}
+
+/// `get_readability` as a JSON string
+/// @deprecated Use `get_readability`, which returns an object instead of JSON text.
+#[deprecated(note = "use `get_readability`, which returns an object")]
+#[wasm_bindgen]
+pub fn get_readability_json(text: &str, language: &str) -> String {
+    to_json(&get_readability_report(text, language), "{}")
+}
@@ -0,0 +1,532 @@
+//! Moderation heuristics
+
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+use crate::entities::{url_spans, Span};
+use crate::js::to_js;
+use crate::normalize::{is_diacritic, is_invisible};
+use crate::segmentation::{graphemes, is_extend};
+use crate::unicode::{normalize_form, Form};
+use std::collections::BTreeMap;
+
+/// Languages with a lexicon of their own; any other falls back to English
+const LEXICON_LANGUAGES: [&str; 7] = ["de", "fr", "it", "pt", "hi", "es", "th"];
+
+/// Weighted abusive terms of a language
+/// Weights: 1.0 for incitement and threats, 0.6–0.8 for profane insults,
+/// 0.2–0.4 for mild insults. Multi-word entries match as phrases.
+fn abuse_lexicon(language: &str) -> &'static [(&'static str, f64)] {
+    match language {
+        "de" => &[
+            ("bring dich um", 1.0), ("stirb", 0.9), ("fick dich", 0.7), ("verpiss dich", 0.6), ("arschloch", 0.6),
+            ("wichser", 0.6), ("hurensohn", 0.8), ("halt die klappe", 0.3), ("idiot", 0.3), ("depp", 0.3),
+            ("vollidiot", 0.4), ("blöd", 0.2), ("dumm", 0.2), ("trottel", 0.3),
+        ],
+        "fr" => &[
+            ("va mourir", 1.0), ("crève", 0.9), ("va te faire foutre", 0.7), ("connard", 0.6), ("connasse", 0.6),
+            ("salope", 0.7), ("enculé", 0.7), ("fils de pute", 0.8), ("ta gueule", 0.4), ("idiot", 0.3),
+            ("imbécile", 0.3), ("crétin", 0.3), ("débile", 0.3), ("nul", 0.2),
+        ],
+        "it" => &[
+            ("muori", 1.0), ("ammazzati", 1.0), ("vaffanculo", 0.7), ("stronzo", 0.6), ("coglione", 0.6),
+            ("figlio di puttana", 0.8), ("stai zitto", 0.3), ("idiota", 0.3), ("stupido", 0.3), ("cretino", 0.3),
+            ("scemo", 0.2),
+        ],
+        "pt" => &[
+            ("se mata", 1.0), ("vai se foder", 0.7), ("filho da puta", 0.8), ("babaca", 0.5), ("otário", 0.5),
+            ("cala a boca", 0.3), ("idiota", 0.3), ("estúpido", 0.3), ("burro", 0.3), ("imbecil", 0.3),
+        ],
+        "hi" => &[
+            ("मर जा", 1.0), ("हरामी", 0.7), ("कमीना", 0.6), ("कुत्ता", 0.5), ("चुप कर", 0.3), ("बेवकूफ", 0.3),
+            ("मूर्ख", 0.3), ("पागल", 0.2),
+        ],
+        "es" => &[
+            ("muérete", 1.0), ("mátate", 1.0), ("hijo de puta", 0.8), ("gilipollas", 0.6), ("pendejo", 0.6),
+            ("cabrón", 0.6), ("vete a la mierda", 0.6), ("cállate", 0.3), ("idiota", 0.3), ("estúpido", 0.3),
+            ("imbécil", 0.3), ("tonto", 0.2),
+        ],
+        "th" => &[
+            ("ไปตาย", 1.0), ("เหี้ย", 0.7), ("ควาย", 0.5), ("ไอ้บ้า", 0.4), ("โง่", 0.3), ("หุบปาก", 0.3),
+        ],
+        _ => &[
+            ("kill yourself", 1.0), ("kys", 1.0), ("go die", 0.9), ("i will kill you", 1.0), ("fuck you", 0.7),
+            ("motherfucker", 0.8), ("asshole", 0.6), ("bastard", 0.5), ("dickhead", 0.6), ("bitch", 0.6),
+            ("shut up", 0.3), ("stfu", 0.4), ("idiot", 0.3), ("moron", 0.3), ("stupid", 0.3), ("jerk", 0.3),
+            ("loser", 0.3), ("dumb", 0.2),
+        ],
+    }
+}
+
+/// Words that negate an insult shortly after them ("don't be a jerk")
+fn negations(language: &str) -> &'static [&'static str] {
+    match language {
+        "de" => &["nicht", "kein", "keine", "nie"],
+        "fr" => &["pas", "ne", "jamais"],
+        "it" => &["non", "mai"],
+        "pt" => &["não", "nunca"],
+        "hi" => &["नहीं", "मत", "न"],
+        "es" => &["no", "nunca"],
+        "th" => &["ไม่"],
+        _ => &["not", "don't", "dont", "never", "no", "isn't", "aren't", "wasn't", "nobody"],
+    }
+}
+
+/// Second-person words that direct an insult at someone ("you idiot")
+fn second_person(language: &str) -> &'static [&'static str] {
+    match language {
+        "de" => &["du", "dich", "dir"],
+        "fr" => &["tu", "toi", "t'es"],
+        "it" => &["tu", "sei", "ti"],
+        "pt" => &["você", "tu", "seu"],
+        "hi" => &["तुम", "तू", "तेरा"],
+        "es" => &["tú", "eres", "te"],
+        "th" => &["มึง"],
+        _ => &["you", "u", "your", "you're", "ur"],
+    }
+}
+
+/// Tokens before an insult that are checked for negations and addressees
+const CONTEXT_WINDOW: usize = 3;
+
+/// Weight factors for context
+const NEGATED_FACTOR: f64 = 0.3;
+const QUOTED_FACTOR: f64 = 0.5;
+const DIRECTED_FACTOR: f64 = 1.25;
+
+/// The letter a digit or symbol commonly stands in for
+fn leet_letter(c: char) -> Option<char> {
+    match c {
+        '0' => Some('o'),
+        '1' | '!' => Some('i'),
+        '3' => Some('e'),
+        '4' | '@' => Some('a'),
+        '5' | '$' => Some('s'),
+        '7' => Some('t'),
+        _ => None,
+    }
+}
+
+/// Undo common letter substitutions in a token that contains a letter
+/// ("1d10t" → "idiot", "@ss" → "ass"); pure numbers are left alone
+fn unleet(token: &str) -> String {
+    if !token.chars().any(char::is_alphabetic) {
+        return token.to_string();
+    }
+    token.chars().map(|c| leet_letter(c).unwrap_or(c)).collect()
+}
+
+/// Collapse runs of three or more identical letters ("iiidiot" → "idiot")
+fn collapse_floods(token: &str) -> String {
+    let chars: Vec<char> = token.chars().collect();
+    let mut result = String::with_capacity(token.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let run = chars[i..].iter().take_while(|c| **c == chars[i]).count();
+        let keep = if run >= 3 { 1 } else { run };
+        result.extend(std::iter::repeat_n(chars[i], keep));
+        i += run;
+    }
+    result
+}
+
+/// A cleaned token and whether it is inside quotation marks
+struct ModerationToken {
+    text: String,
+    quoted: bool,
+}
+
+/// Split text into cleaned lowercase tokens for matching
+/// Tokens are whitespace-separated chunks with surrounding punctuation
+/// removed, letter substitutions undone and floods collapsed. Inside a
+/// token '*' is kept, so masked words ("f*ck") can match.
+fn moderation_tokens(text: &str) -> Vec<ModerationToken> {
+    let mut tokens = Vec::new();
+    let mut in_quotes = false;
+
+    for chunk in text.split_whitespace() {
+        let opens = chunk.starts_with(['"', '“', '«']);
+        let quoted = in_quotes || opens;
+        let quote_marks = chunk.chars().filter(|c| matches!(c, '"' | '“' | '”' | '«' | '»')).count();
+        if quote_marks % 2 == 1 {
+            in_quotes = !in_quotes;
+        }
+
+        let cleaned = chunk
+            .trim_matches(|c: char| !c.is_alphanumeric() && !matches!(c, '*' | '@' | '$' | '\''))
+            .trim_matches('\'')
+            .to_lowercase();
+        let text = collapse_floods(&unleet(&cleaned));
+        if !text.is_empty() {
+            tokens.push(ModerationToken { text, quoted });
+        }
+    }
+
+    tokens
+}
+
+/// Match a lexicon word against a token, where '*' in the token stands
+/// for any single letter
+fn token_matches(token: &str, word: &str) -> bool {
+    token.chars().count() == word.chars().count()
+        && token.chars().zip(word.chars()).all(|(t, w)| t == w || t == '*')
+}
+
+/// Score how abusive a message is, as a heuristic pre-filter
+/// Returns a score from 0.0 to 1.0 for sorting a moderation queue; it is
+/// not a classifier and should not act on its own.
+///
+/// Every match of the language's weighted lexicon contributes its weight,
+/// adjusted for context:
+/// - ×0.3 when a negation is among the three preceding words ("don't be a jerk")
+/// - ×0.5 when the match is inside quotation marks (reported speech)
+/// - ×1.25 when a second-person word precedes it ("you jerk"), capped at 1.0
+///
+/// Contributions combine as 1 − Π(1 − weight), so several mild insults
+/// add up without ever exceeding 1.0. English terms are checked in every
+/// language; a word matched by both lexicons ("idiot") counts once, with
+/// the stronger of its two weights.
+///
+/// False positives are limited by matching whole words and phrases only
+/// ("Scunthorpe" or "classic" never match), and evasions are caught by
+/// undoing letter substitutions ("1d10t"), collapsing floods ("iiidiot")
+/// and treating '*' as a masked letter ("f*ck you"). Thai is matched as a
+/// substring, since it has no spaces, and can over-match.
+///
+/// @param text - Message text
+/// @param language - Language code selecting the lexicon
+#[wasm_bindgen]
+pub fn toxicity_score(text: &str, language: &str) -> f64 {
+    let tokens = moderation_tokens(text);
+    let lexicons: Vec<&str> = if LEXICON_LANGUAGES.contains(&language) { vec![language, "en"] } else { vec!["en"] };
+    // Strongest match starting at each token, or for Thai at each byte
+    let mut strongest: BTreeMap<(bool, usize), f64> = BTreeMap::new();
+    let mut keep = |position: (bool, usize), score: f64| {
+        let entry = strongest.entry(position).or_insert(0.0);
+        *entry = entry.max(score);
+    };
+
+    for lexicon in lexicons {
+        for (term, weight) in abuse_lexicon(lexicon) {
+            if term.chars().any(|c| matches!(c, '\u{0E00}'..='\u{0E7F}')) {
+                for (offset, _) in text.match_indices(term) {
+                    keep((true, offset), *weight);
+                }
+                continue;
+            }
+
+            let parts: Vec<&str> = term.split(' ').collect();
+            for start in 0..tokens.len() {
+                let window = &tokens[start..];
+                if window.len() < parts.len() || !parts.iter().zip(window).all(|(part, token)| token_matches(&token.text, part)) {
+                    continue;
+                }
+
+                let before = &tokens[start.saturating_sub(CONTEXT_WINDOW)..start];
+                let mut score = *weight;
+                if before.iter().any(|token| negations(lexicon).contains(&token.text.as_str())) {
+                    score *= NEGATED_FACTOR;
+                }
+                if tokens[start].quoted {
+                    score *= QUOTED_FACTOR;
+                }
+                if before.iter().any(|token| second_person(lexicon).contains(&token.text.as_str())) {
+                    score = (score * DIRECTED_FACTOR).min(1.0);
+                }
+                keep((false, start), score);
+            }
+        }
+    }
+
+    let clean: f64 = strongest.values().map(|score| 1.0 - score).product();
+    1.0 - clean
+}
+
+/// Punctuation used to split up a word ("f.u.c.k", "f-u-c-k")
+fn is_letter_separator(c: char) -> bool {
+    matches!(c, '.' | '-' | '_' | '~' | '·' | '\'' | ',')
+}
+
+/// A character of folded text and the byte offset it came from
+type FoldedChar = (char, usize);
+
+/// Whether a chunk is a word to fold: one with a letter, or a run of
+/// symbols such as "@$$" (but not "$5", "$$" or "!!!")
+fn is_word_chunk(core: &[FoldedChar]) -> bool {
+    let chars: Vec<char> = core.iter().map(|(c, _)| *c).collect();
+    chars.iter().any(|c| c.is_alphabetic())
+        || (!chars.iter().any(char::is_ascii_digit)
+            && chars.iter().any(|c| matches!(c, '@' | '$'))
+            && chars.iter().any(|c| *c != chars[0]))
+}
+
+/// Fold one whitespace-separated chunk of text
+/// Punctuation around the word ("h3ll0!!") is kept as it is.
+fn fold_chunk(chunk: &[FoldedChar]) -> Vec<FoldedChar> {
+    let is_core = |&(c, _): &FoldedChar| c.is_alphanumeric() || matches!(c, '@' | '$' | '*');
+    let start = chunk.iter().position(is_core).unwrap_or(chunk.len());
+    let end = chunk.iter().rposition(is_core).map_or(start, |end| end + 1);
+    let has_letter = is_word_chunk(&chunk[start..end]);
+    let mut folded: Vec<FoldedChar> = Vec::with_capacity(chunk.len());
+    for (index, &(c, offset)) in chunk.iter().enumerate() {
+        if is_invisible(c) {
+            continue;
+        }
+        let c = if has_letter && (start..end).contains(&index) { leet_letter(c).unwrap_or(c) } else { c };
+        for base in normalize_form(&c.to_string(), Form::Nfd).chars().filter(|c| !is_diacritic(*c)) {
+            folded.extend(base.to_lowercase().map(|lower| (lower, offset)));
+        }
+    }
+
+    let is_letter = |index: usize| folded.get(index).is_some_and(|(c, _)| c.is_alphanumeric() || *c == '*');
+    let kept: Vec<FoldedChar> = (0..folded.len())
+        .filter(|&index| {
+            let between = index > 0 && is_letter(index - 1) && is_letter(index + 1);
+            !(has_letter && between && is_letter_separator(folded[index].0))
+        })
+        .map(|index| folded[index])
+        .collect();
+
+    let mut collapsed: Vec<FoldedChar> = Vec::with_capacity(kept.len());
+    let mut i = 0;
+    while i < kept.len() {
+        let run = kept[i..].iter().take_while(|(c, _)| *c == kept[i].0).count();
+        collapsed.extend_from_slice(&kept[i..i + if run >= 3 { 1 } else { run }]);
+        i += run;
+    }
+    collapsed
+}
+
+/// Folded text with the byte offset of each character in the original
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Folded {
+    text: String,
+    offsets: Vec<usize>,
+    consonants: String,
+    consonant_offsets: Vec<usize>,
+}
+
+fn folded(chars: &[FoldedChar], end: usize) -> (String, Vec<usize>) {
+    let text = chars.iter().map(|(c, _)| *c).collect();
+    let offsets = chars.iter().map(|(_, offset)| *offset).chain([end]).collect();
+    (text, offsets)
+}
+
+fn fold_obfuscation_report(text: &str) -> Folded {
+    let urls = url_spans(text);
+    let in_url = |offset: usize| urls.iter().any(|url| url.overlaps(&Span { start: offset, end: offset + 1 }));
+
+    // Alternating runs of whitespace and of everything else
+    let mut groups: Vec<(bool, Vec<FoldedChar>)> = Vec::new();
+    for (offset, c) in text.char_indices() {
+        let space = c.is_whitespace() && !in_url(offset);
+        match groups.last_mut() {
+            Some((last_space, chars)) if *last_space == space => chars.push((c, offset)),
+            _ => groups.push((space, vec![(c, offset)])),
+        }
+    }
+    for (space, chars) in &mut groups {
+        if !*space && !in_url(chars[0].1) {
+            *chars = fold_chunk(chars);
+        }
+    }
+
+    // Letters spelled out one by one ("f u c k") join into a word once
+    // there are three of them
+    let single_letter = |group: &(bool, Vec<FoldedChar>)| !group.0 && group.1.len() == 1 && group.1[0].0.is_alphabetic();
+    let mut all: Vec<FoldedChar> = Vec::with_capacity(text.len());
+    let mut index = 0;
+    while index < groups.len() {
+        let run = groups[index..]
+            .iter()
+            .enumerate()
+            .take_while(|(i, group)| if i % 2 == 0 { single_letter(group) } else { group.0 })
+            .count();
+        let letters = run.div_ceil(2);
+        if letters >= 3 {
+            let end = index + letters * 2 - 1;
+            for group in groups[index..end].iter().step_by(2) {
+                all.extend_from_slice(&group.1);
+            }
+            index = end;
+        } else {
+            all.extend_from_slice(&groups[index].1);
+            index += 1;
+        }
+    }
+
+    let consonants: Vec<FoldedChar> = all.iter().copied().filter(|(c, _)| !matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | '*')).collect();
+    let (folded_text, offsets) = folded(&all, text.len());
+    let (consonants, consonant_offsets) = folded(&consonants, text.len());
+    Folded { text: folded_text, offsets, consonants, consonant_offsets }
+}
+
+/// Undo the obfuscation of words, for matching moderation rules
+/// Returns object: {"text":"idiot","offsets":[0,1,2,4,5,6],"consonants":"dt",
+/// "consonantOffsets":[2,5,6]}
+///
+/// Text is lowercased and accents are removed; in words with a letter,
+/// digits and symbols become the letters they stand in for ("1d10t",
+/// "@$$", "h3ll0"), separators between letters are dropped ("f.u.c.k",
+/// "s-h-i-t"), floods collapse ("iiidiot") and three or more letters
+/// spelled out one by one join ("f u c k"). '*' is kept as a masked
+/// letter. `consonants` also drops vowels and '*', so that "fck", "f*ck"
+/// and "fuck" match the rule "fck". Invisible characters are removed, and
+/// numbers and URLs are not folded.
+///
+/// `offsets[i]` is the byte offset in `text` of the character the i-th
+/// character of the folded text came from, and a last entry holds the
+/// length of `text`. A match of folded characters i..j starts at byte
+/// offsets[i] of the message and ends with the character at offsets[j - 1].
+///
+/// @param text - Message text
+#[wasm_bindgen]
+pub fn fold_obfuscation(text: &str) -> JsValue {
+    to_js(&fold_obfuscation_report(text))
+}
+
+/// Marks on one base from which a cluster counts as stacked
+/// Legitimate text stays below it: Vietnamese uses two marks, Thai a vowel
+/// and a tone mark, and even vocalized Hebrew with cantillation rarely
+/// reaches five.
+const STACKED_MARKS: usize = 5;
+
+/// Combining marks that stack on a base letter, as opposed to the joiners,
+/// variation selectors, skin tones and tags inside emoji and the Hangul
+/// jamo that also extend a cluster
+fn is_stacking_mark(c: char) -> bool {
+    is_extend(c)
+        && !matches!(c,
+            '\u{1160}'..='\u{11FF}' | '\u{200C}' | '\u{FE00}'..='\u{FE0F}' | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}' | '\u{E0100}'..='\u{E01EF}'
+        )
+}
+
+/// Combining-mark abuse in a message
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZalgoReport {
+    /// Some base carries at least five combining marks
+    pub zalgo: bool,
+    /// Combining marks in the whole text
+    pub mark_count: u32,
+    /// Most combining marks on a single base
+    pub max_marks_per_base: u32,
+    /// Grapheme clusters with at least five marks
+    pub stacked_clusters: u32,
+    /// Combining marks per grapheme cluster
+    pub mark_ratio: f64,
+}
+
+pub fn zalgo_report(text: &str) -> ZalgoReport {
+    let clusters = graphemes(text);
+    let marks: Vec<usize> = clusters.iter().map(|cluster| cluster.text.chars().filter(|c| is_stacking_mark(*c)).count()).collect();
+    let mark_count: usize = marks.iter().sum();
+    let stacked_clusters = marks.iter().filter(|count| **count >= STACKED_MARKS).count();
+    ZalgoReport {
+        zalgo: stacked_clusters > 0,
+        mark_count: mark_count as u32,
+        max_marks_per_base: marks.iter().copied().max().unwrap_or(0) as u32,
+        stacked_clusters: stacked_clusters as u32,
+        mark_ratio: if clusters.is_empty() { 0.0 } else { mark_count as f64 / clusters.len() as f64 },
+    }
+}
+
+/// Detect "zalgo" text stacked with combining marks
+/// Returns object: {"zalgo":true,"markCount":48,"maxMarksPerBase":16,
+/// "stackedClusters":3,"markRatio":9.6}
+///
+/// A base letter with five or more combining marks counts as stacked;
+/// ordinary accents, Thai and Devanagari vowel signs and Hebrew and Arabic
+/// vowel points stay below that. Emoji modifiers, joiners and variation
+/// selectors are not marks. Use `strip_excess_marks` to clean flagged text.
+///
+/// @param text - Message text
+#[wasm_bindgen]
+pub fn detect_zalgo(text: &str) -> JsValue {
+    to_js(&zalgo_report(text))
+}
+
+/// Remove combining marks stacked beyond a limit on each base
+/// Every grapheme cluster keeps its base and first `max_marks_per_base`
+/// combining marks, so "Z̵̢̛̖̗͇a̷̙̦l̸g̶o̴" with 1 becomes "Z̵a̷l̸g̶o̴" and rendering is
+/// safe again, while text within the limit is unchanged. With 2 or 3,
+/// Vietnamese, Thai and vocalized Hebrew and Arabic are not touched.
+/// Emoji sequences are never shortened.
+///
+/// @param text - Text to clean
+/// @param max_marks_per_base - Combining marks kept on each base (0 removes them all)
+#[wasm_bindgen]
+pub fn strip_excess_marks(text: &str, max_marks_per_base: u32) -> String {
+    let mut result = String::with_capacity(text.len());
+    for cluster in graphemes(text) {
+        let mut marks = 0;
+        for c in cluster.text.chars() {
+            if is_stacking_mark(c) {
+                marks += 1;
+                if marks > max_marks_per_base {
+                    continue;
+                }
+            }
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_score(text: &str, language: &str, expected: f64) {
+        let score = toxicity_score(text, language);
+        assert!((score - expected).abs() < 1e-9, "{text:?} as {language}: {score}");
+    }
+
+    #[test]
+    fn weights_and_context() {
+        assert_score("what an idiot", "en", 0.3);
+        assert_score("you idiot", "en", 0.375);
+        assert_score("don't be a jerk", "en", 0.09);
+        assert_score("he said \"idiot\" twice", "en", 0.15);
+        assert_score("idiot and moron", "en", 0.51);
+        assert_score("kill yourself", "en", 1.0);
+    }
+
+    #[test]
+    fn evasions_are_caught() {
+        assert_score("1d10t", "en", 0.3);
+        assert_score("iiiiidiot", "en", 0.3);
+        assert_score("f*ck you", "en", 0.7);
+    }
+
+    #[test]
+    fn whole_words_only() {
+        assert_score("Scunthorpe is a classic town", "en", 0.0);
+        assert_score("idiotic plans", "en", 0.0);
+        assert_score("", "en", 0.0);
+    }
+
+    #[test]
+    fn english_scores_the_same_in_every_language() {
+        for text in ["you idiot", "shut up, loser", "kill yourself", "don't be a jerk"] {
+            let english = toxicity_score(text, "en");
+            for language in ["de", "fr", "it", "pt", "hi", "es", "th", "nl", "ja", "", "xx"] {
+                assert_score(text, language, english);
+            }
+        }
+    }
+
+    #[test]
+    fn terms_shared_by_lexicons_count_once() {
+        assert_score("du idiot", "de", 0.375);
+        assert_score("idiot", "fr", 0.3);
+    }
+
+    #[test]
+    fn language_lexicons() {
+        assert_score("du Trottel", "de", 0.375);
+        assert_score("eres un pendejo", "es", 0.75);
+        assert_score("ไปตาย", "th", 1.0);
+    }
+}
@@ -0,0 +1,70 @@
+//! Display name helpers
+
+use wasm_bindgen::prelude::*;
+use crate::script::is_cjk;
+use crate::segmentation::graphemes;
+use crate::unicode::base_char;
+
+/// Returned when a name has no letters to take initials from
+const INITIALS_FALLBACK: &str = "?";
+
+/// Uppercase a letter grapheme, optionally dropping its accents
+fn initial_letter(cluster: &str, strip_accents: bool) -> String {
+    let first = cluster.chars().next().unwrap_or_default();
+    if strip_accents {
+        base_char(first).to_uppercase().collect()
+    } else {
+        let marks = &cluster[first.len_utf8()..];
+        first.to_uppercase().collect::<String>() + marks
+    }
+}
+
+/// Letter graphemes of a word, skipping leading quotes or brackets
+fn letters(word: &str) -> Vec<&str> {
+    graphemes(word)
+        .into_iter()
+        .map(|cluster| cluster.text)
+        .filter(|cluster| cluster.chars().next().is_some_and(char::is_alphabetic))
+        .collect()
+}
+
+/// Generate avatar initials from a display name
+///
+/// - CJK names use their first character ("王小明" → "王")
+/// - Single names use their first two letters ("madonna" → "MA")
+/// - Multi-word names use word initials; when there are more words than
+///   `max`, the last word is kept ("John Ronald Reuel Tolkien" → "JT")
+///
+/// @param name - Display name
+/// @param max - Maximum number of initials (at least 1)
+/// @param strip_accents - Drop accents from initials ("Élodie" → "E" instead of "É")
+/// @returns Uppercase initials, or "?" for empty or symbol-only names
+#[wasm_bindgen]
+pub fn initials(name: &str, max: u32, strip_accents: bool) -> String {
+    let max = max.max(1) as usize;
+    let words: Vec<Vec<&str>> = name
+        .split_whitespace()
+        .map(letters)
+        .filter(|letters| !letters.is_empty())
+        .collect();
+
+    let Some(first_word) = words.first() else {
+        return String::from(INITIALS_FALLBACK);
+    };
+
+    if first_word[0].chars().next().is_some_and(is_cjk) {
+        return String::from(first_word[0]);
+    }
+
+    let picked: Vec<&str> = if words.len() == 1 {
+        first_word.iter().take(2.min(max)).copied().collect()
+    } else {
+        let mut picked: Vec<&str> = words.iter().take(max.min(words.len()) - 1).map(|w| w[0]).collect();
+        if let Some(last) = words.last() {
+            picked.push(last[0]);
+        }
+        picked
+    };
+
+    picked.iter().map(|cluster| initial_letter(cluster, strip_accents)).collect()
+}
@@ -0,0 +1,530 @@
+//! Character trigram language profiles
+
+use crate::segmentation::words;
+
+// Trigram profiles: the 300 most frequent trigrams of each language, ranked
+// from most to least frequent, counted over a few hundred words of everyday
+// chat text. Words are lowercased and padded with one space on each side,
+// so " th" marks a word start and "he " a word end.
+
+const EN_TRIGRAMS: &[&str] = &[
+    " th", "the", "he ", "ng ", "ing", " yo", "you", " to", "thi", "ou ", " i ", "er ", "re ", " we",
+    "at ", "is ", "nd ", " an", "hin", " it", "en ", "to ", "it ", "ld ", "nt ", " be", " so", " wo",
+    "and", "hat", "her", "in ", "ne ", "oul", "tha", "uld", " a ", " ha", " is", " sh", " wa", " wi",
+    "for", "one", "or ", "our", " fo", " he", " ne", " re", " se", " wh", "as ", "ave", "ce ", "een",
+    "ent", "me ", "on ", "ver", "we ", " ca", " ev", " in", " on", "ate", "bou", "ch ", "day", "ere",
+    "eve", "hav", "his", "igh", "ink", "ith", "ly ", "nin", "out", "ow ", "rea", "th ", "ur ", "ve ",
+    "wit", " ab", " ar", " ch", " co", " ho", " la", " ma", " me", " mo", " of", " pl", " pr", " st",
+    " su", "abo", "are", "ay ", "ed ", "ee ", "ery", "eth", "ght", "ht ", "ke ", "kin", "nk ", "now",
+    "off", "ome", "se ", "sho", "te ", "ut ", "wou", " as", " do", " go", " kn", " le", " lo", " ni",
+    " or", " pa", " ri", "ain", "ake", "alk", "all", "bee", "eat", "est", "han", "hou", "ice", "kno",
+    "lat", "lea", "ll ", "lon", "met", "mor", "oin", "ong", "ore", "ork", "oth", "par", "pro", "rin",
+    "rt ", "see", "sen", "som", "st ", "ter", "uch", "und", "was", "wor", "yth", " ag", " at", " br",
+    " ea", " fi", " fr", " gr", " hi", " if", " im", " ki", " mi", " mu", " no", " ou", " pi", " sa",
+    " si", " ta", " ti", " up", "aga", "age", "an ", "ank", "ant", "any", "app", "art", "ase", "ays",
+    "bef", "can", "cha", "cof", "der", "do ", "don", "ds ", "dy ", "ead", "eal", "ear", "eas", "eed",
+    "eek", "efo", "ek ", "elp", "em ", "end", "eps", "ess", "et ", "ey ", "fee", "ffe", "fin", "ful",
+    "gai", "ge ", "ger", "get", "goi", "gre", "har", "hel", "hen", "hey", "hil", "hon", "hop", "if ",
+    "ill", "im ", "ime", "ind", "ine", "ini", "irt", "ks ", "let", "lk ", "lly", "lp ", "mak", "muc",
+    "ndo", "nee", "new", "nig", "nki", "not", "of ", "ond", "ont", "oon", "op ", "ope", "orr", "oun",
+    "pen", "pla", "ple", "pre", "pri", "ps ", "ree", "ren", "rig", "rk ", "rni", "rob", "ryt", "she",
+    "so ", "ste", "sur", "tal", "tch", "tim", "tin", "ts ", "tti", "ty ", "ul ", "ure", "uti", "ven",
+    "wan", "wee", "wer", "wha", "whi", "ws ",
+];
+const DE_TRIGRAMS: &[&str] = &[
+    "en ", "ich", "ch ", "er ", " da", " de", "ein", "st ", "der", "sch", " ic", "cht", " ge", "as ",
+    "das", "te ", " di", " wi", "nd ", "che", "ir ", "und", " ha", " un", "den", "es ", "ie ", "ass",
+    "ht ", "ine", "it ", "wir", " du", " mi", " sc", " si", "ach", "die", "du ", " be", " ei", " so",
+    " wa", " we", "gen", "nen", "ten", " es", "abe", "hen", "in ", "lte", "mit", "nde", "ss ", " an",
+    " bi", " ih", " la", " na", " ni", " se", "ber", "eit", "est", "ist", "lle", "nac", "nge", "nic",
+    "oll", "ste", "ter", "tte", "was", " fr", " he", " im", " is", " ka", " vo", "be ", "bei", "em ",
+    "ese", "ffe", "ges", "hab", "itt", "len", "lic", "llt", "mme", "ne ", "ng ", "och", "rn ", "sei",
+    "sol", "war", " et", " fe", " sp", " st", " wo", "ade", "ag ", "an ", "ang", "bes", "dei", "ede",
+    "enk", "ern", "etw", "fen", "hr ", "ier", "nke", "nn ", "on ", "sag", "sie", "tag", "twa", "ung",
+    "ute", "übe", " br", " in", " le", " no", " re", " sa", " ve", " zu", " üb", "aff", "all", "am ",
+    "and", "ank", "ann", "bit", "chi", "chr", "dan", "des", "dir", "ege", "ehe", "eid", "eiß", "ell",
+    "ers", "ert", "eut", "fe ", "frü", "gem", "hal", "hat", "he ", "heu", "hri", "hte", "id ", "iel",
+    "ihn", "ihr", "im ", "imm", "ind", "iß ", "ke ", "kom", "lad", "les", "men", "mir", "noc", "omm",
+    "rau", "rbe", "ren", "ric", "rin", "rüh", "seh", "sic", "sse", "sta", "uch", "uns", "ver", "von",
+    "vor", "wei", " al", " ar", " dr", " er", " fü", " gr", " gu", " hi", " je", " ki", " me", " mo",
+    " ne", " od", " or", " pa", " pr", " te", " tr", " vi", " wu", "age", "agt", "al ", "ar ", "arb",
+    "at ", "auc", "bar", "ben", "bis", "bra", "cha", "cho", "chs", "chö", "dar", "ech", "ee ", "ehr",
+    "ei ", "eie", "elt", "ema", "end", "enn", "erl", "esc", "ess", "eun", "fee", "fei", "fer", "für",
+    "gef", "geg", "ger", "gut", "hei", "hic", "hli", "hn ", "hre", "hst", "hts", "hön", "ick", "ied",
+    "ies", "ig ", "ing", "inm", "ird", "irk", "is ", "itz", "kaf", "kan", "ken", "kin", "kli", "lan",
+    "las", "lau", "lei", "lfe", "lin", "lt ", "mal", "mei", "mer", "mmt", "mt ", "neu", "nfa", "nk ",
+    "nma", "nnt", "ns ", "nse", "nst", "ode",
+];
+const FR_TRIGRAMS: &[&str] = &[
+    " de", " le", " qu", "que", "is ", "le ", "ue ", "es ", " je", " pa", "nt ", "je ", "it ", "ne ",
+    "re ", " en", " no", "de ", "er ", "rai", " ce", " la", " tu", " un", "ent", "et ", "tu ", " to",
+    "as ", "ce ", "la ", "nou", "ns ", "on ", "ur ", "us ", " au", " av", " et", " pr", "ait", "au ",
+    "il ", "lle", "our", "par", "te ", " il", " à ", "ais", "men", "ous", "pas", "un ", " ch", " di",
+    " es", " mo", " pe", " po", "ant", "est", "eur", "ien", "in ", "les", "ouv", "se ", "st ", "tou",
+    "uis", " as", " be", " da", " ma", " me", " se", " su", " te", " tr", "ai ", "ain", "eau", "en ",
+    "eux", "leu", "oi ", "ons", "out", "pou", "pro", "rs ", "tem", "tre", "ut ", "ux ", " a ", " co",
+    " fa", " ne", " re", " si", " so", " vo", "ava", "ave", "cha", "dan", "der", "ec ", "ell", "ema",
+    "emp", "ens", "fai", "ill", "ine", "ir ", "ire", "jou", "man", "ois", "quo", "sai", "si ", "soi",
+    "sur", "ter", "une", "uve", "vec", "vra", "ère", " bo", " ca", " ou", " pl", " ra", " sa", " ta",
+    " ve", "ans", "app", "ard", "ass", "avo", "che", "ci ", "com", "dep", "des", "dev", "enc", "env",
+    "epu", "era", "evr", "ger", "int", "mai", "me ", "mer", "moi", "mps", "nce", "nde", "nts", "nvo",
+    "oin", "omm", "pen", "peu", "pré", "ps ", "pui", "sem", "sin", "son", "sui", "tai", "ton", "tra",
+    "ts ", "uel", "uit", "uoi", "ver", "vou", "voy", "vu ", "éta", "ête", "êtr", " al", " bi", " dé",
+    " eu", " fe", " ja", " lo", " na", " ri", " va", " vr", " vu", " y ", " ét", "afé", "age", "aid",
+    "aie", "ail", "aim", "air", "anc", "and", "ang", "arl", "art", "aus", "aut", "bea", "bes", "bie",
+    "ble", "blè", "bon", "caf", "ces", "cet", "cho", "dem", "dir", "dit", "dra", "eil", "elq", "end",
+    "enf", "erc", "erm", "ers", "eso", "eu ", "fan", "fé ", "hon", "ide", "ie ", "ime", "ion", "ise",
+    "isi", "iso", "jai", "lon", "lqu", "lus", "lèm", "lé ", "mag", "mis", "mme", "mon", "nai", "nfa",
+    "nge", "nse", "nêt", "obl", "oir", "oit", "ome", "ong", "onn", "ors", "ort", "ou ", "oud", "oyé",
+    "plu", "por", "ppo", "pri", "qui", "rav", "rci", "rd ", "ren", "res", "ret", "rie", "ris", "rob",
+    "roi", "rou", "rri", "rt ", "rte", "rès",
+];
+const IT_TRIGRAMS: &[&str] = &[
+    "to ", "re ", " co", "no ", " ch", " qu", " di", "che", " la", " pe", "di ", "he ", "la ", "on ",
+    " il", " no", " pr", " un", "est", "il ", "na ", "per", " se", "are", "lo ", " de", " e ", "ell",
+    "ima", "man", "mo ", "sa ", "so ", "sto", "ta ", " be", " ma", " po", " tu", "ato", "con", "cos",
+    "er ", "ett", "qua", "ra ", "sta", "ti ", " a ", " ca", " da", " do", " ha", " in", " pa", " te",
+    "amo", "cia", "ei ", "ent", "ggi", "iam", "io ", "le ", "ma ", "ne ", "non", "osa", "pre", "ro ",
+    "tto", "ual", "ver", " fa", " so", "alc", "ci ", "com", "era", "gio", "res", "ssi", "tti", "una",
+    "vor", " al", " ci", " i ", " le", " me", " mi", " pi", " ri", " st", " va", " è ", "and", "arl",
+    "ass", "avo", "bel", "da ", "dov", "emp", "ere", "ha ", "ia ", "iar", "ici", "in ", "ini", "lco",
+    "lla", "men", "mi ", "ni ", "nto", "oi ", "pri", "pro", "que", "ri ", "rim", "se ", "tat", "te ",
+    "tem", "tut", "un ", "utt", "vo ", " ab", " fi", " ho", " lo", " sa", " su", " ve", " vi", " vo",
+    "abb", "ai ", "amb", "ann", "ano", "ard", "ata", "azi", "bbe", "bbi", "be ", "bia", "cor", "dat",
+    "del", "do ", "ebb", "ens", "eri", "ero", "ers", "ess", "gia", "ho ", "ie ", "ina", "inc", "ion",
+    "ire", "iso", "ist", "iù ", "lav", "llo", "mbi", "me ", "mpo", "nda", "ndi", "nno", "ome", "one",
+    "ono", "ort", "ovr", "par", "pas", "pen", "più", "po ", "qui", "reb", "ric", "rta", "si ", "sim",
+    "tar", "tor", "tre", "tro", "ues", "uoi", "uto", "vis", "vre", "zie", "zio", " ai", " an", " ap",
+    " av", " ba", " bi", " bu", " fe", " gr", " ne", " nu", " o ", " og", " pu", " sc", " si", " sp",
+    " ta", " ti", " tr", "ace", "aff", "agg", "aiu", "al ", "all", "ame", "ami", "ana", "ang", "arc",
+    "ari", "ati", "att", "ava", "ave", "avv", "bam", "ben", "bin", "bis", "buo", "caf", "cam", "ce ",
+    "cer", "chi", "cin", "cio", "col", "dar", "dav", "de ", "dei", "det", "dia", "dir", "dis", "dom",
+    "edi", "egg", "el ", "eme", "emm", "ene", "eni", "ffè", "fin", "fè ", "gi ", "gli", "gno", "gra",
+    "hai", "iac", "ica", "iem", "ier", "ins", "ioc", "isp", "iss", "ito", "iut", "ivi", "lio", "lis",
+    "lle", "lli", "mer", "mes", "mic", "min",
+];
+const PT_TRIGRAMS: &[&str] = &[
+    " de", "de ", "que", " qu", " co", "do ", "os ", " a ", "ue ", " es", " no", "ão ", "ar ", "com",
+    "or ", " te", " vo", "est", " o ", "as ", "cê ", "em ", "ocê", "te ", "voc", " pa", " po", " pr",
+    " se", "ent", "er ", "nte", "ou ", "sa ", "da ", "eu ", "ma ", "ndo", "por", "to ", " e ", " mu",
+    " pe", " um", "isa", "om ", "par", "ra ", "se ", "tem", "uma", " ca", " en", " eu", " me", " nã",
+    "and", "ara", "ela", "es ", "ia ", "ir ", "man", "mos", "não", "tra", " el", " fi", " ma", " su",
+    "ado", "ele", "ho ", "la ", "mui", "na ", "ont", "pre", "sso", "uit", "ver", " al", " as", " bo",
+    " fa", " fo", " os", " so", " tr", "ada", "ade", "alg", "amo", "ant", "ass", "bri", "con", "emp",
+    "ess", "inh", "is ", "iss", "ito", "lgu", "me ", "mpo", "no ", "nov", "nto", "obr", "ois", "oje",
+    "ome", "pro", "ria", "ro ", "so ", "ssa", "ta ", "uer", "vam", " ag", " an", " at", " da", " ge",
+    " ho", " lo", " na", " ou", " ta", " va", " vi", " é ", "ais", "am ", "ami", "ava", "boa", "cho",
+    "coi", "cri", "des", "dev", "ema", "ens", "erd", "ere", "evi", "gad", "gar", "gum", "hoj", "ias",
+    "iga", "io ", "ize", "ja ", "je ", "les", "lho", "men", "min", "nho", "nsa", "ntr", "oa ", "oi ",
+    "omp", "ora", "ovo", "pas", "po ", "rar", "rde", "re ", "rem", "rio", "sar", "sse", "sta", "ste",
+    "sto", "sua", "tad", "tou", "tão", "ua ", "udo", "um ", "via", "vo ", "zer", "zin", " ac", " aj",
+    " am", " ce", " ch", " cr", " di", " do", " em", " fe", " ia", " im", " is", " li", " ob", " on",
+    " sa", " to", " tu", " ve", " à ", "aba", "ach", "afé", "ago", "aju", "al ", "alh", "ana", "anh",
+    "anç", "ard", "are", "arr", "art", "ave", "avi", "avo", "bal", "bre", "caf", "cam", "cia", "cis",
+    "dad", "das", "del", "dir", "dor", "eci", "edi", "ega", "eja", "enc", "env", "era", "ero", "ers",
+    "esc", "eça", "fav", "fic", "fiz", "foi", "fé ", "gen", "gor", "gos", "ha ", "hos", "hã ", "ian",
+    "ica", "ilh", "imp", "inc", "ind", "ist", "ite", "jud", "lar", "lat", "le ", "lha", "lme", "mai",
+    "mer", "mpr", "nco", "nda", "nha", "nhã", "noi", "nos", "not", "nvi", "nça", "ode", "oit", "otí",
+    "ozi", "ped", "pen", "per", "pes", "pod",
+];
+const ES_TRIGRAMS: &[&str] = &[
+    "os ", " de", " qu", "de ", "la ", " la", " es", "do ", "en ", "est", "que", "ue ", "as ", "or ",
+    " en", "es ", " el", "el ", " ha", " no", " po", " ve", "na ", "no ", "por", "te ", " co", " lo",
+    " pa", " pr", "sta", "ta ", " to", " y ", "ent", "ien", "lo ", "mos", " al", " te", "alg", "ar ",
+    "emp", "go ", "los", "ra ", "ría", "tra", " ca", " mu", " si", " ti", " un", "da ", "ro ", "tie",
+    "tod", " a ", " ma", " me", " pe", " se", " tr", "aba", "aci", "an ", "ana", "con", "er ", "ier",
+    "lgo", "man", "me ", "mpo", "ndo", "nta", "odo", "pas", "po ", "qui", "tar", "to ", " so", " su",
+    " ta", " tu", "ado", "amo", "and", "ber", "cia", "com", "iem", "ir ", "men", "nte", "nto", "on ",
+    "pre", "pro", "rde", "res", "sa ", "tro", "tu ", "uie", "ver", "ás ", "ía ", " ay", " cu", " di",
+    " ju", " ll", " nu", " ot", " va", " vi", "abl", "ada", "ant", "ard", "ast", "che", "cho", "cin",
+    "deb", "ebe", "eci", "egu", "ema", "end", "ens", "eo ", "era", "erd", "erm", "ero", "erí", "ez ",
+    "gra", "hab", "hac", "he ", "ias", "ita", "jo ", "las", "lle", "muc", "noc", "nos", "nsa", "ntr",
+    "nue", "och", "otr", "par", "qué", "rme", "si ", "sol", "ste", "sto", "uch", "uda", "uen", "un ",
+    "una", "unt", "ué ", "vez", "ños", "ón ", " ac", " an", " aq", " as", " bi", " bu", " ci", " cr",
+    " em", " fa", " fu", " gr", " he", " ho", " le", " lu", " mi", " má", " na", " ne", " ni", " o ",
+    " pu", " sé", "abe", "ace", "ad ", "adr", "afé", "ame", "ami", "ano", "aqu", "ara", "arg", "arl",
+    "asa", "ase", "ate", "avo", "aví", "ayu", "aña", "baj", "ban", "bie", "bla", "ble", "bue", "caf",
+    "cam", "cer", "ces", "cio", "ció", "co ", "cre", "cul", "dad", "des", "dij", "drí", "ece", "ell",
+    "emo", "ene", "env", "ere", "err", "esi", "eza", "fav", "fie", "fue", "fé ", "gos", "gun", "has",
+    "ho ", "iar", "ici", "ido", "ijo", "ima", "imo", "ina", "ist", "iño", "ió ", "ión", "jun", "lar",
+    "leg", "lem", "lgu", "lla", "llo", "mañ", "mer", "min", "mpr", "muy", "más", "nad", "nda", "nec",
+    "nes", "niñ", "nvi", "ola", "olo", "ome", "omp", "ono", "ont", "orm", "ort", "oy ", "pen", "per",
+    "pra", "pue", "quí", "rab", "rac", "rad",
+];
+
+
+const RU_TRIGRAMS: &[&str] = &[
+    " по", "то ", "ть ", " не", "не ", " за", " на", " пр", " я ", " чт", "ли ", "ой ", "что", "это",
+    " вс", " и ", " эт", "го ", "его", " мо", " ни", " с ", " те", " ты", "ать", "дел", "но ", "нов",
+    "ом ", "про", "ся ", "ты ", " бы", " до", " ко", " но", " он", " сп", " хо", "аза", "ал ", "бе ",
+    "ебе", "ени", "ешь", "каз", "ми ", "ое ", "оро", "теб", "тся", "шь ", " ку", " мы", " о ", " об",
+    " ра", " св", " се", " си", " ск", " ст", "ам ", "буд", "год", "дь ", "ел ", "ем ", "ень", "за ",
+    "зат", "или", "ит ", "кол", "ку ", "мог", "мож", "мы ", "на ", "оже", "око", "ото", "оче", "при",
+    "рис", "рош", "сво", "ста", "сто", "сь ", "та ", "тел", "тор", "тре", "удь", " в ", " ви", " вр",
+    " де", " ду", " ег", " ес", " ещ", " зн", " из", " ил", " мн", " ну", " ок", " от", " оч", " сл",
+    " со", " то", "ает", "алу", "ано", "арк", "аси", "аю ", "ают", "бо ", "бы ", "вид", "вой", "вос",
+    "вре", "все", "вст", "всё", "гу ", "дин", "дит", "дня", "до ", "дол", "дум", "еде", "еле", "емя",
+    "есл", "ест", "ет ", "етс", "еть", "ещё", "жал", "же ", "жеш", "жно", "зак", "зал", "зна", "ибо",
+    "ибу", "иде", "ие ", "ин ", "исо", "ита", "итс", "ить", "их ", "иче", "ия ", "йст", "ко ", "кот",
+    "коф", "кры", "ла ", "лго", "луй", "мес", "мне", "мя ", "над", "нам", "нед", "ниб", "нич", "ния",
+    "нуж", "нь ", "ня ", "ого", "огу", "оди", "одн", "ожа", "оим", "оит", "олг", "оль", "омо", "он ",
+    "ост", "офе", "очи", "пас", "по ", "пож", "поз", "пок", "пом", "пра", "ра ", "раз", "ран", "ред",
+    "рем", "рет", "рое", "ром", "рос", "се ", "сег", "сиб", "ска", "ско", "сли", "смо", "спа", "стр",
+    "сё ", "таю", "ти ", "тои", "той", "том", "ужн", "уйс", "ума", "ую ", "фе ", "хор", "чег", "чен",
+    "чер", "чи ", "чит", "щё ", "ый ", "ыть", "ьям", "ют ", " а ", " бо", " бр", " бу", " ве", " вз",
+    " вм", " во", " вч", " вы", " гд", " гу", " да", " др", " её", " жа", " зв", " зд", " зе", " к ",
+    " ка", " ли", " лу", " ма", " ме", " од", " оп", " оф", " па", " пе", " пи", " пь", " ре", " ри",
+    " ру", " са", " сд", " см", " сн", " съ", " та", " тв", " уж", " ут", " фи", " це", " че", " чи",
+    " чё", " ша", " шо", " эк", "або", "абу",
+];
+const UK_TRIGRAMS: &[&str] = &[
+    "ти ", " за", " що", "ого", " на", " не", " я ", " до", " по", " пр", "го ", "не ", "ся ", "що ",
+    " ти", "ми ", " то", " і ", "ні ", " як", "ити", "мож", "ня ", "про", " бу", " ві", " ду", " з ",
+    " зн", " ми", " мо", " св", " це", " чи", "ати", "бі ", "же ", "ий ", "ка ", "ку ", "но ", "нов",
+    "обі", "раз", "ран", "ска", "сь ", "тоб", "ть ", "це ", " но", " об", " пі", " ра", " сп", "ав ",
+    "аза", "ам ", "ачи", "буд", "ву ", "він", "год", "до ", "дь ", "енн", "еш ", "жу ", "зна", "каз",
+    "лен", "на ", "над", "ння", "ніч", "ови", "одн", "оже", "ом ", "ось", "ою ", "пот", "ро ", "сі ",
+    "то ", "трі", "тьс", "удь", "ую ", "щос", "ьог", "ься", "яку", " ба", " би", " в ", " ва", " во",
+    " га", " де", " дя", " зм", " зу", " йо", " ка", " ку", " ла", " ма", " ме", " мі", " ні", " си",
+    " ск", " сь", " ус", " хо", " ці", " ча", " ще", "абу", "аву", "аді", "аз ", "ай ", "ара", "арт",
+    "ас ", "аск", "аю ", "бач", "би ", "бра", "вар", "вит", "вон", "вят", "від", "вій", "гар", "де ",
+    "дні", "дов", "доп", "дуж", "дум", "дяк", "діс", "еле", "емо", "ені", "ере", "жеш", "жня", "за ",
+    "зав", "зар", "зус", "ив ", "ижн", "ини", "ися", "ита", "ить", "йог", "кав", "кра", "кую", "кщо",
+    "ла ", "лас", "ли ", "мен", "най", "нам", "ни ", "нит", "ну ", "оби", "овг", "ово", "оди", "ожу",
+    "омо", "оно", "опо", "оті", "оїм", "пит", "под", "пом", "при", "піз", "ра ", "роб", "рто", "сві",
+    "сла", "стр", "сьо", "тиж", "уже", "узя", "ума", "уст", "усі", "час", "чер", "чи ", "чив", "чин",
+    "чог", "ще ", "ють", "якщ", "єть", "ів ", "ізн", "ій ", "іль", "ім ", "ін ", "ісл", "ічо", "іше",
+    " а ", " аб", " бл", " бр", " вв", " ве", " вж", " ви", " вр", " вс", " гу", " да", " дз", " др",
+    " ді", " ек", " зе", " зр", " зі", " зї", " кр", " кі", " ни", " од", " он", " оф", " па", " пе",
+    " пю", " ри", " рі", " са", " со", " ст", " та", " тв", " те", " тр", " ту", " у ", " уз", " уч",
+    " фі", " ць", " че", " чу", " шм", " шо", " щи", " із", " її", "або", "авт", "ага", "ад ", "адн",
+    "азд", "ази", "азо", "айд", "айк", "айш", "ак ", "ала", "али", "ами", "амо", "ан ", "ано", "анц",
+    "ані", "апі", "арк", "арн", "аря", "аст",
+];
+const BG_TRIGRAMS: &[&str] = &[
+    " да", " за", " пр", "та ", " не", " се", "ва ", "да ", " ка", "те ", " на", " по", "го ", "се ",
+    " и ", " си", " че", "ли ", "то ", "че ", " ми", " мо", " от", " с ", "за ", "не ", "но ", "си ",
+    "що ", " в ", " вс", " го", " то", "ите", "на ", "пра", "про", "ти ", "ще ", " е ", " но", " ра",
+    " ти", " тр", "ам ", "аря", "бва", "во ", "еш ", "ещо", "им ", "иш ", "как", "ля ", "нов", "ова",
+    "ово", "ого", "от ", "рав", "ред", "ряб", "тря", "ца ", "ябв", " би", " ви", " до", " ко", " ли",
+    " мн", " па", " пи", " съ", " те", " ще", "ази", "акв", "ал ", "ата", "вид", "вор", "вси", "дно",
+    "еме", "ени", "ето", "зи ", "или", "ица", "ичк", "кво", "ки ", "ко ", "ми ", "мно", "мож", "нещ",
+    "ног", "оже", "ори", "по ", "раз", "рат", "ря ", "сед", "сич", "сле", "тов", "ят ", "ята", " ак",
+    " бл", " ве", " вр", " де", " дн", " из", " ил", " ис", " ку", " къ", " ни", " ощ", " са", " ст",
+    " та", " тя", "ави", "аго", "аза", "ай ", "ак ", "ако", "акъ", "аре", "ати", "афе", "аш ", "би ",
+    "бла", "бра", "вам", "вре", "все", "га ", "гов", "год", "дал", "дар", "де ", "дел", "ди ", "дми",
+    "дне", "доб", "ед ", "едм", "едн", "еле", "ели", "ем ", "ес ", "еца", "еше", "жеш", "зар", "зат",
+    "иди", "ина", "иск", "исл", "ита", "ищо", "ият", "ка ", "каз", "каф", "клю", "кол", "къс", "лаг",
+    "лед", "люч", "ме ", "мис", "миц", "мог", "мол", "нар", "нес", "ние", "нищ", "нят", "ода", "око",
+    "оля", "омо", "оре", "ост", "ото", "още", "пар", "пом", "поч", "пре", "ра ", "ран", "рем", "риз",
+    "рил", "рия", "са ", "ска", "сти", "съм", "таз", "тел", "тно", "утр", "фе ", "чер", "чки", "ъм ",
+    "ъсн", "ът ", "ява", "ях ", " бе", " бр", " бу", " вз", " вк", " вл", " вч", " гл", " дъ", " ед",
+    " ек", " зд", " зе", " зн", " ла", " ле", " ма", " ме", " ня", " ок", " ор", " оф", " оц", " ре",
+    " ри", " ск", " сл", " сп", " су", " ту", " уд", " ут", " фи", " ху", " чу", " шо", " яд", " ѝ ",
+    "або", "абр", "ава", "аве", "аво", "авя", "ага", "ади", "адо", "адя", "аед", "ажи", "азг", "азн",
+    "азх", "аис", "ала", "али", "аме", "ана", "ано", "апо", "апр", "апт", "ара", "арк", "арч", "ат ",
+    "атв", "ато", "ащи", "аят", "бав", "беш",
+];
+const SR_TRIGRAMS: &[&str] = &[
+    "да ", " да", " по", "је ", " пр", " са", " не", " је", "им ", "ли ", " мо", " се", "мо ", "ам ",
+    "се ", " и ", " ко", " на", " ни", "еш ", "но ", "про", "сам", "ти ", "то ", " ви", " ка", " ре",
+    " у ", "еде", "има", "ма ", "са ", " би", " ве", " ли", " ов", " пи", " св", " ст", " ти", " то",
+    " тр", "ао ", "вар", "ви ", "вид", "дим", "еба", "имо", "ко ", "ово", "оли", "ом ", "реб", "те ",
+    "тре", " вр", " за", " ми", " но", " о ", " од", " па", " ра", " те", "ба ", "во ", "го ", "де ",
+    "дељ", "еле", "ео ", "или", "иш ", "ије", "кој", "ку ", "ла ", "лим", "мож", "на ", "не ", "нед",
+    "нис", "нов", "оји", "оћи", "пос", "ра ", "та ", "тва", "шта", "што", "јед", "ју ", "ће ", "ћи ",
+    " ак", " во", " де", " ду", " ил", " ку", " ла", " он", " си", " хв", " шт", " јо", "ави", "ада",
+    "ако", "ала", "амо", "ара", "арн", "ас ", "асн", "афу", "аје", "би ", "вал", "ве ", "вре", "део",
+    "ду ", "дуг", "едн", "еду", "ем ", "еме", "ему", "ени", "есе", "ет ", "ече", "ешт", "еље", "жеш",
+    "иде", "иди", "иса", "ита", "их ", "ишт", "кас", "каф", "кол", "кор", "кљу", "ле ", "лед", "лиш",
+    "ло ", "мен", "ми ", "мог", "мол", "моћ", "му ", "нај", "неш", "ниш", "ниј", "ну ", "ова", "од ",
+    "оже", "око", "омо", "оно", "ора", "осл", "ост", "ош ", "оју", "пар", "по ", "пом", "пон", "при",
+    "рав", "рад", "раз", "ран", "ред", "рек", "рем", "риј", "рно", "сви", "си ", "сле", "сни", "ста",
+    "ств", "сти", "тал", "тај", "уго", "утр", "учи", "ује", "фу ", "хва", "цим", "че ", "чем", "ји ",
+    "јим", "још", "ље ", "ључ", " а ", " бр", " га", " гд", " до", " ек", " же", " зб", " зд", " зе",
+    " зн", " ис", " ле", " ме", " мн", " ок", " ол", " оп", " от", " пл", " пу", " сл", " см", " су",
+    " ув", " ук", " ур", " ус", " уј", " фи", " хо", " це", " чи", " чо", " чу", " ше", " ја", " ју",
+    " ње", " њи", " ће", " ћу", "або", "авн", "аво", "аву", "аде", "ади", "адн", "азм", "азн", "аку",
+    "али", "алн", "ало", "ан ", "ана", "ано", "анц", "апт", "ари", "арк", "ару", "арч", "ат ", "атв",
+    "ате", "атн", "аци", "ач ", "аш ", "ашл", "ај ", "ајб", "ају", "аље", "бал", "бил", "бих", "бле",
+    "бог", "бор", "бољ", "бра", "ват", "вде",
+];
+
+
+const NL_TRIGRAMS: &[&str] = &[
+    "en ", "et ", " he", "at ", " we", " de", "aar", "de ", "het", " da", "er ", " be", "ar ", "je ",
+    " je", " me", " wa", "dat", "een", "an ", "we ", " ee", " ge", "gen", "nie", " ik", " ni", " va",
+    "ik ", "nd ", "nde", "ten", "van", "ver", " mo", " st", " zi", "cht", "el ", "heb", "ien", "iet",
+    "om ", "ren", "st ", "ter", " ma", " om", " ve", " vo", "end", "ie ", "ij ", "ijn", "in ", "is ",
+    "jn ", "maa", "met", "wee", "zie", " al", " ga", " ha", " is", " mi", " na", " te", " ze", "aan",
+    "aat", "and", "ben", "eb ", "eer", "ete", "gaa", "ht ", "ist", "sch", "ste", "ze ", " do", " en",
+    " in", " no", " op", " pr", "all", "as ", "daa", "den", "der", "doe", "ere", "gez", "ijk", "kt ",
+    "lan", "lie", "lle", "me ", "men", "naa", "ng ", "nog", "oet", "og ", "ond", "oor", "op ", "or ",
+    "rij", "te ", " gi", " ke", " ku", " la", " ov", " pa", "ag ", "al ", "ate", "bed", "dag", "ech",
+    "ee ", "eek", "eel", "ek ", "ele", "erd", "es ", "euw", "ezi", "gee", "gis", "haa", "hee", "ieu",
+    "ing", "jk ", "ken", "kom", "kun", "len", "lge", "lij", "mij", "moe", "nen", "nne", "olg", "ove",
+    "rda", "rge", "roe", "stu", "ull", "ur ", "uur", "vol", "von", "voo", "was", "wat", "zij", " bi",
+    " ec", " er", " ev", " fi", " gr", " hi", " ho", " ja", " jo", " ju", " ka", " ko", " li", " ne",
+    " of", " on", " sa", " sc", " ui", " wi", " zu", "aal", "ach", "ame", "ana", "ang", "ank", "app",
+    "ark", "aro", "ast", "avo", "bbe", "bel", "bij", "cha", "che", "chi", "dan", "del", "ebb", "ect",
+    "ede", "eet", "eft", "ege", "eke", "eli", "ema", "ent", "erg", "eri", "etj", "ets", "euk", "eve",
+    "eze", "ft ", "gin", "gro", "hie", "hij", "hte", "hui", "iek", "ind", "iss", "jou", "jul", "kee",
+    "laa", "lem", "leu", "lli", "mda", "mis", "moo", "mor", "nav", "nk ", "ns ", "nt ", "oe ", "oen",
+    "of ", "omd", "ons", "ooi", "org", "ou ", "pen", "pla", "ppe", "pro", "rec", "rie", "rkt", "rom",
+    "sam", "ssc", "sta", "tje", "tra", "tre", "ts ", "tuu", "uis", "uit", "uk ", "un ", "us ", "uwe",
+    "ven", "waa", "wan", "wel", " aa", " af", " am", " ba", " bo", " br", " di", " du", " el", " et",
+    " fa", " fr", " id", " ie", " ji", " ki",
+];
+
+const PL_TRIGRAMS: &[&str] = &[
+    "ie ", "nie", " po", "dzi", " na", " je", " pr", " w ", "em ", "my ", " mo", " ni", "na ", " cz",
+    " si", " wi", "cie", "esz", "jes", "ze ", "że ", " dz", " o ", " za", "ię ", "moż", "oże", "się",
+    " ch", "aj ", "cze", "ej ", "est", "iał", " ci", " z ", " ze", " że", "ać ", "ałe", "czo", "czy",
+    "ies", "je ", "mi ", "pra", "prz", "rze", "sz ", "wie", "ym ", "zia", "zie", "zor", "zys", " ba",
+    " co", " ko", " ma", " mi", " te", " ty", "am ", "ard", "bar", "dzo", "ied", "ję ", "pow", "raz",
+    "rdz", "wia", "wsz", "zo ", "ści", " bę", " do", " i ", " ob", " od", " pa", " pi", " ra", " sp",
+    " ta", " wc", " ws", " wy", " zn", "amy", "awi", "az ", "będ", "ch ", "co ", "dni", "dę ", "ecz",
+    "edz", "emy", "eś ", "god", "iaj", "isi", "iu ", "ięk", "iśm", "ją ", "kie", "le ", "ma ", "mie",
+    "niu", "now", "ny ", "obi", "och", "ora", "ost", "owa", "owi", "pro", "raj", "ran", "raw", "rob",
+    "sia", "st ", "ste", "stk", "szc", "szy", "szł", "tki", "uje", "uję", "wcz", "yst", "ysz", "yśl",
+    "zcz", "zen", "zep", "zis", "zy ", "ła ", "łem", "śmy", "ść ", "żem", " al", " dl", " fi", " gd",
+    " ju", " ją", " ki", " kr", " ku", " le", " mn", " mu", " my", " no", " oc", " pó", " ro", " st",
+    " sw", " sz", " są", " to", " wa", " zj", " ła", "acj", "acz", "ada", "ado", "adz", "aki", "ala",
+    "ami", "ani", "apr", "as ", "asz", "at ", "awd", "ała", "bie", "bo ", "bra", "chc", "cho", "chę",
+    "ci ", "cia", "czu", "dla", "do ", "dom", "ebi", "ek ", "eka", "ekt", "eni", "epr", "esi", "eść",
+    "go ", "ia ", "iad", "iać", "idz", "ieb", "iec", "iej", "iew", "ij ", "im ", "iąt", "ić ", "ięc",
+    "ja ", "jut", "ka ", "kam", "kaw", "kol", "kra", "ku ", "kuj", "la ", "lac", "liś", "mam", "maw",
+    "mni", "moj", "mu ", "mus", "myś", "nap", "no ", "obo", "ocz", "od ", "odn", "odz", "oja", "oje",
+    "ole", "ore", "oro", "otę", "owo", "ozd", "ozm", "po ", "poc", "por", "pot", "poz", "póź", "ra ",
+    "rac", "rem", "rmi", "row", "roz", "rzy", "sio", "sta", "sze", "szę", "tam", "tan", "tej", "tek",
+    "tem", "ter", "teś", "to ", "tra", "tro", "two", "tyg", "tym", "tę ", "utr", "wa ", "waż", "wdę",
+    "wid", "wię", "wys", "ygo", "zad", "zdr",
+];
+
+const TR_TRIGRAMS: &[&str] = &[
+    "en ", " bi", "da ", "in ", " bu", " ço", "bir", " ge", " ha", " ya", "eni", "ir ", "ok ", "yor",
+    "çok", "unu", " se", "ek ", "im ", "lar", " da", "an ", "den", "edi", "er ", "le ", " be", " ka",
+    "ada", "eme", "nda", "ni ", "ta ", "un ", " dü", " gö", " mi", " ne", " ol", " so", " ye", "aca",
+    "am ", "ar ", "bil", "bu ", "ede", "ele", "eri", "gel", "ist", "iyo", "na ", "nu ", "oru", "sen",
+    "yle", "ınd", " an", " ar", " ba", " is", " iç", " me", " ta", "alı", "ama", "ana", "anı", "ara",
+    "ard", "arı", "ben", "di ", "duğ", "dün", "isi", "içi", "ki ", "ldu", "ler", "mek", "nde", "ne ",
+    "nin", "nın", "old", "rke", "sin", "son", "ste", "um ", "uğu", "çin", "öyl", "ün ", "ür ", "ğun",
+    "ın ", " ak", " de", " er", " he", " ko", " mü", " pa", " sa", " sö", " ve", " yü", " şe", "abi",
+    "aft", "aha", "ak ", "akk", "akş", "ark", "aya", "ağı", "aşa", "aşı", "bun", "cağ", "dah", "der",
+    "dim", "ere", "erk", "eği", "fta", "ger", "gör", "ha ", "haf", "hak", "ile", "ili", "ilm", "kad",
+    "kar", "kkı", "kte", "kür", "kın", "kşa", "led", "len", "lir", "med", "mis", "miy", "nra", "nı ",
+    "onr", "onu", "or ", "ra ", "rad", "rim", "rka", "rla", "rum", "san", "söy", "te ", "uz ", "yem",
+    "zde", "üşü", "ını", "ıyo", "şam", "şey", " al", " du", " ed", " gü", " ke", " o ", " pi", " te",
+    " uy", " uz", " yi", " za", " öğ", " şi", "aba", "adı", "ane", "arl", "ast", "ay ", "aşl", "ban",
+    "baş", "bul", "cak", "cek", "dan", "daş", "de ", "değ", "deş", "dur", "düş", "dır", "ece", "ekk",
+    "ekt", "eli", "end", "erç", "esi", "ey ", "eşe", "eşi", "gön", "güz", "hab", "has", "her", "ikt",
+    "il ", "iml", "ini", "ira", "irk", "iyi", "iz ", "iş ", "kek", "kkü", "kon", "kta", "kü ", "la ",
+    "lac", "lam", "lan", "lec", "lim", "lmi", "lı ", "lış", "ma ", "mal", "man", "mer", "meğ", "mle",
+    "ndi", "ned", "nkü", "num", "nun", "nuş", "pla", "rak", "ray", "raz", "rda", "rde", "rme", "rte",
+    "ruz", "rçe", "rın", "sev", "sta", "sun", "tan", "tar", "ten", "ter", "teş", "tı ", "umu", "uru",
+    "uyo", "uzu", "ve ", "ver", "ya ", "yan", "yap", "yar", "yağ", "yaş", "yen", "yi ", "yla", "yüz",
+    "zam", "zda", "zel", "zun", "çek", "önd",
+];
+
+const VI_TRIGRAMS: &[&str] = &[
+    "ng ", " ch", " tô", "tôi", "ôi ", " th", "ạn ", " bạ", "bạn", " kh", "ông", "hôn", "khô", "nh ",
+    " nh", " tr", "chú", "úng", " ng", " đã", "hún", "đã ", "ới ", "ên ", "ất ", "ết ", " cô", " gi",
+    " qu", " sa", " vớ", " đó", " đư", " ở ", "với", "đó ", "ời ", " có", " mộ", " nà", " nó", " ph",
+    " rấ", " vi", " và", "ai ", "ay ", "cho", "có ", "ho ", "iết", "một", "rất", "ày ", "ình", "đượ",
+    "ược", "ại ", "ột ", "ợc ", " bi", " bà", " gì", " hô", " lạ", " ma", " mu", " rồ", " ta", " ti",
+    " về", " đi", "ang", "au ", "biế", "côn", "gì ", "hiề", "huy", "hôm", "hứ ", "iều", "lại", "ngh",
+    "nhi", "nói", "qua", "rồi", "ta ", "ua ", "việ", "về ", "ào ", "ác ", "ói ", "ôm ", "đi ", "ưa ",
+    "ấy ", "ần ", "ều ", "ện ", "ọi ", "ồi ", " an", " cá", " cả", " gử", " ho", " mọ", " na", " sẽ",
+    " tu", " tố", " vì", " vậ", " đế", " ấy", "anh", "bà ", "chu", "các", "em ", "ghĩ", "giờ", "gửi",
+    "hé ", "hĩ ", "hật", "hể ", "ia ", "iệc", "iờ ", "mọi", "nay", "nhé", "này", "sau", "sẽ ", "thậ",
+    "thể", "thứ", "tuầ", "uần", "và ", "vì ", "vậy", "àn ", "âu ", "ăm ", "ăn ", "đến", "ơn ", "ật ",
+    "ậy ", "ến ", "ệc ", "ối ", "ửi ", " bị", " co", " cu", " cố", " dạ", " gặ", " ha", " hà", " hì",
+    " là", " lắ", " mớ", " nê", " nă", " số", " tì", " xe", " ăn", " đa", " đâ", " ơn", "ao ", "bị ",
+    "chi", "con", "cô ", "cảm", "cố ", "dạo", "eo ", "gia", "gày", "gườ", "gặp", "heo", "hàn", "hìn",
+    "in ", "iên", "iện", "lắm", "mai", "muố", "mới", "ngà", "ngư", "như", "nào", "nên", "năm", "on ",
+    "ong", "rai", "ron", "rên", "sao", "sốn", "the", "thà", "tiế", "tra", "tro", "trê", "tối", "uyệ",
+    "uốn", "xem", "yện", "ài ", "ành", "áng", "òng", "úc ", "đan", "đâu", "ười", "ạo ", "ảm ", "ắm ",
+    "ặp ", "ếp ", "ốn ", "ống", "ừng", " bâ", " bư", " bả", " bắ", " bế", " bộ", " cà", " cò", " cù",
+    " cơ", " cạ", " củ", " dễ", " dờ", " dự", " em", " gá", " gắ", " gọ", " hơ", " hạ", " hẹ", " hế",
+    " họ", " hỏ", " hợ", " kế", " ly", " lâ", " lò", " lú", " lầ", " lỗ", " lờ", " mà", " mè", " mò",
+    " mó", " mư", " mẹ", " mừ", " nú", " nẵ", " nế", " nộ", " nữ", " pi", " rả", " rằ", " sá", " sắ",
+    " sẻ", " sứ", " sự", " ty", " tà", " tò",
+];
+
+const SV_TRIGRAMS: &[&str] = &[
+    "et ", "en ", "ar ", " de", " i ", "tt ", " vi", "det", " me", " ti", "ag ", "ll ", " ha", "ill",
+    "om ", "är ", "att", "er ", "med", "te ", "til", "vi ", " at", " in", " ja", " va", "an ", "ed ",
+    "ta ", " är", "för", "har", "ing", "jag", " du", " på", "cka", "du ", "ig ", "kan", "på ", " di",
+    " fö", " ka", " må", " oc", " om", " pr", " sk", " så", " ta", "ch ", "dig", "gen", "int", "ka ",
+    "la ", "ter", "var", "yck", "år ", "ör ", " he", " mi", " se", "ack", "gt ", "igt", "in ", "nga",
+    "nte", "och", "ste", "så ", " en", " fr", " gå", " ko", " ny", " nä", " vä", "ad ", "and", "bor",
+    "ck ", "cke", "dag", "frå", "ga ", "nen", "ng ", "on ", "org", "rån", "som", "sta", "tac", "väl",
+    "äst", "ån ", "ång", " al", " bo", " ef", " fi", " hä", " ni", " pa", " ri", " sj", " ve", "all",
+    "de ", "eft", "em ", "ena", "es ", "fte", "get", "går", "han", "ket", "kom", "kvä", "lan", "lig",
+    "men", "mig", "mån", "na ", "nde", "nge", "nin", "näs", "or ", "pro", "ret", "rt ", "sa ", "sen",
+    "tet", "tor", "vad", "ytt", "äll", "ätt", " br", " bä", " da", " dä", " fl", " fo", " gö", " ho",
+    " hu", " jo", " kv", " li", " lu", " lå", " mo", " my", " nå", " os", " sa", " so", " st", " to",
+    " ty", " ät", "amm", "ane", "ans", "ara", "art", "ass", "at ", "ata", "del", "den", "din", "där",
+    "eck", "ela", "enn", "ent", "ers", "ett", "far", "fin", "fly", "for", "gon", "hen", "hon", "hur",
+    "häl", "ick", "id ", "ige", "ikt", "it ", "ja ", "job", "juk", "jäl", "ken", "ker", "kic", "kla",
+    "kte", "kti", "lar", "ldi", "lla", "lle", "lsa", "lyt", "låt", "mer", "min", "mma", "mme", "mor",
+    "myc", "mås", "nad", "nar", "ni ", "nne", "nt ", "nta", "någ", "obb", "ock", "omm", "ort", "oss",
+    "pas", "pla", "pra", "ra ", "ran", "rat", "re ", "rfö", "rgo", "rso", "rtf", "sar", "ses", "set",
+    "sju", "ska", "ski", "ss ", "ssa", "såg", "tag", "tar", "tat", "tfa", "tid", "tig", "tre", "tta",
+    "tte", "tyc", "ur ", "vec", "vän", "äld", "äls", "äta", "åg ", "åst", "öte", " an", " ba", " bi",
+    " bö", " ch", " el", " et", " fa", " fj", " få", " gl", " hi", " hå", " hö", " ig", " jä", " kl",
+    " kö", " lo", " ly", " lä", " lö", " ma",
+];
+
+const CS_TRIGRAMS: &[&str] = &[
+    "je ", " ne", " pr", " je", " js", " na", "me ", "pro", " po", " se", "na ", "se ", "že ", "em ",
+    "si ", " mo", " to", " v ", "li ", "mi ", "to ", "moc", " mě", " př", " si", " ta", " te", " za",
+    " že", "du ", "děl", "ji ", "jse", "oje", "ou ", "ra ", "rav", "sem", "čer", "ěl ", " a ", " by",
+    " dě", " mů", " o ", " s ", " st", " ve", " vč", "co ", "eme", "eš ", "mě ", "můž", "nev", "oc ",
+    "pra", "tí ", "uje", "vid", "ít ", "ště", "ůže", " bu", " ch", " co", " ji", " jí", " mi", " my",
+    " má", " op", " vš", "ak ", "avd", "ch ", "chn", "de ", "dne", "ech", "edn", "idě", "it ", "jsi",
+    "la ", "le ", "mys", "nem", "nou", "ní ", "ně ", "ole", "om ", "opr", "oto", "pří", "roc", "rom",
+    "tak", "tra", "tu ", "uvi", "vdu", "vče", "věd", "ysl", "zít", "ím ", "čas", " br", " de", " dn",
+    " fi", " ho", " kd", " kr", " lé", " mn", " no", " ob", " od", " rá", " sp", " sv", " tr", " tu",
+    " tý", " zp", " zí", " ře", " ťu", "akž", "am ", "as ", "ase", "be ", "bra", "bud", "byl", "chu",
+    "ci ", "da ", "dem", "den", "dno", "do ", "dra", "děk", "dět", "ebe", "ej ", "ekl", "el ", "emo",
+    "en ", "ent", "ení", "er ", "era", "es ", "est", "et ", "evi", "evě", "eza", "eče", "ešt", "hno",
+    "jde", "jed", "ješ", "jsm", "jí ", "ku ", "kuj", "kže", "lat", "lež", "luv", "ma ", "mlu", "mno",
+    "moj", "mám", "měl", "naš", "nes", "nez", "ni ", "nic", "no ", "nov", "ný ", "och", "ocn", "oml",
+    "ozd", "očk", "ože", "pol", "poz", "poč", "prá", "rot", "rác", "slí", "sme", "spo", "sti", "stí",
+    "sím", "ta ", "tam", "te ", "teb", "tel", "ter", "tom", "tož", "té ", "týd", "tě ", "těs", "uch",
+    "ude", "uk ", "več", "vit", "vou", "vuj", "vše", "zas", "zdr", "ád ", "áme", "ích", "íme", "íte",
+    "ítr", "íšt", "ýde", "ědě", "ěku", "ěli", "ěst", "ět ", "řek", "říš", "šec", "ští", "ší ", "ťuk",
+    "žem", "žeš", "ží ", " ah", " al", " až", " da", " dl", " do", " dv", " dů", " he", " ja", " ka",
+    " kl", " ko", " kt", " ku", " ká", " le", " mu", " mí", " ni", " ná", " ně", " ol", " pa", " pi",
+    " pl", " pu", " pá", " ro", " rý", " sa", " sd", " so", " sr", " ti", " tv", " té", " tě", " uv",
+    " už", " vi", " vl", " vy", " vz", " vá",
+];
+
+// Romanized Hindi and Thai, as typed in chat
+
+const HI_LATN_TRIGRAMS: &[&str] = &[
+    " ha", "hai", " ka", "ai ", " ba", "hi ", "in ", " th", "na ", " ho", "ya ", "ar ", "cha", " ch",
+    " na", "ain", " me", "ahi", "ha ", "he ", "kar", " aa", " ma", " ra", " sa", "ho ", "ne ", " ky",
+    "rah", " pa", " tu", "hoo", " ki", "bah", "ein", "hal", "hut", "nah", "on ", "tum", " de", "ab ",
+    "ahu", "am ", "ek ", "kya", "mei", "se ", "the", "um ", "ut ", " hu", "aar", "baa", "eek", "lo ",
+    "oon", " kh", " mu", "aha", "ais", "an ", "bhi", "hee", "iya", "kha", "te ", "tha", " bh", " ja",
+    "aat", "al ", "at ", "aya", "ch ", "ga ", "haa", "jhe", "mai", "muj", "ujh", " bo", " ga", " pe",
+    "aan", "ahe", "aro", "ata", "di ", "han", "har", "hna", "hod", "kai", "kal", "tho", "yaa", "ye ",
+    " ab", " do", " ku", " ph", " se", " wa", "abh", "ana", "as ", "aye", "eng", "ge ", "ir ", "ise",
+    "ki ", "kuc", "lte", "re ", "sab", "ta ", "uch", " ac", " gh", " ko", " la", " lo", " ti", " ya",
+    "aad", "aap", "acc", "ad ", "alo", "alt", "ao ", "ara", "bat", "bol", "cch", "da ", "dek", "ekh",
+    "gay", "hir", "hua", "hum", "itn", "khn", "lag", "me ", "mer", "nge", "oh ", "pe ", "phi", "sa ",
+    "ua ", " au", " to", "aa ", "aam", "aay", "adh", "ah ", "ald", "ap ", "ass", "ath", "ayi", "bha",
+    "chh", "de ", "dha", "do ", "ega", "gha", "ime", "iye", "jal", "ka ", "kab", "kah", "kit", "kiy",
+    "ko ", "kyu", "ldi", "mne", "nga", "oda", "odi", "olo", "pad", "pas", "ra ", "ram", "ro ", "saa",
+    "si ", "sne", "ss ", "st ", "th ", "tim", "toh", "yeg", "yi ", "yun", " ap", " ar", " bi", " bu",
+    " dh", " ek", " gu", " it", " ke", " li", " mi", " ne", " ni", " po", " sh", " su", " ta", " tr",
+    " ye", " za", "aaj", "aas", "ach", "aiy", "aj ", "ak ", "akh", "aln", "ama", "apn", "are", "arn",
+    "arr", "ast", "aur", "bas", "bil", "den", "der", "dos", "egi", "eh ", "ena", "er ", "ere", "et ",
+    "ffi", "fic", "gi ", "gus", "hah", "hay", "hen", "hiy", "ilk", "isi", "isn", "jay", "kaa", "keh",
+    "ket", "khi", "kis", "koi", "kul", "le ", "liy", "lku", "lna", "log", "maa", "mas", "mat", "mil",
+    "nd ", "och", "oi ", "ok ", "ooc", "ook", "oor", "or ", "ost", "pak", "pay", "pna", "poo", "rak",
+    "rna", "roo", "rre", "sac", "sam", "sha",
+];
+
+const TH_LATN_TRIGRAMS: &[&str] = &[
+    "ai ", " ma", "ee ", "ng ", "mai", " na", "an ", " kr", "kru", " ka", "rub", "ub ", "ak ", "ao ",
+    " ch", " pa", " sa", "dee", "ka ", " de", "na ", "ang", "rai", " ja", " te", "cha", "pai", " ra",
+    "ew ", "mak", "ong", " ba", " ne", " no", "bai", "en ", "nee", "oo ", " ar", " da", " kh", " pe",
+    " to", "aba", "aew", "dai", "gan", "it ", "oi ", "on ", "sab", "tee", " ki", " la", " lo", " ro",
+    "am ", "er ", "in ", "lae", " ao", " ta", " wa", " ya", " yo", "ara", "ay ", "han", "ja ", "noi",
+    "ok ", "ot ", "pen", "uay", "un ", "wan", "yoo", " ga", " ph", " po", " re", "at ", "ban", "eng",
+    "ey ", "ha ", "jai", "loe", "oey", "om ", "or ", "run", " du", " ha", " ko", " me", " ng", " pl",
+    " pr", " tu", "ae ", "dua", "eu ", "hai", "hun", "kao", "khu", "kin", "lao", "mee", "nan", "nga",
+    "pla", "reu", "ter", "ua ", "ung", "yan", " go", " je", " kl", " ni", " nu", " ti", " ye", "hao",
+    "hop", "hut", "ian", "jer", "kho", "kit", "klu", "kor", "lua", "ma ", "nai", "nit", "op ", "phu",
+    "pom", "pru", "rak", "tam", "ton", "tue", "ue ", "uea", "uen", "ut ", "yen", " bi", " ee", " fa",
+    " ho", " jo", " ke", " ku", " kw", " mo", " ri", " si", " th", " yu", "aht", "ap ", "awa", "bah",
+    "bin", "chu", "ea ", "eek", "ek ", "et ", "gon", "hi ", "hok", "ht ", "hue", "iew", "jon", "kae",
+    "kwa", "lot", "nae", "nak", "nam", "non", "nua", "pak", "pet", "phi", "pon", "ria", "roi", "ron",
+    "roo", "rot", "sai", "saw", "tao", "tha", "tie", "yak", " aa", " bo", " do", " fo", " gl", " gr",
+    " he", " li", " mu", " oh", " ok", " pi", " ru", " so", " su", " tr", " wi", " yi", "aat", "ab ",
+    "ad ", "aen", "afe", "aha", "ala", "anu", "aro", "aru", "asd", "ati", "bat", "bok", "che", "chi",
+    "cho", "ck ", "doo", "ean", "eb ", "eck", "eeb", "fak", "fan", "fe ", "fi ", "fon", "gai", "gla",
+    "goo", "gro", "ham", "hat", "hec", "hew", "hia", "hon", "id ", "ifi", "ik ", "ine", "ip ", "jak",
+    "kaf", "kap", "kee", "ken", "kha", "kon", "kua", "kun", "lab", "lat", "lin", "mae", "mon", "mot",
+    "mue", "ne ", "nok", "nue", "nuk", "ob ", "oh ", "oon", "pad", "pae", "pit", "pri", "rah", "ree",
+    "rik", "rom", "ror", "rua", "rue", "san",
+];
+
+/// Trigram profile for a language
+/// Languages identified by their script alone (Hindi, Thai, Chinese,
+/// Japanese, Korean) have no profile and return an empty list.
+pub fn trigram_profile(language: &str) -> &'static [&'static str] {
+    match language {
+        "en" => EN_TRIGRAMS,
+        "de" => DE_TRIGRAMS,
+        "fr" => FR_TRIGRAMS,
+        "it" => IT_TRIGRAMS,
+        "pt" => PT_TRIGRAMS,
+        "es" => ES_TRIGRAMS,
+        "ru" => RU_TRIGRAMS,
+        "uk" => UK_TRIGRAMS,
+        "bg" => BG_TRIGRAMS,
+        "sr" => SR_TRIGRAMS,
+        "nl" => NL_TRIGRAMS,
+        "pl" => PL_TRIGRAMS,
+        "tr" => TR_TRIGRAMS,
+        "vi" => VI_TRIGRAMS,
+        "sv" => SV_TRIGRAMS,
+        "cs" => CS_TRIGRAMS,
+        "hi-Latn" => HI_LATN_TRIGRAMS,
+        "th-Latn" => TH_LATN_TRIGRAMS,
+        _ => &[],
+    }
+}
+
+/// Padded, lowercased letter trigrams of every word in a text
+/// Digits and other non-letters are dropped from words first.
+pub fn trigrams(text: &str) -> Vec<String> {
+    let mut grams = Vec::new();
+    for word in words(text) {
+        let letters: String = word.text.chars().filter(|c| c.is_alphabetic()).flat_map(char::to_lowercase).collect();
+        if letters.is_empty() {
+            continue;
+        }
+        let padded: Vec<char> = format!(" {} ", letters).chars().collect();
+        grams.extend(padded.windows(3).map(|window| window.iter().collect::<String>()));
+    }
+    grams
+}
+
+/// Rank-weighted hits of trigrams in a ranked profile
+/// Each trigram found in the profile scores by its rank, from 1.0 for the
+/// most frequent down towards 0.0 for the last. Divided by the number of
+/// trigrams this gives a similarity from 0.0 to 1.0.
+pub fn profile_hits<S: AsRef<str>>(grams: &[String], profile: &[S]) -> f64 {
+    grams
+        .iter()
+        .filter_map(|gram| profile.iter().position(|entry| entry.as_ref() == gram))
+        .map(|rank| 1.0 - rank as f64 / profile.len() as f64)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trigrams_are_padded_per_word() {
+        assert_eq!(trigrams("Hi, yo2u!"), [" hi", "hi ", " yo", "you", "ou "]);
+        assert!(trigrams("42 !!").is_empty());
+    }
+
+    #[test]
+    fn similarity_weights_rank() {
+        let grams = trigrams("the");
+        assert_eq!(profile_hits::<&str>(&grams, &[]), 0.0);
+        assert_eq!(profile_hits(&[], EN_TRIGRAMS), 0.0);
+        let top = EN_TRIGRAMS[0].to_string();
+        assert_eq!(profile_hits(&[top.clone(), top], EN_TRIGRAMS), 2.0);
+        assert!(trigram_profile("hi").is_empty() && trigram_profile("th").is_empty());
+    }
+}
+
@@ -0,0 +1,1259 @@
+//! Character-level normalization helpers
+
+use wasm_bindgen::prelude::*;
+use crate::entities::{code_spans, fenced_code_spans, url_spans, Span};
+use crate::unicode::{normalize_form, Form};
+use crate::emoji::is_emoji;
+use crate::segmentation::{graphemes, is_extend};
+use crate::confusables::skeleton_of;
+use crate::js::to_js;
+use serde::Serialize;
+use std::ops::Range;
+
+/// Characters that may be typed in place of an apostrophe
+/// Backtick and acute accent only count between two letters ("don`t").
+fn is_apostrophe_like(c: char) -> bool {
+    matches!(c, '\'' | '’' | '‘' | 'ʼ' | '＇' | '‛' | '`' | '´' | '′')
+}
+
+/// Words written with a leading apostrophe ('til, 'cause, 'em)
+const LEADING_ELISIONS: [&str; 7] = ["til", "tis", "twas", "cause", "em", "round", "n"];
+
+/// Decide whether the character at `index` is used as an apostrophe
+fn is_apostrophe_at(chars: &[char], index: usize) -> bool {
+    let c = chars[index];
+    let prev = index.checked_sub(1).map(|i| chars[i]);
+    let next = chars.get(index + 1).copied();
+    let is_letter = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+
+    // Contractions and elisions: don't, l'homme, rock'n'roll
+    if is_letter(prev) && is_letter(next) {
+        return true;
+    }
+    if matches!(c, '`' | '´' | '′') {
+        return false;
+    }
+
+    // Plural possessive: the students' room
+    if prev.is_some_and(|p| p == 's' || p == 'S') && !is_letter(next) && c != '‘' {
+        return true;
+    }
+
+    // Leading elision: '90s, 'til
+    if !is_letter(prev) {
+        let rest: String = chars[index + 1..].iter().take_while(|c| c.is_alphanumeric()).collect();
+        let decade = rest.len() >= 2 && rest.chars().take(2).all(|c| c.is_ascii_digit());
+        return decade || LEADING_ELISIONS.contains(&rest.to_lowercase().as_str());
+    }
+
+    false
+}
+
+/// Normalize apostrophe-like characters to a single form
+///
+/// Straight ('), curly (’), modifier (ʼ), full-width and mistyped accent
+/// characters are rewritten only where they act as an apostrophe: inside
+/// words ("don`t" → "don't"), after a plural possessive ("students’") and
+/// before elided words or decades ("'90s"). Quotation marks around words
+/// are left alone, as are fenced code blocks and backticks that delimit
+/// inline code, and combining accents on letters are never touched.
+///
+/// @param text - Text to normalize
+/// @param curly - Use the typographic apostrophe (’) instead of the straight one (')
+#[wasm_bindgen]
+pub fn normalize_apostrophes(text: &str, curly: bool) -> String {
+    let target = if curly { '’' } else { '\'' };
+    let fenced = fenced_code_spans(text);
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let plain: Vec<char> = chars.iter().map(|(_, c)| *c).collect();
+
+    let mut result = String::with_capacity(text.len());
+    let mut in_inline_code = false;
+
+    for (index, &(offset, c)) in chars.iter().enumerate() {
+        if fenced.iter().any(|span| span.start <= offset && offset < span.end) {
+            result.push(c);
+            continue;
+        }
+
+        let apostrophe = is_apostrophe_like(c) && is_apostrophe_at(&plain, index);
+        if c == '`' && !apostrophe {
+            in_inline_code = !in_inline_code;
+        } else if c == '\n' {
+            in_inline_code = false;
+        }
+
+        if apostrophe && !in_inline_code {
+            result.push(target);
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// Punctuation spacing conventions of a language
+struct PunctuationSpacing {
+    /// Space required before ; : ! ? (French espace fine insécable)
+    before_high: Option<char>,
+    /// Space required inside « guillemets »
+    inside_guillemets: Option<char>,
+}
+
+fn punctuation_spacing(language: &str) -> PunctuationSpacing {
+    match language {
+        "fr" => PunctuationSpacing { before_high: Some('\u{202F}'), inside_guillemets: Some('\u{202F}') },
+        _ => PunctuationSpacing { before_high: None, inside_guillemets: None },
+    }
+}
+
+/// Horizontal spaces that punctuation spacing may replace (not line breaks)
+fn is_inline_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\u{00A0}' | '\u{202F}' | '\u{2009}')
+}
+
+fn is_high_punctuation(c: char) -> bool {
+    matches!(c, ';' | ':' | '!' | '?')
+}
+
+fn is_closing_punctuation(c: char) -> bool {
+    matches!(c, '.' | ',' | '…') || is_high_punctuation(c)
+}
+
+/// ':' or ';' starting an emoticon (":)", ";-)", ":D")
+fn starts_emoticon(chars: &[char], index: usize) -> bool {
+    if !matches!(chars[index], ':' | ';') {
+        return false;
+    }
+    match chars.get(index + 1) {
+        Some(')' | '(' | '-' | '\'' | '/' | '|' | '*') => true,
+        Some('D' | 'P' | 'p' | 'O' | 'o' | '3') => !chars.get(index + 2).is_some_and(|c| c.is_alphanumeric()),
+        _ => false,
+    }
+}
+
+/// Normalize the spacing around punctuation to a language's convention
+///
+/// - No space before . , … and, outside French, ; : ! ?
+/// - French: a narrow no-break space (U+202F) before ; : ! ? and inside
+///   « guillemets », replacing any ordinary or no-break space there
+/// - Spanish ¿ and ¡ are attached to the following word
+/// - A single space after punctuation that is directly followed by a
+///   letter ("yes,and" → "yes, and"); after '.' only before a capital,
+///   so abbreviations like "e.g." stay intact
+///
+/// Numbers ("3.14", "10:30", "3:2"), emoticons (":)"), code and URLs are
+/// left alone, as are line breaks. The result is stable: normalizing it again
+/// changes nothing.
+///
+/// @param text - Text to normalize
+/// @param language - Language code selecting the spacing rules
+#[wasm_bindgen]
+pub fn normalize_punctuation_spacing(text: &str, language: &str) -> String {
+    let rules = punctuation_spacing(language);
+    let excluded: Vec<Span> = code_spans(text).into_iter().chain(url_spans(text)).collect();
+    let offsets: Vec<usize> = text.char_indices().map(|(offset, _)| offset).collect();
+    let chars: Vec<char> = text.chars().collect();
+    let is_excluded = |index: usize| excluded.iter().any(|span| span.start <= offsets[index] && offsets[index] < span.end);
+
+    let mut result = String::with_capacity(text.len());
+    let mut index = 0;
+
+    while index < chars.len() {
+        let c = chars[index];
+        if is_excluded(index) {
+            result.push(c);
+            index += 1;
+            continue;
+        }
+
+        if is_inline_space(c) {
+            let next = (index..chars.len()).find(|&j| !is_inline_space(chars[j])).unwrap_or(chars.len());
+            let after_word = result.chars().last().is_some_and(|last| !last.is_whitespace());
+            let before_punctuation = chars.get(next).is_some_and(|&p| {
+                (is_closing_punctuation(p) && !starts_emoticon(&chars, next))
+                    || (p == '»' && rules.inside_guillemets.is_some())
+            });
+            if next < chars.len() && !is_excluded(next) && after_word && before_punctuation {
+                // The punctuation decides the spacing before it
+                index = next;
+                continue;
+            }
+            result.push(c);
+            index += 1;
+            continue;
+        }
+
+        let previous = result.chars().last();
+        // Times and ratios ("10:30", "3:2")
+        let in_number = index > 0 && chars[index - 1].is_ascii_digit() && chars.get(index + 1).is_some_and(char::is_ascii_digit);
+        let needs_space_before = match rules.before_high {
+            Some(_) if is_high_punctuation(c) && !starts_emoticon(&chars, index) && !in_number => rules.before_high,
+            _ if c == '»' => rules.inside_guillemets,
+            _ => None,
+        };
+        if let Some(space) = needs_space_before {
+            if previous.is_some_and(|last| !last.is_whitespace() && !is_high_punctuation(last)) {
+                result.push(space);
+            }
+        }
+        result.push(c);
+
+        // Opening marks attach to the next word, or take the guillemet space
+        let opening = c == '¿' || c == '¡' || (c == '«' && rules.inside_guillemets.is_some());
+        if opening {
+            let next = (index + 1..chars.len()).find(|&j| !is_inline_space(chars[j])).unwrap_or(chars.len());
+            if next < chars.len() && !is_excluded(next) && !chars[next].is_whitespace() {
+                if c == '«' {
+                    result.extend(rules.inside_guillemets);
+                }
+                index = next;
+                continue;
+            }
+        }
+
+        // One space after punctuation glued to the next word
+        let next = chars.get(index + 1).copied();
+        let glued = previous.is_some_and(|p| p.is_alphanumeric() || is_high_punctuation(p))
+            || matches!(previous, Some(')' | '"' | '»' | '”' | '’'));
+        if is_closing_punctuation(c) && glued && !starts_emoticon(&chars, index) {
+            if let Some(next) = next.filter(|next| next.is_alphabetic()) {
+                if c != '.' || next.is_uppercase() {
+                    result.push(' ');
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    result
+}
+
+/// Letters with a stroke or slash, which have no decomposition to strip
+const STROKED_LETTERS: [(char, char); 10] = [
+    ('Đ', 'D'), ('đ', 'd'), ('Ħ', 'H'), ('ħ', 'h'), ('Ł', 'L'), ('ł', 'l'), ('Ø', 'O'), ('ø', 'o'), ('Ŧ', 'T'), ('ŧ', 't'),
+];
+
+/// Accents of Latin, Greek and Cyrillic letters, as opposed to marks that
+/// spell vowels or consonants in other scripts
+pub fn is_diacritic(c: char) -> bool {
+    matches!(c, '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}' | '\u{20D0}'..='\u{20FF}' | '\u{FE20}'..='\u{FE2F}')
+}
+
+/// Remove accents so that "café", "cafe" and "cafè" index alike
+///
+/// Text is decomposed (NFD), its accent marks are dropped and the rest is
+/// recomposed (NFC). Stroked letters fold too ("ł" → "l", "ø" → "o",
+/// "đ" → "d"), while letters of their own ("ß", "æ", "ı") are kept.
+/// Only the accents of Latin, Greek and Cyrillic are removed: Devanagari
+/// and Thai vowel signs, kana voicing marks, and Hebrew and Arabic vowel
+/// points are part of the spelling and stay (see `strip_vowel_marks`).
+/// Case is unchanged.
+///
+/// @param text - Text to fold
+#[wasm_bindgen]
+pub fn fold_diacritics(text: &str) -> String {
+    let stripped: String = normalize_form(text, Form::Nfd)
+        .chars()
+        .filter(|c| !is_diacritic(*c))
+        .map(|c| STROKED_LETTERS.iter().find(|(letter, _)| *letter == c).map_or(c, |(_, plain)| *plain))
+        .collect();
+    normalize_form(&stripped, Form::Nfc)
+}
+
+/// Arabic harakat, Quranic annotation marks and tatweel, and Hebrew
+/// cantillation marks and vowel points (niqqud)
+fn is_vowel_mark(c: char) -> bool {
+    matches!(c,
+        '\u{0591}'..='\u{05BD}' | '\u{05BF}' | '\u{05C1}' | '\u{05C2}' | '\u{05C4}' | '\u{05C5}' | '\u{05C7}'
+        | '\u{0610}'..='\u{061A}' | '\u{0640}' | '\u{064B}'..='\u{0653}' | '\u{0655}'..='\u{065F}' | '\u{0670}'
+        | '\u{06D6}'..='\u{06DC}' | '\u{06DF}'..='\u{06E4}' | '\u{06E7}' | '\u{06E8}' | '\u{06EA}'..='\u{06ED}'
+    )
+}
+
+/// Remove vowel marks so vocalized and unvocalized spellings match
+///
+/// Arabic harakat ("مُحَمَّد" → "محمد"), Quranic marks and the tatweel that
+/// stretches words ("جـــميل" → "جميل") are removed, as are Hebrew vowel
+/// points and cantillation ("שָׁלוֹם" → "שלום"), including those of
+/// precomposed presentation forms. Hamza is a letter and stays: "أ" is not
+/// "ا" here, which `unify_arabic_letters` handles.
+///
+/// @param text - Text to strip
+#[wasm_bindgen]
+pub fn strip_vowel_marks(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in normalize_form(text, Form::Nfc).chars() {
+        if ('\u{FB1D}'..='\u{FB4E}').contains(&c) {
+            result.extend(normalize_form(&c.to_string(), Form::Nfd).chars().filter(|c| !is_vowel_mark(*c)));
+        } else if !is_vowel_mark(c) {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Arabic letters that are commonly written interchangeably
+const ARABIC_FOLDS: [(char, char); 8] = [
+    ('آ', 'ا'), ('أ', 'ا'), ('إ', 'ا'), ('ٱ', 'ا'), ('ؤ', 'و'), ('ئ', 'ي'), ('ى', 'ي'), ('ة', 'ه'),
+];
+
+/// Unify Arabic letters that casual writing does not distinguish
+/// Alef with hamza or madda and alef wasla become bare alef ("أحمد" →
+/// "احمد"), hamza on waw and yeh become the bare letter, alef maksura
+/// becomes yeh and teh marbuta becomes heh, as search engines fold them.
+/// Standalone hamza stays. Best combined with `strip_vowel_marks`.
+///
+/// @param text - Text to unify
+#[wasm_bindgen]
+pub fn unify_arabic_letters(text: &str) -> String {
+    normalize_form(text, Form::Nfc)
+        .chars()
+        .map(|c| ARABIC_FOLDS.iter().find(|(letter, _)| *letter == c).map_or(c, |(_, plain)| *plain))
+        .collect()
+}
+
+/// Folds added by full case folding, applied after lowercasing
+/// One character becomes several, so "STRASSE", "Straße" and "straße"
+/// share a key; final sigma folds to σ so word position does not matter.
+const FULL_FOLDS: [(char, &str); 11] = [
+    ('ß', "ss"), ('ŉ', "ʼn"), ('ſ', "s"), ('ς', "σ"), ('ﬀ', "ff"), ('ﬁ', "fi"),
+    ('ﬂ', "fl"), ('ﬃ', "ffi"), ('ﬄ', "ffl"), ('ﬅ', "st"), ('ﬆ', "st"),
+];
+
+/// Lowercase text for matching, following the language's casing rules
+/// Turkish and Azerbaijani pair dotted and dotless i ("I" → "ı", "İ" →
+/// "i"); elsewhere "I" → "i". Greek capital sigma lowercases to "ς" at the
+/// end of a word and "σ" elsewhere. With `full`, the result is fully case
+/// folded: "ß" → "ss", ligatures are split and "ς" → "σ".
+pub fn fold_case(text: &str, language: &str, full: bool) -> String {
+    let lower = match language {
+        "tr" | "az" => text.replace("I\u{0307}", "i").replace('I', "ı").replace('İ', "i").to_lowercase(),
+        _ => text.to_lowercase(),
+    };
+    if !full {
+        return lower;
+    }
+
+    let mut folded = String::with_capacity(lower.len());
+    for c in lower.chars() {
+        match FULL_FOLDS.iter().find(|(from, _)| *from == c) {
+            Some((_, to)) => folded.push_str(to),
+            None => folded.push(c),
+        }
+    }
+    folded
+}
+
+/// Characters that render as nothing and only get in the way of matching:
+/// zero-width space, word joiner and invisible operators, byte order mark,
+/// soft hyphen, Mongolian vowel separator and bidi controls
+pub fn is_invisible(c: char) -> bool {
+    matches!(c,
+        '\u{00AD}' | '\u{061C}' | '\u{180E}' | '\u{200B}' | '\u{200E}' | '\u{200F}'
+        | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{2064}' | '\u{2066}'..='\u{2069}' | '\u{FEFF}'
+    )
+}
+
+/// Spaces other than U+0020 that are typed or pasted between words:
+/// no-break, narrow no-break, en to hair spaces, medium mathematical and
+/// ideographic space
+fn is_unusual_space(c: char) -> bool {
+    matches!(c, '\u{00A0}' | '\u{2000}'..='\u{200A}' | '\u{202F}' | '\u{205F}' | '\u{3000}')
+}
+
+/// Whether a zero-width joiner or non-joiner between two characters shapes
+/// something: an emoji sequence ("👩‍👩‍👧"), or letters of a script that uses
+/// them, such as Persian "می‌خواهم" and Devanagari conjuncts
+fn joins(previous: Option<char>, joiner: char, next: Option<char>) -> bool {
+    let (Some(previous), Some(next)) = (previous, next) else {
+        return false;
+    };
+    let emoji = joiner == '\u{200D}' && (is_emoji(previous) || is_extend(previous)) && is_emoji(next);
+    let letters = (previous.is_alphabetic() || is_extend(previous)) && !previous.is_ascii() && next.is_alphabetic() && !next.is_ascii();
+    emoji || letters
+}
+
+/// Whitespace waiting to be collapsed into a separator: the first space
+/// and the line breaks of the run, as character indices
+#[derive(Default)]
+struct PendingWhitespace {
+    space: Option<usize>,
+    newlines: Vec<usize>,
+}
+
+/// Write the whitespace collapsed before the next kept character: up to
+/// two line breaks, else one space, and nothing at the start
+/// Each written character comes from the whitespace it keeps.
+fn push_separator(result: &mut Mapped, pending: &mut PendingWhitespace) {
+    let pending = std::mem::take(pending);
+    if result.text.is_empty() {
+        return;
+    }
+    if !pending.newlines.is_empty() {
+        // A code block may already end with a line break
+        let written = result.text.chars().rev().take_while(|c| *c == '\n').count();
+        for &index in pending.newlines.iter().take(2usize.saturating_sub(written)) {
+            result.push('\n', index..index + 1);
+        }
+    } else if let Some(index) = pending.space {
+        result.push(' ', index..index + 1);
+    }
+}
+
+/// Clean up whitespace and invisible characters pasted into a message
+///
+/// - Zero-width spaces, byte order marks, soft hyphens, word joiners and
+///   bidi controls (LRM, RLM, embeddings and isolates) are removed
+/// - Zero-width joiners and non-joiners are removed unless they join an
+///   emoji sequence or two letters of a script that needs them
+/// - No-break, ideographic and other special spaces become plain spaces,
+///   tabs included, and runs of them collapse to one
+/// - Spaces at the start and end of lines are dropped, "\r\n" becomes
+///   "\n" and more than one blank line in a row collapses to one
+/// - The text is trimmed
+///
+/// Code blocks and inline code are copied as is, keeping indentation.
+///
+/// @param text - Text to clean
+#[wasm_bindgen]
+pub fn clean_whitespace(text: &str) -> String {
+    clean_whitespace_mapped(text).text
+}
+
+fn clean_whitespace_mapped(text: &str) -> Mapped {
+    let code = code_spans(text);
+    let in_code = |offset: usize| code.iter().any(|span| span.start <= offset && offset < span.end);
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+
+    let mut result = Mapped::with_capacity(text.len());
+    let mut pending = PendingWhitespace::default();
+    for (index, &(offset, c)) in chars.iter().enumerate() {
+        if in_code(offset) {
+            push_separator(&mut result, &mut pending);
+            result.push(c, index..index + 1);
+            continue;
+        }
+
+        let waiting = pending.space.is_some() || !pending.newlines.is_empty();
+        let previous = if waiting { None } else { result.text.chars().last() };
+        let next = chars[index + 1..].iter().map(|&(_, c)| c).find(|c| !is_invisible(*c));
+        let dropped = is_invisible(c) || (matches!(c, '\u{200C}' | '\u{200D}') && !joins(previous, c, next));
+        if dropped || c == '\r' {
+            continue;
+        }
+        if c == '\n' {
+            pending.newlines.push(index);
+            pending.space = None;
+            continue;
+        }
+        if c.is_whitespace() || is_unusual_space(c) {
+            if pending.newlines.is_empty() {
+                pending.space.get_or_insert(index);
+            }
+            continue;
+        }
+
+        push_separator(&mut result, &mut pending);
+        result.push(c, index..index + 1);
+    }
+
+    result
+}
+
+/// Half-width katakana and punctuation (U+FF61 to U+FF9F) and their
+/// full-width forms, in code point order
+const HALF_WIDTH_KANA: [char; 63] = [
+    '。', '「', '」', '、', '・', 'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ', 'ー',
+    'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', 'タ',
+    'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', 'ミ',
+    'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ン', '゛', '゜',
+];
+
+/// Width to convert East Asian text to
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Width {
+    /// ASCII letters, digits and punctuation, full-width katakana: the usual
+    /// form for matching Japanese input
+    Narrow,
+    /// Full-width letters, digits and katakana
+    Wide,
+    /// ASCII and half-width katakana, as on legacy terminals
+    Half,
+}
+
+impl Width {
+    /// Parse "narrow", "wide" or "half"
+    pub fn from_name(name: &str) -> Option<Width> {
+        match name.to_ascii_lowercase().as_str() {
+            "narrow" => Some(Width::Narrow),
+            "wide" => Some(Width::Wide),
+            "half" => Some(Width::Half),
+            _ => None,
+        }
+    }
+}
+
+/// Convert between full-width and half-width forms
+/// ASCII converts to and from full-width forms (U+FF01 to U+FF5E, and the
+/// ideographic space), and katakana to and from half-width katakana, where
+/// a voiced kana is two half-width characters ("ガ" and "ｶﾞ").
+pub fn convert_width(text: &str, width: Width) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let code = c as u32;
+        let wide_ascii = (0xFF01..=0xFF5E).contains(&code) || c == '\u{3000}';
+        let narrow_ascii = ('!'..='~').contains(&c) || c == ' ';
+        match width {
+            Width::Narrow | Width::Half if wide_ascii => {
+                result.extend(char::from_u32(if c == '\u{3000}' { 0x20 } else { code - 0xFF01 + 0x21 }));
+            }
+            Width::Wide if narrow_ascii => {
+                result.extend(char::from_u32(if c == ' ' { 0x3000 } else { code - 0x21 + 0xFF01 }));
+            }
+            Width::Narrow | Width::Wide if ('\u{FF61}'..='\u{FF9F}').contains(&c) => {
+                let wide = HALF_WIDTH_KANA[(code - 0xFF61) as usize];
+                let mark = match chars.peek() {
+                    Some('ﾞ') => Some('\u{3099}'),
+                    Some('ﾟ') => Some('\u{309A}'),
+                    _ => None,
+                };
+                let voiced = mark
+                    .map(|mark| normalize_form(&format!("{wide}{mark}"), Form::Nfc))
+                    .filter(|composed| composed.chars().count() == 1);
+                match voiced {
+                    Some(voiced) => {
+                        result.push_str(&voiced);
+                        chars.next();
+                    }
+                    None => result.push(wide),
+                }
+            }
+            Width::Half => match HALF_WIDTH_KANA.iter().position(|wide| *wide == c) {
+                Some(index) => result.extend(char::from_u32(0xFF61 + index as u32)),
+                None => {
+                    let decomposed = normalize_form(&c.to_string(), Form::Nfd);
+                    let mut parts = decomposed.chars();
+                    let half = parts.next().and_then(|base| HALF_WIDTH_KANA.iter().position(|wide| *wide == base));
+                    match (half, parts.next(), parts.next()) {
+                        (Some(index), Some(mark @ ('\u{3099}' | '\u{309A}')), None) => {
+                            result.extend(char::from_u32(0xFF61 + index as u32));
+                            result.push(if mark == '\u{3099}' { 'ﾞ' } else { 'ﾟ' });
+                        }
+                        _ => result.push(c),
+                    }
+                }
+            },
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Remove emoji, counting a sequence ("👍🏽", "🇩🇪", "👩‍👩‍👧") as one
+pub fn strip_emoji(text: &str) -> String {
+    graphemes(text)
+        .iter()
+        .filter(|cluster| !cluster.text.chars().any(is_emoji))
+        .map(|cluster| cluster.text)
+        .collect()
+}
+
+fn strip_emoji_mapped(text: &str) -> Mapped {
+    let mut result = Mapped::with_capacity(text.len());
+    let mut index = 0;
+    for cluster in graphemes(text) {
+        let count = cluster.text.chars().count();
+        if !cluster.text.chars().any(is_emoji) {
+            result.push_replacement(cluster.text, index..index + count);
+        }
+        index += count;
+    }
+    result
+}
+
+/// Typographic quotes, dashes and ellipses and their ASCII forms
+fn plain_form(c: char) -> Option<&'static str> {
+    match c {
+        '‘' | '’' | '‚' | '‛' | '‹' | '›' | '＇' | '′' => Some("'"),
+        '“' | '”' | '„' | '‟' | '«' | '»' | '＂' | '″' | '「' | '」' | '『' | '』' => Some("\""),
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' => Some("-"),
+        '…' => Some("..."),
+        _ => None,
+    }
+}
+
+/// Convert typographic punctuation to its plain ASCII form
+///
+/// Curly, low and angle quotes ("“…”", "„…“", "«…»", "「…」") become '"' or
+/// "'", apostrophes become "'" as in `normalize_apostrophes`, every dash
+/// (hyphen, en, em, minus) becomes "-" and "…" becomes "...", so text
+/// pasted from a word processor meets exact-match rules. Code and URLs are
+/// left alone.
+///
+/// @param text - Text to convert
+#[wasm_bindgen]
+pub fn plain_punctuation(text: &str) -> String {
+    plain_punctuation_mapped(text).text
+}
+
+fn plain_punctuation_mapped(text: &str) -> Mapped {
+    // Apostrophes are replaced one for one, so indices still line up
+    let text = normalize_apostrophes(text, false);
+    let excluded: Vec<Span> = code_spans(&text).into_iter().chain(url_spans(&text)).collect();
+    let mut result = Mapped::with_capacity(text.len());
+    for (index, (offset, c)) in text.char_indices().enumerate() {
+        let kept = excluded.iter().any(|span| span.start <= offset && offset < span.end);
+        match plain_form(c).filter(|_| !kept) {
+            Some(plain) => result.push_replacement(plain, index..index + 1),
+            None => result.push(c, index..index + 1),
+        }
+    }
+    result
+}
+
+/// Opening and closing double quotes, then single quotes, of a language
+fn quote_marks(language: &str) -> [char; 4] {
+    match language {
+        "de" | "cs" | "bg" | "sr" => ['„', '“', '‚', '‘'],
+        "pl" => ['„', '”', '‚', '’'],
+        "fr" | "ar" | "fa" => ['«', '»', '‹', '›'],
+        "ru" | "uk" => ['«', '»', '„', '“'],
+        "es" | "it" => ['«', '»', '“', '”'],
+        "sv" => ['”', '”', '’', '’'],
+        "ja" => ['「', '」', '『', '』'],
+        _ => ['“', '”', '‘', '’'],
+    }
+}
+
+/// Convert plain ASCII punctuation to typographic punctuation
+///
+/// Straight quotes become the language's quotation marks, opening after
+/// a space, bracket or dash and closing elsewhere: “English”, „Deutsch“,
+/// «français», «русский» with „inner“ quotes, 「日本語」. Apostrophes become
+/// "’", "--" becomes "—" and "..." becomes "…". Spacing inside French
+/// guillemets is left to `normalize_punctuation_spacing`. Code and URLs are
+/// left alone.
+///
+/// @param text - Text to convert
+/// @param language - Language code selecting the quotation marks
+#[wasm_bindgen]
+pub fn smart_punctuation(text: &str, language: &str) -> String {
+    let [open_double, close_double, open_single, close_single] = quote_marks(language);
+    let excluded: Vec<Span> = code_spans(text).into_iter().chain(url_spans(text)).collect();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let plain: Vec<char> = chars.iter().map(|(_, c)| *c).collect();
+
+    let mut result = String::with_capacity(text.len());
+    let mut index = 0;
+    while index < chars.len() {
+        let (offset, c) = chars[index];
+        if excluded.iter().any(|span| span.start <= offset && offset < span.end) {
+            result.push(c);
+            index += 1;
+            continue;
+        }
+
+        let opening = result.chars().last().is_none_or(|previous| {
+            previous.is_whitespace() || matches!(previous, '(' | '[' | '{' | '-' | '—' | '–') || previous == open_double
+        });
+        let rest = &text[offset..];
+        if rest.starts_with("...") {
+            result.push('…');
+            index += 3;
+            continue;
+        }
+        if rest.starts_with("--") {
+            result.push('—');
+            index += 2;
+            continue;
+        }
+        match c {
+            '"' => result.push(if opening { open_double } else { close_double }),
+            '\'' if is_apostrophe_at(&plain, index) => result.push('’'),
+            '\'' => result.push(if opening { open_single } else { close_single }),
+            _ => result.push(c),
+        }
+        index += 1;
+    }
+    result
+}
+
+/// A stretch of characters that a step replaced, removed or inserted
+/// Ranges are character indices into the text before and after the step.
+struct Hunk {
+    old: Range<usize>,
+    new: Range<usize>,
+}
+
+/// Combining marks, and the half-width voicing marks that width
+/// conversion merges into their kana
+fn is_mark(c: char) -> bool {
+    is_extend(c) || matches!(c, '\u{FF9E}' | '\u{FF9F}')
+}
+
+/// Add a hunk, merging it into the previous one when they touch
+fn push_hunk(hunks: &mut Vec<Hunk>, hunk: Hunk) {
+    match hunks.last_mut() {
+        Some(last) if last.old.end == hunk.old.start && last.new.end == hunk.new.start => {
+            last.old.end = hunk.old.end;
+            last.new.end = hunk.new.end;
+        }
+        _ => hunks.push(hunk),
+    }
+}
+
+/// The output of a step, with the characters of its input that each
+/// output character came from
+struct Mapped {
+    text: String,
+    /// Character range of the input per output character; empty where the
+    /// character was inserted
+    sources: Vec<Range<usize>>,
+}
+
+impl Mapped {
+    fn with_capacity(bytes: usize) -> Mapped {
+        Mapped { text: String::with_capacity(bytes), sources: Vec::with_capacity(bytes) }
+    }
+
+    fn push(&mut self, c: char, source: Range<usize>) {
+        self.text.push(c);
+        self.sources.push(source);
+    }
+
+    /// Add what a stretch of the input became: character by character when
+    /// the lengths agree, otherwise every new character comes from all of it
+    fn push_replacement(&mut self, replacement: &str, source: Range<usize>) {
+        let paired = replacement.chars().count() == source.len();
+        for (k, c) in replacement.chars().enumerate() {
+            let start = source.start + k;
+            self.push(c, if paired { start..start + 1 } else { source.clone() });
+        }
+    }
+}
+
+/// Run a step on each character with its combining marks, so accents,
+/// expansions and merged voicing marks map to the letter they belong to
+/// Where the step looks further than that (final sigma, composition across
+/// letters) the word is mapped as a whole, and the whole text if even
+/// words do not add up.
+fn map_clusters(text: &str, apply: impl Fn(&str) -> String) -> Mapped {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let slice = |range: &Range<usize>| {
+        let byte = |index: usize| chars.get(index).map_or(text.len(), |&(offset, _)| offset);
+        &text[byte(range.start)..byte(range.end)]
+    };
+    // Runs of non-whitespace, and each whitespace character on its own
+    let mut words: Vec<Range<usize>> = Vec::new();
+    for (index, &(_, c)) in chars.iter().enumerate() {
+        match words.last_mut() {
+            Some(word) if !c.is_whitespace() && !chars[index - 1].1.is_whitespace() => word.end = index + 1,
+            _ => words.push(index..index + 1),
+        }
+    }
+
+    let whole = apply(text);
+    let outputs: Vec<String> = words.iter().map(|word| apply(slice(word))).collect();
+    let mut result = Mapped::with_capacity(whole.len());
+    if outputs.concat() != whole {
+        result.push_replacement(&whole, 0..chars.len());
+        return result;
+    }
+    for (word, output) in words.iter().zip(&outputs) {
+        let mut clusters: Vec<Range<usize>> = Vec::new();
+        for index in word.clone() {
+            match clusters.last_mut() {
+                Some(cluster) if is_mark(chars[index].1) => cluster.end = index + 1,
+                _ => clusters.push(index..index + 1),
+            }
+        }
+        let pieces: Vec<String> = match clusters.len() {
+            1 => vec![output.clone()],
+            _ => clusters.iter().map(|cluster| apply(slice(cluster))).collect(),
+        };
+        if pieces.concat() == *output {
+            for (cluster, piece) in clusters.iter().zip(&pieces) {
+                result.push_replacement(piece, cluster.clone());
+            }
+        } else {
+            result.push_replacement(output, word.clone());
+        }
+    }
+    result
+}
+
+fn trim_mapped(text: &str) -> Mapped {
+    let chars: Vec<char> = text.chars().collect();
+    let start = chars.iter().position(|c| !c.is_whitespace()).unwrap_or(chars.len());
+    let end = chars.iter().rposition(|c| !c.is_whitespace()).map_or(start, |last| last + 1);
+    let mut result = Mapped::with_capacity(text.len());
+    for (index, &c) in chars.iter().enumerate().take(end).skip(start) {
+        result.push(c, index..index + 1);
+    }
+    result
+}
+
+/// What a step changed, from where each of its characters came from
+/// Characters that came from the same stretch of input replace it
+/// together, input nothing came from was removed, and characters from no
+/// input were inserted. A character kept as it was is no change.
+fn changes(old: &[char], mapped: &Mapped) -> Vec<Hunk> {
+    let new: Vec<char> = mapped.text.chars().collect();
+    let sources = &mapped.sources;
+    let mut changes = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while j < new.len() {
+        let source = sources[j].clone();
+        let mut end = j + 1;
+        while end < new.len() && !source.is_empty() && sources[end] == source {
+            end += 1;
+        }
+        if i < source.start {
+            push_hunk(&mut changes, Hunk { old: i..source.start, new: j..j });
+        }
+        let kept = source.len() == 1 && end == j + 1 && old[source.start] == new[j];
+        if !kept {
+            push_hunk(&mut changes, Hunk { old: source.start.max(i)..source.end.max(i), new: j..end });
+        }
+        i = i.max(source.end);
+        j = end;
+    }
+    if i < old.len() {
+        push_hunk(&mut changes, Hunk { old: i..old.len(), new: new.len()..new.len() });
+    }
+    changes
+}
+
+/// A change made by one step of a `Normalizer`
+#[derive(Serialize)]
+struct Edit {
+    /// Builder method that added the step, such as "case_fold"
+    step: &'static str,
+    /// "removed", "inserted" or "replaced"
+    kind: &'static str,
+    /// Text before and after the step
+    original: String,
+    replacement: String,
+    /// Byte range of the change in the original text
+    start: usize,
+    end: usize,
+}
+
+/// Normalized text, where each character came from and what was changed
+struct Tracked {
+    text: String,
+    offsets: Vec<(usize, usize)>,
+    edits: Vec<Edit>,
+}
+
+/// Normalized text with the original byte range of each character
+#[derive(Serialize)]
+struct OffsetText<'a> {
+    text: &'a str,
+    offsets: &'a [(usize, usize)],
+}
+
+/// Normalized text with the edits made to it
+#[derive(Serialize)]
+struct EditedText<'a> {
+    text: &'a str,
+    edits: &'a [Edit],
+}
+
+/// One step of a `Normalizer` pipeline
+#[derive(Clone, Copy)]
+enum Step {
+    UnicodeForm(Form),
+    Width(Width),
+    CaseFold { turkic: bool, full: bool },
+    StripDiacritics,
+    StripVowelMarks,
+    UnifyArabicLetters,
+    CollapseWhitespace,
+    StripEmoji,
+    PlainPunctuation,
+    Skeleton,
+    Trim,
+}
+
+impl Step {
+    fn name(self) -> &'static str {
+        match self {
+            Step::UnicodeForm(_) => "unicode_form",
+            Step::Width(_) => "width",
+            Step::CaseFold { .. } => "case_fold",
+            Step::StripDiacritics => "strip_diacritics",
+            Step::StripVowelMarks => "strip_vowel_marks",
+            Step::UnifyArabicLetters => "unify_arabic_letters",
+            Step::CollapseWhitespace => "collapse_whitespace",
+            Step::StripEmoji => "strip_emoji",
+            Step::PlainPunctuation => "plain_punctuation",
+            Step::Skeleton => "skeleton",
+            Step::Trim => "trim",
+        }
+    }
+
+    fn apply(self, text: &str) -> String {
+        match self {
+            Step::UnicodeForm(form) => normalize_form(text, form),
+            Step::Width(width) => convert_width(text, width),
+            Step::CaseFold { turkic, full } => fold_case(text, if turkic { "tr" } else { "" }, full),
+            Step::StripDiacritics => fold_diacritics(text),
+            Step::StripVowelMarks => strip_vowel_marks(text),
+            Step::UnifyArabicLetters => unify_arabic_letters(text),
+            Step::CollapseWhitespace => clean_whitespace(text),
+            Step::StripEmoji => strip_emoji(text),
+            Step::PlainPunctuation => plain_punctuation(text),
+            Step::Skeleton => skeleton_of(text),
+            Step::Trim => text.trim().to_string(),
+        }
+    }
+
+    /// `apply`, with where each character of the result came from
+    fn apply_mapped(self, text: &str) -> Mapped {
+        match self {
+            Step::CollapseWhitespace => clean_whitespace_mapped(text),
+            Step::StripEmoji => strip_emoji_mapped(text),
+            Step::PlainPunctuation => plain_punctuation_mapped(text),
+            Step::Trim => trim_mapped(text),
+            _ => map_clusters(text, |piece| self.apply(piece)),
+        }
+    }
+}
+
+/// A reusable text normalization pipeline
+/// Steps run in the order they are added, so configure once and call
+/// `normalize` for every message:
+///
+/// ```js
+/// const keys = new Normalizer().width("narrow").case_fold("de", true)
+///     .strip_diacritics().collapse_whitespace().unicode_form("NFC");
+/// keys.normalize("  Straße ＡＢＣ ") // "strasse abc"
+/// ```
+///
+/// Each builder method returns the pipeline with the step added; the
+/// object it was called on can no longer be used. Unknown form or width
+/// names add no step.
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct Normalizer {
+    steps: Vec<Step>,
+}
+
+#[wasm_bindgen]
+impl Normalizer {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Normalizer {
+        Normalizer::default()
+    }
+
+    /// Put text in a Unicode normalization form: "NFC", "NFD", "NFKC" or "NFKD"
+    pub fn unicode_form(self, form: &str) -> Normalizer {
+        self.with(Form::from_name(form).map(Step::UnicodeForm))
+    }
+
+    /// Convert full-width and half-width forms: "narrow", "wide" or "half",
+    /// as in `normalize_text`
+    pub fn width(self, width: &str) -> Normalizer {
+        self.with(Width::from_name(width).map(Step::Width))
+    }
+
+    /// Lowercase with the language's rules (Turkish and Azerbaijani dotless
+    /// i); `full` also folds "ß" to "ss", ligatures and final sigma
+    pub fn case_fold(self, language: &str, full: bool) -> Normalizer {
+        self.with(Some(Step::CaseFold { turkic: matches!(language, "tr" | "az"), full }))
+    }
+
+    /// Remove accents, as `fold_diacritics`
+    pub fn strip_diacritics(self) -> Normalizer {
+        self.with(Some(Step::StripDiacritics))
+    }
+
+    /// Remove Arabic harakat and tatweel and Hebrew niqqud, as `strip_vowel_marks`
+    pub fn strip_vowel_marks(self) -> Normalizer {
+        self.with(Some(Step::StripVowelMarks))
+    }
+
+    /// Fold alef and hamza variants, as `unify_arabic_letters`
+    pub fn unify_arabic_letters(self) -> Normalizer {
+        self.with(Some(Step::UnifyArabicLetters))
+    }
+
+    /// Collapse whitespace and remove invisible characters, as `clean_whitespace`
+    pub fn collapse_whitespace(self) -> Normalizer {
+        self.with(Some(Step::CollapseWhitespace))
+    }
+
+    /// Remove emoji, including skin-tone, flag and ZWJ sequences
+    pub fn strip_emoji(self) -> Normalizer {
+        self.with(Some(Step::StripEmoji))
+    }
+
+    /// Convert curly quotes, dashes and ellipses to ASCII, as `plain_punctuation`
+    pub fn plain_punctuation(self) -> Normalizer {
+        self.with(Some(Step::PlainPunctuation))
+    }
+
+    /// Replace lookalike characters by their prototypes, as `skeleton`
+    pub fn skeleton(self) -> Normalizer {
+        self.with(Some(Step::Skeleton))
+    }
+
+    /// Remove leading and trailing whitespace
+    pub fn trim(self) -> Normalizer {
+        self.with(Some(Step::Trim))
+    }
+
+    /// Run every step on a text
+    pub fn normalize(&self, text: &str) -> String {
+        let mut normalized = text.to_string();
+        for step in &self.steps {
+            normalized = step.apply(&normalized);
+        }
+        normalized
+    }
+
+    /// Run every step, keeping track of where each character came from
+    /// Returns object: {"text":"strasse","offsets":[[0,1],[1,2],[2,3],[3,4],[4,6],[4,6],[6,7]]}
+    ///
+    /// `offsets` has one [start, end] pair per character (code point) of
+    /// the normalized text: the byte range of the original text it came
+    /// from, so a match found in the normalized text can be highlighted in
+    /// the raw message. Characters a step expanded ("ß" → "ss") share the
+    /// range, characters it merged ("ｶﾞ" → "ガ") cover all of theirs, and
+    /// removed accents and vowel points stay with their letter (the "e" of
+    /// "é" covers both). Inserted characters get an empty range at their
+    /// position. Each step reports where its characters came from, and the
+    /// steps' mappings are composed in order.
+    pub fn normalize_with_offsets(&self, text: &str) -> JsValue {
+        let tracked = self.normalize_tracked(text);
+        to_js(&OffsetText { text: &tracked.text, offsets: &tracked.offsets })
+    }
+
+    /// Run every step and list what each one changed, for an audit trail
+    /// Returns object, here for "Café   ok": {"text":"cafe ok","edits":[{"step":"case_fold",
+    /// "kind":"replaced","original":"C","replacement":"c","start":0,"end":1},
+    /// {"step":"strip_diacritics","kind":"replaced","original":"é","replacement":"e",
+    /// "start":3,"end":5},{"step":"collapse_whitespace","kind":"removed","original":"  ",
+    /// "replacement":"","start":6,"end":8}]}
+    ///
+    /// Edits are listed step by step in pipeline order, each with the text
+    /// it changed as that step saw it, what it became, and the byte range
+    /// of the change in the original text (empty for insertions). Edits
+    /// come from where each step says its characters came from, and
+    /// neighbouring changes are merged, so lowercasing "HELLO World" gives
+    /// two edits, "HELLO" and "W".
+    pub fn normalize_with_edits(&self, text: &str) -> JsValue {
+        let tracked = self.normalize_tracked(text);
+        to_js(&EditedText { text: &tracked.text, edits: &tracked.edits })
+    }
+
+    /// Run every step on many texts in one call
+    /// Returns array of strings in input order. Null entries count as empty
+    /// text; anything other than an array of strings returns an empty array.
+    ///
+    /// @param texts - Array of message strings
+    pub fn normalize_all(&self, texts: JsValue) -> JsValue {
+        let texts: Vec<Option<String>> = serde_wasm_bindgen::from_value(texts).unwrap_or_default();
+        let normalized: Vec<String> = texts.iter().map(|text| self.normalize(text.as_deref().unwrap_or(""))).collect();
+        to_js(&normalized)
+    }
+}
+
+impl Normalizer {
+    fn normalize_tracked(&self, text: &str) -> Tracked {
+        let mut current = text.to_string();
+        let mut offsets: Vec<(usize, usize)> = text.char_indices().map(|(start, c)| (start, start + c.len_utf8())).collect();
+        let mut edits = Vec::new();
+        for step in &self.steps {
+            let mapped = step.apply_mapped(&current);
+            let old: Vec<char> = current.chars().collect();
+            let new: Vec<char> = mapped.text.chars().collect();
+            let original_range = |range: &Range<usize>| match range.is_empty() {
+                false => (offsets[range.start].0, offsets[range.end - 1].1),
+                true => {
+                    let at = offsets.get(range.start).map_or(text.len(), |offset| offset.0);
+                    (at, at)
+                }
+            };
+
+            for change in changes(&old, &mapped) {
+                let (start, end) = original_range(&change.old);
+                let kind = match (change.old.is_empty(), change.new.is_empty()) {
+                    (_, true) => "removed",
+                    (true, _) => "inserted",
+                    _ => "replaced",
+                };
+                edits.push(Edit {
+                    step: step.name(),
+                    kind,
+                    original: old[change.old].iter().collect(),
+                    replacement: new[change.new].iter().collect(),
+                    start,
+                    end,
+                });
+            }
+            offsets = mapped.sources.iter().map(original_range).collect();
+            current = mapped.text;
+        }
+        Tracked { text: current, offsets, edits }
+    }
+
+    fn with(mut self, step: Option<Step>) -> Normalizer {
+        self.steps.extend(step);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pipeline_runs_steps_in_order() {
+        let keys = Normalizer::new().width("narrow").case_fold("de", true).strip_diacritics().collapse_whitespace().unicode_form("NFC");
+        assert_eq!(keys.normalize("  Straße ＡＢＣ "), "strasse abc");
+        assert_eq!(keys.normalize("Crème  BRÛLÉE"), "creme brulee");
+
+        // Composed accents are stripped too, and later steps see the result
+        let nfd_last = Normalizer::new().strip_diacritics().unicode_form("NFD");
+        assert_eq!(nfd_last.normalize("é"), "e");
+        assert_eq!(Normalizer::new().unicode_form("NFD").normalize("é"), "e\u{301}");
+    }
+
+    #[test]
+    fn unknown_names_add_no_step() {
+        let normalizer = Normalizer::new().unicode_form("NFX").width("tall");
+        assert_eq!(normalizer.steps.len(), 0);
+        assert_eq!(normalizer.normalize("ＡＢＣ é"), "ＡＢＣ é");
+        assert_eq!(Normalizer::new().unicode_form("nfkc").steps.len(), 1);
+    }
+
+    #[test]
+    fn steps_match_their_functions() {
+        let text = "  İstanbul 👍🏽 ok\u{200B}  ";
+        assert_eq!(Normalizer::new().case_fold("tr", false).normalize("İI"), fold_case("İI", "tr", false));
+        assert_eq!(Normalizer::new().strip_emoji().collapse_whitespace().normalize(text), "İstanbul ok");
+        assert_eq!(Normalizer::new().trim().normalize(text), text.trim());
+        assert_eq!(Normalizer::new().skeleton().normalize("pаypal"), skeleton_of("pаypal"));
+        assert_eq!(Normalizer::new().normalize(text), text);
+    }
+
+    #[test]
+    fn punctuation_spacing_keeps_times_and_ratios() {
+        assert_eq!(normalize_punctuation_spacing("at 10:30 ok", "fr"), "at 10:30 ok");
+        assert_eq!(normalize_punctuation_spacing("Ratio 3:2", "fr"), "Ratio 3:2");
+        assert_eq!(normalize_punctuation_spacing("Score 2:1!", "fr"), "Score 2:1\u{202F}!");
+        assert_eq!(normalize_punctuation_spacing("Note: 10:30", "fr"), "Note\u{202F}: 10:30");
+        assert_eq!(normalize_punctuation_spacing("at 10:30 , ok", "en"), "at 10:30, ok");
+    }
+
+    #[test]
+    fn punctuation_spacing_is_idempotent() {
+        let samples = [
+            ("Bonjour!Ça va? «oui» à 10:30 ; ratio 3:2", "fr"),
+            ("yes,and no . Really ?", "en"),
+            ("Hola ¿ qué tal ?Bien", "es"),
+            ("see `a : b` and https://x.io/a:b", "fr"),
+        ];
+        for (text, language) in samples {
+            let once = normalize_punctuation_spacing(text, language);
+            assert_eq!(normalize_punctuation_spacing(&once, language), once, "{text}");
+        }
+    }
+
+    fn tracked_offsets(normalizer: Normalizer, text: &str) -> (String, Vec<(usize, usize)>) {
+        let tracked = normalizer.normalize_tracked(text);
+        (tracked.text, tracked.offsets)
+    }
+
+    #[test]
+    fn offsets_follow_expansions_and_merges() {
+        let (text, offsets) = tracked_offsets(Normalizer::new().case_fold("de", true), "Straße");
+        assert_eq!(text, "strasse");
+        assert_eq!(offsets, [(0, 1), (1, 2), (2, 3), (3, 4), (4, 6), (4, 6), (6, 7)]);
+
+        let (text, offsets) = tracked_offsets(Normalizer::new().width("wide"), "ｶﾞｷ");
+        assert_eq!(text, "ガキ");
+        assert_eq!(offsets, [(0, 6), (6, 9)]);
+
+        let (text, offsets) = tracked_offsets(Normalizer::new().unicode_form("NFD").strip_diacritics(), "né ok");
+        assert_eq!(text, "ne ok");
+        assert_eq!(offsets, [(0, 1), (1, 3), (3, 4), (4, 5), (5, 6)]);
+    }
+
+    #[test]
+    fn offsets_follow_whitespace_and_punctuation() {
+        let (text, offsets) = tracked_offsets(Normalizer::new().collapse_whitespace().trim(), "  a \t b\n\n\nc ");
+        assert_eq!(text, "a b\n\nc");
+        assert_eq!(offsets, [(2, 3), (3, 4), (6, 7), (7, 8), (8, 9), (10, 11)]);
+
+        let (text, offsets) = tracked_offsets(Normalizer::new().plain_punctuation(), "so… “ok”");
+        assert_eq!(text, "so... \"ok\"");
+        assert_eq!(offsets[2..5], [(2, 5), (2, 5), (2, 5)]);
+        assert_eq!(offsets[6..], [(6, 9), (9, 10), (10, 11), (11, 14)]);
+    }
+
+    #[test]
+    fn offsets_stay_exact_in_long_changes() {
+        let text = format!("{}x {}", "ß".repeat(300), "É".repeat(4000));
+        let (normalized, offsets) = tracked_offsets(Normalizer::new().case_fold("de", true).strip_diacritics(), &text);
+        assert_eq!(normalized, format!("{}x {}", "ss".repeat(300), "e".repeat(4000)));
+        assert_eq!(offsets[600], (600, 601));
+        assert_eq!(offsets[602], (602, 604));
+        assert_eq!(offsets.last(), Some(&(text.len() - 2, text.len())));
+    }
+
+    fn edits(normalizer: Normalizer, text: &str) -> Vec<(&'static str, &'static str, String, String, usize, usize)> {
+        let tracked = normalizer.normalize_tracked(text);
+        tracked.edits.into_iter().map(|edit| (edit.step, edit.kind, edit.original, edit.replacement, edit.start, edit.end)).collect()
+    }
+
+    #[test]
+    fn edits_come_from_each_step() {
+        let normalizer = Normalizer::new().case_fold("", false).unicode_form("NFD").strip_diacritics().collapse_whitespace().unicode_form("NFC");
+        let found = edits(normalizer, "Café   ok");
+        assert_eq!(
+            found[..2],
+            [
+                ("case_fold", "replaced", "C".to_string(), "c".to_string(), 0, 1),
+                ("unicode_form", "replaced", "é".to_string(), "e\u{301}".to_string(), 3, 5),
+            ]
+        );
+        assert_eq!(
+            found[2..],
+            [
+                ("strip_diacritics", "replaced", "e\u{301}".to_string(), "e".to_string(), 3, 5),
+                ("collapse_whitespace", "removed", "  ".to_string(), String::new(), 6, 8),
+            ]
+        );
+
+        let found = edits(Normalizer::new().case_fold("", false), "HELLO World");
+        assert_eq!(
+            found,
+            [
+                ("case_fold", "replaced", "HELLO".to_string(), "hello".to_string(), 0, 5),
+                ("case_fold", "replaced", "W".to_string(), "w".to_string(), 6, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn edits_cover_removals_and_expansions() {
+        let found = edits(Normalizer::new().case_fold("de", true).strip_emoji().trim(), " Straße 👍🏽");
+        assert_eq!(
+            found,
+            [
+                ("case_fold", "replaced", "S".to_string(), "s".to_string(), 1, 2),
+                ("case_fold", "replaced", "ß".to_string(), "ss".to_string(), 5, 7),
+                ("strip_emoji", "removed", "👍🏽".to_string(), String::new(), 9, 17),
+                ("trim", "removed", " ".to_string(), String::new(), 0, 1),
+                ("trim", "removed", " ".to_string(), String::new(), 8, 9),
+            ]
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Number format detection
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use serde::Serialize;
+use crate::entities::Span;
+
+/// Which character a writer uses as the decimal separator
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DecimalConvention {
+    /// 1,234.5 (English, Hindi, Thai, Chinese, Japanese)
+    Point,
+    /// 1.234,5 (German, French, Italian, Portuguese, Spanish)
+    Comma,
+}
+
+/// Decimal convention of a language, if it has a settled one
+pub fn decimal_convention(language: &str) -> Option<DecimalConvention> {
+    match language {
+        "en" | "hi" | "th" | "zh" | "ja" => Some(DecimalConvention::Point),
+        "de" | "fr" | "it" | "pt" | "es" => Some(DecimalConvention::Comma),
+        _ => None,
+    }
+}
+
+/// How a number token's separators can be read
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NumberFormat {
+    /// Plain digits
+    Integer,
+    /// The format fixes the convention ("1,234.5", "3,14", "1.234.567")
+    Resolved(DecimalConvention),
+    /// One separator followed by exactly three digits ("1,234", "2.500")
+    Ambiguous(char),
+}
+
+/// Classify the separators of a number token made of digits, '.' and ','
+/// Returns `None` for tokens that are no valid number in any convention,
+/// such as version strings ("1.2.3").
+pub fn classify_number(token: &str) -> Option<NumberFormat> {
+    let separators: Vec<char> = token.chars().filter(|c| matches!(c, '.' | ',')).collect();
+    let Some(&last) = separators.last() else {
+        return Some(NumberFormat::Integer);
+    };
+    let convention_for_decimal = |sep: char| if sep == '.' { DecimalConvention::Point } else { DecimalConvention::Comma };
+
+    let groups: Vec<&str> = token.split(['.', ',']).collect();
+    let leading = groups[0];
+    let grouped = |groups: &[&str]| {
+        (1..=3).contains(&leading.len()) && !(leading.len() > 1 && leading.starts_with('0')) && groups[1..].iter().all(|g| g.len() == 3)
+    };
+
+    if separators.iter().any(|&sep| sep != last) {
+        // "1,234.5": every separator before the last one groups digits
+        let first = separators[0];
+        if separators[..separators.len() - 1].iter().any(|&sep| sep != first) || !grouped(&groups[..groups.len() - 1]) {
+            return None;
+        }
+        return Some(NumberFormat::Resolved(convention_for_decimal(last)));
+    }
+
+    if separators.len() > 1 {
+        // "1.234.567": repeated separators can only group digits
+        let decimal = if last == '.' { ',' } else { '.' };
+        return grouped(&groups).then_some(NumberFormat::Resolved(convention_for_decimal(decimal)));
+    }
+
+    // A single separator is a decimal point unless it could group thousands
+    if grouped(&groups) && leading != "0" {
+        Some(NumberFormat::Ambiguous(last))
+    } else {
+        Some(NumberFormat::Resolved(convention_for_decimal(last)))
+    }
+}
+
+/// Find number tokens: digits with '.' or ',' between them
+/// Digits glued to letters ("v1.2", "3rd") are not numbers.
+pub fn number_spans(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        // Also skips the rest of a rejected token ("2" in "v1.2")
+        let glued = i > 0
+            && (chars[i - 1].1.is_alphanumeric()
+                || (matches!(chars[i - 1].1, '.' | ',') && i > 1 && chars[i - 2].1.is_alphanumeric()));
+        if !c.is_ascii_digit() || glued {
+            i += 1;
+            continue;
+        }
+
+        let mut j = i + 1;
+        while j < chars.len() {
+            let c = chars[j].1;
+            let separator_between_digits = matches!(c, '.' | ',') && chars.get(j + 1).is_some_and(|(_, n)| n.is_ascii_digit());
+            if c.is_ascii_digit() || separator_between_digits {
+                j += 1;
+            } else {
+                break;
+            }
+        }
+
+        let end = chars.get(j).map_or(text.len(), |(offset, _)| *offset);
+        if !chars.get(j).is_some_and(|(_, c)| c.is_alphanumeric()) {
+            spans.push(Span { start, end });
+        }
+        i = j;
+    }
+
+    spans
+}
+
+/// A number whose value depends on the reader's locale
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NumberAmbiguity<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+    /// Value with the separator read as a thousands separator
+    as_grouped: f64,
+    /// Value with the separator read as a decimal point
+    as_decimal: f64,
+    /// "no-hint", "mixed-formats" or "conflicts-with-hint"
+    reason: &'static str,
+}
+
+fn number_ambiguities_report<'a>(text: &'a str, language_hint: &str) -> Vec<NumberAmbiguity<'a>> {
+    let numbers: Vec<(Span, NumberFormat)> = number_spans(text)
+        .into_iter()
+        .filter_map(|span| classify_number(&text[span.start..span.end]).map(|format| (span, format)))
+        .collect();
+
+    let seen = |convention| numbers.iter().any(|(_, format)| *format == NumberFormat::Resolved(convention));
+    let point = seen(DecimalConvention::Point);
+    let comma = seen(DecimalConvention::Comma);
+    let hint = decimal_convention(language_hint);
+
+    let reason = match (point, comma, hint) {
+        (true, true, _) => Some("mixed-formats"),
+        (false, false, None) => Some("no-hint"),
+        (true, false, Some(DecimalConvention::Comma)) | (false, true, Some(DecimalConvention::Point)) => Some("conflicts-with-hint"),
+        _ => None,
+    };
+
+    match reason {
+        Some(reason) => numbers
+            .iter()
+            .filter_map(|(span, format)| {
+                let NumberFormat::Ambiguous(separator) = format else {
+                    return None;
+                };
+                let raw = &text[span.start..span.end];
+                Some(NumberAmbiguity {
+                    text: raw,
+                    start: span.start,
+                    end: span.end,
+                    as_grouped: raw.replace(*separator, "").parse().unwrap_or(0.0),
+                    as_decimal: raw.replace(*separator, ".").parse().unwrap_or(0.0),
+                    reason,
+                })
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Find numbers whose reading depends on the locale
+/// Returns array: [{"text":"1,234","start":7,"end":12,"asGrouped":1234.0,"asDecimal":1.234,"reason":"no-hint"}]
+///
+/// Only a single separator followed by exactly three digits, with one to
+/// three digits before it and no leading zero, is ambiguous ("1,234",
+/// "2.500"). Every other format fixes its own reading: two different
+/// separators ("1,234.5"), repeated ones ("1.234.567"), or a separator
+/// followed by other than three digits ("3,14").
+///
+/// Those unambiguous numbers reveal the writer's own convention, which
+/// decides the ambiguous ones together with the language hint:
+/// - `no-hint`: no other number in the text and the hint has no settled
+///   convention (unknown or empty)
+/// - `mixed-formats`: the text uses both conventions elsewhere
+/// - `conflicts-with-hint`: the text consistently uses the convention the
+///   hint does not
+///
+/// A number is not reported when the evidence and the hint agree, or when
+/// only one of them is available.
+#[wasm_bindgen]
+pub fn number_ambiguities(text: &str, language_hint: &str) -> JsValue {
+    to_js(&number_ambiguities_report(text, language_hint))
+}
+
+/// `number_ambiguities` as a JSON string
+/// @deprecated Use `number_ambiguities`, which returns an object instead of JSON text.
+#[deprecated(note = "use `number_ambiguities`, which returns an object")]
+#[wasm_bindgen]
+pub fn number_ambiguities_json(text: &str, language_hint: &str) -> String {
+    to_json(&number_ambiguities_report(text, language_hint), "[]")
+}
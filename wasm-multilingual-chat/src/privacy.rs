@@ -0,0 +1,73 @@
+//! Privacy-preserving text previews
+
+use wasm_bindgen::prelude::*;
+use crate::segmentation::{char_category, graphemes, words, CharCategory};
+
+/// Mask inserted in place of redacted graphemes
+///
+/// The mask has a fixed length so the redacted preview does not leak how
+/// long each word was.
+const REDACTION_MASK: &str = "***";
+
+/// Redact words, keeping only their first and last `keep` graphemes
+/// Whitespace, punctuation and emoji between words are preserved as-is.
+///
+/// Words with `2 * keep` graphemes or fewer are replaced entirely by the mask,
+/// since keeping their edges would reveal the whole word.
+///
+/// @param text - Text to redact
+/// @param keep - Graphemes to keep at each end of a word
+/// @returns Redacted text, e.g. "hello café" with keep=1 → "h***o c***é"
+#[wasm_bindgen]
+pub fn redact_words(text: &str, keep: u32) -> String {
+    let keep = keep as usize;
+    let mut result = String::with_capacity(text.len());
+    let mut last_end = 0;
+
+    for word in words(text) {
+        result.push_str(&text[last_end..word.start]);
+
+        let clusters = graphemes(word.text);
+        if clusters.len() <= keep * 2 {
+            result.push_str(REDACTION_MASK);
+        } else {
+            let head_end = clusters[keep].start;
+            let tail_start = clusters
+                .get(clusters.len() - keep)
+                .map_or(word.text.len(), |g| g.start);
+            result.push_str(&word.text[..head_end]);
+            result.push_str(REDACTION_MASK);
+            result.push_str(&word.text[tail_start..]);
+        }
+
+        last_end = word.end;
+    }
+
+    result.push_str(&text[last_end..]);
+    result
+}
+
+/// Map text to its shape, hiding the content
+/// Each grapheme cluster becomes one symbol based on its first character:
+/// letters (any script) → 'a', digits → '0', whitespace → ' ' (line breaks
+/// are kept as '\n'), punctuation → '.', symbols and emoji → '*'.
+///
+/// @param text - Text to anonymize
+/// @returns Shape string, e.g. "Hi, Bob! 42" → "aa. aaa. 00"
+#[wasm_bindgen]
+pub fn shape_signature(text: &str) -> String {
+    graphemes(text)
+        .iter()
+        .map(|cluster| {
+            let first = cluster.text.chars().next().unwrap_or(' ');
+            match char_category(first) {
+                CharCategory::Letter => 'a',
+                CharCategory::Digit => '0',
+                CharCategory::Whitespace if cluster.text.contains('\n') => '\n',
+                CharCategory::Whitespace => ' ',
+                CharCategory::Punctuation => '.',
+                CharCategory::Other => '*',
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,210 @@
+//! Language profiles registered at runtime
+
+use wasm_bindgen::prelude::*;
+use crate::js::to_js;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, LazyLock, Mutex};
+use crate::detection::DetectionOptions;
+use crate::lexicon::LANGUAGES;
+use crate::ngrams::trigrams;
+use crate::segmentation::words;
+
+/// Words kept per profile, as many as the built-in common-word lists
+const PROFILE_WORDS: usize = 20;
+
+/// Trigrams kept per profile, as many as the built-in trigram profiles
+const PROFILE_TRIGRAMS: usize = 300;
+
+/// Longest accepted language code (BCP 47 tags rarely exceed this)
+const MAX_CODE_LENGTH: usize = 35;
+
+/// Format version written by `export_detector_model`
+const MODEL_VERSION: u32 = 1;
+
+/// Most frequent words and trigrams of a language, most frequent first,
+/// and the non-ASCII letters it uses
+#[derive(Serialize, Deserialize, Clone)]
+pub struct LanguageProfile {
+    pub words: Vec<String>,
+    pub trigrams: Vec<String>,
+    /// Lowercase accented and non-Latin letters, scored like the built-in
+    /// diacritic hints
+    #[serde(default)]
+    pub letters: String,
+}
+
+/// A registered profile with its language code
+pub type CustomProfile = (&'static str, Arc<LanguageProfile>);
+
+/// Registered profiles, in registration order
+static CUSTOM_PROFILES: LazyLock<Mutex<Vec<CustomProfile>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Codes that have ever been registered
+/// Scores carry `&'static str` codes, so each custom code is leaked once
+/// and reused when it is registered again.
+static INTERNED_CODES: LazyLock<Mutex<Vec<&'static str>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+fn intern(code: &str) -> &'static str {
+    let mut interned = INTERNED_CODES.lock().unwrap();
+    if let Some(existing) = interned.iter().find(|existing| **existing == code) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(code.to_string().into_boxed_str());
+    interned.push(leaked);
+    leaked
+}
+
+/// The `limit` most frequent items, ties in alphabetical order
+fn most_frequent(items: impl Iterator<Item = String>, limit: usize) -> Vec<String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for item in items {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    let mut ranked: Vec<(String, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.into_iter().take(limit).map(|(item, _)| item).collect()
+}
+
+/// Build a profile from sample text
+/// Trigrams are counted the same way as for the built-in profiles.
+pub fn build_profile(corpus: &str) -> LanguageProfile {
+    LanguageProfile {
+        words: most_frequent(words(corpus).iter().map(|word| word.text.to_lowercase()), PROFILE_WORDS),
+        trigrams: most_frequent(trigrams(corpus).into_iter(), PROFILE_TRIGRAMS),
+        letters: corpus
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphabetic() && !c.is_ascii())
+            .collect::<BTreeSet<char>>()
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Registered profiles, in registration order
+pub fn custom_profiles() -> Vec<CustomProfile> {
+    CUSTOM_PROFILES.lock().map(|profiles| profiles.clone()).unwrap_or_default()
+}
+
+/// Built-in languages followed by the registered ones
+pub fn detectable_languages() -> Vec<&'static str> {
+    LANGUAGES.iter().copied().chain(custom_profiles().into_iter().map(|(code, _)| code)).collect()
+}
+
+/// Register a profile under a code, replacing any profile with that code
+/// Returns false, changing nothing, for built-in codes, empty codes, codes
+/// with whitespace or longer than 35 characters, and profiles without
+/// trigrams.
+pub fn install_profile(code: &str, profile: LanguageProfile) -> bool {
+    let valid = !code.is_empty()
+        && code.chars().count() <= MAX_CODE_LENGTH
+        && !code.contains(char::is_whitespace)
+        && !LANGUAGES.contains(&code)
+        && !profile.trigrams.is_empty();
+    if !valid {
+        return false;
+    }
+
+    let code = intern(code);
+    let mut profiles = CUSTOM_PROFILES.lock().unwrap();
+    let profile = Arc::new(profile);
+    match profiles.iter_mut().find(|(existing, _)| *existing == code) {
+        Some(entry) => entry.1 = profile,
+        None => profiles.push((code, profile)),
+    }
+    true
+}
+
+/// Teach detection a new language from sample text
+/// Builds a profile of the corpus's 20 most frequent words, 300 most
+/// frequent trigrams and its accented letters, which `detect_language` and the other detection
+/// functions then score like a built-in language. A larger corpus (a few
+/// hundred words of everyday text) gives a more reliable profile.
+///
+/// Registering a code again replaces its profile. Profiles live until the
+/// module is reloaded.
+///
+/// @param code - Language code to report, such as "gsw" (not a built-in code)
+/// @param sample_corpus - Text written in the language
+/// @returns false when the code is built-in or invalid, or the corpus has no letters
+#[wasm_bindgen]
+pub fn register_language_profile(code: &str, sample_corpus: &str) -> bool {
+    install_profile(code, build_profile(sample_corpus))
+}
+
+/// Remove a registered language profile
+/// Returns false when no profile has the code.
+#[wasm_bindgen]
+pub fn remove_language_profile(code: &str) -> bool {
+    let mut profiles = CUSTOM_PROFILES.lock().unwrap();
+    let before = profiles.len();
+    profiles.retain(|(existing, _)| *existing != code);
+    profiles.len() != before
+}
+
+/// A registered profile as stored in a detector model
+#[derive(Serialize, Deserialize)]
+struct StoredProfile {
+    code: String,
+    #[serde(flatten)]
+    profile: LanguageProfile,
+}
+
+/// Everything needed to restore detection in a later session
+#[derive(Serialize, Deserialize)]
+struct DetectorModel {
+    version: u32,
+    profiles: Vec<StoredProfile>,
+    #[serde(default)]
+    options: Option<DetectionOptions>,
+}
+
+/// Save registered language profiles and detection options
+/// Returns bytes (a Uint8Array) to store, for example in IndexedDB, and
+/// pass to `load_detector_model` in a later session
+///
+/// The bytes are versioned UTF-8 JSON:
+/// {"version":1,"profiles":[{"code":"gsw","words":[...],"trigrams":[...],"letters":"äöü"}],"options":{...}}
+///
+/// @param options - Tuned detection options to keep with the profiles, as
+/// passed to `detect_language_with_options` (optional)
+#[wasm_bindgen]
+pub fn export_detector_model(options: JsValue) -> Vec<u8> {
+    let options = if options.is_undefined() || options.is_null() { None } else { Some(DetectionOptions::from_js(options)) };
+    let model = DetectorModel {
+        version: MODEL_VERSION,
+        profiles: custom_profiles()
+            .into_iter()
+            .map(|(code, profile)| StoredProfile { code: code.to_string(), profile: profile.as_ref().clone() })
+            .collect(),
+        options,
+    };
+    serde_json::to_vec(&model).unwrap_or_default()
+}
+
+/// Restore a model saved with `export_detector_model`
+/// Returns the saved detection options (the defaults when none were saved)
+/// to pass to `detect_language_with_options` or `LanguageDetector.set_options`,
+/// or null when the bytes are not a model of a supported version
+///
+/// Every profile in the model is registered as with
+/// `register_language_profile`, replacing profiles with the same code and
+/// keeping others; nothing is registered when the bytes cannot be read.
+///
+/// @param bytes - Bytes returned by `export_detector_model`
+#[wasm_bindgen]
+pub fn load_detector_model(bytes: &[u8]) -> JsValue {
+    let model: DetectorModel = match serde_json::from_slice(bytes) {
+        Ok(model) => model,
+        Err(_) => return JsValue::NULL,
+    };
+    if model.version != MODEL_VERSION {
+        return JsValue::NULL;
+    }
+
+    for stored in model.profiles {
+        install_profile(&stored.code, stored.profile);
+    }
+    to_js(&model.options.unwrap_or_default())
+}
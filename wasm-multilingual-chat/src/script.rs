@@ -0,0 +1,129 @@
+//! Writing system (script) classification
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use serde::Serialize;
+
+/// Writing system of a character
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Arabic,
+    Hebrew,
+    Devanagari,
+    Thai,
+    Han,
+    Hiragana,
+    Katakana,
+    Hangul,
+    /// Digits, punctuation, spaces and symbols shared by all scripts
+    Common,
+    /// Letters of scripts not listed above
+    Other,
+}
+
+/// Script of a character
+/// Combining marks belong to the script of their block (Thai vowels are
+/// Thai, Devanagari matras Devanagari); generic combining diacritics
+/// (U+0300–U+036F) are `Common`.
+pub fn script_of(c: char) -> Script {
+    match c {
+        'a'..='z' | 'A'..='Z' | '\u{00C0}'..='\u{00D6}' | '\u{00D8}'..='\u{00F6}' | '\u{00F8}'..='\u{024F}'
+        | '\u{1E00}'..='\u{1EFF}' | '\u{FF21}'..='\u{FF3A}' | '\u{FF41}'..='\u{FF5A}' => Script::Latin,
+        '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}' => Script::Greek,
+        '\u{0400}'..='\u{052F}' => Script::Cyrillic,
+        '\u{0590}'..='\u{05FF}' | '\u{FB1D}'..='\u{FB4F}' => Script::Hebrew,
+        '\u{0600}'..='\u{06FF}' | '\u{0750}'..='\u{077F}' | '\u{FB50}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}' => Script::Arabic,
+        '\u{0900}'..='\u{097F}' => Script::Devanagari,
+        '\u{0E00}'..='\u{0E7F}' => Script::Thai,
+        '\u{3040}'..='\u{309F}' => Script::Hiragana,
+        '\u{30A0}'..='\u{30FF}' | '\u{FF66}'..='\u{FF9F}' => Script::Katakana,
+        '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => Script::Hangul,
+        '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' | '\u{20000}'..='\u{2FA1F}' => Script::Han,
+        _ if c.is_alphabetic() => Script::Other,
+        _ => Script::Common,
+    }
+}
+
+/// Han, kana and Hangul, which are written without spaces between words
+pub fn is_cjk(c: char) -> bool {
+    matches!(script_of(c), Script::Han | Script::Hiragana | Script::Katakana | Script::Hangul)
+}
+
+/// Number of letters in each script, most frequent first
+/// `Common` characters are not counted; ties keep `Script` order.
+pub fn script_counts(text: &str) -> Vec<(Script, usize)> {
+    let mut counts: Vec<(Script, usize)> = Vec::new();
+    for script in text.chars().map(script_of).filter(|script| *script != Script::Common) {
+        match counts.iter_mut().find(|(s, _)| *s == script) {
+            Some(entry) => entry.1 += 1,
+            None => counts.push((script, 1)),
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    counts
+}
+
+/// Script a language is normally written in
+pub fn expected_script(language: &str) -> Script {
+    match language {
+        "hi" => Script::Devanagari,
+        "th" => Script::Thai,
+        "zh" => Script::Han,
+        "ko" => Script::Hangul,
+        "ru" | "uk" | "bg" | "sr" => Script::Cyrillic,
+        "ar" | "fa" | "ur" => Script::Arabic,
+        _ => Script::Latin,
+    }
+}
+
+/// Share of a text's letters written in one script
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScriptShare {
+    script: Script,
+    characters: u32,
+    /// Percentage of the text's letters (0.0 to 100.0)
+    percentage: f64,
+}
+
+fn detect_scripts_report(text: &str) -> Vec<ScriptShare> {
+    let counts = script_counts(text);
+    let total: usize = counts.iter().map(|(_, count)| count).sum();
+    counts
+        .into_iter()
+        .map(|(script, count)| ScriptShare {
+            script,
+            characters: count as u32,
+            percentage: count as f64 * 100.0 / total as f64,
+        })
+        .collect()
+}
+
+/// Detect the writing systems used in a text
+/// Returns array: [{"script":"latin","characters":12,"percentage":75.0},
+/// {"script":"han","characters":4,"percentage":25.0}]
+///
+/// Scripts are listed most frequent first. Digits, punctuation, spaces and
+/// symbols (including emoji) belong to every script and are not counted;
+/// letters of scripts without a name of their own are reported as "other".
+/// Only character ranges are inspected, so this is cheap enough to pick
+/// fonts before running language detection. Text without letters returns
+/// an empty array.
+///
+/// @param text - Message text
+#[wasm_bindgen]
+pub fn detect_scripts(text: &str) -> JsValue {
+    to_js(&detect_scripts_report(text))
+}
+
+/// `detect_scripts` as a JSON string
+/// @deprecated Use `detect_scripts`, which returns an object instead of JSON text.
+#[deprecated(note = "use `detect_scripts`, which returns an object")]
+#[wasm_bindgen]
+pub fn detect_scripts_json(text: &str) -> String {
+    to_json(&detect_scripts_report(text), "[]")
+}
@@ -0,0 +1,662 @@
+//! Text segmentation helpers shared across the crate
+//!
+//! **Learning Point**: Grapheme clusters approximate UAX #29 with the mark
+//! table of `unicode` and a few hand-written ranges. A cluster is a base
+//! character followed by any combining or spacing marks, variation
+//! selectors, emoji modifiers or ZWJ-joined characters, which covers
+//! accented Latin, Indic vowel signs, Thai vowels and emoji sequences. Devanagari conjuncts (a consonant,
+//! virama and consonant, as in "क्ष") stay one cluster as in UAX #29.
+
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+use crate::emoji::is_emoji;
+use crate::entities::{code_spans, hashtag_spans, mentions, url_spans, Span};
+use crate::js::to_js;
+use crate::numbers::number_spans;
+use crate::unicode::is_mark;
+use crate::wordbreak;
+
+/// A slice of the input text with its byte offsets
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Characters that extend the preceding grapheme cluster
+/// Marks come from the Unicode tables, spacing vowel signs included, so
+/// Thai "น้ำ" and Tamil "மி" are one cluster each.
+pub fn is_extend(c: char) -> bool {
+    is_mark(c)
+        || matches!(c,
+            '\u{1160}'..='\u{11FF}'   // Hangul medial vowels and final consonants
+            | '\u{200C}'                // Zero width non-joiner
+            | '\u{1F3FB}'..='\u{1F3FF}' // Emoji skin tone modifiers
+            | '\u{E0020}'..='\u{E007F}' // Emoji tag sequences
+        )
+}
+
+/// Coarse Unicode category of a character
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CharCategory {
+    Letter,
+    Digit,
+    Whitespace,
+    Punctuation,
+    /// Symbols, emoji and anything else
+    Other,
+}
+
+/// Punctuation outside ASCII: Latin-1 marks, general punctuation,
+/// CJK brackets and full stops, full-width forms and the Devanagari danda
+fn is_unicode_punctuation(c: char) -> bool {
+    matches!(c,
+        '\u{00A1}' | '\u{00A7}' | '\u{00AB}' | '\u{00B6}' | '\u{00B7}' | '\u{00BB}' | '\u{00BF}'
+        | '\u{037E}' | '\u{0387}'
+        | '\u{055A}'..='\u{055F}' | '\u{0589}'
+        | '\u{05BE}' | '\u{05C0}' | '\u{05C3}' | '\u{05F3}'..='\u{05F4}'
+        | '\u{060C}' | '\u{061B}' | '\u{061F}' | '\u{066A}'..='\u{066D}' | '\u{06D4}'
+        | '\u{0964}'..='\u{0965}' | '\u{0970}'
+        | '\u{0E4F}' | '\u{0E5A}'..='\u{0E5B}'
+        | '\u{2010}'..='\u{2027}' | '\u{2030}'..='\u{205E}'
+        | '\u{3001}'..='\u{3003}' | '\u{3008}'..='\u{3011}' | '\u{3014}'..='\u{301F}' | '\u{30FB}'
+        | '\u{FE10}'..='\u{FE19}' | '\u{FE30}'..='\u{FE4F}'
+        | '\u{FF01}'..='\u{FF0F}' | '\u{FF1A}'..='\u{FF20}' | '\u{FF3B}'..='\u{FF3D}' | '\u{FF5B}'..='\u{FF65}'
+    )
+}
+
+/// Classify a character into a coarse category
+/// ASCII `$+<=>^`|~` count as symbols rather than punctuation, matching
+/// their Unicode general category.
+pub fn char_category(c: char) -> CharCategory {
+    if c.is_alphabetic() {
+        CharCategory::Letter
+    } else if c.is_numeric() {
+        CharCategory::Digit
+    } else if c.is_whitespace() {
+        CharCategory::Whitespace
+    } else if (c.is_ascii_punctuation() && !"$+<=>^`|~".contains(c)) || is_unicode_punctuation(c) {
+        CharCategory::Punctuation
+    } else {
+        CharCategory::Other
+    }
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+fn is_devanagari_consonant(c: char) -> bool {
+    matches!(c, '\u{0915}'..='\u{0939}' | '\u{0958}'..='\u{095F}' | '\u{0978}'..='\u{097F}')
+}
+
+/// Split text into grapheme clusters with byte offsets
+pub fn graphemes(text: &str) -> Vec<Token<'_>> {
+    let mut clusters = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let mut end = start + c.len_utf8();
+        let mut prev = c;
+        let mut regional_count = if is_regional_indicator(c) { 1 } else { 0 };
+
+        while let Some(&(i, next)) = chars.peek() {
+            let joins = is_extend(next)
+                || next == '\u{200D}'
+                || prev == '\u{200D}'
+                || (prev == '\u{094D}' && is_devanagari_consonant(next))
+                || (prev == '\r' && next == '\n')
+                || (regional_count == 1 && is_regional_indicator(next));
+            if !joins {
+                break;
+            }
+            if is_regional_indicator(next) {
+                regional_count += 1;
+            }
+            end = i + next.len_utf8();
+            prev = next;
+            chars.next();
+        }
+
+        clusters.push(Token { text: &text[start..end], start, end });
+    }
+
+    clusters
+}
+
+/// Count grapheme clusters (user-perceived characters)
+pub fn grapheme_count(text: &str) -> usize {
+    graphemes(text).len()
+}
+
+/// Letters and digits start a word; combining marks only continue one, so
+/// a lone variation selector or skin tone is not a word
+fn is_word_start(c: char) -> bool {
+    c.is_alphanumeric()
+}
+
+fn is_word_char(c: char) -> bool {
+    is_word_start(c) || is_extend(c)
+}
+
+fn is_apostrophe(c: char) -> bool {
+    matches!(c, '\'' | '\u{2019}')
+}
+
+/// Split text into word tokens with byte offsets
+///
+/// This is the canonical tokenization used by the analysis functions: a word
+/// is a letter or digit followed by letters, digits and combining marks,
+/// with apostrophes kept
+/// when they sit between two word characters ("don't", "l'homme").
+/// Everything else (whitespace, punctuation, symbols, emoji) separates words.
+pub fn words(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if !is_word_start(chars[i].1) {
+            i += 1;
+            continue;
+        }
+
+        let start = chars[i].0;
+        while i < chars.len() {
+            let c = chars[i].1;
+            let internal_apostrophe = is_apostrophe(c)
+                && i + 1 < chars.len()
+                && is_word_start(chars[i + 1].1);
+            if is_word_char(c) || internal_apostrophe {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+        let end = if i < chars.len() { chars[i].0 } else { text.len() };
+        tokens.push(Token { text: &text[start..end], start, end });
+    }
+
+    tokens
+}
+
+/// Count words using the canonical tokenization
+pub fn word_count(text: &str) -> usize {
+    words(text).len()
+}
+
+/// Split text into paragraphs separated by blank lines
+/// Each paragraph is trimmed; blank paragraphs are dropped.
+pub fn paragraphs(text: &str) -> Vec<Token<'_>> {
+    let mut blocks = Vec::new();
+    let mut block_start: Option<usize> = None;
+    let mut block_end = 0;
+    let mut line_start = 0;
+
+    for line in text.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = block_start.take() {
+                blocks.push(trimmed_token(text, start, block_end));
+            }
+        } else {
+            block_start.get_or_insert(line_start);
+            block_end = line_start + line.len();
+        }
+        line_start += line.len();
+    }
+
+    if let Some(start) = block_start {
+        blocks.push(trimmed_token(text, start, block_end));
+    }
+
+    blocks
+}
+
+/// Token for `text[start..end]` with surrounding whitespace removed
+fn trimmed_token(text: &str, start: usize, end: usize) -> Token<'_> {
+    let slice = &text[start..end];
+    let leading = slice.len() - slice.trim_start().len();
+    let trimmed = slice.trim();
+    let start = start + leading;
+    Token { text: trimmed, start, end: start + trimmed.len() }
+}
+
+/// Abbreviations that end in a period without ending the sentence, by language
+/// Compared lowercased and without the final period. The shared ones under
+/// "" are recognized in every language.
+const ABBREVIATIONS: &[(&str, &[&str])] = &[
+    ("", &["dr", "prof", "etc", "e.g", "i.e", "vs", "approx", "inc", "ltd"]),
+    ("en", &["mr", "mrs", "ms", "sr", "jr", "st", "no", "fig"]),
+    ("de", &["z.b", "bzw", "usw", "ca", "nr", "evtl", "ggf", "d.h", "u.a", "vgl", "inkl", "str", "hr", "fr", "bspw", "mio", "mrd"]),
+    ("fr", &["mme", "mlle", "env", "p.ex", "cf"]),
+    ("es", &["sr", "sra", "srta", "dra", "ud", "uds", "pág", "sig", "av"]),
+    ("it", &["dott", "sig", "sig.ra", "pag", "ecc"]),
+    ("pt", &["sra", "dra", "pág", "av", "pe"]),
+    ("nl", &["bijv", "o.a", "dhr", "mevr", "d.w.z", "enz", "blz"]),
+    ("pl", &["np", "itd", "itp", "tzn", "godz", "ul"]),
+    ("cs", &["např", "atd", "tzv", "ul"]),
+    ("sv", &["t.ex", "bl.a", "osv", "dvs", "ca"]),
+    ("tr", &["vb", "vs"]),
+    ("ru", &["т.е", "т.д", "т.п", "др", "см", "стр", "тыс", "руб"]),
+    ("uk", &["т.д", "т.п", "див", "грн", "тис"]),
+    ("hi", &["डॉ", "प्रो"]),
+];
+
+/// Marks that end a sentence when followed by whitespace or the end of text
+fn is_sentence_terminal(c: char) -> bool {
+    matches!(c, '.' | '!' | '?' | '…' | '؟' | '۔')
+}
+
+/// Full-width marks, and the Devanagari danda, that end a sentence even
+/// without a following space
+fn is_fullwidth_terminal(c: char) -> bool {
+    matches!(c, '。' | '！' | '？' | '।' | '॥')
+}
+
+/// Closing quotes and brackets that belong to the sentence before them
+fn is_sentence_closer(c: char) -> bool {
+    matches!(c, '"' | '\'' | ')' | ']' | '”' | '’' | '»' | '」' | '』' | '）')
+}
+
+/// Check whether the period at `dot` ends an abbreviation or an initial
+/// Languages without their own list check every list.
+fn ends_abbreviation(text: &str, dot: usize, language: &str) -> bool {
+    let before = &text[..dot];
+    let start = before
+        .rfind(|c: char| c.is_whitespace() || matches!(c, '(' | '"' | '“'))
+        .map_or(0, |at| at + before[at..].chars().next().map_or(1, char::len_utf8));
+    let word = before[start..].to_lowercase();
+    let single_letter = word.chars().count() == 1 && word.chars().all(char::is_alphabetic);
+    let keyed = !language.is_empty() && ABBREVIATIONS.iter().any(|(code, _)| *code == language);
+    single_letter
+        || ABBREVIATIONS
+            .iter()
+            .filter(|(code, _)| !keyed || code.is_empty() || *code == language)
+            .any(|(_, list)| list.contains(&word.as_str()))
+}
+
+fn is_thai(c: char) -> bool {
+    matches!(c, '\u{0E00}'..='\u{0E7F}')
+}
+
+/// Split text into sentences with byte offsets
+///
+/// A sentence ends at '.', '!', '?', '…', '؟' or '۔' followed by whitespace
+/// (or the end of the text), at '。', '！', '？' or the danda '।' directly, and at line
+/// breaks. Closing quotes and brackets after the mark stay with the
+/// sentence, and runs like "?!" or "..." end it once. A period does not end
+/// a sentence after an abbreviation or initial ("Dr.", "e.g.", "J. Smith"),
+/// inside a number ("3.14") or before a lowercase word, and an ellipsis or
+/// quoted speech continues before a lowercase word ("Well... maybe",
+/// "Done?" she asked).
+/// Sentences are trimmed, and ones without a letter or digit ("---", "```",
+/// "!!!" or a lone "😂" on its own line) are dropped, where UAX #29 would
+/// keep each as a sentence; they are separators and reactions in chat.
+pub fn sentences(text: &str) -> Vec<Token<'_>> {
+    sentences_in(text, "")
+}
+
+/// Split text in a known language into sentences
+/// Like `sentences`, but only shared abbreviations ("Dr.", "etc.") and the
+/// language's own ("z.B." in German, "т.е." in Russian) keep a period from
+/// ending a sentence, so Spanish "no." ends one. Thai, which marks sentence breaks with
+/// a space rather than punctuation, also ends a sentence at a space between
+/// Thai letters. An empty or unlisted language checks every list.
+pub fn sentences_in<'a>(text: &'a str, language: &str) -> Vec<Token<'a>> {
+    let mut found = Vec::new();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut start = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (offset, c) = chars[i];
+
+        let thai_space = language == "th"
+            && c == ' '
+            && i > 0
+            && is_thai(chars[i - 1].1)
+            && chars.get(i + 1).is_some_and(|(_, next)| is_thai(*next));
+        if c == '\n' || thai_space {
+            found.push(trimmed_token(text, start, offset));
+            start = offset + 1;
+            i += 1;
+            continue;
+        }
+
+        if !is_sentence_terminal(c) && !is_fullwidth_terminal(c) {
+            i += 1;
+            continue;
+        }
+
+        let mut j = i + 1;
+        while j < chars.len() && (is_sentence_terminal(chars[j].1) || is_fullwidth_terminal(chars[j].1)) {
+            j += 1;
+        }
+        let marks_end = j;
+        while j < chars.len() && is_sentence_closer(chars[j].1) {
+            j += 1;
+        }
+        let end = chars.get(j).map_or(text.len(), |(offset, _)| *offset);
+
+        let next = chars.get(j).map(|(_, c)| *c);
+        let ends = match next {
+            None => true,
+            Some(_) if is_fullwidth_terminal(c) => true,
+            Some(next) if !next.is_whitespace() => false,
+            Some(_) => {
+                // "e.g. the" and quoted speech ("Done?" she asked) continue
+                let following = chars[j..].iter().map(|(_, c)| *c).find(|c| !c.is_whitespace());
+                let lowercase = following.is_some_and(char::is_lowercase);
+                let single_period = c == '.' && marks_end == i + 1;
+                let ellipsis = chars[i..marks_end].iter().all(|(_, mark)| matches!(mark, '.' | '…'));
+                let quoted = j > marks_end;
+                !(single_period && ends_abbreviation(text, offset, language))
+                    && !((single_period || ellipsis || quoted) && lowercase)
+            }
+        };
+
+        if ends {
+            found.push(trimmed_token(text, start, end));
+            start = end;
+        }
+        i = j;
+    }
+
+    found.push(trimmed_token(text, start, text.len()));
+    found.retain(|sentence| sentence.text.chars().any(char::is_alphanumeric));
+    found
+}
+
+/// Units `segment_text` splits text into
+#[derive(Clone, Copy, PartialEq)]
+pub enum Granularity {
+    Grapheme,
+    Word,
+    Sentence,
+}
+
+impl Granularity {
+    pub fn from_name(name: &str) -> Option<Granularity> {
+        match name {
+            "grapheme" => Some(Granularity::Grapheme),
+            "word" => Some(Granularity::Word),
+            "sentence" => Some(Granularity::Sentence),
+            _ => None,
+        }
+    }
+}
+
+/// A token with both byte and character (code point) offsets
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Segment<'a> {
+    pub text: &'a str,
+    pub start: usize,
+    pub end: usize,
+    pub char_start: usize,
+    pub char_end: usize,
+}
+
+/// Add character offsets to tokens of `text`, given in text order
+pub fn with_char_offsets<'a>(text: &'a str, tokens: &[Token<'a>]) -> Vec<Segment<'a>> {
+    let (mut byte, mut char) = (0, 0);
+    let mut char_at = |offset: usize| {
+        char += text[byte..offset].chars().count();
+        byte = offset;
+        char
+    };
+    tokens
+        .iter()
+        .map(|token| {
+            let char_start = char_at(token.start);
+            let char_end = char_at(token.end);
+            Segment { text: token.text, start: token.start, end: token.end, char_start, char_end }
+        })
+        .collect()
+}
+
+/// Tokens of one granularity, as `segment_text` splits them
+pub fn tokens_of<'a>(text: &'a str, granularity: Granularity, language: &str) -> Vec<Token<'a>> {
+    match granularity {
+        Granularity::Grapheme => graphemes(text),
+        Granularity::Word => wordbreak::segment(text, language),
+        Granularity::Sentence => sentences_in(text, language),
+    }
+}
+
+/// Split text into graphemes, words or sentences
+/// Returns array: [{"text":"Hi","start":0,"end":2,"charStart":0,"charEnd":2}]
+///
+/// These are the splits every other function uses, following UAX #29
+/// where it matters for chat:
+/// - "grapheme": user-perceived characters; "👩‍👩‍👧", "🇩🇪", "é" written
+///   with a combining accent and "क्ष" are one each
+/// - "word": runs of letters, digits and marks with inner apostrophes
+///   ("don't"); Thai, Chinese and Japanese split by dictionary, as in
+///   `segment_words`. Whitespace, punctuation and emoji are not words.
+/// - "sentence": trimmed sentences, which do not end after abbreviations
+///   of the language ("Dr.", "z.B.") or inside numbers
+///
+/// `start` and `end` are byte offsets, `charStart` and `charEnd` count
+/// Unicode code points. An unknown granularity gives an empty array.
+///
+/// @param text - Text to split
+/// @param granularity - "grapheme", "word" or "sentence"
+/// @param language - Language code for word and sentence rules (may be empty)
+#[wasm_bindgen]
+pub fn segment_text(text: &str, granularity: &str, language: &str) -> JsValue {
+    let Some(granularity) = Granularity::from_name(granularity) else {
+        return to_js(&Vec::<Segment>::new());
+    };
+    to_js(&with_char_offsets(text, &tokens_of(text, granularity, language)))
+}
+
+/// Split text into sentences, for translating or reading them one by one
+/// Returns array: [{"text":"Dr. Weber kommt z.B. um 3.30 Uhr.","start":0,"end":33,
+/// "charStart":0,"charEnd":33},...]
+///
+/// Sentences end at '.', '!', '?' and '…' before a space, at '。', '！',
+/// '？' and the Hindi danda '।' even without one, at the Arabic '؟' and
+/// Urdu '۔', and at line breaks. They do not end:
+/// - after abbreviations of the language ("Dr.", "Sr.", "z.B.", "т.е.",
+///   "डॉ.") or an initial ("J. Smith")
+/// - inside numbers ("3.14", "1.000")
+/// - at an ellipsis, abbreviation or quoted question before a lowercase
+///   word ("Well... maybe", "Done?" she asked)
+///
+/// Closing quotes and brackets stay with their sentence, sentences are
+/// trimmed and empty ones dropped. An empty or unknown language checks the
+/// abbreviations of every language. Offsets are in bytes and characters,
+/// as in `segment_text`.
+///
+/// @param text - Text to split
+/// @param language - Language code for abbreviations (may be empty)
+#[wasm_bindgen]
+pub fn split_sentences(text: &str, language: &str) -> JsValue {
+    to_js(&with_char_offsets(text, &sentences_in(text, language)))
+}
+
+/// A token of `tokenize_with_spans`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KindedSegment<'a> {
+    #[serde(flatten)]
+    segment: Segment<'a>,
+    kind: &'static str,
+}
+
+/// Entities found first, as (span, kind), sorted and without overlaps
+fn entity_spans(text: &str) -> Vec<(Span, &'static str)> {
+    let mut found: Vec<(Span, &'static str)> = Vec::new();
+    let candidates = code_spans(text)
+        .into_iter()
+        .map(|span| (span, "code"))
+        .chain(url_spans(text).into_iter().map(|span| (span, "url")))
+        .chain(mentions(text).into_iter().map(|mention| (mention.span, "mention")))
+        .chain(hashtag_spans(text).into_iter().map(|span| (span, "hashtag")))
+        .chain(number_spans(text).into_iter().map(|span| (span, "number")));
+    for (span, kind) in candidates {
+        if !found.iter().any(|(other, _)| other.overlaps(&span)) {
+            found.push((span, kind));
+        }
+    }
+    found.sort_by_key(|(span, _)| span.start);
+    found
+}
+
+/// Words of text[start..end] and its other characters, as punctuation or
+/// symbols, one grapheme cluster at a time
+fn word_tokens<'a>(text: &'a str, start: usize, end: usize, language: &str, tokens: &mut Vec<(Token<'a>, &'static str)>) {
+    let marks = |from: usize, to: usize, tokens: &mut Vec<(Token<'a>, &'static str)>| {
+        for cluster in graphemes(&text[from..to]) {
+            let kind = match char_category(cluster.text.chars().next().unwrap_or(' ')) {
+                CharCategory::Whitespace => continue,
+                CharCategory::Punctuation => "punctuation",
+                _ => "symbol",
+            };
+            tokens.push((Token { start: cluster.start + from, end: cluster.end + from, ..cluster }, kind));
+        }
+    };
+    let mut last = start;
+    for word in wordbreak::segment(&text[start..end], language) {
+        let word = Token { start: word.start + start, end: word.end + start, ..word };
+        marks(last, word.start, tokens);
+        let kind = if word.text.chars().all(|c| c.is_numeric()) { "number" } else { "word" };
+        tokens.push((word, kind));
+        last = word.end;
+    }
+    marks(last, end, tokens);
+}
+
+/// Tokens of text between entities
+/// Emoji clusters are cut out first, so word rules never join a skin
+/// tone or a following letter to them.
+fn plain_tokens<'a>(text: &'a str, start: usize, end: usize, language: &str, tokens: &mut Vec<(Token<'a>, &'static str)>) {
+    let mut last = start;
+    for cluster in graphemes(&text[start..end]) {
+        if cluster.text.chars().any(is_emoji) {
+            word_tokens(text, last, cluster.start + start, language, tokens);
+            tokens.push((Token { start: cluster.start + start, end: cluster.end + start, ..cluster }, "emoji"));
+            last = cluster.end + start;
+        }
+    }
+    word_tokens(text, last, end, language, tokens);
+}
+
+/// Split text into typed tokens for highlighting and linking
+/// Returns array: [{"text":"Hi","start":0,"end":2,"charStart":0,"charEnd":2,"kind":"word"},
+/// {"text":"@sam","start":3,"end":7,"charStart":3,"charEnd":7,"kind":"mention"}]
+///
+/// Every character except whitespace belongs to exactly one token, in
+/// text order. Kinds:
+/// - "url", "code" (inline or fenced), "mention" and "hashtag", found as
+///   by `parse_mentions` and `extract_code_blocks`, each one token
+/// - "number": digit runs with their separators ("1,234.5")
+/// - "word": as in `segment_text`, with Thai, Chinese and Japanese split
+///   by dictionary for their language
+/// - "emoji": one per emoji sequence ("👍🏽", "👩‍👩‍👧")
+/// - "punctuation" and "symbol": one per other character
+///
+/// Offsets are in bytes and characters, as in `segment_text`.
+///
+/// @param text - Text to tokenize
+/// @param language - Language code for word rules (may be empty)
+#[wasm_bindgen]
+pub fn tokenize_with_spans(text: &str, language: &str) -> JsValue {
+    let mut tokens: Vec<(Token, &'static str)> = Vec::new();
+    let mut last = 0;
+    for (span, kind) in entity_spans(text) {
+        plain_tokens(text, last, span.start, language, &mut tokens);
+        tokens.push((Token { text: &text[span.start..span.end], start: span.start, end: span.end }, kind));
+        last = span.end;
+    }
+    plain_tokens(text, last, text.len(), language, &mut tokens);
+
+    let (spans, kinds): (Vec<Token>, Vec<&'static str>) = tokens.into_iter().unzip();
+    let segments: Vec<KindedSegment> = with_char_offsets(text, &spans)
+        .into_iter()
+        .zip(kinds)
+        .map(|(segment, kind)| KindedSegment { segment, kind })
+        .collect();
+    to_js(&segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::text_stats;
+
+    fn texts<'a>(tokens: &[Token<'a>]) -> Vec<&'a str> {
+        tokens.iter().map(|token| token.text).collect()
+    }
+
+    #[test]
+    fn graphemes_keep_sequences_together() {
+        assert_eq!(texts(&graphemes("👩‍👩‍👧🇩🇪e\u{301}क्ष")), ["👩‍👩‍👧", "🇩🇪", "e\u{301}", "क्ष"]);
+        assert_eq!(grapheme_count("👍🏽 ok"), 4);
+    }
+
+    #[test]
+    fn spacing_marks_stay_with_their_base() {
+        assert_eq!(texts(&graphemes("น้ำ")), ["น้ำ"]);
+        assert_eq!(grapheme_count("ทำ"), 1);
+        assert_eq!(texts(&graphemes("বাংলা")), ["বাং", "লা"]);
+        assert_eq!(texts(&graphemes("தமிழ்")), ["த", "மி", "ழ்"]);
+        assert_eq!(crate::truncate_to_limit("ทำงาน", 1, "graphemes", None), "ทำ");
+    }
+
+    #[test]
+    fn words_and_sentences() {
+        assert_eq!(texts(&tokens_of("Don't stop, 2 go!", Granularity::Word, "en")), ["Don't", "stop", "2", "go"]);
+        assert_eq!(
+            texts(&tokens_of("Dr. Smith is here. Are you? Yes", Granularity::Sentence, "en")),
+            ["Dr. Smith is here.", "Are you?", "Yes"]
+        );
+        assert_eq!(texts(&sentences_in("Siehe z.B. hier. Gut.", "de")), ["Siehe z.B. hier.", "Gut."]);
+        assert_eq!(texts(&sentences("Pi is 3.14. \"Done?\" she asked.")), ["Pi is 3.14.", "\"Done?\" she asked."]);
+    }
+
+    #[test]
+    fn sentences_need_a_letter_or_digit() {
+        assert_eq!(texts(&sentences("```\nx\n```")), ["x"]);
+        assert_eq!(texts(&sentences("Hi\n---\nBye")), ["Hi", "Bye"]);
+        assert_eq!(texts(&sentences("!!!\n😂\nRoom 42")), ["Room 42"]);
+        assert_eq!(texts(&sentences("Great news! 😂")), ["Great news!"]);
+        assert!(sentences("😂").is_empty());
+    }
+
+    #[test]
+    fn segments_count_bytes_and_chars() {
+        let text = "héllo wörld";
+        let segments = with_char_offsets(text, &words(text));
+        let offsets: Vec<_> = segments.iter().map(|s| (s.text, s.start, s.end, s.char_start, s.char_end)).collect();
+        assert_eq!(offsets, [("héllo", 0, 6, 0, 5), ("wörld", 7, 13, 6, 11)]);
+    }
+
+    #[test]
+    fn granularity_names() {
+        assert!(Granularity::from_name("grapheme") == Some(Granularity::Grapheme));
+        assert!(Granularity::from_name("sentence") == Some(Granularity::Sentence));
+        assert!(Granularity::from_name("line").is_none());
+    }
+
+    fn word_texts(text: &str) -> Vec<&str> {
+        words(text).iter().map(|word| word.text).collect()
+    }
+
+    #[test]
+    fn lone_emoji_modifiers_are_not_words() {
+        assert_eq!(word_texts("I ❤️ you 👍🏽"), ["I", "you"]);
+        assert_eq!(word_texts("\u{FE0F} \u{1F3FD}"), Vec::<&str>::new());
+        let stats = text_stats("I ❤️ you 👍🏽");
+        assert_eq!(stats.word_count, 2);
+        assert_eq!(stats.average_word_length, 2.0);
+    }
+
+    #[test]
+    fn marks_continue_words() {
+        assert_eq!(word_texts("cafe\u{0301} don't नमस्ते"), ["cafe\u{0301}", "don't", "नमस्ते"]);
+        assert_eq!(word_texts("'quoted' x'"), ["quoted", "x"]);
+    }
+}
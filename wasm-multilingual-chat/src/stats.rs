@@ -0,0 +1,291 @@
+//! Text statistics, computed at once or over streamed text
+//!
+//! **Learning Point**: Every statistic is kept as a sum (or a maximum) that
+//! can be added to piece by piece, and pieces are cut at line breaks, which
+//! no word, sentence, URL or grapheme cluster spans. Only paragraphs can
+//! continue across a cut, so each piece remembers whether it ended inside
+//! one. Averages and ratios are divided out at the end.
+
+use wasm_bindgen::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::js::to_js;
+use crate::{detection, emoji, entities, numbers, segmentation};
+use crate::segmentation::Granularity;
+use crate::script::{script_of, Script};
+
+/// Bytes buffered before the language of a stream is detected
+const DETECTION_SAMPLE: usize = 4096;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextStats {
+    pub word_count: u32,
+    pub character_count: u32,
+    pub character_count_no_spaces: u32,
+    /// UTF-8 bytes, as stored by the backend
+    pub byte_length: u32,
+    /// UTF-16 code units, JavaScript's `text.length`
+    pub utf16_length: u32,
+    /// User-perceived characters: "👩‍👩‍👧" and "नमस्ते" count 1 and 3
+    pub grapheme_count: u32,
+    pub grapheme_count_no_spaces: u32,
+    pub sentence_count: u32,
+    pub average_word_length: f64,
+    /// Emoji, counting a sequence ("👍🏽", "🇩🇪", "👩‍👩‍👧") once
+    pub emoji_count: u32,
+    pub url_count: u32,
+    pub mention_count: u32,
+    pub hashtag_count: u32,
+    pub number_count: u32,
+    pub paragraph_count: u32,
+    /// Lines, counting blank ones; 0 for empty text
+    pub line_count: u32,
+    /// Longest word in grapheme clusters, the first one on ties
+    pub longest_word: String,
+    /// Words per sentence
+    pub average_sentence_length: f64,
+    /// Punctuation marks per letter
+    pub punctuation_ratio: f64,
+}
+
+/// Running sums behind `TextStats`
+#[derive(Clone, Default)]
+pub struct StatTotals {
+    words: usize,
+    word_characters: usize,
+    characters: usize,
+    characters_no_spaces: usize,
+    bytes: usize,
+    utf16: usize,
+    graphemes: usize,
+    graphemes_no_spaces: usize,
+    sentences: usize,
+    emoji: usize,
+    urls: usize,
+    mentions: usize,
+    hashtags: usize,
+    numbers: usize,
+    paragraphs: usize,
+    lines: usize,
+    longest_word: String,
+    longest_length: usize,
+    letters: usize,
+    punctuation: usize,
+    /// Whether the text so far ends on a non-blank line
+    in_paragraph: bool,
+}
+
+impl StatTotals {
+    /// Add the statistics of more text
+    /// Every piece but the last should end with a line break.
+    pub fn add(&mut self, text: &str, language: &str) {
+        let words = segmentation::tokens_of(text, Granularity::Word, language);
+        self.words += words.len();
+        self.word_characters += words.iter().map(|word| word.text.chars().count()).sum::<usize>();
+
+        self.characters += text.chars().count();
+        self.characters_no_spaces += text.chars().filter(|c| !c.is_whitespace()).count();
+        self.bytes += text.len();
+        self.utf16 += text.encode_utf16().count();
+
+        let clusters = segmentation::tokens_of(text, Granularity::Grapheme, language);
+        self.graphemes += clusters.len();
+        self.graphemes_no_spaces += clusters
+            .iter()
+            .filter(|cluster| !cluster.text.chars().all(char::is_whitespace))
+            .count();
+        self.sentences += segmentation::tokens_of(text, Granularity::Sentence, language).len();
+
+        let urls = entities::url_spans(text);
+        let code = entities::code_spans(text);
+        let outside = |span: &entities::Span| !urls.iter().chain(&code).any(|other| other.overlaps(span));
+        self.emoji += clusters.iter().filter(|cluster| cluster.text.chars().any(emoji::is_emoji)).count();
+        self.urls += urls.len();
+        self.mentions += entities::mentions(text).iter().filter(|mention| outside(&mention.span)).count();
+        self.hashtags += entities::hashtag_spans(text).iter().filter(|span| outside(span)).count();
+        self.numbers += numbers::number_spans(text).iter().filter(|span| outside(span)).count();
+
+        for word in &words {
+            let length = segmentation::grapheme_count(word.text);
+            if length > self.longest_length {
+                self.longest_word = word.text.to_string();
+                self.longest_length = length;
+            }
+        }
+        self.letters += text.chars().filter(|c| c.is_alphabetic()).count();
+        self.punctuation += text
+            .chars()
+            .filter(|c| segmentation::char_category(*c) == segmentation::CharCategory::Punctuation)
+            .count();
+
+        let is_text = |line: &str| !line.trim().is_empty();
+        self.paragraphs += segmentation::paragraphs(text).len();
+        if self.in_paragraph && text.lines().next().is_some_and(is_text) {
+            self.paragraphs -= 1;
+        }
+        if !text.is_empty() {
+            self.in_paragraph = text.lines().next_back().is_some_and(is_text);
+        }
+        self.lines += text.lines().count();
+    }
+
+    pub fn finish(&self) -> TextStats {
+        let per = |part: usize, whole: usize| if whole > 0 { part as f64 / whole as f64 } else { 0.0 };
+        TextStats {
+            word_count: self.words as u32,
+            character_count: self.characters as u32,
+            character_count_no_spaces: self.characters_no_spaces as u32,
+            byte_length: self.bytes as u32,
+            utf16_length: self.utf16 as u32,
+            grapheme_count: self.graphemes as u32,
+            grapheme_count_no_spaces: self.graphemes_no_spaces as u32,
+            sentence_count: self.sentences as u32,
+            average_word_length: per(self.word_characters, self.words),
+            emoji_count: self.emoji as u32,
+            url_count: self.urls as u32,
+            mention_count: self.mentions as u32,
+            hashtag_count: self.hashtags as u32,
+            number_count: self.numbers as u32,
+            paragraph_count: self.paragraphs as u32,
+            line_count: self.lines as u32,
+            longest_word: self.longest_word.clone(),
+            average_sentence_length: per(self.words, self.sentences),
+            punctuation_ratio: per(self.punctuation, self.letters),
+        }
+    }
+}
+
+/// Statistics of a whole text, in the language detected for it
+pub fn text_stats(text: &str) -> TextStats {
+    let mut totals = StatTotals::default();
+    totals.add(text, detection::best_language(text).0);
+    totals.finish()
+}
+
+/// Text statistics over text streamed in chunks
+/// Only the text after the last complete line is kept between pushes (or
+/// a fenced code block still open), so a multi-megabyte chat export read
+/// from a Blob never has to be in wasm memory at once. Chunks may end
+/// anywhere, even inside a word.
+///
+/// The language used for word and sentence rules is detected from the
+/// first 4 KB, so `finish` matches `get_text_stats` on the whole text
+/// whenever that sample is in the language of the rest.
+#[wasm_bindgen]
+pub struct StatsAccumulator {
+    totals: StatTotals,
+    /// Text after the last complete line, which may still grow
+    pending: String,
+    /// Detected language, once enough text has arrived
+    language: Option<&'static str>,
+}
+
+impl Default for StatsAccumulator {
+    fn default() -> Self {
+        StatsAccumulator::new()
+    }
+}
+
+#[wasm_bindgen]
+impl StatsAccumulator {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> StatsAccumulator {
+        StatsAccumulator { totals: StatTotals::default(), pending: String::new(), language: None }
+    }
+
+    /// Append the next chunk of text
+    pub fn push(&mut self, chunk: &str) {
+        self.pending.push_str(chunk);
+        if self.language.is_none() {
+            if self.pending.len() < DETECTION_SAMPLE {
+                return;
+            }
+            self.language = Some(detection::best_language(&self.pending).0);
+        }
+
+        let Some(newline) = self.pending.rfind('\n') else {
+            return;
+        };
+        // A fence still open would be counted as prose up to the cut
+        if self.pending[..newline].matches("```").count() % 2 == 1 {
+            return;
+        }
+        let complete: String = self.pending.drain(..=newline).collect();
+        self.totals.add(&complete, self.language.unwrap_or("en"));
+    }
+
+    /// Statistics of everything pushed so far
+    /// Returns the same object as `get_text_stats`. More chunks can still
+    /// be pushed afterwards.
+    pub fn finish(&self) -> JsValue {
+        to_js(&self.report())
+    }
+
+    /// Forget all pushed text
+    pub fn reset(&mut self) {
+        *self = StatsAccumulator::new();
+    }
+}
+
+impl StatsAccumulator {
+    fn report(&self) -> TextStats {
+        let language = self.language.unwrap_or_else(|| detection::best_language(&self.pending).0);
+        let mut totals = self.totals.clone();
+        totals.add(&self.pending, language);
+        totals.finish()
+    }
+}
+
+/// Writing-system make-up of Chinese and Japanese text
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CjkStats {
+    /// Han characters (kanji, hanzi), including the iteration mark 々
+    pub han_count: u32,
+    /// Distinct Han characters
+    pub unique_han_count: u32,
+    pub hiragana_count: u32,
+    /// Katakana, including half-width katakana and the long vowel mark ー
+    pub katakana_count: u32,
+    /// Latin letters, including full-width ones
+    pub latin_count: u32,
+    /// Letters of any other script
+    pub other_letter_count: u32,
+    /// Shares of all letters (0.0 to 1.0)
+    pub kanji_ratio: f64,
+    pub kana_ratio: f64,
+    pub latin_ratio: f64,
+}
+
+pub fn cjk_stats(text: &str) -> CjkStats {
+    let mut han: Vec<char> = Vec::new();
+    let (mut hiragana, mut katakana, mut latin, mut other) = (0, 0, 0, 0);
+    for c in text.chars() {
+        match script_of(c) {
+            _ if c == '々' => han.push(c),
+            Script::Han => han.push(c),
+            Script::Hiragana => hiragana += 1,
+            Script::Katakana => katakana += 1,
+            Script::Latin => latin += 1,
+            Script::Common => {}
+            _ => other += 1,
+        }
+    }
+
+    let han_count = han.len();
+    han.sort_unstable();
+    han.dedup();
+    let letters = han_count + hiragana + katakana + latin + other;
+    let per = |part: usize| if letters > 0 { part as f64 / letters as f64 } else { 0.0 };
+    CjkStats {
+        han_count: han_count as u32,
+        unique_han_count: han.len() as u32,
+        hiragana_count: hiragana as u32,
+        katakana_count: katakana as u32,
+        latin_count: latin as u32,
+        other_letter_count: other as u32,
+        kanji_ratio: per(han_count),
+        kana_ratio: per(hiragana + katakana),
+        latin_ratio: per(latin),
+    }
+}
@@ -0,0 +1,313 @@
+//! Character flooding, emphasis and shouting detection
+
+use wasm_bindgen::prelude::*;
+use serde::Serialize;
+use crate::entities::{code_spans, url_spans, Span};
+use crate::js::to_js;
+use crate::segmentation::{char_category, graphemes, words, CharCategory, Token};
+
+/// Minimum run of the same letter that counts as intentional elongation
+///
+/// Runs of two are ordinary spelling ("good", "committee"), so elongation
+/// starts at three repeats ("sooo").
+pub const ELONGATION_THRESHOLD: usize = 3;
+
+/// Words at least this long may legitimately contain a triple letter
+/// (German compounds like "Schifffahrt"), so runs of exactly
+/// `ELONGATION_THRESHOLD` inside them are not treated as elongation.
+const COMPOUND_WORD_MIN_LEN: usize = 8;
+
+/// Letters that commonly appear doubled inside a word ("good", "sweet",
+/// "hello", "class", "little"), so internal runs collapse to two of them
+const DOUBLING_LETTERS: [&str; 5] = ["e", "o", "l", "s", "t"];
+
+/// A run of identical letter graphemes inside a word
+pub struct Run {
+    /// Index of the first grapheme in the run
+    pub index: usize,
+    /// Number of repeated graphemes
+    pub len: usize,
+}
+
+fn is_letter_cluster(cluster: &str) -> bool {
+    cluster.chars().next().is_some_and(|c| c.is_alphabetic())
+}
+
+/// Find elongated letter runs in a single word
+/// Non-letter runs ("!!!", "1000") are never reported.
+pub fn elongated_runs(clusters: &[Token<'_>]) -> Vec<Run> {
+    let mut runs = Vec::new();
+    let mut i = 0;
+
+    while i < clusters.len() {
+        let current = clusters[i].text.to_lowercase();
+        let mut j = i + 1;
+        while j < clusters.len() && clusters[j].text.to_lowercase() == current {
+            j += 1;
+        }
+
+        let len = j - i;
+        let exempt_compound = len == ELONGATION_THRESHOLD && clusters.len() >= COMPOUND_WORD_MIN_LEN;
+        if len >= ELONGATION_THRESHOLD && is_letter_cluster(clusters[i].text) && !exempt_compound {
+            runs.push(Run { index: i, len });
+        }
+        i = j;
+    }
+
+    runs
+}
+
+/// Collapse the elongated runs of a word into its likely intended spelling
+/// Trailing runs collapse to one letter ("sooo" → "so"), internal runs of
+/// commonly doubled letters collapse to two ("goooood" → "good").
+fn collapse_runs(clusters: &[Token<'_>], runs: &[Run]) -> String {
+    let mut result = String::new();
+    let mut i = 0;
+    let mut runs = runs.iter().peekable();
+
+    while i < clusters.len() {
+        match runs.peek() {
+            Some(run) if run.index == i => {
+                let letter = clusters[i].text;
+                let trailing = run.index + run.len == clusters.len();
+                let keep = if !trailing && DOUBLING_LETTERS.contains(&letter.to_lowercase().as_str()) {
+                    2
+                } else {
+                    1
+                };
+                for _ in 0..keep {
+                    result.push_str(letter);
+                }
+                i += run.len;
+                runs.next();
+            }
+            _ => {
+                result.push_str(clusters[i].text);
+                i += 1;
+            }
+        }
+    }
+
+    result
+}
+
+/// Convert elongated words into emphasis markup
+/// Words containing a run of `ELONGATION_THRESHOLD` or more identical letters
+/// are collapsed and wrapped in `*...*`; everything else is left untouched.
+///
+/// @param text - Text to process
+/// @returns Text with markup, e.g. "soooo good" → "*so* good"
+#[wasm_bindgen]
+pub fn emphasis_from_elongation(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last_end = 0;
+
+    for word in words(text) {
+        result.push_str(&text[last_end..word.start]);
+
+        let clusters = graphemes(word.text);
+        let runs = elongated_runs(&clusters);
+        if runs.is_empty() {
+            result.push_str(word.text);
+        } else {
+            result.push('*');
+            result.push_str(&collapse_runs(&clusters, &runs));
+            result.push('*');
+        }
+
+        last_end = word.end;
+    }
+
+    result.push_str(&text[last_end..]);
+    result
+}
+
+/// A letter run shortened by `collapse_elongation`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CollapsedRun<'a> {
+    /// The run as written, such as "ooooo"
+    original: &'a str,
+    /// Byte offsets of the run in the original text
+    start: usize,
+    end: usize,
+    /// Byte offsets of what is left of it in the collapsed text
+    collapsed_start: usize,
+    collapsed_end: usize,
+}
+
+#[derive(Serialize)]
+struct Collapsed<'a> {
+    text: String,
+    runs: Vec<CollapsedRun<'a>>,
+}
+
+fn collapse_elongation_report(text: &str, max_repeat: usize) -> Collapsed<'_> {
+    let skipped: Vec<Span> = url_spans(text).into_iter().chain(code_spans(text)).collect();
+    let clusters = graphemes(text);
+    let mut collapsed = Collapsed { text: String::with_capacity(text.len()), runs: Vec::new() };
+
+    let mut i = 0;
+    while i < clusters.len() {
+        let first = &clusters[i];
+        let letter = first.text.to_lowercase();
+        let mut j = i + 1;
+        while j < clusters.len() && clusters[j].text.to_lowercase() == letter {
+            j += 1;
+        }
+
+        let run = Span { start: first.start, end: clusters[j - 1].end };
+        let kept = if is_letter_cluster(first.text) && !skipped.iter().any(|span| span.overlaps(&run)) {
+            max_repeat.min(j - i)
+        } else {
+            j - i
+        };
+        let collapsed_start = collapsed.text.len();
+        for cluster in &clusters[i..i + kept] {
+            collapsed.text.push_str(cluster.text);
+        }
+        if kept < j - i {
+            collapsed.runs.push(CollapsedRun {
+                original: &text[run.start..run.end],
+                start: run.start,
+                end: run.end,
+                collapsed_start,
+                collapsed_end: collapsed.text.len(),
+            });
+        }
+        i = j;
+    }
+
+    collapsed
+}
+
+/// Shorten repeated letters to a canonical form for search and filtering
+/// Returns object: {"text":"soo good","runs":[{"original":"ooooo","start":1,
+/// "end":6,"collapsedStart":1,"collapsedEnd":3},...]}
+///
+/// Every run of more than `max_repeat` identical letters keeps its first
+/// `max_repeat`, so with 2 "sooooo goooood" and "soooo good" both become
+/// "soo good", and with 1 even "good" becomes "god"; pick the same value
+/// for indexed text and queries. Letters compare case-insensitively
+/// ("NOooo" → "NO" with 1), and accented letters and other grapheme
+/// clusters repeat as a whole. Digits, punctuation, URLs and code are
+/// left alone. Offsets are in bytes, so a match in the collapsed text can
+/// be mapped back to the message.
+///
+/// @param text - Text to collapse
+/// @param max_repeat - Longest run kept (at least 1)
+#[wasm_bindgen]
+pub fn collapse_elongation(text: &str, max_repeat: u32) -> JsValue {
+    to_js(&collapse_elongation_report(text, max_repeat.max(1) as usize))
+}
+
+/// Share of uppercase letters above which text counts as shouting
+const SHOUTING_CAPS_RATIO: f64 = 0.7;
+
+/// Cased letters needed before text can count as shouting, so "OK" and
+/// "USA" alone are not flagged
+const SHOUTING_MIN_LETTERS: usize = 6;
+
+/// Exclamation and question marks, which repeat as one class ("?!?!")
+fn is_exclamation(c: char) -> bool {
+    matches!(c, '!' | '?' | '¡' | '¿' | '！' | '？' | '‼' | '⁇' | '⁈' | '⁉')
+}
+
+/// Shortest run of a punctuation mark that counts as repeated
+/// Three periods are an ellipsis, so periods need four.
+fn repeat_threshold(c: char) -> usize {
+    match c {
+        '.' | '。' | '．' => ELONGATION_THRESHOLD + 1,
+        _ => ELONGATION_THRESHOLD,
+    }
+}
+
+/// Shouting and flooding signals of a message
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StyleFlags {
+    /// Uppercase share of cased letters (0.0 to 1.0)
+    pub caps_ratio: f64,
+    /// Words of two or more cased letters, all uppercase
+    pub all_caps_words: u32,
+    /// Runs such as "!!!", "?!?!" or "....."
+    pub repeated_punctuation: u32,
+    /// Longest run of repeated punctuation, in characters
+    pub longest_punctuation_run: u32,
+    /// Words with an elongated letter run ("noooo", "sooo")
+    pub elongated_words: u32,
+    pub shouting: bool,
+}
+
+pub fn style_flags(text: &str) -> StyleFlags {
+    let skipped: Vec<Span> = url_spans(text).into_iter().chain(code_spans(text)).collect();
+    let skip = |start: usize, end: usize| skipped.iter().any(|span| span.overlaps(&Span { start, end }));
+
+    let (mut upper, mut cased) = (0, 0);
+    let mut all_caps_words = 0;
+    let mut elongated_words = 0;
+    for word in words(text).iter().filter(|word| !skip(word.start, word.end)) {
+        let letters = word.text.chars().filter(|c| c.is_uppercase() || c.is_lowercase());
+        let (word_upper, word_cased) = letters.fold((0, 0), |(u, n), c| (u + c.is_uppercase() as usize, n + 1));
+        upper += word_upper;
+        cased += word_cased;
+        if word_cased > 1 && word_upper == word_cased {
+            all_caps_words += 1;
+        }
+        if !elongated_runs(&graphemes(word.text)).is_empty() {
+            elongated_words += 1;
+        }
+    }
+
+    let mut repeated_punctuation = 0;
+    let mut longest_punctuation_run = 0;
+    let mut run: Option<(char, usize)> = None;
+    let mut close_run = |run: Option<(char, usize)>| {
+        if let Some((c, len)) = run {
+            if len >= repeat_threshold(c) {
+                repeated_punctuation += 1;
+                longest_punctuation_run = longest_punctuation_run.max(len);
+            }
+        }
+    };
+    for (index, c) in text.char_indices() {
+        if char_category(c) != CharCategory::Punctuation || skip(index, index + c.len_utf8()) {
+            close_run(run.take());
+            continue;
+        }
+        run = match run {
+            Some((first, len)) if first == c || (is_exclamation(first) && is_exclamation(c)) => Some((first, len + 1)),
+            previous => {
+                close_run(previous);
+                Some((c, 1))
+            }
+        };
+    }
+    close_run(run);
+
+    let caps_ratio = if cased > 0 { upper as f64 / cased as f64 } else { 0.0 };
+    StyleFlags {
+        caps_ratio,
+        all_caps_words,
+        repeated_punctuation,
+        longest_punctuation_run: longest_punctuation_run as u32,
+        elongated_words,
+        shouting: cased >= SHOUTING_MIN_LETTERS && caps_ratio >= SHOUTING_CAPS_RATIO,
+    }
+}
+
+/// Get shouting and flooding signals for moderation
+/// Returns object: {"capsRatio":0.92,"allCapsWords":3,"repeatedPunctuation":1,
+/// "longestPunctuationRun":4,"elongatedWords":1,"shouting":true}
+///
+/// Case follows Unicode, so Cyrillic and Greek capitals count and scripts
+/// without case (Han, Thai, Arabic) neither raise nor lower the caps ratio.
+/// `shouting` needs at least 6 cased letters, 70% of them uppercase.
+/// Repeated punctuation is a run of at least three of the same mark, with
+/// '!' and '?' (in any width) counted as one mark and periods needing four
+/// so an ellipsis passes. Elongation uses the rules of
+/// `emphasis_from_elongation`. URLs and code are ignored.
+#[wasm_bindgen]
+pub fn get_style_flags(text: &str) -> JsValue {
+    to_js(&style_flags(text))
+}
@@ -0,0 +1,1304 @@
+//! Transliteration and pronunciation respelling
+
+use wasm_bindgen::prelude::*;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+use crate::detection::best_language;
+use crate::hanzi::pinyin;
+use crate::normalize::{fold_diacritics, is_diacritic};
+use crate::script::{script_of, Script};
+use crate::unicode::{normalize_form, Form};
+use crate::wordbreak;
+
+/// Rewrite text with an ordered table of (pattern, replacement) rules
+///
+/// At each position the longest matching pattern wins; characters without
+/// a rule are copied unchanged. A pattern starting with '^' only matches
+/// at the start of the text, one ending in '$' only at its end.
+pub fn rewrite<P: AsRef<str>, R: AsRef<str>>(text: &str, rules: &[(P, R)]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        let best = rules
+            .iter()
+            .filter_map(|(pattern, replacement)| {
+                let pattern = pattern.as_ref();
+                let (anchored_start, pattern) = pattern.strip_prefix('^').map_or((false, pattern), |p| (true, p));
+                let (anchored_end, core) = pattern.strip_suffix('$').map_or((false, pattern), |p| (true, p));
+                let fits = !core.is_empty()
+                    && rest.starts_with(core)
+                    && (!anchored_start || i == 0)
+                    && (!anchored_end || rest.len() == core.len());
+                // Anchored patterns win ties over free ones
+                fits.then_some((core.len(), anchored_start || anchored_end, replacement.as_ref()))
+            })
+            .max_by_key(|(len, anchored, _)| (*len, *anchored));
+
+        match best {
+            Some((len, _, replacement)) => {
+                result.push_str(replacement);
+                i += len;
+            }
+            None => {
+                let c = rest.chars().next().unwrap_or_default();
+                result.push(c);
+                i += c.len_utf8();
+            }
+        }
+    }
+
+    result
+}
+
+/// English words whose spelling no rule recovers
+const EN_IRREGULAR: &[(&str, &str)] = &[
+    ("though", "thoh"), ("although", "awl-thoh"), ("through", "throo"), ("tough", "tuff"), ("rough", "ruff"),
+    ("enough", "ih-nuff"), ("cough", "koff"), ("thought", "thawt"), ("bought", "bawt"), ("brought", "brawt"),
+    ("one", "wun"), ("two", "too"), ("once", "wunss"), ("women", "wim-in"), ("said", "sed"), ("says", "sez"),
+    ("the", "thuh"), ("of", "uv"), ("you", "yoo"), ("are", "ar"), ("have", "hav"), ("give", "giv"),
+    ("live", "liv"), ("love", "luv"), ("come", "kum"), ("some", "sum"), ("done", "dun"), ("gone", "gon"),
+    ("was", "wuz"), ("what", "wut"), ("do", "doo"), ("to", "too"), ("who", "hoo"), ("my", "my"), ("by", "by"),
+    ("why", "wy"), ("busy", "biz-ee"), ("colonel", "ker-nul"), ("island", "eye-lund"), ("knowledge", "nol-ij"),
+];
+
+/// English letter-to-sound rules, in the "sounds like" spelling learners know
+/// ("ay" as in day, "ee" as in see, "y" as in my, "oh", "yoo")
+fn english_rules() -> Vec<(String, String)> {
+    let fixed: &[(&str, &str)] = &[
+        ("tion", "shun"), ("sion", "zhun"), ("ture", "cher"), ("ough", "off"), ("augh", "aw"), ("eigh", "ay"),
+        ("igh", "y"), ("tch", "ch"), ("dge", "j"), ("^kn", "n"), ("^wr", "r"), ("^ps", "s"), ("^gh", "g"),
+        ("mb$", "m"), ("ph", "f"), ("ch", "ch"), ("sh", "sh"), ("th", "th"), ("ck", "k"), ("qu", "kw"), ("wh", "w"), ("ee", "ee"), ("ea", "ee"),
+        ("oo", "oo"), ("ai", "ay"), ("ay", "ay"), ("ey$", "ee"), ("oa", "oh"), ("oe$", "oh"), ("ow$", "oh"),
+        ("ow", "ow"), ("ou", "ow"), ("oi", "oy"), ("oy", "oy"), ("au", "aw"), ("aw", "aw"), ("ew", "yoo"),
+        ("ue$", "oo"), ("ie$", "y"), ("y$", "ee"), ("le$", "ul"), ("ce", "se"), ("ci", "si"), ("cy", "see"),
+        ("ge$", "j"), ("c", "k"), ("^x", "z"), ("x", "ks"), ("e$", ""),
+        ("ll", "l"), ("ss", "ss"), ("tt", "t"), ("pp", "p"), ("nn", "n"), ("mm", "m"), ("rr", "r"), ("ff", "ff"),
+        ("dd", "d"), ("bb", "b"), ("gg", "g"), ("zz", "z"),
+    ];
+    let mut rules: Vec<(String, String)> = fixed.iter().map(|(p, r)| (p.to_string(), r.to_string())).collect();
+
+    // Silent final e lengthens the vowel before it: "make" → "mayk", "time" → "tym"
+    for (vowel, long) in [('a', "ay"), ('e', "ee"), ('i', "y"), ('o', "oh"), ('u', "yoo")] {
+        for consonant in "bcdfgklmnprstvz".chars() {
+            let sound = match consonant {
+                'c' => "s",
+                'g' => "j",
+                _ => "",
+            };
+            let sound = if sound.is_empty() { consonant.to_string() } else { sound.to_string() };
+            rules.push((format!("{}{}e$", vowel, consonant), format!("{}{}", long, sound)));
+        }
+    }
+
+    rules
+}
+
+fn is_spanish_vowel(c: char) -> bool {
+    "aeiouáéíóúü".contains(c)
+}
+
+/// Strong vowels form separate syllables when adjacent ("le-er"); an
+/// accented weak vowel breaks a diphthong too ("dí-a")
+fn is_spanish_strong(c: char) -> bool {
+    "aeoáéóíú".contains(c)
+}
+
+/// Consonant pairs that start a syllable together ("ha-blar", "o-tro")
+fn is_spanish_onset(pair: &str) -> bool {
+    matches!(pair, "bl" | "br" | "cl" | "cr" | "dr" | "fl" | "fr" | "gl" | "gr" | "kl" | "kr" | "pl" | "pr" | "tr" | "ch" | "ll" | "rr")
+}
+
+/// Split a lowercased Spanish word into syllables
+fn spanish_syllables(word: &str) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut syllables = Vec::new();
+    let mut current = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        current.push(chars[i]);
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        if is_spanish_vowel(c) {
+            match next {
+                // Hiatus: two strong vowels split
+                Some(n) if is_spanish_vowel(n) && is_spanish_strong(c) && is_spanish_strong(n) => {
+                    syllables.push(std::mem::take(&mut current));
+                }
+                Some(n) if is_spanish_vowel(n) => {}
+                Some(_) => {
+                    // Consonants up to the next vowel
+                    let run: Vec<char> = chars[i + 1..].iter().take_while(|c| !is_spanish_vowel(**c)).copied().collect();
+                    let ends_word = i + 1 + run.len() == chars.len();
+                    if !ends_word {
+                        let keep = match run.len() {
+                            1 => 0,
+                            n => {
+                                let last_pair: String = run[n - 2..].iter().collect();
+                                if is_spanish_onset(&last_pair) { n - 2 } else { n - 1 }
+                            }
+                        };
+                        current.extend(&run[..keep]);
+                        syllables.push(std::mem::take(&mut current));
+                        i += keep;
+                    }
+                }
+                None => {}
+            }
+        }
+        i += 1;
+    }
+
+    if !current.is_empty() {
+        syllables.push(current);
+    }
+    syllables
+}
+
+/// Spanish sounds of a syllable, with Latin American "s" for "z" and soft "c"
+const ES_RULES: &[(&str, &str)] = &[
+    ("^h", ""), ("ch", "ch"), ("ll", "y"), ("ñ", "ny"), ("rr", "rr"), ("^r", "rr"),
+    ("que", "keh"), ("qui", "kee"), ("gue", "geh"), ("gui", "gee"), ("güe", "gweh"), ("güi", "gwee"),
+    ("ge", "heh"), ("gi", "hee"), ("ce", "seh"), ("ci", "see"),
+    ("cia", "syah"), ("cie", "syeh"), ("cio", "syoh"), ("ciu", "syoo"),
+    ("gia", "hyah"), ("gie", "hyeh"), ("gio", "hyoh"),
+    ("ia", "yah"), ("ie", "yeh"), ("io", "yoh"), ("iu", "yoo"),
+    ("ua", "wah"), ("ue", "weh"), ("ui", "wee"), ("uo", "woh"),
+    ("ai", "eye"), ("ay", "eye"), ("ei", "ay"), ("ey", "ay"), ("oi", "oy"), ("oy", "oy"), ("au", "ow"), ("eu", "eh-oo"),
+    ("z", "s"), ("j", "h"), ("v", "b"), ("x", "ks"), ("c", "k"), ("h", ""), ("y$", "ee"),
+    ("a", "ah"), ("á", "ah"), ("e", "eh"), ("é", "eh"), ("i", "ee"), ("í", "ee"), ("o", "oh"), ("ó", "oh"), ("u", "oo"), ("ú", "oo"),
+];
+
+/// Index of the stressed syllable of a Spanish word
+/// A written accent marks it; otherwise words ending in a vowel, "n" or
+/// "s" stress the second-to-last syllable and all others the last.
+fn spanish_stress(syllables: &[String]) -> usize {
+    if syllables.is_empty() {
+        return 0;
+    }
+    if let Some(index) = syllables.iter().position(|s| s.contains(['á', 'é', 'í', 'ó', 'ú'])) {
+        return index;
+    }
+    let last = syllables.last().and_then(|s| s.chars().last()).unwrap_or('a');
+    if syllables.len() > 1 && (is_spanish_vowel(last) || last == 'n' || last == 's') {
+        syllables.len() - 2
+    } else {
+        syllables.len() - 1
+    }
+}
+
+fn spanish_hint(word: &str) -> String {
+    let syllables = spanish_syllables(word);
+    let stress = spanish_stress(&syllables);
+    syllables
+        .iter()
+        .enumerate()
+        .map(|(index, syllable)| {
+            let sound = rewrite(syllable, ES_RULES);
+            if index == stress && syllables.len() > 1 { sound.to_uppercase() } else { sound }
+        })
+        .filter(|sound| !sound.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Respell a word so a learner can pronounce it
+/// Returns an approximate "sounds like" spelling for English readers.
+///
+/// - English ("en"): irregular common words from a table ("though" →
+///   "thoh"), then letter-to-sound rules such as "tion" → "shun", "ph" →
+///   "f" and the silent final e that lengthens its vowel ("make" → "mayk")
+/// - Spanish ("es"): split into syllables with the stressed one in capitals
+///   ("gracias" → "GRAH-syahs", "hola" → "OH-lah"), using Latin American
+///   pronunciation ("z" and soft "c" as "s")
+///
+/// This is a learning aid, not a phonetic transcription: English spelling
+/// is too irregular for rules to be exact. Other languages return the word
+/// unchanged, lowercased like all output.
+///
+/// @param word - A single word
+/// @param language - Language code: "en" or "es"
+#[wasm_bindgen]
+pub fn pronunciation_hint(word: &str, language: &str) -> String {
+    let word = word.trim().to_lowercase();
+    if word.is_empty() {
+        return word;
+    }
+    match language {
+        "en" => EN_IRREGULAR
+            .iter()
+            .find(|(irregular, _)| *irregular == word)
+            .map_or_else(|| rewrite(&word, &english_rules()), |(_, hint)| hint.to_string()),
+        "es" => spanish_hint(&word),
+        _ => word,
+    }
+}
+
+/// Latin schemes for Devanagari
+#[derive(Clone, Copy, PartialEq)]
+enum IndicScheme {
+    /// International Alphabet of Sanskrit Transliteration ("ā", "ṭ", "ś")
+    Iast,
+    /// ASCII-only ITRANS ("A", "T", "sh")
+    Itrans,
+}
+
+impl IndicScheme {
+    fn from_name(name: &str) -> Option<IndicScheme> {
+        match name {
+            "latn" | "iast" => Some(IndicScheme::Iast),
+            "itrans" => Some(IndicScheme::Itrans),
+            _ => None,
+        }
+    }
+}
+
+/// Devanagari vowels: independent letter, vowel sign, IAST, ITRANS
+/// The inherent "a" has no sign.
+const DEVANAGARI_VOWELS: &[(char, Option<char>, &str, &str)] = &[
+    ('अ', None, "a", "a"), ('आ', Some('ा'), "ā", "A"), ('इ', Some('ि'), "i", "i"), ('ई', Some('ी'), "ī", "I"),
+    ('उ', Some('ु'), "u", "u"), ('ऊ', Some('ू'), "ū", "U"), ('ऋ', Some('ृ'), "ṛ", "RRi"), ('ॠ', Some('ॄ'), "ṝ", "RRI"),
+    ('ऌ', Some('ॢ'), "ḷ", "LLi"), ('ए', Some('े'), "e", "e"), ('ऐ', Some('ै'), "ai", "ai"), ('ओ', Some('ो'), "o", "o"),
+    ('औ', Some('ौ'), "au", "au"), ('ऑ', Some('ॉ'), "ô", "^o"), ('ऍ', Some('ॅ'), "ê", "^e"),
+];
+
+/// Devanagari consonants (nukta forms decomposed, as NFC has them), IAST, ITRANS
+const DEVANAGARI_CONSONANTS: &[(&str, &str, &str)] = &[
+    ("क", "k", "k"), ("ख", "kh", "kh"), ("ग", "g", "g"), ("घ", "gh", "gh"), ("ङ", "ṅ", "~N"),
+    ("च", "c", "ch"), ("छ", "ch", "Ch"), ("ज", "j", "j"), ("झ", "jh", "jh"), ("ञ", "ñ", "~n"),
+    ("ट", "ṭ", "T"), ("ठ", "ṭh", "Th"), ("ड", "ḍ", "D"), ("ढ", "ḍh", "Dh"), ("ण", "ṇ", "N"),
+    ("त", "t", "t"), ("थ", "th", "th"), ("द", "d", "d"), ("ध", "dh", "dh"), ("न", "n", "n"),
+    ("प", "p", "p"), ("फ", "ph", "ph"), ("ब", "b", "b"), ("भ", "bh", "bh"), ("म", "m", "m"),
+    ("य", "y", "y"), ("र", "r", "r"), ("ल", "l", "l"), ("ळ", "ḻ", "L"), ("व", "v", "v"),
+    ("श", "ś", "sh"), ("ष", "ṣ", "Sh"), ("स", "s", "s"), ("ह", "h", "h"),
+    ("क\u{093C}", "q", "q"), ("ख\u{093C}", "ḵh", "K"), ("ग\u{093C}", "ġ", "G"), ("ज\u{093C}", "z", "z"),
+    ("ड\u{093C}", "ṙ", ".D"), ("ढ\u{093C}", "ṙh", ".Dh"), ("फ\u{093C}", "f", "f"), ("य\u{093C}", "ẏ", "Y"),
+];
+
+/// Devanagari signs and punctuation, IAST, ITRANS
+const DEVANAGARI_SIGNS: &[(char, &str, &str)] = &[
+    ('ं', "ṃ", "M"), ('ँ', "m̐", ".N"), ('ः', "ḥ", "H"), ('ऽ', "'", ".a"), ('।', ".", "|"), ('॥', ".", "||"),
+    ('ॐ', "oṃ", "OM"),
+];
+
+const VIRAMA: char = '\u{094D}';
+
+/// Languages whose word-final inherent "a" is silent ("कमल" is "kamal")
+fn drops_final_schwa(language: &str) -> bool {
+    matches!(language, "hi" | "mr" | "ne")
+}
+
+fn is_devanagari(c: char) -> bool {
+    matches!(c, '\u{0900}'..='\u{097F}')
+}
+
+/// Romanize Devanagari text
+fn devanagari_to_latin(text: &str, language: &str, scheme: IndicScheme) -> String {
+    let pick = |iast: &'static str, itrans: &'static str| if scheme == IndicScheme::Iast { iast } else { itrans };
+    let text = normalize_form(text, Form::Nfd);
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len());
+    // Aksharas in the current word, and where its last inherent "a" was written
+    let mut syllables = 0;
+    let mut inherent: Option<usize> = None;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if !is_devanagari(c) {
+            if let (Some(at), true) = (inherent, drops_final_schwa(language) && syllables > 1) {
+                result.remove(at);
+            }
+            syllables = 0;
+            inherent = None;
+            result.push(c);
+            i += 1;
+            continue;
+        }
+
+        let nukta = chars.get(i + 1) == Some(&'\u{093C}');
+        let letter: String = if nukta { [c, '\u{093C}'].iter().collect() } else { c.to_string() };
+        if let Some((_, iast, itrans)) = DEVANAGARI_CONSONANTS.iter().find(|(consonant, _, _)| *consonant == letter) {
+            i += if nukta { 2 } else { 1 };
+            result.push_str(pick(iast, itrans));
+            syllables += 1;
+            inherent = None;
+            match chars.get(i) {
+                Some(&VIRAMA) => i += 1,
+                Some(sign) if DEVANAGARI_VOWELS.iter().any(|(_, matra, _, _)| *matra == Some(*sign)) => {}
+                _ => {
+                    // A vowel after a conjunct is heard ("mitra"), keep it
+                    let conjunct = i.checked_sub(2 + usize::from(nukta)).is_some_and(|before| chars[before] == VIRAMA);
+                    if !conjunct {
+                        inherent = Some(result.len());
+                    }
+                    result.push('a');
+                }
+            }
+            continue;
+        }
+
+        inherent = None;
+        if let Some((_, _, iast, itrans)) = DEVANAGARI_VOWELS.iter().find(|(independent, matra, _, _)| *independent == c || *matra == Some(c)) {
+            if DEVANAGARI_VOWELS.iter().any(|(independent, _, _, _)| *independent == c) {
+                syllables += 1;
+            }
+            result.push_str(pick(iast, itrans));
+        } else if let Some((_, iast, itrans)) = DEVANAGARI_SIGNS.iter().find(|(sign, _, _)| *sign == c) {
+            result.push_str(pick(iast, itrans));
+        } else if let '०'..='९' = c {
+            result.push(char::from(b'0' + (c as u32 - 0x0966) as u8));
+        } else if c != '\u{093C}' && c != VIRAMA {
+            result.push(c);
+        }
+        i += 1;
+    }
+    if let (Some(at), true) = (inherent, drops_final_schwa(language) && syllables > 1) {
+        result.remove(at);
+    }
+    result
+}
+
+/// A unit of romanized text read back into Devanagari
+enum IndicUnit {
+    Consonant(&'static str),
+    Vowel(char, Option<char>),
+    Sign(char),
+}
+
+/// Write romanized text in Devanagari
+fn latin_to_devanagari(text: &str, language: &str, scheme: IndicScheme) -> String {
+    let text = match scheme {
+        IndicScheme::Iast => normalize_form(&text.to_lowercase(), Form::Nfc),
+        IndicScheme::Itrans => text.to_string(),
+    };
+    let mut units: Vec<(&str, IndicUnit)> = Vec::new();
+    for (consonant, iast, itrans) in DEVANAGARI_CONSONANTS {
+        units.push((if scheme == IndicScheme::Iast { iast } else { itrans }, IndicUnit::Consonant(consonant)));
+    }
+    for (independent, matra, iast, itrans) in DEVANAGARI_VOWELS {
+        units.push((if scheme == IndicScheme::Iast { iast } else { itrans }, IndicUnit::Vowel(*independent, *matra)));
+    }
+    for (sign, iast, itrans) in DEVANAGARI_SIGNS.iter().filter(|(sign, _, _)| !matches!(sign, '।' | '॥' | 'ऽ') && (*sign != 'ॐ' || scheme == IndicScheme::Itrans)) {
+        units.push((if scheme == IndicScheme::Iast { iast } else { itrans }, IndicUnit::Sign(*sign)));
+    }
+    // Long vowels as typed on an ASCII keyboard ("naam")
+    units.extend([("aa", IndicUnit::Vowel('आ', Some('ा'))), ("ii", IndicUnit::Vowel('ई', Some('ी'))), ("uu", IndicUnit::Vowel('ऊ', Some('ू')))]);
+    if scheme == IndicScheme::Iast {
+        units.push(("sh", IndicUnit::Consonant("श")));
+    } else {
+        units.extend([("|", IndicUnit::Sign('।')), ("||", IndicUnit::Sign('॥')), (".a", IndicUnit::Sign('ऽ'))]);
+    }
+
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut after_consonant = false;
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        let best = units.iter().filter(|(latin, _)| rest.starts_with(*latin)).max_by_key(|(latin, _)| latin.len());
+        match best {
+            Some((latin, unit)) => {
+                match unit {
+                    IndicUnit::Consonant(consonant) => {
+                        if after_consonant {
+                            result.push(VIRAMA);
+                        }
+                        result.push_str(consonant);
+                    }
+                    IndicUnit::Vowel(independent, matra) => match (after_consonant, matra) {
+                        (true, Some(matra)) => result.push(*matra),
+                        (true, None) => {}
+                        (false, _) => result.push(*independent),
+                    },
+                    IndicUnit::Sign(sign) => result.push(*sign),
+                }
+                after_consonant = matches!(unit, IndicUnit::Consonant(_));
+                i += latin.len();
+            }
+            None => {
+                if after_consonant && !drops_final_schwa(language) {
+                    result.push(VIRAMA);
+                }
+                after_consonant = false;
+                let c = rest.chars().next().unwrap_or_default();
+                result.push(match c.to_digit(10) {
+                    Some(digit) if c.is_ascii_digit() && language == "sa" => char::from_u32(0x0966 + digit).unwrap_or(c),
+                    _ => c,
+                });
+                i += c.len_utf8();
+            }
+        }
+    }
+    if after_consonant && !drops_final_schwa(language) {
+        result.push(VIRAMA);
+    }
+    result
+}
+
+/// RTGS spelling of Thai consonants at the start and at the end of a syllable
+/// "อ" only carries vowels; "ย" and "ว" end a syllable as the vowels "i"
+/// and "o".
+const THAI_CONSONANTS: &[(char, &str, &str)] = &[
+    ('ก', "k", "k"), ('ข', "kh", "k"), ('ฃ', "kh", "k"), ('ค', "kh", "k"), ('ฅ', "kh", "k"), ('ฆ', "kh", "k"),
+    ('ง', "ng", "ng"), ('จ', "ch", "t"), ('ฉ', "ch", "t"), ('ช', "ch", "t"), ('ซ', "s", "t"), ('ฌ', "ch", "t"),
+    ('ญ', "y", "n"), ('ฎ', "d", "t"), ('ฏ', "t", "t"), ('ฐ', "th", "t"), ('ฑ', "th", "t"), ('ฒ', "th", "t"),
+    ('ณ', "n", "n"), ('ด', "d", "t"), ('ต', "t", "t"), ('ถ', "th", "t"), ('ท', "th", "t"), ('ธ', "th", "t"),
+    ('น', "n", "n"), ('บ', "b", "p"), ('ป', "p", "p"), ('ผ', "ph", "p"), ('ฝ', "f", "p"), ('พ', "ph", "p"),
+    ('ฟ', "f", "p"), ('ภ', "ph", "p"), ('ม', "m", "m"), ('ย', "y", "i"), ('ร', "r", "n"), ('ล', "l", "n"),
+    ('ว', "w", "o"), ('ศ', "s", "t"), ('ษ', "s", "t"), ('ส', "s", "t"), ('ห', "h", ""), ('ฬ', "l", "n"),
+    ('อ', "", ""), ('ฮ', "h", ""),
+];
+
+/// Initial clusters and their RTGS spelling; "r" is silent after "ท",
+/// "ส", "ศ", "ซ" and "จ"
+const THAI_CLUSTERS: &[(&str, &str)] = &[
+    ("กร", "kr"), ("กล", "kl"), ("กว", "kw"), ("ขร", "khr"), ("ขล", "khl"), ("ขว", "khw"), ("คร", "khr"),
+    ("คล", "khl"), ("คว", "khw"), ("ตร", "tr"), ("ปร", "pr"), ("ปล", "pl"), ("พร", "phr"), ("พล", "phl"),
+    ("ผล", "phl"), ("ทร", "s"), ("สร", "s"), ("ศร", "s"), ("ซร", "s"), ("จร", "ch"),
+];
+
+/// Vowel signs written after or over the consonant they follow
+fn is_thai_vowel_sign(c: char) -> bool {
+    matches!(c, 'ะ' | 'ั' | 'า' | 'ำ' | 'ิ' | 'ี' | 'ึ' | 'ื' | 'ุ' | 'ู' | '็')
+}
+
+/// Vowels written before the consonant they follow
+fn is_thai_leading_vowel(c: char) -> bool {
+    matches!(c, 'เ' | 'แ' | 'โ' | 'ใ' | 'ไ')
+}
+
+fn thai_consonant(c: char) -> Option<(&'static str, &'static str)> {
+    THAI_CONSONANTS.iter().find(|(consonant, _, _)| *consonant == c).map(|(_, initial, last)| (*initial, *last))
+}
+
+/// The initial consonant or cluster at chars[i], with the characters it takes
+/// `leading` is set after a leading vowel, where a cluster needs no vowel
+/// sign after it to be recognized ("เพลง").
+fn thai_initial(chars: &[char], i: usize, leading: bool) -> Option<(&'static str, usize)> {
+    let (initial, _) = thai_consonant(chars[i])?;
+    let Some(&second) = chars.get(i + 1) else {
+        return Some((initial, 1));
+    };
+    let voiced = chars.get(i + 2).is_some_and(|c| is_thai_vowel_sign(*c) || *c == 'อ');
+    // A silent "ห" before a sonorant, and "อย"
+    let silent_first = (chars[i] == 'ห' && "งญนมยรลว".contains(second)) || (chars[i] == 'อ' && second == 'ย');
+    if silent_first {
+        return thai_consonant(second).map(|(initial, _)| (initial, 2));
+    }
+    let pair: String = [chars[i], second].iter().collect();
+    if let Some((_, cluster)) = THAI_CLUSTERS.iter().find(|(cluster, _)| *cluster == pair) {
+        if voiced || leading {
+            return Some((cluster, 2));
+        }
+    }
+    Some((initial, 1))
+}
+
+/// Romanize one Thai word
+fn thai_word_to_latin(word: &str) -> String {
+    // Tone marks are not written; "์" silences the consonant under it
+    let mut chars: Vec<char> = Vec::new();
+    for c in word.chars() {
+        match c {
+            '\u{0E48}'..='\u{0E4B}' => {}
+            '์' => {
+                while chars.last().is_some_and(|c| is_thai_vowel_sign(*c)) {
+                    chars.pop();
+                }
+                chars.pop();
+            }
+            _ => chars.push(c),
+        }
+    }
+
+    let at = |i: usize| chars.get(i).copied();
+    let mut result = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let (vowel, takes_final) = if is_thai_leading_vowel(c) {
+            let Some((initial, length)) = chars.get(i + 1).and_then(|_| thai_initial(&chars, i + 1, true)) else {
+                i += 1;
+                continue;
+            };
+            result.push_str(initial);
+            i += 1 + length;
+            let (vowel, length, takes_final) = match (c, at(i), at(i + 1)) {
+                ('เ', Some('ี'), Some('ย')) => ("ia", 2, true),
+                ('เ', Some('ื'), Some('อ')) => ("uea", 2, true),
+                ('เ', Some('า'), Some('ะ')) => ("o", 2, false),
+                ('เ', Some('า'), _) => ("ao", 1, false),
+                ('เ', Some('อ'), Some('ะ')) => ("oe", 2, false),
+                ('เ', Some('อ'), _) | ('เ', Some('ิ'), _) => ("oe", 1, true),
+                ('เ', Some('ย'), _) => ("oei", 1, false),
+                ('เ', Some('ะ'), _) | ('แ', Some('ะ'), _) | ('โ', Some('ะ'), _) => (if c == 'แ' { "ae" } else if c == 'โ' { "o" } else { "e" }, 1, false),
+                ('เ', Some('็'), _) => ("e", 1, true),
+                ('แ', Some('็'), _) => ("ae", 1, true),
+                ('เ', _, _) => ("e", 0, true),
+                ('แ', _, _) => ("ae", 0, true),
+                ('โ', _, _) => ("o", 0, true),
+                _ => {
+                    // "ไทย": a "ย" after "ไ" is not read
+                    let silent = at(i) == Some('ย') && !at(i + 1).is_some_and(is_thai_vowel_sign);
+                    ("ai", usize::from(silent), false)
+                }
+            };
+            i += length;
+            (vowel, takes_final)
+        } else if let Some((initial, length)) = thai_initial(&chars, i, false) {
+            result.push_str(initial);
+            i += length;
+            let next_is_final = |j: usize| at(j).is_some_and(|c| thai_consonant(c).is_some()) && !at(j + 1).is_some_and(is_thai_vowel_sign);
+            match (at(i), at(i + 1)) {
+                (Some('ั'), Some('ว')) => {
+                    i += 2;
+                    ("ua", true)
+                }
+                (Some('ื'), Some('อ')) => {
+                    i += 2;
+                    ("ue", true)
+                }
+                (Some('็'), Some('อ')) => {
+                    i += 2;
+                    ("o", true)
+                }
+                (Some('ร'), Some('ร')) => {
+                    i += 2;
+                    if next_is_final(i) { ("a", true) } else { ("an", false) }
+                }
+                (Some(sign), _) if is_thai_vowel_sign(sign) => {
+                    i += 1;
+                    match sign {
+                        'ะ' => ("a", false),
+                        'ำ' => ("am", false),
+                        'ั' | 'า' => ("a", true),
+                        'ิ' | 'ี' => ("i", true),
+                        'ึ' | 'ื' => ("ue", true),
+                        'ุ' | 'ู' => ("u", true),
+                        _ => ("o", true),
+                    }
+                }
+                (Some('อ'), _) if !at(i + 1).is_some_and(is_thai_vowel_sign) => {
+                    i += 1;
+                    ("o", true)
+                }
+                (Some('ว'), _) if next_is_final(i + 1) => {
+                    i += 1;
+                    ("ua", true)
+                }
+                (Some(next), _) if thai_consonant(next).is_some() => {
+                    // No vowel written: "a" before a syllable of its own
+                    // ("สบาย", "ถนน"), otherwise "o" closed by the next consonant
+                    let own_syllable = at(i + 1).is_some_and(|c| is_thai_vowel_sign(c) || is_thai_leading_vowel(c) || c == 'ร')
+                        || (at(i + 1).is_some_and(|c| thai_consonant(c).is_some()) && i + 2 == chars.len());
+                    if own_syllable { ("a", false) } else { ("o", true) }
+                }
+                _ => ("a", false),
+            }
+        } else {
+            i += 1;
+            match c {
+                'ฤ' => result.push_str("rue"),
+                'ฦ' => result.push_str("lue"),
+                '๐'..='๙' => result.push(char::from(b'0' + (c as u32 - 0x0E50) as u8)),
+                'ฯ' => result.push('.'),
+                _ => result.push(c),
+            }
+            continue;
+        };
+
+        result.push_str(vowel);
+        let starts_syllable = at(i + 1).is_some_and(|c| is_thai_vowel_sign(c) || c == 'อ') || (at(i + 1) == Some('ร') && at(i + 2) == Some('ร'));
+        if takes_final && at(i).is_some_and(|c| thai_consonant(c).is_some()) && !starts_syllable {
+            if let Some((_, last)) = thai_consonant(chars[i]) {
+                result.push_str(last);
+            }
+            i += 1;
+        }
+    }
+    result
+}
+
+fn is_thai(c: char) -> bool {
+    matches!(c, '\u{0E01}'..='\u{0E5B}')
+}
+
+/// Romanize Thai text with the Royal Thai General System
+/// Runs of Thai are split into words first, and the words written apart.
+fn thai_to_latin(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(is_thai) {
+        result.push_str(&rest[..start]);
+        let run = &rest[start..];
+        let end = run.find(|c: char| !is_thai(c)).unwrap_or(run.len());
+        // A consonant left over by the dictionary starts the word after it
+        // ("ห" + "มา"), or closes the last one
+        let mut words: Vec<(usize, usize)> = Vec::new();
+        let mut pending: Option<usize> = None;
+        for word in wordbreak::segment(&run[..end], "th") {
+            if word.text.chars().count() == 1 && word.text != "ๆ" {
+                pending = pending.or(Some(word.start));
+                continue;
+            }
+            words.push((pending.take().unwrap_or(word.start), word.end));
+        }
+        if let Some(start) = pending {
+            match words.last_mut() {
+                Some(last) => last.1 = end,
+                None => words.push((start, end)),
+            }
+        }
+        let mut previous = String::new();
+        for (index, word) in words.iter().map(|(start, end)| &run[*start..*end]).enumerate() {
+            if index > 0 {
+                result.push(' ');
+            }
+            // "ๆ" repeats the word before it
+            let latin = if word == "ๆ" { previous.clone() } else { thai_word_to_latin(word.trim_end_matches('ๆ')) };
+            result.push_str(&latin);
+            if word.ends_with('ๆ') && word != "ๆ" {
+                result.push(' ');
+                result.push_str(&latin);
+            }
+            previous = latin;
+        }
+        rest = &run[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Cyrillic letters shared by the national Latin tables, as BGN/PCGN writes them
+const CYRILLIC_COMMON: &[(char, &str)] = &[
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "g"), ('д', "d"), ('е', "e"), ('ж', "zh"), ('з', "z"), ('и', "i"),
+    ('й', "y"), ('к', "k"), ('л', "l"), ('м', "m"), ('н', "n"), ('о', "o"), ('п', "p"), ('р', "r"), ('с', "s"),
+    ('т', "t"), ('у', "u"), ('ф', "f"), ('х', "kh"), ('ц', "ts"), ('ч', "ch"), ('ш', "sh"), ('щ', "shch"),
+    ('ъ', "\""), ('ы', "y"), ('ь', "'"), ('э', "e"), ('ю', "yu"), ('я', "ya"), ('ё', "yo"),
+];
+
+/// Letters each language writes differently from `CYRILLIC_COMMON`
+/// Ukrainian follows the 2010 national system, Bulgarian the 2009
+/// Streamlined System, Serbian and Macedonian their own Latin alphabets.
+const CYRILLIC_LANGUAGES: &[(&str, &[(char, &str)])] = &[
+    ("ru", &[]),
+    ("uk", &[('г', "h"), ('ґ', "g"), ('и', "y"), ('і', "i"), ('є', "ie"), ('ї', "i"), ('й', "i"), ('ю', "iu"), ('я', "ia"), ('ь', ""), ('\'', ""), ('’', "")]),
+    ("be", &[('г', "h"), ('і', "i"), ('ў', "w"), ('\'', ""), ('’', "")]),
+    ("bg", &[('х', "h"), ('щ', "sht"), ('ъ', "a"), ('ь', "y")]),
+    ("sr", &[('ђ', "đ"), ('ж', "ž"), ('ј', "j"), ('љ', "lj"), ('њ', "nj"), ('ћ', "ć"), ('х', "h"), ('ц', "c"), ('ч', "č"), ('џ', "dž"), ('ш', "š")]),
+    ("mk", &[('ѓ', "ǵ"), ('ж', "ž"), ('ѕ', "dz"), ('ј', "j"), ('љ', "lj"), ('њ', "nj"), ('ќ', "ḱ"), ('х', "h"), ('ц', "c"), ('ч', "č"), ('џ', "dž"), ('ш', "š")]),
+];
+
+/// GOST 7.79-2000 system B, the ASCII-only Russian standard, with the
+/// letters of the other languages it covers
+const CYRILLIC_GOST: &[(char, &str)] = &[
+    ('а', "a"), ('б', "b"), ('в', "v"), ('г', "g"), ('ґ', "g"), ('д', "d"), ('ђ', "d`"), ('ѓ', "g`"), ('е', "e"), ('є', "ye"),
+    ('ё', "yo"), ('ж', "zh"), ('з', "z"), ('ѕ', "z`"), ('и', "i"), ('і', "i"), ('ї', "yi"), ('й', "j"), ('ј', "j"),
+    ('к', "k"), ('ќ', "k`"), ('л', "l"), ('љ', "l`"), ('м', "m"), ('н', "n"), ('њ', "n`"), ('о', "o"), ('п', "p"),
+    ('р', "r"), ('с', "s"), ('т', "t"), ('ћ', "c`"), ('у', "u"), ('ў', "u`"), ('ф', "f"), ('х', "x"), ('ц', "cz"), ('ч', "ch"),
+    ('џ', "dh"), ('ш', "sh"), ('щ', "shh"), ('ъ', "``"), ('ы', "y`"), ('ь', "`"), ('э', "e`"), ('ю', "yu"), ('я', "ya"),
+    ('\'', "'"), ('’', "'"),
+];
+
+fn is_cyrillic(c: char) -> bool {
+    matches!(c, '\u{0400}'..='\u{04FF}')
+}
+
+/// Latin spelling of one lowercase Cyrillic letter in its context
+fn cyrillic_letter(c: char, previous: Option<char>, next: Option<char>, gost: bool, language: &str) -> Option<&'static str> {
+    let word_start = !previous.is_some_and(|p| p.is_alphabetic() || p == '\'' || p == '’');
+    let after_vowel = previous.is_some_and(|p| "аеёиіїоуыэюяєъь'’".contains(p));
+    if gost {
+        // "c" before the front vowels and "й", "cz" elsewhere
+        if c == 'ц' {
+            return Some(if next.is_some_and(|n| "еёиіыйэюяє".contains(n)) { "c" } else { "cz" });
+        }
+        if language == "uk" {
+            match c {
+                'г' => return Some("g`"),
+                'и' => return Some("y`"),
+                _ => {}
+            }
+        }
+        if language == "bg" && c == 'ъ' {
+            return Some("a`");
+        }
+        return CYRILLIC_GOST.iter().find(|(letter, _)| *letter == c).map(|(_, latin)| *latin);
+    }
+
+    match (language, c) {
+        ("ru" | "be", 'е') if word_start || after_vowel => return Some("ye"),
+        ("uk", 'є') if word_start => return Some("ye"),
+        ("uk", 'ї') if word_start => return Some("yi"),
+        ("uk", 'й') if word_start => return Some("y"),
+        ("uk", 'ю') if word_start => return Some("yu"),
+        ("uk", 'я') if word_start => return Some("ya"),
+        // "зг" is "zgh", keeping it apart from "ж"
+        ("uk", 'г') if previous == Some('з') => return Some("gh"),
+        // A final "ия" is "ia" ("България" → "Balgaria")
+        ("bg", 'я') if previous == Some('и') && !next.is_some_and(char::is_alphabetic) => return Some("a"),
+        _ => {}
+    }
+    let table = CYRILLIC_LANGUAGES.iter().find(|(code, _)| *code == language).map_or(&[][..], |(_, table)| *table);
+    table
+        .iter()
+        .chain(CYRILLIC_COMMON)
+        .find(|(letter, _)| *letter == c)
+        .map(|(_, latin)| *latin)
+}
+
+/// Romanize Cyrillic text with a language's table, or GOST 7.79 system B
+fn cyrillic_to_latin(text: &str, language: &str, gost: bool) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let lower = |i: usize| chars.get(i).map(|c| c.to_lowercase().next().unwrap_or(*c));
+    let mut result = String::with_capacity(text.len());
+    for (i, c) in chars.iter().enumerate() {
+        let latin = if is_cyrillic(*c) || "'’".contains(*c) {
+            cyrillic_letter(lower(i).unwrap_or(*c), i.checked_sub(1).and_then(lower), lower(i + 1), gost, language)
+        } else {
+            None
+        };
+        let Some(latin) = latin else {
+            result.push(*c);
+            continue;
+        };
+        if !c.is_uppercase() {
+            result.push_str(latin);
+            continue;
+        }
+        // "Ж" is "Zh" in "Жанна" but "ZH" in "ЖКХ"
+        let capitals = [i.checked_sub(1), Some(i + 1)].into_iter().flatten().any(|j| chars.get(j).is_some_and(|c| c.is_uppercase()));
+        if capitals {
+            result.push_str(&latin.to_uppercase());
+        } else {
+            let mut letters = latin.chars();
+            if let Some(first) = letters.next() {
+                result.extend(first.to_uppercase());
+                result.push_str(letters.as_str());
+            }
+        }
+    }
+    result
+}
+
+/// Modified Hepburn spelling of each hiragana; katakana are looked up as
+/// the hiragana they match
+const KANA: &[(char, &str)] = &[
+    ('あ', "a"), ('い', "i"), ('う', "u"), ('え', "e"), ('お', "o"), ('か', "ka"), ('き', "ki"), ('く', "ku"),
+    ('け', "ke"), ('こ', "ko"), ('が', "ga"), ('ぎ', "gi"), ('ぐ', "gu"), ('げ', "ge"), ('ご', "go"), ('さ', "sa"),
+    ('し', "shi"), ('す', "su"), ('せ', "se"), ('そ', "so"), ('ざ', "za"), ('じ', "ji"), ('ず', "zu"), ('ぜ', "ze"),
+    ('ぞ', "zo"), ('た', "ta"), ('ち', "chi"), ('つ', "tsu"), ('て', "te"), ('と', "to"), ('だ', "da"), ('ぢ', "ji"),
+    ('づ', "zu"), ('で', "de"), ('ど', "do"), ('な', "na"), ('に', "ni"), ('ぬ', "nu"), ('ね', "ne"), ('の', "no"),
+    ('は', "ha"), ('ひ', "hi"), ('ふ', "fu"), ('へ', "he"), ('ほ', "ho"), ('ば', "ba"), ('び', "bi"), ('ぶ', "bu"),
+    ('べ', "be"), ('ぼ', "bo"), ('ぱ', "pa"), ('ぴ', "pi"), ('ぷ', "pu"), ('ぺ', "pe"), ('ぽ', "po"), ('ま', "ma"),
+    ('み', "mi"), ('む', "mu"), ('め', "me"), ('も', "mo"), ('や', "ya"), ('ゆ', "yu"), ('よ', "yo"), ('ら', "ra"),
+    ('り', "ri"), ('る', "ru"), ('れ', "re"), ('ろ', "ro"), ('わ', "wa"), ('ゐ', "i"), ('ゑ', "e"), ('を', "o"),
+    ('ん', "n"), ('ゔ', "vu"), ('ゕ', "ka"), ('ゖ', "ke"),
+];
+
+/// Small kana that change the vowel of the kana before them
+const SMALL_KANA: &[(char, &str)] = &[
+    ('ゃ', "a"), ('ゅ', "u"), ('ょ', "o"), ('ぁ', "a"), ('ぃ', "i"), ('ぅ', "u"), ('ぇ', "e"), ('ぉ', "o"), ('ゎ', "a"),
+];
+
+fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{3096}' | '\u{30A1}'..='\u{30FA}' | 'ー')
+}
+
+fn with_macron(vowel: char) -> Option<char> {
+    Some(match vowel {
+        'a' => 'ā',
+        'i' => 'ī',
+        'u' => 'ū',
+        'e' => 'ē',
+        'o' => 'ō',
+        _ => return None,
+    })
+}
+
+/// Romanize hiragana and katakana with modified Hepburn
+/// Long "o" and "u" ("おう", "うう") and the katakana mark "ー" are written
+/// with a macron; "ん" is "n'" before a vowel or "y".
+fn kana_to_latin(text: &str) -> String {
+    // Katakana sit 0x60 code points after the matching hiragana
+    let hiragana = |c: char| match c {
+        '\u{30A1}'..='\u{30F6}' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        'ヷ' => 'わ',
+        'ヸ' => 'ゐ',
+        'ヹ' => 'ゑ',
+        'ヺ' => 'を',
+        _ => c,
+    };
+    let mut result = String::with_capacity(text.len());
+    // The syllable written last, while still inside a run of kana
+    let mut last = String::new();
+    let mut double = false;
+    for c in text.chars() {
+        if !is_kana(c) {
+            result.push(c);
+            last.clear();
+            double = false;
+            continue;
+        }
+        let c = hiragana(c);
+        let vowel = last.chars().last();
+        let long = match c {
+            'ー' => true,
+            'う' => matches!(vowel, Some('o' | 'u')),
+            'お' => vowel == Some('o'),
+            _ => false,
+        };
+        let small = SMALL_KANA.iter().find(|(small, _)| *small == c).filter(|_| !last.is_empty());
+        let replaced = if long {
+            vowel.and_then(with_macron).map(|macron| {
+                let mut syllable = last.clone();
+                syllable.pop();
+                syllable.push(macron);
+                syllable
+            })
+        } else if let Some((small, sound)) = small {
+            // "きゃ" is "kya", "しゃ" "sha", "ファ" "fa", "ティ" "ti", "ウィ" "wi"
+            let palatal = ["shi", "chi", "ji"].iter().any(|ending| last.ends_with(ending));
+            let stem = match (last.as_str(), small) {
+                (_, 'ゃ' | 'ゅ' | 'ょ' | 'ぇ') if palatal => last[..last.len() - 1].to_string(),
+                (_, 'ゃ' | 'ゅ' | 'ょ') if last.len() > 1 && last.ends_with('i') => format!("{}y", &last[..last.len() - 1]),
+                ("fu" | "vu" | "tsu", _) => last[..last.len() - 1].to_string(),
+                ("te" | "de", 'ぃ' | 'ぅ') => last[..1].to_string(),
+                ("u", 'ぃ' | 'ぇ' | 'ぉ') => "w".to_string(),
+                _ => last.clone(),
+            };
+            Some(stem + sound)
+        } else {
+            None
+        };
+        if let Some(syllable) = replaced {
+            result.truncate(result.len() - last.len());
+            result.push_str(&syllable);
+            last = syllable;
+            continue;
+        }
+        if c == 'っ' {
+            double = true;
+            continue;
+        }
+        let mut syllable = match SMALL_KANA.iter().chain(KANA).find(|(kana, _)| *kana == c) {
+            Some((_, latin)) => latin.to_string(),
+            None => continue,
+        };
+        if last == "n" && syllable.starts_with(['a', 'i', 'u', 'e', 'o', 'y']) {
+            result.push('\'');
+        }
+        if std::mem::take(&mut double) {
+            let consonant = if syllable.starts_with("ch") { Some('t') } else { syllable.chars().next().filter(|c| !"aiueon".contains(*c)) };
+            if let Some(consonant) = consonant {
+                syllable.insert(0, consonant);
+            }
+        }
+        result.push_str(&syllable);
+        last = syllable;
+    }
+    result
+}
+
+/// Kanji words and their kana readings, loaded with `load_kanji_readings`
+#[derive(Default)]
+struct KanjiReadings {
+    readings: HashMap<String, String>,
+    /// Characters in the longest word
+    longest: usize,
+}
+
+static KANJI_READINGS: LazyLock<RwLock<Arc<KanjiReadings>>> = LazyLock::new(|| RwLock::new(Arc::default()));
+
+fn is_kanji(c: char) -> bool {
+    script_of(c) == Script::Han || matches!(c, '々' | '〆' | 'ヶ')
+}
+
+/// Replace the kanji words of loaded readings with their kana, longest first
+fn kanji_to_kana(text: &str) -> String {
+    let Some(readings) = KANJI_READINGS.read().ok().map(|readings| Arc::clone(&readings)) else {
+        return text.to_string();
+    };
+    if readings.readings.is_empty() {
+        return text.to_string();
+    }
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut i = 0;
+    while i < chars.len() {
+        let (start, c) = chars[i];
+        let found = is_kanji(c)
+            .then(|| {
+                (1..=readings.longest.min(chars.len() - i)).rev().find_map(|length| {
+                    let end = chars.get(i + length).map_or(text.len(), |(end, _)| *end);
+                    readings.readings.get(&text[start..end]).map(|reading| (length, reading))
+                })
+            })
+            .flatten();
+        match found {
+            Some((length, reading)) => {
+                result.push_str(reading);
+                i += length;
+            }
+            None => {
+                result.push(c);
+                i += 1;
+            }
+        }
+    }
+    result
+}
+
+/// Romanize Japanese: kanji through the loaded readings, then kana with Hepburn
+fn japanese_to_latin(text: &str) -> String {
+    kana_to_latin(&kanji_to_kana(text))
+}
+
+/// Load readings of kanji words for romanizing Japanese
+/// Returns the number of readings read.
+///
+/// `readings` holds one word per line followed by its reading in hiragana
+/// or katakana, separated by a tab, comma or space ("東京\tとうきょう",
+/// "山田,やまだ"), so a name list or a column pair exported from a
+/// dictionary can be passed in whole; blank lines, lines starting with '#'
+/// and readings that are not kana are skipped. A word loaded again takes
+/// the new reading. Where several words match, the longest wins, so "日本"
+/// and "日本語" can both be listed. Readings are used by `transliterate`
+/// from "ja" and by `slugify` until `clear_kanji_readings` is called.
+///
+/// @param readings - Newline-separated word and reading pairs
+#[wasm_bindgen]
+pub fn load_kanji_readings(readings: &str) -> u32 {
+    let mut loaded = KANJI_READINGS.write().unwrap();
+    let mut updated = KanjiReadings { readings: loaded.readings.clone(), longest: loaded.longest };
+    let mut count = 0;
+    for line in readings.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        let Some((word, reading)) = line.split_once(['\t', ',', ' ']) else {
+            continue;
+        };
+        let (word, reading) = (word.trim(), reading.trim());
+        if word.is_empty() || reading.is_empty() || !reading.chars().all(is_kana) {
+            continue;
+        }
+        updated.longest = updated.longest.max(word.chars().count());
+        updated.readings.insert(word.to_string(), reading.to_string());
+        count += 1;
+    }
+    *loaded = Arc::new(updated);
+    count
+}
+
+/// Forget the kanji readings loaded with `load_kanji_readings`
+#[wasm_bindgen]
+pub fn clear_kanji_readings() {
+    if let Ok(mut loaded) = KANJI_READINGS.write() {
+        *loaded = Arc::default();
+    }
+}
+
+/// Revised Romanization of the 19 initial consonants, the 21 vowels, and
+/// the 27 final consonants of a Hangul syllable, in Unicode order
+const HANGUL_INITIALS: [&str; 19] = ["g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p", "h"];
+const HANGUL_VOWELS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we", "wi", "yu", "eu", "ui", "i",
+];
+
+/// Each final as it sounds before a consonant, and before a silent "ㅇ",
+/// where its last consonant moves on to start the next syllable
+/// ("한국어" → "hangugeo"): (final, kept, moved)
+const HANGUL_FINALS: [(&str, &str, &str); 28] = [
+    ("", "", ""), ("k", "", "g"), ("k", "", "kk"), ("k", "k", "s"), ("n", "", "n"), ("n", "n", "j"), ("n", "", "n"),
+    ("t", "", "d"), ("l", "", "r"), ("k", "l", "g"), ("m", "l", "m"), ("l", "l", "b"), ("l", "l", "s"), ("l", "l", "t"),
+    ("p", "l", "p"), ("l", "", "r"), ("m", "", "m"), ("p", "", "b"), ("p", "p", "s"), ("t", "", "s"), ("t", "", "ss"),
+    ("ng", "ng", ""), ("t", "", "j"), ("t", "", "ch"), ("k", "", "k"), ("t", "", "t"), ("p", "", "p"), ("t", "", ""),
+];
+
+/// Romanize Hangul with the Revised Romanization of Korean
+/// Sound changes between the syllables of a word are spelled as spoken:
+/// "ㄱ", "ㄷ", "ㅂ" become nasals before "ㄴ" and "ㅁ" ("감사합니다" →
+/// "gamsahamnida"), "ㄴ" and "ㄹ" meet as "ll" ("신라" → "silla"), and
+/// "ㅎ" makes the next consonant aspirated ("좋다" → "jota").
+fn hangul_to_latin(text: &str) -> String {
+    let syllable = |c: char| (c as u32).checked_sub(0xAC00).filter(|index| *index < 11172).map(|index| index as usize);
+    let chars: Vec<char> = text.chars().collect();
+    let mut result = String::with_capacity(text.len() * 2);
+    // Initial of the next syllable when the final before changed it
+    let mut next_initial: Option<&str> = None;
+    for (i, c) in chars.iter().enumerate() {
+        let Some(index) = syllable(*c) else {
+            // Jamo written on their own ("ㅋㅋ"), as NFKC leaves them
+            match *c as u32 {
+                jamo @ 0x1100..=0x1112 => result.push_str(HANGUL_INITIALS[(jamo - 0x1100) as usize]),
+                jamo @ 0x1161..=0x1175 => result.push_str(HANGUL_VOWELS[(jamo - 0x1161) as usize]),
+                jamo @ 0x11A8..=0x11C2 => result.push_str(HANGUL_FINALS[(jamo - 0x11A7) as usize].0),
+                _ => result.push(*c),
+            }
+            next_initial = None;
+            continue;
+        };
+        let (initial, vowel, last) = (index / 588, index % 588 / 28, index % 28);
+        result.push_str(next_initial.take().unwrap_or(HANGUL_INITIALS[initial]));
+        result.push_str(HANGUL_VOWELS[vowel]);
+        let (sound, kept, moved) = HANGUL_FINALS[last];
+        let Some(next) = chars.get(i + 1).and_then(|c| syllable(*c)).map(|index| index / 588) else {
+            result.push_str(sound);
+            continue;
+        };
+        // Finals ending in "ㅎ": ㄶ, ㅀ, ㅎ
+        let aspirated = matches!(last, 6 | 15 | 27);
+        let (sound, initial) = match (sound, next) {
+            _ if last == 0 => ("", None),
+            (_, 11) => (kept, Some(moved)),
+            _ if aspirated && matches!(next, 0 | 3 | 12) => {
+                let sound = if last == 27 { "" } else { sound };
+                (sound, Some(match next { 0 => "k", 3 => "t", _ => "ch" }))
+            }
+            ("k", 2 | 6) => ("ng", None),
+            ("t", 2 | 6) => ("n", None),
+            ("p", 2 | 6) => ("m", None),
+            ("k", 5) => ("ng", Some("n")),
+            ("t", 5) => ("n", Some("n")),
+            ("p", 5) => ("m", Some("n")),
+            ("n" | "l", 5) | ("l", 2) => ("l", Some("l")),
+            ("ng" | "m", 5) => (sound, Some("n")),
+            _ => (sound, None),
+        };
+        result.push_str(sound);
+        next_initial = initial;
+    }
+    result
+}
+
+/// Convert text from one script to another
+/// Supported conversions:
+/// - Devanagari ("hi", "mr", "ne", "sa") to "latn" (IAST: "नमस्ते दुनिया" →
+///   "namaste duniyā") or "itrans" (ASCII: "namaste duniyA"), and back
+///   from "latn"/"iast" or "itrans" to Devanagari
+/// - Thai ("th") to "latn" or "rtgs", the Royal Thai General System used
+///   on road signs: "สวัสดีครับ" → "sawatdi khrap". Words are found with
+///   the `segment_words` dictionary and written apart; tones and vowel
+///   length are not marked, as in RTGS
+/// - Cyrillic to "latn" with each language's own table: Russian ("ru") and
+///   Belarusian ("be") as BGN/PCGN ("Щукин" → "Shchukin"), Ukrainian
+///   ("uk") as its 2010 national system ("Київ" → "Kyiv"), Bulgarian
+///   ("bg") as the Streamlined System ("щастие" → "shtastie"), Serbian
+///   ("sr") and Macedonian ("mk") to their Latin alphabets ("Ђоковић" →
+///   "Đoković"); or to "gost", the ASCII-only GOST 7.79 system B
+///   ("Щукин" → "Shhukin")
+/// - Japanese ("ja") to "latn" or "hepburn", modified Hepburn with macrons
+///   for long vowels: "トウキョウ" and "とうきょう" → "tōkyō", "コーヒー" →
+///   "kōhī", "きって" → "kitte". Kanji are read through the words loaded
+///   with `load_kanji_readings` and kept as they are otherwise; `fold_diacritics`
+///   turns the result into what users type ("tokyo")
+///
+/// Hindi, Marathi and Nepali drop the silent inherent "a" at the end of a
+/// word ("कमल" → "kamal", not Sanskrit "kamala"), and read a romanized
+/// word ending in a consonant the same way; Sanskrit ("sa") keeps it and
+/// writes a virama. Romanized input may also spell long vowels and "ś" as
+/// typed on an ASCII keyboard ("naam", "shanti"). Text in other scripts is
+/// copied, and pairs of codes not listed return the text unchanged.
+///
+/// @param text - Text to convert
+/// @param from - Language of the text, or "latn", "iast", "itrans" for romanized text
+/// @param to - "latn", "iast", "itrans", "rtgs", "gost" or "hepburn", or the language to write romanized text in
+#[wasm_bindgen]
+pub fn transliterate(text: &str, from: &str, to: &str) -> String {
+    let devanagari = |language: &str| matches!(language, "hi" | "mr" | "ne" | "sa");
+    if let (true, Some(scheme)) = (devanagari(from), IndicScheme::from_name(to)) {
+        return devanagari_to_latin(text, from, scheme);
+    }
+    if let (Some(scheme), true) = (IndicScheme::from_name(from), devanagari(to)) {
+        return latin_to_devanagari(text, to, scheme);
+    }
+    if from == "th" && matches!(to, "latn" | "rtgs") {
+        return thai_to_latin(text);
+    }
+    if CYRILLIC_LANGUAGES.iter().any(|(code, _)| *code == from) && matches!(to, "latn" | "gost") {
+        return cyrillic_to_latin(text, from, to == "gost");
+    }
+    if from == "ja" && matches!(to, "latn" | "hepburn") {
+        return japanese_to_latin(text);
+    }
+    text.to_string()
+}
+
+/// Greek letters after accents are removed, ELOT 743 without its marks
+const GREEK_RULES: &[(&str, &str)] = &[
+    ("α", "a"), ("β", "v"), ("γ", "g"), ("δ", "d"), ("ε", "e"), ("ζ", "z"), ("η", "i"), ("θ", "th"), ("ι", "i"),
+    ("κ", "k"), ("λ", "l"), ("μ", "m"), ("ν", "n"), ("ξ", "x"), ("ο", "o"), ("π", "p"), ("ρ", "r"), ("σ", "s"),
+    ("ς", "s"), ("τ", "t"), ("υ", "y"), ("φ", "f"), ("χ", "ch"), ("ψ", "ps"), ("ω", "o"), ("ου", "ou"),
+    ("αυ", "av"), ("ευ", "ev"), ("γγ", "ng"), ("γκ", "gk"), ("^μπ", "b"),
+];
+
+/// Arabic, Persian and Urdu consonants and long vowels; short vowels are
+/// rarely written, so words keep only the letters they are spelled with
+const ARABIC_RULES: &[(&str, &str)] = &[
+    ("ا", "a"), ("أ", "a"), ("إ", "i"), ("آ", "a"), ("ب", "b"), ("پ", "p"), ("ت", "t"), ("ث", "th"), ("ج", "j"),
+    ("چ", "ch"), ("ح", "h"), ("خ", "kh"), ("د", "d"), ("ذ", "dh"), ("ر", "r"), ("ز", "z"), ("ژ", "zh"), ("س", "s"),
+    ("ش", "sh"), ("ص", "s"), ("ض", "d"), ("ط", "t"), ("ظ", "z"), ("ع", ""), ("غ", "gh"), ("ف", "f"), ("ق", "q"),
+    ("ك", "k"), ("ک", "k"), ("گ", "g"), ("ل", "l"), ("م", "m"), ("ن", "n"), ("ه", "h"), ("ة", "a"), ("و", "w"),
+    ("ي", "y"), ("ی", "y"), ("ى", "a"), ("ء", ""), ("ؤ", ""), ("ئ", ""), ("ٹ", "t"), ("ڈ", "d"), ("ڑ", "r"),
+    ("ں", "n"), ("ے", "e"), ("ھ", "h"), ("٠", "0"), ("١", "1"), ("٢", "2"), ("٣", "3"), ("٤", "4"), ("٥", "5"),
+    ("٦", "6"), ("٧", "7"), ("٨", "8"), ("٩", "9"), ("۰", "0"), ("۱", "1"), ("۲", "2"), ("۳", "3"), ("۴", "4"),
+    ("۵", "5"), ("۶", "6"), ("۷", "7"), ("۸", "8"), ("۹", "9"),
+];
+
+/// Hebrew consonants; vowel points are removed before these apply
+const HEBREW_RULES: &[(&str, &str)] = &[
+    ("א", ""), ("ב", "b"), ("ג", "g"), ("ד", "d"), ("ה", "h"), ("ו", "v"), ("וו", "w"), ("ז", "z"), ("ח", "ch"),
+    ("ט", "t"), ("י", "y"), ("כ", "k"), ("ך", "kh"), ("ל", "l"), ("מ", "m"), ("ם", "m"), ("נ", "n"), ("ן", "n"),
+    ("ס", "s"), ("ע", ""), ("פ", "p"), ("ף", "f"), ("צ", "ts"), ("ץ", "ts"), ("ק", "k"), ("ר", "r"), ("ש", "sh"),
+    ("ת", "t"),
+];
+
+/// Latin letters with no base letter to fold to
+const LATIN_LETTERS: &[(&str, &str)] = &[
+    ("ß", "ss"), ("æ", "ae"), ("œ", "oe"), ("þ", "th"), ("ð", "d"), ("ı", "i"), ("ŋ", "ng"), ("ə", "e"), ("ĸ", "q"),
+    ("ſ", "s"), ("ɛ", "e"), ("ɔ", "o"),
+];
+
+/// Romanize one run of text written in a single script
+fn romanize_run(run: &str, script: Script) -> String {
+    let folded = || fold_diacritics(&run.to_lowercase());
+    match script {
+        Script::Devanagari => {
+            let language = match best_language(run).0 {
+                language @ ("mr" | "ne") => language,
+                _ => "hi",
+            };
+            devanagari_to_latin(run, language, IndicScheme::Iast)
+        }
+        Script::Thai => thai_to_latin(run),
+        Script::Cyrillic => {
+            let language = best_language(run).0;
+            let language = if CYRILLIC_LANGUAGES.iter().any(|(code, _)| *code == language) { language } else { "ru" };
+            cyrillic_to_latin(run, language, false)
+        }
+        Script::Hangul => hangul_to_latin(run),
+        Script::Hiragana | Script::Katakana => kana_to_latin(run),
+        // Kanji without a loaded reading get their Chinese one, which is
+        // still a stable, readable spelling
+        Script::Han => wordbreak::segment(run, "zh")
+            .iter()
+            .map(|word| word.text.chars().map(|c| pinyin(c).map_or_else(|| format!(" {:x} ", c as u32), str::to_string)).collect::<String>())
+            .collect::<Vec<_>>()
+            .join(" "),
+        Script::Greek => rewrite(&folded(), GREEK_RULES),
+        Script::Arabic => rewrite(&folded(), ARABIC_RULES),
+        Script::Hebrew => rewrite(&folded(), HEBREW_RULES),
+        _ => run.to_string(),
+    }
+}
+
+/// Turn text in any script into a lowercase ASCII slug
+pub fn slug(text: &str) -> String {
+    let text = normalize_form(text, Form::Nfkc);
+    // Loaded readings are Japanese
+    let text = kanji_to_kana(&text);
+    // Split into runs of one script; accents stay with the letter before
+    let mut runs: Vec<(Script, String)> = Vec::new();
+    for c in text.chars() {
+        let script = match script_of(c) {
+            Script::Common if is_diacritic(c) => runs.last().map_or(Script::Common, |(script, _)| *script),
+            script => script,
+        };
+        match runs.last_mut() {
+            Some((last, run)) if *last == script => run.push(c),
+            _ => runs.push((script, c.to_string())),
+        }
+    }
+    let mut latin = String::with_capacity(text.len());
+    let mut previous = Script::Common;
+    for (script, run) in &runs {
+        // "中文chat" is two words
+        if previous != Script::Common && *script != Script::Common {
+            latin.push(' ');
+        }
+        latin.push_str(&romanize_run(run, *script));
+        previous = *script;
+    }
+    let latin = rewrite(&fold_diacritics(&latin.to_lowercase()), LATIN_LETTERS);
+    let mut spelled = String::with_capacity(latin.len());
+    for c in latin.chars() {
+        match c {
+            'a'..='z' | '0'..='9' => spelled.push(c),
+            // "don't" → "dont", "o'clock" → "oclock"
+            '\'' | '’' | 'ʼ' | 'ʻ' | '`' => {}
+            // Letters no table covers are kept as their code point
+            _ if c.is_alphanumeric() => spelled.push_str(&format!(" {:x} ", c as u32)),
+            _ => spelled.push(' '),
+        }
+    }
+    spelled.split_whitespace().collect::<Vec<_>>().join("-")
+}
+
+/// Turn a channel or file name in any script into a URL-safe slug
+/// Returns lowercase ASCII letters and digits with single hyphens between
+/// words
+///
+/// Each script is romanized the way `transliterate` does: Devanagari with
+/// IAST ("नमस्ते दुनिया" → "namaste-duniya"), Thai with RTGS, Cyrillic
+/// with the table of the language it is detected as ("Київ" → "kyiv"),
+/// Korean with the Revised Romanization ("한국어" → "hangugeo"), kana with
+/// Hepburn ("こんにちは" → "konnichiha"), Chinese with toneless pinyin,
+/// one word per dictionary word ("你好世界" → "nihao-shijie"), and Greek,
+/// Arabic and Hebrew letter by letter. Accents are then removed
+/// ("Crème Brûlée" → "creme-brulee", "Straße" → "strasse"), apostrophes
+/// dropped and everything else becomes a separator.
+///
+/// Words loaded with `load_kanji_readings` are read in Japanese first;
+/// other kanji get their pinyin, also in Japanese text ("東京タワー" →
+/// "dongjing-tawa" without readings, "tokyo-tawa" with them). Only
+/// characters no table spells (rare hanzi, scripts without a table) are
+/// written as their hexadecimal code point, so different names never share
+/// a slug only because they could not be spelled.
+///
+/// Text with nothing to spell, only separators or symbols ("--__--",
+/// "!!!"), gives "": callers that need a slug for every name should fall
+/// back to one of their own, such as the channel id.
+///
+/// @param text - Name to turn into a slug
+#[wasm_bindgen]
+pub fn slugify(text: &str) -> String {
+    slug(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pronunciation_hint_of_blank_input_is_empty() {
+        for blank in ["", " ", "\n", "\t\t", "\u{A0}", "\u{202F}"] {
+            assert_eq!(pronunciation_hint(blank, "es"), "");
+            assert_eq!(pronunciation_hint(blank, "en"), "");
+        }
+    }
+
+    #[test]
+    fn spanish_stress_of_no_syllables() {
+        assert_eq!(spanish_stress(&[]), 0);
+        assert_eq!(pronunciation_hint("hola", "es"), "OH-lah");
+    }
+
+    #[test]
+    fn slug_reads_kanji() {
+        // One test, as readings are shared by every caller
+        clear_kanji_readings();
+        assert_eq!(slug("東京タワー"), "dongjing-tawa");
+        assert_eq!(slug("你好世界"), "nihao-shijie");
+        load_kanji_readings("東京\tとうきょう");
+        assert_eq!(slug("東京タワー"), "tokyo-tawa");
+        clear_kanji_readings();
+        assert_eq!(slug("\u{20000}"), "20000");
+    }
+
+    #[test]
+    fn slug_of_separators_is_empty() {
+        assert_eq!(slug("--__--"), "");
+        assert_eq!(slug("!!! ..."), "");
+        assert_eq!(slug(" Crème Brûlée! "), "creme-brulee");
+    }
+}
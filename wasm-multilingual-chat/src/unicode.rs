@@ -0,0 +1,1548 @@
+//! Unicode decomposition data and normalization forms
+//!
+//! **Learning Point**: Instead of depending on a normalization crate, the
+//! crate ships tables generated from the Unicode Character Database (version
+//! 14.0): every canonical decomposition in UnicodeData.txt, fully decomposed
+//! so one lookup gives a character's NFD, the canonical combining classes,
+//! the compatibility decompositions and the composition exclusions, along
+//! with the marks that extend a grapheme cluster. Tables are sorted by code
+//! point so lookups are a binary search.
+//!
+//! The four normalization forms are built from them. The pairs composition
+//! joins are derived from the decompositions on first use, and Hangul
+//! syllables are composed and decomposed arithmetically, as the standard
+//! specifies, rather than listed.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Canonical decompositions: precomposed character → base + combining marks
+/// Fully decomposed, so one lookup gives the NFD of a character.
+const DECOMPOSITIONS: &[(char, &str)] = &[
+    ('À', "A\u{0300}"), ('Á', "A\u{0301}"), ('Â', "A\u{0302}"), ('Ã', "A\u{0303}"),
+    ('Ä', "A\u{0308}"), ('Å', "A\u{030A}"), ('Ç', "C\u{0327}"), ('È', "E\u{0300}"),
+    ('É', "E\u{0301}"), ('Ê', "E\u{0302}"), ('Ë', "E\u{0308}"), ('Ì', "I\u{0300}"),
+    ('Í', "I\u{0301}"), ('Î', "I\u{0302}"), ('Ï', "I\u{0308}"), ('Ñ', "N\u{0303}"),
+    ('Ò', "O\u{0300}"), ('Ó', "O\u{0301}"), ('Ô', "O\u{0302}"), ('Õ', "O\u{0303}"),
+    ('Ö', "O\u{0308}"), ('Ù', "U\u{0300}"), ('Ú', "U\u{0301}"), ('Û', "U\u{0302}"),
+    ('Ü', "U\u{0308}"), ('Ý', "Y\u{0301}"), ('à', "a\u{0300}"), ('á', "a\u{0301}"),
+    ('â', "a\u{0302}"), ('ã', "a\u{0303}"), ('ä', "a\u{0308}"), ('å', "a\u{030A}"),
+    ('ç', "c\u{0327}"), ('è', "e\u{0300}"), ('é', "e\u{0301}"), ('ê', "e\u{0302}"),
+    ('ë', "e\u{0308}"), ('ì', "i\u{0300}"), ('í', "i\u{0301}"), ('î', "i\u{0302}"),
+    ('ï', "i\u{0308}"), ('ñ', "n\u{0303}"), ('ò', "o\u{0300}"), ('ó', "o\u{0301}"),
+    ('ô', "o\u{0302}"), ('õ', "o\u{0303}"), ('ö', "o\u{0308}"), ('ù', "u\u{0300}"),
+    ('ú', "u\u{0301}"), ('û', "u\u{0302}"), ('ü', "u\u{0308}"), ('ý', "y\u{0301}"),
+    ('ÿ', "y\u{0308}"), ('Ā', "A\u{0304}"), ('ā', "a\u{0304}"), ('Ă', "A\u{0306}"),
+    ('ă', "a\u{0306}"), ('Ą', "A\u{0328}"), ('ą', "a\u{0328}"), ('Ć', "C\u{0301}"),
+    ('ć', "c\u{0301}"), ('Ĉ', "C\u{0302}"), ('ĉ', "c\u{0302}"), ('Ċ', "C\u{0307}"),
+    ('ċ', "c\u{0307}"), ('Č', "C\u{030C}"), ('č', "c\u{030C}"), ('Ď', "D\u{030C}"),
+    ('ď', "d\u{030C}"), ('Ē', "E\u{0304}"), ('ē', "e\u{0304}"), ('Ĕ', "E\u{0306}"),
+    ('ĕ', "e\u{0306}"), ('Ė', "E\u{0307}"), ('ė', "e\u{0307}"), ('Ę', "E\u{0328}"),
+    ('ę', "e\u{0328}"), ('Ě', "E\u{030C}"), ('ě', "e\u{030C}"), ('Ĝ', "G\u{0302}"),
+    ('ĝ', "g\u{0302}"), ('Ğ', "G\u{0306}"), ('ğ', "g\u{0306}"), ('Ġ', "G\u{0307}"),
+    ('ġ', "g\u{0307}"), ('Ģ', "G\u{0327}"), ('ģ', "g\u{0327}"), ('Ĥ', "H\u{0302}"),
+    ('ĥ', "h\u{0302}"), ('Ĩ', "I\u{0303}"), ('ĩ', "i\u{0303}"), ('Ī', "I\u{0304}"),
+    ('ī', "i\u{0304}"), ('Ĭ', "I\u{0306}"), ('ĭ', "i\u{0306}"), ('Į', "I\u{0328}"),
+    ('į', "i\u{0328}"), ('İ', "I\u{0307}"), ('Ĵ', "J\u{0302}"), ('ĵ', "j\u{0302}"),
+    ('Ķ', "K\u{0327}"), ('ķ', "k\u{0327}"), ('Ĺ', "L\u{0301}"), ('ĺ', "l\u{0301}"),
+    ('Ļ', "L\u{0327}"), ('ļ', "l\u{0327}"), ('Ľ', "L\u{030C}"), ('ľ', "l\u{030C}"),
+    ('Ń', "N\u{0301}"), ('ń', "n\u{0301}"), ('Ņ', "N\u{0327}"), ('ņ', "n\u{0327}"),
+    ('Ň', "N\u{030C}"), ('ň', "n\u{030C}"), ('Ō', "O\u{0304}"), ('ō', "o\u{0304}"),
+    ('Ŏ', "O\u{0306}"), ('ŏ', "o\u{0306}"), ('Ő', "O\u{030B}"), ('ő', "o\u{030B}"),
+    ('Ŕ', "R\u{0301}"), ('ŕ', "r\u{0301}"), ('Ŗ', "R\u{0327}"), ('ŗ', "r\u{0327}"),
+    ('Ř', "R\u{030C}"), ('ř', "r\u{030C}"), ('Ś', "S\u{0301}"), ('ś', "s\u{0301}"),
+    ('Ŝ', "S\u{0302}"), ('ŝ', "s\u{0302}"), ('Ş', "S\u{0327}"), ('ş', "s\u{0327}"),
+    ('Š', "S\u{030C}"), ('š', "s\u{030C}"), ('Ţ', "T\u{0327}"), ('ţ', "t\u{0327}"),
+    ('Ť', "T\u{030C}"), ('ť', "t\u{030C}"), ('Ũ', "U\u{0303}"), ('ũ', "u\u{0303}"),
+    ('Ū', "U\u{0304}"), ('ū', "u\u{0304}"), ('Ŭ', "U\u{0306}"), ('ŭ', "u\u{0306}"),
+    ('Ů', "U\u{030A}"), ('ů', "u\u{030A}"), ('Ű', "U\u{030B}"), ('ű', "u\u{030B}"),
+    ('Ų', "U\u{0328}"), ('ų', "u\u{0328}"), ('Ŵ', "W\u{0302}"), ('ŵ', "w\u{0302}"),
+    ('Ŷ', "Y\u{0302}"), ('ŷ', "y\u{0302}"), ('Ÿ', "Y\u{0308}"), ('Ź', "Z\u{0301}"),
+    ('ź', "z\u{0301}"), ('Ż', "Z\u{0307}"), ('ż', "z\u{0307}"), ('Ž', "Z\u{030C}"),
+    ('ž', "z\u{030C}"), ('Ơ', "O\u{031B}"), ('ơ', "o\u{031B}"), ('Ư', "U\u{031B}"),
+    ('ư', "u\u{031B}"), ('Ǎ', "A\u{030C}"), ('ǎ', "a\u{030C}"), ('Ǐ', "I\u{030C}"),
+    ('ǐ', "i\u{030C}"), ('Ǒ', "O\u{030C}"), ('ǒ', "o\u{030C}"), ('Ǔ', "U\u{030C}"),
+    ('ǔ', "u\u{030C}"), ('Ǖ', "U\u{0308}\u{0304}"), ('ǖ', "u\u{0308}\u{0304}"),
+    ('Ǘ', "U\u{0308}\u{0301}"), ('ǘ', "u\u{0308}\u{0301}"), ('Ǚ', "U\u{0308}\u{030C}"),
+    ('ǚ', "u\u{0308}\u{030C}"), ('Ǜ', "U\u{0308}\u{0300}"), ('ǜ', "u\u{0308}\u{0300}"),
+    ('Ǟ', "A\u{0308}\u{0304}"), ('ǟ', "a\u{0308}\u{0304}"), ('Ǡ', "A\u{0307}\u{0304}"),
+    ('ǡ', "a\u{0307}\u{0304}"), ('Ǣ', "Æ\u{0304}"), ('ǣ', "æ\u{0304}"), ('Ǧ', "G\u{030C}"),
+    ('ǧ', "g\u{030C}"), ('Ǩ', "K\u{030C}"), ('ǩ', "k\u{030C}"), ('Ǫ', "O\u{0328}"),
+    ('ǫ', "o\u{0328}"), ('Ǭ', "O\u{0328}\u{0304}"), ('ǭ', "o\u{0328}\u{0304}"), ('Ǯ', "Ʒ\u{030C}"),
+    ('ǯ', "ʒ\u{030C}"), ('ǰ', "j\u{030C}"), ('Ǵ', "G\u{0301}"), ('ǵ', "g\u{0301}"),
+    ('Ǹ', "N\u{0300}"), ('ǹ', "n\u{0300}"), ('Ǻ', "A\u{030A}\u{0301}"), ('ǻ', "a\u{030A}\u{0301}"),
+    ('Ǽ', "Æ\u{0301}"), ('ǽ', "æ\u{0301}"), ('Ǿ', "Ø\u{0301}"), ('ǿ', "ø\u{0301}"),
+    ('Ȁ', "A\u{030F}"), ('ȁ', "a\u{030F}"), ('Ȃ', "A\u{0311}"), ('ȃ', "a\u{0311}"),
+    ('Ȅ', "E\u{030F}"), ('ȅ', "e\u{030F}"), ('Ȇ', "E\u{0311}"), ('ȇ', "e\u{0311}"),
+    ('Ȉ', "I\u{030F}"), ('ȉ', "i\u{030F}"), ('Ȋ', "I\u{0311}"), ('ȋ', "i\u{0311}"),
+    ('Ȍ', "O\u{030F}"), ('ȍ', "o\u{030F}"), ('Ȏ', "O\u{0311}"), ('ȏ', "o\u{0311}"),
+    ('Ȑ', "R\u{030F}"), ('ȑ', "r\u{030F}"), ('Ȓ', "R\u{0311}"), ('ȓ', "r\u{0311}"),
+    ('Ȕ', "U\u{030F}"), ('ȕ', "u\u{030F}"), ('Ȗ', "U\u{0311}"), ('ȗ', "u\u{0311}"),
+    ('Ș', "S\u{0326}"), ('ș', "s\u{0326}"), ('Ț', "T\u{0326}"), ('ț', "t\u{0326}"),
+    ('Ȟ', "H\u{030C}"), ('ȟ', "h\u{030C}"), ('Ȧ', "A\u{0307}"), ('ȧ', "a\u{0307}"),
+    ('Ȩ', "E\u{0327}"), ('ȩ', "e\u{0327}"), ('Ȫ', "O\u{0308}\u{0304}"), ('ȫ', "o\u{0308}\u{0304}"),
+    ('Ȭ', "O\u{0303}\u{0304}"), ('ȭ', "o\u{0303}\u{0304}"), ('Ȯ', "O\u{0307}"), ('ȯ', "o\u{0307}"),
+    ('Ȱ', "O\u{0307}\u{0304}"), ('ȱ', "o\u{0307}\u{0304}"), ('Ȳ', "Y\u{0304}"), ('ȳ', "y\u{0304}"),
+    ('\u{0340}', "\u{0300}"), ('\u{0341}', "\u{0301}"), ('\u{0343}', "\u{0313}"),
+    ('\u{0344}', "\u{0308}\u{0301}"), ('ʹ', "ʹ"), (';', ";"), ('΅', "¨\u{0301}"),
+    ('Ά', "Α\u{0301}"), ('·', "·"), ('Έ', "Ε\u{0301}"), ('Ή', "Η\u{0301}"), ('Ί', "Ι\u{0301}"),
+    ('Ό', "Ο\u{0301}"), ('Ύ', "Υ\u{0301}"), ('Ώ', "Ω\u{0301}"), ('ΐ', "ι\u{0308}\u{0301}"),
+    ('Ϊ', "Ι\u{0308}"), ('Ϋ', "Υ\u{0308}"), ('ά', "α\u{0301}"), ('έ', "ε\u{0301}"),
+    ('ή', "η\u{0301}"), ('ί', "ι\u{0301}"), ('ΰ', "υ\u{0308}\u{0301}"), ('ϊ', "ι\u{0308}"),
+    ('ϋ', "υ\u{0308}"), ('ό', "ο\u{0301}"), ('ύ', "υ\u{0301}"), ('ώ', "ω\u{0301}"),
+    ('ϓ', "ϒ\u{0301}"), ('ϔ', "ϒ\u{0308}"), ('Ѐ', "Е\u{0300}"), ('Ё', "Е\u{0308}"),
+    ('Ѓ', "Г\u{0301}"), ('Ї', "І\u{0308}"), ('Ќ', "К\u{0301}"), ('Ѝ', "И\u{0300}"),
+    ('Ў', "У\u{0306}"), ('Й', "И\u{0306}"), ('й', "и\u{0306}"), ('ѐ', "е\u{0300}"),
+    ('ё', "е\u{0308}"), ('ѓ', "г\u{0301}"), ('ї', "і\u{0308}"), ('ќ', "к\u{0301}"),
+    ('ѝ', "и\u{0300}"), ('ў', "у\u{0306}"), ('Ѷ', "Ѵ\u{030F}"), ('ѷ', "ѵ\u{030F}"),
+    ('Ӂ', "Ж\u{0306}"), ('ӂ', "ж\u{0306}"), ('Ӑ', "А\u{0306}"), ('ӑ', "а\u{0306}"),
+    ('Ӓ', "А\u{0308}"), ('ӓ', "а\u{0308}"), ('Ӗ', "Е\u{0306}"), ('ӗ', "е\u{0306}"),
+    ('Ӛ', "Ә\u{0308}"), ('ӛ', "ә\u{0308}"), ('Ӝ', "Ж\u{0308}"), ('ӝ', "ж\u{0308}"),
+    ('Ӟ', "З\u{0308}"), ('ӟ', "з\u{0308}"), ('Ӣ', "И\u{0304}"), ('ӣ', "и\u{0304}"),
+    ('Ӥ', "И\u{0308}"), ('ӥ', "и\u{0308}"), ('Ӧ', "О\u{0308}"), ('ӧ', "о\u{0308}"),
+    ('Ӫ', "Ө\u{0308}"), ('ӫ', "ө\u{0308}"), ('Ӭ', "Э\u{0308}"), ('ӭ', "э\u{0308}"),
+    ('Ӯ', "У\u{0304}"), ('ӯ', "у\u{0304}"), ('Ӱ', "У\u{0308}"), ('ӱ', "у\u{0308}"),
+    ('Ӳ', "У\u{030B}"), ('ӳ', "у\u{030B}"), ('Ӵ', "Ч\u{0308}"), ('ӵ', "ч\u{0308}"),
+    ('Ӹ', "Ы\u{0308}"), ('ӹ', "ы\u{0308}"), ('آ', "ا\u{0653}"), ('أ', "ا\u{0654}"),
+    ('ؤ', "و\u{0654}"), ('إ', "ا\u{0655}"), ('ئ', "ي\u{0654}"), ('ۀ', "ە\u{0654}"),
+    ('ۂ', "ہ\u{0654}"), ('ۓ', "ے\u{0654}"), ('ऩ', "न\u{093C}"), ('ऱ', "र\u{093C}"),
+    ('ऴ', "ळ\u{093C}"), ('क़', "क\u{093C}"), ('ख़', "ख\u{093C}"), ('ग़', "ग\u{093C}"),
+    ('ज़', "ज\u{093C}"), ('ड़', "ड\u{093C}"), ('ढ़', "ढ\u{093C}"), ('फ़', "फ\u{093C}"),
+    ('य़', "य\u{093C}"), ('\u{09CB}', "\u{09C7}\u{09BE}"), ('\u{09CC}', "\u{09C7}\u{09D7}"),
+    ('ড়', "ড\u{09BC}"), ('ঢ়', "ঢ\u{09BC}"), ('য়', "য\u{09BC}"), ('ਲ਼', "ਲ\u{0A3C}"),
+    ('ਸ਼', "ਸ\u{0A3C}"), ('ਖ਼', "ਖ\u{0A3C}"), ('ਗ਼', "ਗ\u{0A3C}"), ('ਜ਼', "ਜ\u{0A3C}"),
+    ('ਫ਼', "ਫ\u{0A3C}"), ('\u{0B48}', "\u{0B47}\u{0B56}"), ('\u{0B4B}', "\u{0B47}\u{0B3E}"),
+    ('\u{0B4C}', "\u{0B47}\u{0B57}"), ('ଡ଼', "ଡ\u{0B3C}"), ('ଢ଼', "ଢ\u{0B3C}"), ('ஔ', "ஒ\u{0BD7}"),
+    ('\u{0BCA}', "\u{0BC6}\u{0BBE}"), ('\u{0BCB}', "\u{0BC7}\u{0BBE}"),
+    ('\u{0BCC}', "\u{0BC6}\u{0BD7}"), ('\u{0C48}', "\u{0C46}\u{0C56}"),
+    ('\u{0CC0}', "\u{0CBF}\u{0CD5}"), ('\u{0CC7}', "\u{0CC6}\u{0CD5}"),
+    ('\u{0CC8}', "\u{0CC6}\u{0CD6}"), ('\u{0CCA}', "\u{0CC6}\u{0CC2}"),
+    ('\u{0CCB}', "\u{0CC6}\u{0CC2}\u{0CD5}"), ('\u{0D4A}', "\u{0D46}\u{0D3E}"),
+    ('\u{0D4B}', "\u{0D47}\u{0D3E}"), ('\u{0D4C}', "\u{0D46}\u{0D57}"),
+    ('\u{0DDA}', "\u{0DD9}\u{0DCA}"), ('\u{0DDC}', "\u{0DD9}\u{0DCF}"),
+    ('\u{0DDD}', "\u{0DD9}\u{0DCF}\u{0DCA}"), ('\u{0DDE}', "\u{0DD9}\u{0DDF}"), ('གྷ', "ག\u{0FB7}"),
+    ('ཌྷ', "ཌ\u{0FB7}"), ('དྷ', "ད\u{0FB7}"), ('བྷ', "བ\u{0FB7}"), ('ཛྷ', "ཛ\u{0FB7}"),
+    ('ཀྵ', "ཀ\u{0FB5}"), ('\u{0F73}', "\u{0F71}\u{0F72}"), ('\u{0F75}', "\u{0F71}\u{0F74}"),
+    ('\u{0F76}', "\u{0FB2}\u{0F80}"), ('\u{0F78}', "\u{0FB3}\u{0F80}"),
+    ('\u{0F81}', "\u{0F71}\u{0F80}"), ('\u{0F93}', "\u{0F92}\u{0FB7}"),
+    ('\u{0F9D}', "\u{0F9C}\u{0FB7}"), ('\u{0FA2}', "\u{0FA1}\u{0FB7}"),
+    ('\u{0FA7}', "\u{0FA6}\u{0FB7}"), ('\u{0FAC}', "\u{0FAB}\u{0FB7}"),
+    ('\u{0FB9}', "\u{0F90}\u{0FB5}"), ('ဦ', "ဥ\u{102E}"), ('ᬆ', "ᬅ\u{1B35}"), ('ᬈ', "ᬇ\u{1B35}"),
+    ('ᬊ', "ᬉ\u{1B35}"), ('ᬌ', "ᬋ\u{1B35}"), ('ᬎ', "ᬍ\u{1B35}"), ('ᬒ', "ᬑ\u{1B35}"),
+    ('\u{1B3B}', "\u{1B3A}\u{1B35}"), ('\u{1B3D}', "\u{1B3C}\u{1B35}"),
+    ('\u{1B40}', "\u{1B3E}\u{1B35}"), ('\u{1B41}', "\u{1B3F}\u{1B35}"),
+    ('\u{1B43}', "\u{1B42}\u{1B35}"), ('Ḁ', "A\u{0325}"), ('ḁ', "a\u{0325}"), ('Ḃ', "B\u{0307}"),
+    ('ḃ', "b\u{0307}"), ('Ḅ', "B\u{0323}"), ('ḅ', "b\u{0323}"), ('Ḇ', "B\u{0331}"),
+    ('ḇ', "b\u{0331}"), ('Ḉ', "C\u{0327}\u{0301}"), ('ḉ', "c\u{0327}\u{0301}"), ('Ḋ', "D\u{0307}"),
+    ('ḋ', "d\u{0307}"), ('Ḍ', "D\u{0323}"), ('ḍ', "d\u{0323}"), ('Ḏ', "D\u{0331}"),
+    ('ḏ', "d\u{0331}"), ('Ḑ', "D\u{0327}"), ('ḑ', "d\u{0327}"), ('Ḓ', "D\u{032D}"),
+    ('ḓ', "d\u{032D}"), ('Ḕ', "E\u{0304}\u{0300}"), ('ḕ', "e\u{0304}\u{0300}"),
+    ('Ḗ', "E\u{0304}\u{0301}"), ('ḗ', "e\u{0304}\u{0301}"), ('Ḙ', "E\u{032D}"), ('ḙ', "e\u{032D}"),
+    ('Ḛ', "E\u{0330}"), ('ḛ', "e\u{0330}"), ('Ḝ', "E\u{0327}\u{0306}"), ('ḝ', "e\u{0327}\u{0306}"),
+    ('Ḟ', "F\u{0307}"), ('ḟ', "f\u{0307}"), ('Ḡ', "G\u{0304}"), ('ḡ', "g\u{0304}"),
+    ('Ḣ', "H\u{0307}"), ('ḣ', "h\u{0307}"), ('Ḥ', "H\u{0323}"), ('ḥ', "h\u{0323}"),
+    ('Ḧ', "H\u{0308}"), ('ḧ', "h\u{0308}"), ('Ḩ', "H\u{0327}"), ('ḩ', "h\u{0327}"),
+    ('Ḫ', "H\u{032E}"), ('ḫ', "h\u{032E}"), ('Ḭ', "I\u{0330}"), ('ḭ', "i\u{0330}"),
+    ('Ḯ', "I\u{0308}\u{0301}"), ('ḯ', "i\u{0308}\u{0301}"), ('Ḱ', "K\u{0301}"), ('ḱ', "k\u{0301}"),
+    ('Ḳ', "K\u{0323}"), ('ḳ', "k\u{0323}"), ('Ḵ', "K\u{0331}"), ('ḵ', "k\u{0331}"),
+    ('Ḷ', "L\u{0323}"), ('ḷ', "l\u{0323}"), ('Ḹ', "L\u{0323}\u{0304}"), ('ḹ', "l\u{0323}\u{0304}"),
+    ('Ḻ', "L\u{0331}"), ('ḻ', "l\u{0331}"), ('Ḽ', "L\u{032D}"), ('ḽ', "l\u{032D}"),
+    ('Ḿ', "M\u{0301}"), ('ḿ', "m\u{0301}"), ('Ṁ', "M\u{0307}"), ('ṁ', "m\u{0307}"),
+    ('Ṃ', "M\u{0323}"), ('ṃ', "m\u{0323}"), ('Ṅ', "N\u{0307}"), ('ṅ', "n\u{0307}"),
+    ('Ṇ', "N\u{0323}"), ('ṇ', "n\u{0323}"), ('Ṉ', "N\u{0331}"), ('ṉ', "n\u{0331}"),
+    ('Ṋ', "N\u{032D}"), ('ṋ', "n\u{032D}"), ('Ṍ', "O\u{0303}\u{0301}"), ('ṍ', "o\u{0303}\u{0301}"),
+    ('Ṏ', "O\u{0303}\u{0308}"), ('ṏ', "o\u{0303}\u{0308}"), ('Ṑ', "O\u{0304}\u{0300}"),
+    ('ṑ', "o\u{0304}\u{0300}"), ('Ṓ', "O\u{0304}\u{0301}"), ('ṓ', "o\u{0304}\u{0301}"),
+    ('Ṕ', "P\u{0301}"), ('ṕ', "p\u{0301}"), ('Ṗ', "P\u{0307}"), ('ṗ', "p\u{0307}"),
+    ('Ṙ', "R\u{0307}"), ('ṙ', "r\u{0307}"), ('Ṛ', "R\u{0323}"), ('ṛ', "r\u{0323}"),
+    ('Ṝ', "R\u{0323}\u{0304}"), ('ṝ', "r\u{0323}\u{0304}"), ('Ṟ', "R\u{0331}"), ('ṟ', "r\u{0331}"),
+    ('Ṡ', "S\u{0307}"), ('ṡ', "s\u{0307}"), ('Ṣ', "S\u{0323}"), ('ṣ', "s\u{0323}"),
+    ('Ṥ', "S\u{0301}\u{0307}"), ('ṥ', "s\u{0301}\u{0307}"), ('Ṧ', "S\u{030C}\u{0307}"),
+    ('ṧ', "s\u{030C}\u{0307}"), ('Ṩ', "S\u{0323}\u{0307}"), ('ṩ', "s\u{0323}\u{0307}"),
+    ('Ṫ', "T\u{0307}"), ('ṫ', "t\u{0307}"), ('Ṭ', "T\u{0323}"), ('ṭ', "t\u{0323}"),
+    ('Ṯ', "T\u{0331}"), ('ṯ', "t\u{0331}"), ('Ṱ', "T\u{032D}"), ('ṱ', "t\u{032D}"),
+    ('Ṳ', "U\u{0324}"), ('ṳ', "u\u{0324}"), ('Ṵ', "U\u{0330}"), ('ṵ', "u\u{0330}"),
+    ('Ṷ', "U\u{032D}"), ('ṷ', "u\u{032D}"), ('Ṹ', "U\u{0303}\u{0301}"), ('ṹ', "u\u{0303}\u{0301}"),
+    ('Ṻ', "U\u{0304}\u{0308}"), ('ṻ', "u\u{0304}\u{0308}"), ('Ṽ', "V\u{0303}"), ('ṽ', "v\u{0303}"),
+    ('Ṿ', "V\u{0323}"), ('ṿ', "v\u{0323}"), ('Ẁ', "W\u{0300}"), ('ẁ', "w\u{0300}"),
+    ('Ẃ', "W\u{0301}"), ('ẃ', "w\u{0301}"), ('Ẅ', "W\u{0308}"), ('ẅ', "w\u{0308}"),
+    ('Ẇ', "W\u{0307}"), ('ẇ', "w\u{0307}"), ('Ẉ', "W\u{0323}"), ('ẉ', "w\u{0323}"),
+    ('Ẋ', "X\u{0307}"), ('ẋ', "x\u{0307}"), ('Ẍ', "X\u{0308}"), ('ẍ', "x\u{0308}"),
+    ('Ẏ', "Y\u{0307}"), ('ẏ', "y\u{0307}"), ('Ẑ', "Z\u{0302}"), ('ẑ', "z\u{0302}"),
+    ('Ẓ', "Z\u{0323}"), ('ẓ', "z\u{0323}"), ('Ẕ', "Z\u{0331}"), ('ẕ', "z\u{0331}"),
+    ('ẖ', "h\u{0331}"), ('ẗ', "t\u{0308}"), ('ẘ', "w\u{030A}"), ('ẙ', "y\u{030A}"),
+    ('ẛ', "ſ\u{0307}"), ('Ạ', "A\u{0323}"), ('ạ', "a\u{0323}"), ('Ả', "A\u{0309}"),
+    ('ả', "a\u{0309}"), ('Ấ', "A\u{0302}\u{0301}"), ('ấ', "a\u{0302}\u{0301}"),
+    ('Ầ', "A\u{0302}\u{0300}"), ('ầ', "a\u{0302}\u{0300}"), ('Ẩ', "A\u{0302}\u{0309}"),
+    ('ẩ', "a\u{0302}\u{0309}"), ('Ẫ', "A\u{0302}\u{0303}"), ('ẫ', "a\u{0302}\u{0303}"),
+    ('Ậ', "A\u{0323}\u{0302}"), ('ậ', "a\u{0323}\u{0302}"), ('Ắ', "A\u{0306}\u{0301}"),
+    ('ắ', "a\u{0306}\u{0301}"), ('Ằ', "A\u{0306}\u{0300}"), ('ằ', "a\u{0306}\u{0300}"),
+    ('Ẳ', "A\u{0306}\u{0309}"), ('ẳ', "a\u{0306}\u{0309}"), ('Ẵ', "A\u{0306}\u{0303}"),
+    ('ẵ', "a\u{0306}\u{0303}"), ('Ặ', "A\u{0323}\u{0306}"), ('ặ', "a\u{0323}\u{0306}"),
+    ('Ẹ', "E\u{0323}"), ('ẹ', "e\u{0323}"), ('Ẻ', "E\u{0309}"), ('ẻ', "e\u{0309}"),
+    ('Ẽ', "E\u{0303}"), ('ẽ', "e\u{0303}"), ('Ế', "E\u{0302}\u{0301}"), ('ế', "e\u{0302}\u{0301}"),
+    ('Ề', "E\u{0302}\u{0300}"), ('ề', "e\u{0302}\u{0300}"), ('Ể', "E\u{0302}\u{0309}"),
+    ('ể', "e\u{0302}\u{0309}"), ('Ễ', "E\u{0302}\u{0303}"), ('ễ', "e\u{0302}\u{0303}"),
+    ('Ệ', "E\u{0323}\u{0302}"), ('ệ', "e\u{0323}\u{0302}"), ('Ỉ', "I\u{0309}"), ('ỉ', "i\u{0309}"),
+    ('Ị', "I\u{0323}"), ('ị', "i\u{0323}"), ('Ọ', "O\u{0323}"), ('ọ', "o\u{0323}"),
+    ('Ỏ', "O\u{0309}"), ('ỏ', "o\u{0309}"), ('Ố', "O\u{0302}\u{0301}"), ('ố', "o\u{0302}\u{0301}"),
+    ('Ồ', "O\u{0302}\u{0300}"), ('ồ', "o\u{0302}\u{0300}"), ('Ổ', "O\u{0302}\u{0309}"),
+    ('ổ', "o\u{0302}\u{0309}"), ('Ỗ', "O\u{0302}\u{0303}"), ('ỗ', "o\u{0302}\u{0303}"),
+    ('Ộ', "O\u{0323}\u{0302}"), ('ộ', "o\u{0323}\u{0302}"), ('Ớ', "O\u{031B}\u{0301}"),
+    ('ớ', "o\u{031B}\u{0301}"), ('Ờ', "O\u{031B}\u{0300}"), ('ờ', "o\u{031B}\u{0300}"),
+    ('Ở', "O\u{031B}\u{0309}"), ('ở', "o\u{031B}\u{0309}"), ('Ỡ', "O\u{031B}\u{0303}"),
+    ('ỡ', "o\u{031B}\u{0303}"), ('Ợ', "O\u{031B}\u{0323}"), ('ợ', "o\u{031B}\u{0323}"),
+    ('Ụ', "U\u{0323}"), ('ụ', "u\u{0323}"), ('Ủ', "U\u{0309}"), ('ủ', "u\u{0309}"),
+    ('Ứ', "U\u{031B}\u{0301}"), ('ứ', "u\u{031B}\u{0301}"), ('Ừ', "U\u{031B}\u{0300}"),
+    ('ừ', "u\u{031B}\u{0300}"), ('Ử', "U\u{031B}\u{0309}"), ('ử', "u\u{031B}\u{0309}"),
+    ('Ữ', "U\u{031B}\u{0303}"), ('ữ', "u\u{031B}\u{0303}"), ('Ự', "U\u{031B}\u{0323}"),
+    ('ự', "u\u{031B}\u{0323}"), ('Ỳ', "Y\u{0300}"), ('ỳ', "y\u{0300}"), ('Ỵ', "Y\u{0323}"),
+    ('ỵ', "y\u{0323}"), ('Ỷ', "Y\u{0309}"), ('ỷ', "y\u{0309}"), ('Ỹ', "Y\u{0303}"),
+    ('ỹ', "y\u{0303}"), ('ἀ', "α\u{0313}"), ('ἁ', "α\u{0314}"), ('ἂ', "α\u{0313}\u{0300}"),
+    ('ἃ', "α\u{0314}\u{0300}"), ('ἄ', "α\u{0313}\u{0301}"), ('ἅ', "α\u{0314}\u{0301}"),
+    ('ἆ', "α\u{0313}\u{0342}"), ('ἇ', "α\u{0314}\u{0342}"), ('Ἀ', "Α\u{0313}"), ('Ἁ', "Α\u{0314}"),
+    ('Ἂ', "Α\u{0313}\u{0300}"), ('Ἃ', "Α\u{0314}\u{0300}"), ('Ἄ', "Α\u{0313}\u{0301}"),
+    ('Ἅ', "Α\u{0314}\u{0301}"), ('Ἆ', "Α\u{0313}\u{0342}"), ('Ἇ', "Α\u{0314}\u{0342}"),
+    ('ἐ', "ε\u{0313}"), ('ἑ', "ε\u{0314}"), ('ἒ', "ε\u{0313}\u{0300}"), ('ἓ', "ε\u{0314}\u{0300}"),
+    ('ἔ', "ε\u{0313}\u{0301}"), ('ἕ', "ε\u{0314}\u{0301}"), ('Ἐ', "Ε\u{0313}"), ('Ἑ', "Ε\u{0314}"),
+    ('Ἒ', "Ε\u{0313}\u{0300}"), ('Ἓ', "Ε\u{0314}\u{0300}"), ('Ἔ', "Ε\u{0313}\u{0301}"),
+    ('Ἕ', "Ε\u{0314}\u{0301}"), ('ἠ', "η\u{0313}"), ('ἡ', "η\u{0314}"), ('ἢ', "η\u{0313}\u{0300}"),
+    ('ἣ', "η\u{0314}\u{0300}"), ('ἤ', "η\u{0313}\u{0301}"), ('ἥ', "η\u{0314}\u{0301}"),
+    ('ἦ', "η\u{0313}\u{0342}"), ('ἧ', "η\u{0314}\u{0342}"), ('Ἠ', "Η\u{0313}"), ('Ἡ', "Η\u{0314}"),
+    ('Ἢ', "Η\u{0313}\u{0300}"), ('Ἣ', "Η\u{0314}\u{0300}"), ('Ἤ', "Η\u{0313}\u{0301}"),
+    ('Ἥ', "Η\u{0314}\u{0301}"), ('Ἦ', "Η\u{0313}\u{0342}"), ('Ἧ', "Η\u{0314}\u{0342}"),
+    ('ἰ', "ι\u{0313}"), ('ἱ', "ι\u{0314}"), ('ἲ', "ι\u{0313}\u{0300}"), ('ἳ', "ι\u{0314}\u{0300}"),
+    ('ἴ', "ι\u{0313}\u{0301}"), ('ἵ', "ι\u{0314}\u{0301}"), ('ἶ', "ι\u{0313}\u{0342}"),
+    ('ἷ', "ι\u{0314}\u{0342}"), ('Ἰ', "Ι\u{0313}"), ('Ἱ', "Ι\u{0314}"), ('Ἲ', "Ι\u{0313}\u{0300}"),
+    ('Ἳ', "Ι\u{0314}\u{0300}"), ('Ἴ', "Ι\u{0313}\u{0301}"), ('Ἵ', "Ι\u{0314}\u{0301}"),
+    ('Ἶ', "Ι\u{0313}\u{0342}"), ('Ἷ', "Ι\u{0314}\u{0342}"), ('ὀ', "ο\u{0313}"), ('ὁ', "ο\u{0314}"),
+    ('ὂ', "ο\u{0313}\u{0300}"), ('ὃ', "ο\u{0314}\u{0300}"), ('ὄ', "ο\u{0313}\u{0301}"),
+    ('ὅ', "ο\u{0314}\u{0301}"), ('Ὀ', "Ο\u{0313}"), ('Ὁ', "Ο\u{0314}"), ('Ὂ', "Ο\u{0313}\u{0300}"),
+    ('Ὃ', "Ο\u{0314}\u{0300}"), ('Ὄ', "Ο\u{0313}\u{0301}"), ('Ὅ', "Ο\u{0314}\u{0301}"),
+    ('ὐ', "υ\u{0313}"), ('ὑ', "υ\u{0314}"), ('ὒ', "υ\u{0313}\u{0300}"), ('ὓ', "υ\u{0314}\u{0300}"),
+    ('ὔ', "υ\u{0313}\u{0301}"), ('ὕ', "υ\u{0314}\u{0301}"), ('ὖ', "υ\u{0313}\u{0342}"),
+    ('ὗ', "υ\u{0314}\u{0342}"), ('Ὑ', "Υ\u{0314}"), ('Ὓ', "Υ\u{0314}\u{0300}"),
+    ('Ὕ', "Υ\u{0314}\u{0301}"), ('Ὗ', "Υ\u{0314}\u{0342}"), ('ὠ', "ω\u{0313}"), ('ὡ', "ω\u{0314}"),
+    ('ὢ', "ω\u{0313}\u{0300}"), ('ὣ', "ω\u{0314}\u{0300}"), ('ὤ', "ω\u{0313}\u{0301}"),
+    ('ὥ', "ω\u{0314}\u{0301}"), ('ὦ', "ω\u{0313}\u{0342}"), ('ὧ', "ω\u{0314}\u{0342}"),
+    ('Ὠ', "Ω\u{0313}"), ('Ὡ', "Ω\u{0314}"), ('Ὢ', "Ω\u{0313}\u{0300}"), ('Ὣ', "Ω\u{0314}\u{0300}"),
+    ('Ὤ', "Ω\u{0313}\u{0301}"), ('Ὥ', "Ω\u{0314}\u{0301}"), ('Ὦ', "Ω\u{0313}\u{0342}"),
+    ('Ὧ', "Ω\u{0314}\u{0342}"), ('ὰ', "α\u{0300}"), ('ά', "α\u{0301}"), ('ὲ', "ε\u{0300}"),
+    ('έ', "ε\u{0301}"), ('ὴ', "η\u{0300}"), ('ή', "η\u{0301}"), ('ὶ', "ι\u{0300}"),
+    ('ί', "ι\u{0301}"), ('ὸ', "ο\u{0300}"), ('ό', "ο\u{0301}"), ('ὺ', "υ\u{0300}"),
+    ('ύ', "υ\u{0301}"), ('ὼ', "ω\u{0300}"), ('ώ', "ω\u{0301}"), ('ᾀ', "α\u{0313}\u{0345}"),
+    ('ᾁ', "α\u{0314}\u{0345}"), ('ᾂ', "α\u{0313}\u{0300}\u{0345}"),
+    ('ᾃ', "α\u{0314}\u{0300}\u{0345}"), ('ᾄ', "α\u{0313}\u{0301}\u{0345}"),
+    ('ᾅ', "α\u{0314}\u{0301}\u{0345}"), ('ᾆ', "α\u{0313}\u{0342}\u{0345}"),
+    ('ᾇ', "α\u{0314}\u{0342}\u{0345}"), ('ᾈ', "Α\u{0313}\u{0345}"), ('ᾉ', "Α\u{0314}\u{0345}"),
+    ('ᾊ', "Α\u{0313}\u{0300}\u{0345}"), ('ᾋ', "Α\u{0314}\u{0300}\u{0345}"),
+    ('ᾌ', "Α\u{0313}\u{0301}\u{0345}"), ('ᾍ', "Α\u{0314}\u{0301}\u{0345}"),
+    ('ᾎ', "Α\u{0313}\u{0342}\u{0345}"), ('ᾏ', "Α\u{0314}\u{0342}\u{0345}"),
+    ('ᾐ', "η\u{0313}\u{0345}"), ('ᾑ', "η\u{0314}\u{0345}"), ('ᾒ', "η\u{0313}\u{0300}\u{0345}"),
+    ('ᾓ', "η\u{0314}\u{0300}\u{0345}"), ('ᾔ', "η\u{0313}\u{0301}\u{0345}"),
+    ('ᾕ', "η\u{0314}\u{0301}\u{0345}"), ('ᾖ', "η\u{0313}\u{0342}\u{0345}"),
+    ('ᾗ', "η\u{0314}\u{0342}\u{0345}"), ('ᾘ', "Η\u{0313}\u{0345}"), ('ᾙ', "Η\u{0314}\u{0345}"),
+    ('ᾚ', "Η\u{0313}\u{0300}\u{0345}"), ('ᾛ', "Η\u{0314}\u{0300}\u{0345}"),
+    ('ᾜ', "Η\u{0313}\u{0301}\u{0345}"), ('ᾝ', "Η\u{0314}\u{0301}\u{0345}"),
+    ('ᾞ', "Η\u{0313}\u{0342}\u{0345}"), ('ᾟ', "Η\u{0314}\u{0342}\u{0345}"),
+    ('ᾠ', "ω\u{0313}\u{0345}"), ('ᾡ', "ω\u{0314}\u{0345}"), ('ᾢ', "ω\u{0313}\u{0300}\u{0345}"),
+    ('ᾣ', "ω\u{0314}\u{0300}\u{0345}"), ('ᾤ', "ω\u{0313}\u{0301}\u{0345}"),
+    ('ᾥ', "ω\u{0314}\u{0301}\u{0345}"), ('ᾦ', "ω\u{0313}\u{0342}\u{0345}"),
+    ('ᾧ', "ω\u{0314}\u{0342}\u{0345}"), ('ᾨ', "Ω\u{0313}\u{0345}"), ('ᾩ', "Ω\u{0314}\u{0345}"),
+    ('ᾪ', "Ω\u{0313}\u{0300}\u{0345}"), ('ᾫ', "Ω\u{0314}\u{0300}\u{0345}"),
+    ('ᾬ', "Ω\u{0313}\u{0301}\u{0345}"), ('ᾭ', "Ω\u{0314}\u{0301}\u{0345}"),
+    ('ᾮ', "Ω\u{0313}\u{0342}\u{0345}"), ('ᾯ', "Ω\u{0314}\u{0342}\u{0345}"), ('ᾰ', "α\u{0306}"),
+    ('ᾱ', "α\u{0304}"), ('ᾲ', "α\u{0300}\u{0345}"), ('ᾳ', "α\u{0345}"), ('ᾴ', "α\u{0301}\u{0345}"),
+    ('ᾶ', "α\u{0342}"), ('ᾷ', "α\u{0342}\u{0345}"), ('Ᾰ', "Α\u{0306}"), ('Ᾱ', "Α\u{0304}"),
+    ('Ὰ', "Α\u{0300}"), ('Ά', "Α\u{0301}"), ('ᾼ', "Α\u{0345}"), ('ι', "ι"), ('῁', "¨\u{0342}"),
+    ('ῂ', "η\u{0300}\u{0345}"), ('ῃ', "η\u{0345}"), ('ῄ', "η\u{0301}\u{0345}"), ('ῆ', "η\u{0342}"),
+    ('ῇ', "η\u{0342}\u{0345}"), ('Ὲ', "Ε\u{0300}"), ('Έ', "Ε\u{0301}"), ('Ὴ', "Η\u{0300}"),
+    ('Ή', "Η\u{0301}"), ('ῌ', "Η\u{0345}"), ('῍', "᾿\u{0300}"), ('῎', "᾿\u{0301}"),
+    ('῏', "᾿\u{0342}"), ('ῐ', "ι\u{0306}"), ('ῑ', "ι\u{0304}"), ('ῒ', "ι\u{0308}\u{0300}"),
+    ('ΐ', "ι\u{0308}\u{0301}"), ('ῖ', "ι\u{0342}"), ('ῗ', "ι\u{0308}\u{0342}"), ('Ῐ', "Ι\u{0306}"),
+    ('Ῑ', "Ι\u{0304}"), ('Ὶ', "Ι\u{0300}"), ('Ί', "Ι\u{0301}"), ('῝', "῾\u{0300}"),
+    ('῞', "῾\u{0301}"), ('῟', "῾\u{0342}"), ('ῠ', "υ\u{0306}"), ('ῡ', "υ\u{0304}"),
+    ('ῢ', "υ\u{0308}\u{0300}"), ('ΰ', "υ\u{0308}\u{0301}"), ('ῤ', "ρ\u{0313}"), ('ῥ', "ρ\u{0314}"),
+    ('ῦ', "υ\u{0342}"), ('ῧ', "υ\u{0308}\u{0342}"), ('Ῠ', "Υ\u{0306}"), ('Ῡ', "Υ\u{0304}"),
+    ('Ὺ', "Υ\u{0300}"), ('Ύ', "Υ\u{0301}"), ('Ῥ', "Ρ\u{0314}"), ('῭', "¨\u{0300}"),
+    ('΅', "¨\u{0301}"), ('`', "`"), ('ῲ', "ω\u{0300}\u{0345}"), ('ῳ', "ω\u{0345}"),
+    ('ῴ', "ω\u{0301}\u{0345}"), ('ῶ', "ω\u{0342}"), ('ῷ', "ω\u{0342}\u{0345}"), ('Ὸ', "Ο\u{0300}"),
+    ('Ό', "Ο\u{0301}"), ('Ὼ', "Ω\u{0300}"), ('Ώ', "Ω\u{0301}"), ('ῼ', "Ω\u{0345}"), ('´', "´"),
+    ('\u{2000}', "\u{2002}"), ('\u{2001}', "\u{2003}"), ('Ω', "Ω"), ('K', "K"), ('Å', "A\u{030A}"),
+    ('↚', "←\u{0338}"), ('↛', "→\u{0338}"), ('↮', "↔\u{0338}"), ('⇍', "⇐\u{0338}"),
+    ('⇎', "⇔\u{0338}"), ('⇏', "⇒\u{0338}"), ('∄', "∃\u{0338}"), ('∉', "∈\u{0338}"),
+    ('∌', "∋\u{0338}"), ('∤', "∣\u{0338}"), ('∦', "∥\u{0338}"), ('≁', "∼\u{0338}"),
+    ('≄', "≃\u{0338}"), ('≇', "≅\u{0338}"), ('≉', "≈\u{0338}"), ('≠', "=\u{0338}"),
+    ('≢', "≡\u{0338}"), ('≭', "≍\u{0338}"), ('≮', "<\u{0338}"), ('≯', ">\u{0338}"),
+    ('≰', "≤\u{0338}"), ('≱', "≥\u{0338}"), ('≴', "≲\u{0338}"), ('≵', "≳\u{0338}"),
+    ('≸', "≶\u{0338}"), ('≹', "≷\u{0338}"), ('⊀', "≺\u{0338}"), ('⊁', "≻\u{0338}"),
+    ('⊄', "⊂\u{0338}"), ('⊅', "⊃\u{0338}"), ('⊈', "⊆\u{0338}"), ('⊉', "⊇\u{0338}"),
+    ('⊬', "⊢\u{0338}"), ('⊭', "⊨\u{0338}"), ('⊮', "⊩\u{0338}"), ('⊯', "⊫\u{0338}"),
+    ('⋠', "≼\u{0338}"), ('⋡', "≽\u{0338}"), ('⋢', "⊑\u{0338}"), ('⋣', "⊒\u{0338}"),
+    ('⋪', "⊲\u{0338}"), ('⋫', "⊳\u{0338}"), ('⋬', "⊴\u{0338}"), ('⋭', "⊵\u{0338}"), ('〈', "〈"),
+    ('〉', "〉"), ('⫝̸', "⫝\u{0338}"), ('が', "か\u{3099}"), ('ぎ', "き\u{3099}"), ('ぐ', "く\u{3099}"),
+    ('げ', "け\u{3099}"), ('ご', "こ\u{3099}"), ('ざ', "さ\u{3099}"), ('じ', "し\u{3099}"),
+    ('ず', "す\u{3099}"), ('ぜ', "せ\u{3099}"), ('ぞ', "そ\u{3099}"), ('だ', "た\u{3099}"),
+    ('ぢ', "ち\u{3099}"), ('づ', "つ\u{3099}"), ('で', "て\u{3099}"), ('ど', "と\u{3099}"),
+    ('ば', "は\u{3099}"), ('ぱ', "は\u{309A}"), ('び', "ひ\u{3099}"), ('ぴ', "ひ\u{309A}"),
+    ('ぶ', "ふ\u{3099}"), ('ぷ', "ふ\u{309A}"), ('べ', "へ\u{3099}"), ('ぺ', "へ\u{309A}"),
+    ('ぼ', "ほ\u{3099}"), ('ぽ', "ほ\u{309A}"), ('ゔ', "う\u{3099}"), ('ゞ', "ゝ\u{3099}"),
+    ('ガ', "カ\u{3099}"), ('ギ', "キ\u{3099}"), ('グ', "ク\u{3099}"), ('ゲ', "ケ\u{3099}"),
+    ('ゴ', "コ\u{3099}"), ('ザ', "サ\u{3099}"), ('ジ', "シ\u{3099}"), ('ズ', "ス\u{3099}"),
+    ('ゼ', "セ\u{3099}"), ('ゾ', "ソ\u{3099}"), ('ダ', "タ\u{3099}"), ('ヂ', "チ\u{3099}"),
+    ('ヅ', "ツ\u{3099}"), ('デ', "テ\u{3099}"), ('ド', "ト\u{3099}"), ('バ', "ハ\u{3099}"),
+    ('パ', "ハ\u{309A}"), ('ビ', "ヒ\u{3099}"), ('ピ', "ヒ\u{309A}"), ('ブ', "フ\u{3099}"),
+    ('プ', "フ\u{309A}"), ('ベ', "ヘ\u{3099}"), ('ペ', "ヘ\u{309A}"), ('ボ', "ホ\u{3099}"),
+    ('ポ', "ホ\u{309A}"), ('ヴ', "ウ\u{3099}"), ('ヷ', "ワ\u{3099}"), ('ヸ', "ヰ\u{3099}"),
+    ('ヹ', "ヱ\u{3099}"), ('ヺ', "ヲ\u{3099}"), ('ヾ', "ヽ\u{3099}"), ('豈', "豈"), ('更', "更"), ('車', "車"),
+    ('賈', "賈"), ('滑', "滑"), ('串', "串"), ('句', "句"), ('龜', "龜"), ('龜', "龜"), ('契', "契"), ('金', "金"),
+    ('喇', "喇"), ('奈', "奈"), ('懶', "懶"), ('癩', "癩"), ('羅', "羅"), ('蘿', "蘿"), ('螺', "螺"), ('裸', "裸"),
+    ('邏', "邏"), ('樂', "樂"), ('洛', "洛"), ('烙', "烙"), ('珞', "珞"), ('落', "落"), ('酪', "酪"), ('駱', "駱"),
+    ('亂', "亂"), ('卵', "卵"), ('欄', "欄"), ('爛', "爛"), ('蘭', "蘭"), ('鸞', "鸞"), ('嵐', "嵐"), ('濫', "濫"),
+    ('藍', "藍"), ('襤', "襤"), ('拉', "拉"), ('臘', "臘"), ('蠟', "蠟"), ('廊', "廊"), ('朗', "朗"), ('浪', "浪"),
+    ('狼', "狼"), ('郎', "郎"), ('來', "來"), ('冷', "冷"), ('勞', "勞"), ('擄', "擄"), ('櫓', "櫓"), ('爐', "爐"),
+    ('盧', "盧"), ('老', "老"), ('蘆', "蘆"), ('虜', "虜"), ('路', "路"), ('露', "露"), ('魯', "魯"), ('鷺', "鷺"),
+    ('碌', "碌"), ('祿', "祿"), ('綠', "綠"), ('菉', "菉"), ('錄', "錄"), ('鹿', "鹿"), ('論', "論"), ('壟', "壟"),
+    ('弄', "弄"), ('籠', "籠"), ('聾', "聾"), ('牢', "牢"), ('磊', "磊"), ('賂', "賂"), ('雷', "雷"), ('壘', "壘"),
+    ('屢', "屢"), ('樓', "樓"), ('淚', "淚"), ('漏', "漏"), ('累', "累"), ('縷', "縷"), ('陋', "陋"), ('勒', "勒"),
+    ('肋', "肋"), ('凜', "凜"), ('凌', "凌"), ('稜', "稜"), ('綾', "綾"), ('菱', "菱"), ('陵', "陵"), ('讀', "讀"),
+    ('拏', "拏"), ('樂', "樂"), ('諾', "諾"), ('丹', "丹"), ('寧', "寧"), ('怒', "怒"), ('率', "率"), ('異', "異"),
+    ('北', "北"), ('磻', "磻"), ('便', "便"), ('復', "復"), ('不', "不"), ('泌', "泌"), ('數', "數"), ('索', "索"),
+    ('參', "參"), ('塞', "塞"), ('省', "省"), ('葉', "葉"), ('說', "說"), ('殺', "殺"), ('辰', "辰"), ('沈', "沈"),
+    ('拾', "拾"), ('若', "若"), ('掠', "掠"), ('略', "略"), ('亮', "亮"), ('兩', "兩"), ('凉', "凉"), ('梁', "梁"),
+    ('糧', "糧"), ('良', "良"), ('諒', "諒"), ('量', "量"), ('勵', "勵"), ('呂', "呂"), ('女', "女"), ('廬', "廬"),
+    ('旅', "旅"), ('濾', "濾"), ('礪', "礪"), ('閭', "閭"), ('驪', "驪"), ('麗', "麗"), ('黎', "黎"), ('力', "力"),
+    ('曆', "曆"), ('歷', "歷"), ('轢', "轢"), ('年', "年"), ('憐', "憐"), ('戀', "戀"), ('撚', "撚"), ('漣', "漣"),
+    ('煉', "煉"), ('璉', "璉"), ('秊', "秊"), ('練', "練"), ('聯', "聯"), ('輦', "輦"), ('蓮', "蓮"), ('連', "連"),
+    ('鍊', "鍊"), ('列', "列"), ('劣', "劣"), ('咽', "咽"), ('烈', "烈"), ('裂', "裂"), ('說', "說"), ('廉', "廉"),
+    ('念', "念"), ('捻', "捻"), ('殮', "殮"), ('簾', "簾"), ('獵', "獵"), ('令', "令"), ('囹', "囹"), ('寧', "寧"),
+    ('嶺', "嶺"), ('怜', "怜"), ('玲', "玲"), ('瑩', "瑩"), ('羚', "羚"), ('聆', "聆"), ('鈴', "鈴"), ('零', "零"),
+    ('靈', "靈"), ('領', "領"), ('例', "例"), ('禮', "禮"), ('醴', "醴"), ('隸', "隸"), ('惡', "惡"), ('了', "了"),
+    ('僚', "僚"), ('寮', "寮"), ('尿', "尿"), ('料', "料"), ('樂', "樂"), ('燎', "燎"), ('療', "療"), ('蓼', "蓼"),
+    ('遼', "遼"), ('龍', "龍"), ('暈', "暈"), ('阮', "阮"), ('劉', "劉"), ('杻', "杻"), ('柳', "柳"), ('流', "流"),
+    ('溜', "溜"), ('琉', "琉"), ('留', "留"), ('硫', "硫"), ('紐', "紐"), ('類', "類"), ('六', "六"), ('戮', "戮"),
+    ('陸', "陸"), ('倫', "倫"), ('崙', "崙"), ('淪', "淪"), ('輪', "輪"), ('律', "律"), ('慄', "慄"), ('栗', "栗"),
+    ('率', "率"), ('隆', "隆"), ('利', "利"), ('吏', "吏"), ('履', "履"), ('易', "易"), ('李', "李"), ('梨', "梨"),
+    ('泥', "泥"), ('理', "理"), ('痢', "痢"), ('罹', "罹"), ('裏', "裏"), ('裡', "裡"), ('里', "里"), ('離', "離"),
+    ('匿', "匿"), ('溺', "溺"), ('吝', "吝"), ('燐', "燐"), ('璘', "璘"), ('藺', "藺"), ('隣', "隣"), ('鱗', "鱗"),
+    ('麟', "麟"), ('林', "林"), ('淋', "淋"), ('臨', "臨"), ('立', "立"), ('笠', "笠"), ('粒', "粒"), ('狀', "狀"),
+    ('炙', "炙"), ('識', "識"), ('什', "什"), ('茶', "茶"), ('刺', "刺"), ('切', "切"), ('度', "度"), ('拓', "拓"),
+    ('糖', "糖"), ('宅', "宅"), ('洞', "洞"), ('暴', "暴"), ('輻', "輻"), ('行', "行"), ('降', "降"), ('見', "見"),
+    ('廓', "廓"), ('兀', "兀"), ('嗀', "嗀"), ('塚', "塚"), ('晴', "晴"), ('凞', "凞"), ('猪', "猪"), ('益', "益"),
+    ('礼', "礼"), ('神', "神"), ('祥', "祥"), ('福', "福"), ('靖', "靖"), ('精', "精"), ('羽', "羽"), ('蘒', "蘒"),
+    ('諸', "諸"), ('逸', "逸"), ('都', "都"), ('飯', "飯"), ('飼', "飼"), ('館', "館"), ('鶴', "鶴"), ('郞', "郞"),
+    ('隷', "隷"), ('侮', "侮"), ('僧', "僧"), ('免', "免"), ('勉', "勉"), ('勤', "勤"), ('卑', "卑"), ('喝', "喝"),
+    ('嘆', "嘆"), ('器', "器"), ('塀', "塀"), ('墨', "墨"), ('層', "層"), ('屮', "屮"), ('悔', "悔"), ('慨', "慨"),
+    ('憎', "憎"), ('懲', "懲"), ('敏', "敏"), ('既', "既"), ('暑', "暑"), ('梅', "梅"), ('海', "海"), ('渚', "渚"),
+    ('漢', "漢"), ('煮', "煮"), ('爫', "爫"), ('琢', "琢"), ('碑', "碑"), ('社', "社"), ('祉', "祉"), ('祈', "祈"),
+    ('祐', "祐"), ('祖', "祖"), ('祝', "祝"), ('禍', "禍"), ('禎', "禎"), ('穀', "穀"), ('突', "突"), ('節', "節"),
+    ('練', "練"), ('縉', "縉"), ('繁', "繁"), ('署', "署"), ('者', "者"), ('臭', "臭"), ('艹', "艹"), ('艹', "艹"),
+    ('著', "著"), ('褐', "褐"), ('視', "視"), ('謁', "謁"), ('謹', "謹"), ('賓', "賓"), ('贈', "贈"), ('辶', "辶"),
+    ('逸', "逸"), ('難', "難"), ('響', "響"), ('頻', "頻"), ('恵', "恵"), ('𤋮', "𤋮"), ('舘', "舘"), ('並', "並"),
+    ('况', "况"), ('全', "全"), ('侀', "侀"), ('充', "充"), ('冀', "冀"), ('勇', "勇"), ('勺', "勺"), ('喝', "喝"),
+    ('啕', "啕"), ('喙', "喙"), ('嗢', "嗢"), ('塚', "塚"), ('墳', "墳"), ('奄', "奄"), ('奔', "奔"), ('婢', "婢"),
+    ('嬨', "嬨"), ('廒', "廒"), ('廙', "廙"), ('彩', "彩"), ('徭', "徭"), ('惘', "惘"), ('慎', "慎"), ('愈', "愈"),
+    ('憎', "憎"), ('慠', "慠"), ('懲', "懲"), ('戴', "戴"), ('揄', "揄"), ('搜', "搜"), ('摒', "摒"), ('敖', "敖"),
+    ('晴', "晴"), ('朗', "朗"), ('望', "望"), ('杖', "杖"), ('歹', "歹"), ('殺', "殺"), ('流', "流"), ('滛', "滛"),
+    ('滋', "滋"), ('漢', "漢"), ('瀞', "瀞"), ('煮', "煮"), ('瞧', "瞧"), ('爵', "爵"), ('犯', "犯"), ('猪', "猪"),
+    ('瑱', "瑱"), ('甆', "甆"), ('画', "画"), ('瘝', "瘝"), ('瘟', "瘟"), ('益', "益"), ('盛', "盛"), ('直', "直"),
+    ('睊', "睊"), ('着', "着"), ('磌', "磌"), ('窱', "窱"), ('節', "節"), ('类', "类"), ('絛', "絛"), ('練', "練"),
+    ('缾', "缾"), ('者', "者"), ('荒', "荒"), ('華', "華"), ('蝹', "蝹"), ('襁', "襁"), ('覆', "覆"), ('視', "視"),
+    ('調', "調"), ('諸', "諸"), ('請', "請"), ('謁', "謁"), ('諾', "諾"), ('諭', "諭"), ('謹', "謹"), ('變', "變"),
+    ('贈', "贈"), ('輸', "輸"), ('遲', "遲"), ('醙', "醙"), ('鉶', "鉶"), ('陼', "陼"), ('難', "難"), ('靖', "靖"),
+    ('韛', "韛"), ('響', "響"), ('頋', "頋"), ('頻', "頻"), ('鬒', "鬒"), ('龜', "龜"), ('𢡊', "𢡊"), ('𢡄', "𢡄"),
+    ('𣏕', "𣏕"), ('㮝', "㮝"), ('䀘', "䀘"), ('䀹', "䀹"), ('𥉉', "𥉉"), ('𥳐', "𥳐"), ('𧻓', "𧻓"), ('齃', "齃"),
+    ('龎', "龎"), ('יִ', "י\u{05B4}"), ('ײַ', "ײ\u{05B7}"), ('שׁ', "ש\u{05C1}"), ('שׂ', "ש\u{05C2}"),
+    ('שּׁ', "ש\u{05BC}\u{05C1}"), ('שּׂ', "ש\u{05BC}\u{05C2}"), ('אַ', "א\u{05B7}"), ('אָ', "א\u{05B8}"),
+    ('אּ', "א\u{05BC}"), ('בּ', "ב\u{05BC}"), ('גּ', "ג\u{05BC}"), ('דּ', "ד\u{05BC}"),
+    ('הּ', "ה\u{05BC}"), ('וּ', "ו\u{05BC}"), ('זּ', "ז\u{05BC}"), ('טּ', "ט\u{05BC}"),
+    ('יּ', "י\u{05BC}"), ('ךּ', "ך\u{05BC}"), ('כּ', "כ\u{05BC}"), ('לּ', "ל\u{05BC}"),
+    ('מּ', "מ\u{05BC}"), ('נּ', "נ\u{05BC}"), ('סּ', "ס\u{05BC}"), ('ףּ', "ף\u{05BC}"),
+    ('פּ', "פ\u{05BC}"), ('צּ', "צ\u{05BC}"), ('קּ', "ק\u{05BC}"), ('רּ', "ר\u{05BC}"),
+    ('שּ', "ש\u{05BC}"), ('תּ', "ת\u{05BC}"), ('וֹ', "ו\u{05B9}"), ('בֿ', "ב\u{05BF}"),
+    ('כֿ', "כ\u{05BF}"), ('פֿ', "פ\u{05BF}"), ('𑂚', "𑂙\u{110BA}"), ('𑂜', "𑂛\u{110BA}"),
+    ('𑂫', "𑂥\u{110BA}"), ('\u{1112E}', "\u{11131}\u{11127}"), ('\u{1112F}', "\u{11132}\u{11127}"),
+    ('\u{1134B}', "\u{11347}\u{1133E}"), ('\u{1134C}', "\u{11347}\u{11357}"),
+    ('\u{114BB}', "\u{114B9}\u{114BA}"), ('\u{114BC}', "\u{114B9}\u{114B0}"),
+    ('\u{114BE}', "\u{114B9}\u{114BD}"), ('\u{115BA}', "\u{115B8}\u{115AF}"),
+    ('\u{115BB}', "\u{115B9}\u{115AF}"), ('\u{11938}', "\u{11935}\u{11930}"), ('𝅗𝅥', "𝅗\u{1D165}"),
+    ('𝅘𝅥', "𝅘\u{1D165}"), ('𝅘𝅥𝅮', "𝅘\u{1D165}\u{1D16E}"), ('𝅘𝅥𝅯', "𝅘\u{1D165}\u{1D16F}"),
+    ('𝅘𝅥𝅰', "𝅘\u{1D165}\u{1D170}"), ('𝅘𝅥𝅱', "𝅘\u{1D165}\u{1D171}"), ('𝅘𝅥𝅲', "𝅘\u{1D165}\u{1D172}"),
+    ('𝆹𝅥', "𝆹\u{1D165}"), ('𝆺𝅥', "𝆺\u{1D165}"), ('𝆹𝅥𝅮', "𝆹\u{1D165}\u{1D16E}"),
+    ('𝆺𝅥𝅮', "𝆺\u{1D165}\u{1D16E}"), ('𝆹𝅥𝅯', "𝆹\u{1D165}\u{1D16F}"), ('𝆺𝅥𝅯', "𝆺\u{1D165}\u{1D16F}"),
+    ('丽', "丽"), ('丸', "丸"), ('乁', "乁"), ('𠄢', "𠄢"), ('你', "你"), ('侮', "侮"), ('侻', "侻"), ('倂', "倂"),
+    ('偺', "偺"), ('備', "備"), ('僧', "僧"), ('像', "像"), ('㒞', "㒞"), ('𠘺', "𠘺"), ('免', "免"), ('兔', "兔"),
+    ('兤', "兤"), ('具', "具"), ('𠔜', "𠔜"), ('㒹', "㒹"), ('內', "內"), ('再', "再"), ('𠕋', "𠕋"), ('冗', "冗"),
+    ('冤', "冤"), ('仌', "仌"), ('冬', "冬"), ('况', "况"), ('𩇟', "𩇟"), ('凵', "凵"), ('刃', "刃"), ('㓟', "㓟"),
+    ('刻', "刻"), ('剆', "剆"), ('割', "割"), ('剷', "剷"), ('㔕', "㔕"), ('勇', "勇"), ('勉', "勉"), ('勤', "勤"),
+    ('勺', "勺"), ('包', "包"), ('匆', "匆"), ('北', "北"), ('卉', "卉"), ('卑', "卑"), ('博', "博"), ('即', "即"),
+    ('卽', "卽"), ('卿', "卿"), ('卿', "卿"), ('卿', "卿"), ('𠨬', "𠨬"), ('灰', "灰"), ('及', "及"), ('叟', "叟"),
+    ('𠭣', "𠭣"), ('叫', "叫"), ('叱', "叱"), ('吆', "吆"), ('咞', "咞"), ('吸', "吸"), ('呈', "呈"), ('周', "周"),
+    ('咢', "咢"), ('哶', "哶"), ('唐', "唐"), ('啓', "啓"), ('啣', "啣"), ('善', "善"), ('善', "善"), ('喙', "喙"),
+    ('喫', "喫"), ('喳', "喳"), ('嗂', "嗂"), ('圖', "圖"), ('嘆', "嘆"), ('圗', "圗"), ('噑', "噑"), ('噴', "噴"),
+    ('切', "切"), ('壮', "壮"), ('城', "城"), ('埴', "埴"), ('堍', "堍"), ('型', "型"), ('堲', "堲"), ('報', "報"),
+    ('墬', "墬"), ('𡓤', "𡓤"), ('売', "売"), ('壷', "壷"), ('夆', "夆"), ('多', "多"), ('夢', "夢"), ('奢', "奢"),
+    ('𡚨', "𡚨"), ('𡛪', "𡛪"), ('姬', "姬"), ('娛', "娛"), ('娧', "娧"), ('姘', "姘"), ('婦', "婦"), ('㛮', "㛮"),
+    ('㛼', "㛼"), ('嬈', "嬈"), ('嬾', "嬾"), ('嬾', "嬾"), ('𡧈', "𡧈"), ('寃', "寃"), ('寘', "寘"), ('寧', "寧"),
+    ('寳', "寳"), ('𡬘', "𡬘"), ('寿', "寿"), ('将', "将"), ('当', "当"), ('尢', "尢"), ('㞁', "㞁"), ('屠', "屠"),
+    ('屮', "屮"), ('峀', "峀"), ('岍', "岍"), ('𡷤', "𡷤"), ('嵃', "嵃"), ('𡷦', "𡷦"), ('嵮', "嵮"), ('嵫', "嵫"),
+    ('嵼', "嵼"), ('巡', "巡"), ('巢', "巢"), ('㠯', "㠯"), ('巽', "巽"), ('帨', "帨"), ('帽', "帽"), ('幩', "幩"),
+    ('㡢', "㡢"), ('𢆃', "𢆃"), ('㡼', "㡼"), ('庰', "庰"), ('庳', "庳"), ('庶', "庶"), ('廊', "廊"), ('𪎒', "𪎒"),
+    ('廾', "廾"), ('𢌱', "𢌱"), ('𢌱', "𢌱"), ('舁', "舁"), ('弢', "弢"), ('弢', "弢"), ('㣇', "㣇"), ('𣊸', "𣊸"),
+    ('𦇚', "𦇚"), ('形', "形"), ('彫', "彫"), ('㣣', "㣣"), ('徚', "徚"), ('忍', "忍"), ('志', "志"), ('忹', "忹"),
+    ('悁', "悁"), ('㤺', "㤺"), ('㤜', "㤜"), ('悔', "悔"), ('𢛔', "𢛔"), ('惇', "惇"), ('慈', "慈"), ('慌', "慌"),
+    ('慎', "慎"), ('慌', "慌"), ('慺', "慺"), ('憎', "憎"), ('憲', "憲"), ('憤', "憤"), ('憯', "憯"), ('懞', "懞"),
+    ('懲', "懲"), ('懶', "懶"), ('成', "成"), ('戛', "戛"), ('扝', "扝"), ('抱', "抱"), ('拔', "拔"), ('捐', "捐"),
+    ('𢬌', "𢬌"), ('挽', "挽"), ('拼', "拼"), ('捨', "捨"), ('掃', "掃"), ('揤', "揤"), ('𢯱', "𢯱"), ('搢', "搢"),
+    ('揅', "揅"), ('掩', "掩"), ('㨮', "㨮"), ('摩', "摩"), ('摾', "摾"), ('撝', "撝"), ('摷', "摷"), ('㩬', "㩬"),
+    ('敏', "敏"), ('敬', "敬"), ('𣀊', "𣀊"), ('旣', "旣"), ('書', "書"), ('晉', "晉"), ('㬙', "㬙"), ('暑', "暑"),
+    ('㬈', "㬈"), ('㫤', "㫤"), ('冒', "冒"), ('冕', "冕"), ('最', "最"), ('暜', "暜"), ('肭', "肭"), ('䏙', "䏙"),
+    ('朗', "朗"), ('望', "望"), ('朡', "朡"), ('杞', "杞"), ('杓', "杓"), ('𣏃', "𣏃"), ('㭉', "㭉"), ('柺', "柺"),
+    ('枅', "枅"), ('桒', "桒"), ('梅', "梅"), ('𣑭', "𣑭"), ('梎', "梎"), ('栟', "栟"), ('椔', "椔"), ('㮝', "㮝"),
+    ('楂', "楂"), ('榣', "榣"), ('槪', "槪"), ('檨', "檨"), ('𣚣', "𣚣"), ('櫛', "櫛"), ('㰘', "㰘"), ('次', "次"),
+    ('𣢧', "𣢧"), ('歔', "歔"), ('㱎', "㱎"), ('歲', "歲"), ('殟', "殟"), ('殺', "殺"), ('殻', "殻"), ('𣪍', "𣪍"),
+    ('𡴋', "𡴋"), ('𣫺', "𣫺"), ('汎', "汎"), ('𣲼', "𣲼"), ('沿', "沿"), ('泍', "泍"), ('汧', "汧"), ('洖', "洖"),
+    ('派', "派"), ('海', "海"), ('流', "流"), ('浩', "浩"), ('浸', "浸"), ('涅', "涅"), ('𣴞', "𣴞"), ('洴', "洴"),
+    ('港', "港"), ('湮', "湮"), ('㴳', "㴳"), ('滋', "滋"), ('滇', "滇"), ('𣻑', "𣻑"), ('淹', "淹"), ('潮', "潮"),
+    ('𣽞', "𣽞"), ('𣾎', "𣾎"), ('濆', "濆"), ('瀹', "瀹"), ('瀞', "瀞"), ('瀛', "瀛"), ('㶖', "㶖"), ('灊', "灊"),
+    ('災', "災"), ('灷', "灷"), ('炭', "炭"), ('𠔥', "𠔥"), ('煅', "煅"), ('𤉣', "𤉣"), ('熜', "熜"), ('𤎫', "𤎫"),
+    ('爨', "爨"), ('爵', "爵"), ('牐', "牐"), ('𤘈', "𤘈"), ('犀', "犀"), ('犕', "犕"), ('𤜵', "𤜵"), ('𤠔', "𤠔"),
+    ('獺', "獺"), ('王', "王"), ('㺬', "㺬"), ('玥', "玥"), ('㺸', "㺸"), ('㺸', "㺸"), ('瑇', "瑇"), ('瑜', "瑜"),
+    ('瑱', "瑱"), ('璅', "璅"), ('瓊', "瓊"), ('㼛', "㼛"), ('甤', "甤"), ('𤰶', "𤰶"), ('甾', "甾"), ('𤲒', "𤲒"),
+    ('異', "異"), ('𢆟', "𢆟"), ('瘐', "瘐"), ('𤾡', "𤾡"), ('𤾸', "𤾸"), ('𥁄', "𥁄"), ('㿼', "㿼"), ('䀈', "䀈"),
+    ('直', "直"), ('𥃳', "𥃳"), ('𥃲', "𥃲"), ('𥄙', "𥄙"), ('𥄳', "𥄳"), ('眞', "眞"), ('真', "真"), ('真', "真"),
+    ('睊', "睊"), ('䀹', "䀹"), ('瞋', "瞋"), ('䁆', "䁆"), ('䂖', "䂖"), ('𥐝', "𥐝"), ('硎', "硎"), ('碌', "碌"),
+    ('磌', "磌"), ('䃣', "䃣"), ('𥘦', "𥘦"), ('祖', "祖"), ('𥚚', "𥚚"), ('𥛅', "𥛅"), ('福', "福"), ('秫', "秫"),
+    ('䄯', "䄯"), ('穀', "穀"), ('穊', "穊"), ('穏', "穏"), ('𥥼', "𥥼"), ('𥪧', "𥪧"), ('𥪧', "𥪧"), ('竮', "竮"),
+    ('䈂', "䈂"), ('𥮫', "𥮫"), ('篆', "篆"), ('築', "築"), ('䈧', "䈧"), ('𥲀', "𥲀"), ('糒', "糒"), ('䊠', "䊠"),
+    ('糨', "糨"), ('糣', "糣"), ('紀', "紀"), ('𥾆', "𥾆"), ('絣', "絣"), ('䌁', "䌁"), ('緇', "緇"), ('縂', "縂"),
+    ('繅', "繅"), ('䌴', "䌴"), ('𦈨', "𦈨"), ('𦉇', "𦉇"), ('䍙', "䍙"), ('𦋙', "𦋙"), ('罺', "罺"), ('𦌾', "𦌾"),
+    ('羕', "羕"), ('翺', "翺"), ('者', "者"), ('𦓚', "𦓚"), ('𦔣', "𦔣"), ('聠', "聠"), ('𦖨', "𦖨"), ('聰', "聰"),
+    ('𣍟', "𣍟"), ('䏕', "䏕"), ('育', "育"), ('脃', "脃"), ('䐋', "䐋"), ('脾', "脾"), ('媵', "媵"), ('𦞧', "𦞧"),
+    ('𦞵', "𦞵"), ('𣎓', "𣎓"), ('𣎜', "𣎜"), ('舁', "舁"), ('舄', "舄"), ('辞', "辞"), ('䑫', "䑫"), ('芑', "芑"),
+    ('芋', "芋"), ('芝', "芝"), ('劳', "劳"), ('花', "花"), ('芳', "芳"), ('芽', "芽"), ('苦', "苦"), ('𦬼', "𦬼"),
+    ('若', "若"), ('茝', "茝"), ('荣', "荣"), ('莭', "莭"), ('茣', "茣"), ('莽', "莽"), ('菧', "菧"), ('著', "著"),
+    ('荓', "荓"), ('菊', "菊"), ('菌', "菌"), ('菜', "菜"), ('𦰶', "𦰶"), ('𦵫', "𦵫"), ('𦳕', "𦳕"), ('䔫', "䔫"),
+    ('蓱', "蓱"), ('蓳', "蓳"), ('蔖', "蔖"), ('𧏊', "𧏊"), ('蕤', "蕤"), ('𦼬', "𦼬"), ('䕝', "䕝"), ('䕡', "䕡"),
+    ('𦾱', "𦾱"), ('𧃒', "𧃒"), ('䕫', "䕫"), ('虐', "虐"), ('虜', "虜"), ('虧', "虧"), ('虩', "虩"), ('蚩', "蚩"),
+    ('蚈', "蚈"), ('蜎', "蜎"), ('蛢', "蛢"), ('蝹', "蝹"), ('蜨', "蜨"), ('蝫', "蝫"), ('螆', "螆"), ('䗗', "䗗"),
+    ('蟡', "蟡"), ('蠁', "蠁"), ('䗹', "䗹"), ('衠', "衠"), ('衣', "衣"), ('𧙧', "𧙧"), ('裗', "裗"), ('裞', "裞"),
+    ('䘵', "䘵"), ('裺', "裺"), ('㒻', "㒻"), ('𧢮', "𧢮"), ('𧥦', "𧥦"), ('䚾', "䚾"), ('䛇', "䛇"), ('誠', "誠"),
+    ('諭', "諭"), ('變', "變"), ('豕', "豕"), ('𧲨', "𧲨"), ('貫', "貫"), ('賁', "賁"), ('贛', "贛"), ('起', "起"),
+    ('𧼯', "𧼯"), ('𠠄', "𠠄"), ('跋', "跋"), ('趼', "趼"), ('跰', "跰"), ('𠣞', "𠣞"), ('軔', "軔"), ('輸', "輸"),
+    ('𨗒', "𨗒"), ('𨗭', "𨗭"), ('邔', "邔"), ('郱', "郱"), ('鄑', "鄑"), ('𨜮', "𨜮"), ('鄛', "鄛"), ('鈸', "鈸"),
+    ('鋗', "鋗"), ('鋘', "鋘"), ('鉼', "鉼"), ('鏹', "鏹"), ('鐕', "鐕"), ('𨯺', "𨯺"), ('開', "開"), ('䦕', "䦕"),
+    ('閷', "閷"), ('𨵷', "𨵷"), ('䧦', "䧦"), ('雃', "雃"), ('嶲', "嶲"), ('霣', "霣"), ('𩅅', "𩅅"), ('𩈚', "𩈚"),
+    ('䩮', "䩮"), ('䩶', "䩶"), ('韠', "韠"), ('𩐊', "𩐊"), ('䪲', "䪲"), ('𩒖', "𩒖"), ('頋', "頋"), ('頋', "頋"),
+    ('頩', "頩"), ('𩖶', "𩖶"), ('飢', "飢"), ('䬳', "䬳"), ('餩', "餩"), ('馧', "馧"), ('駂', "駂"), ('駾', "駾"),
+    ('䯎', "䯎"), ('𩬰', "𩬰"), ('鬒', "鬒"), ('鱀', "鱀"), ('鳽', "鳽"), ('䳎', "䳎"), ('䳭', "䳭"), ('鵧', "鵧"),
+    ('𪃎', "𪃎"), ('䳸', "䳸"), ('𪄅', "𪄅"), ('𪈎', "𪈎"), ('𪊑', "𪊑"), ('麻', "麻"), ('䵖', "䵖"), ('黹', "黹"),
+    ('黾', "黾"), ('鼅', "鼅"), ('鼏', "鼏"), ('鼖', "鼖"), ('鼻', "鼻"), ('𪘀', "𪘀"),
+];
+
+/// Canonical combining classes of combining marks, as inclusive ranges
+/// Characters outside every range have class 0 (starters).
+const COMBINING_CLASSES: &[(char, char, u8)] = &[
+    ('\u{0300}', '\u{0314}', 230), ('\u{0315}', '\u{0315}', 232), ('\u{0316}', '\u{0319}', 220),
+    ('\u{031A}', '\u{031A}', 232), ('\u{031B}', '\u{031B}', 216), ('\u{031C}', '\u{0320}', 220),
+    ('\u{0321}', '\u{0322}', 202), ('\u{0323}', '\u{0326}', 220), ('\u{0327}', '\u{0328}', 202),
+    ('\u{0329}', '\u{0333}', 220), ('\u{0334}', '\u{0338}', 1), ('\u{0339}', '\u{033C}', 220),
+    ('\u{033D}', '\u{0344}', 230), ('\u{0345}', '\u{0345}', 240), ('\u{0346}', '\u{0346}', 230),
+    ('\u{0347}', '\u{0349}', 220), ('\u{034A}', '\u{034C}', 230), ('\u{034D}', '\u{034E}', 220),
+    ('\u{0350}', '\u{0352}', 230), ('\u{0353}', '\u{0356}', 220), ('\u{0357}', '\u{0357}', 230),
+    ('\u{0358}', '\u{0358}', 232), ('\u{0359}', '\u{035A}', 220), ('\u{035B}', '\u{035B}', 230),
+    ('\u{035C}', '\u{035C}', 233), ('\u{035D}', '\u{035E}', 234), ('\u{035F}', '\u{035F}', 233),
+    ('\u{0360}', '\u{0361}', 234), ('\u{0362}', '\u{0362}', 233), ('\u{0363}', '\u{036F}', 230),
+    ('\u{0483}', '\u{0487}', 230), ('\u{0591}', '\u{0591}', 220), ('\u{0592}', '\u{0595}', 230),
+    ('\u{0596}', '\u{0596}', 220), ('\u{0597}', '\u{0599}', 230), ('\u{059A}', '\u{059A}', 222),
+    ('\u{059B}', '\u{059B}', 220), ('\u{059C}', '\u{05A1}', 230), ('\u{05A2}', '\u{05A7}', 220),
+    ('\u{05A8}', '\u{05A9}', 230), ('\u{05AA}', '\u{05AA}', 220), ('\u{05AB}', '\u{05AC}', 230),
+    ('\u{05AD}', '\u{05AD}', 222), ('\u{05AE}', '\u{05AE}', 228), ('\u{05AF}', '\u{05AF}', 230),
+    ('\u{05B0}', '\u{05B0}', 10), ('\u{05B1}', '\u{05B1}', 11), ('\u{05B2}', '\u{05B2}', 12),
+    ('\u{05B3}', '\u{05B3}', 13), ('\u{05B4}', '\u{05B4}', 14), ('\u{05B5}', '\u{05B5}', 15),
+    ('\u{05B6}', '\u{05B6}', 16), ('\u{05B7}', '\u{05B7}', 17), ('\u{05B8}', '\u{05B8}', 18),
+    ('\u{05B9}', '\u{05BA}', 19), ('\u{05BB}', '\u{05BB}', 20), ('\u{05BC}', '\u{05BC}', 21),
+    ('\u{05BD}', '\u{05BD}', 22), ('\u{05BF}', '\u{05BF}', 23), ('\u{05C1}', '\u{05C1}', 24),
+    ('\u{05C2}', '\u{05C2}', 25), ('\u{05C4}', '\u{05C4}', 230), ('\u{05C5}', '\u{05C5}', 220),
+    ('\u{05C7}', '\u{05C7}', 18), ('\u{0610}', '\u{0617}', 230), ('\u{0618}', '\u{0618}', 30),
+    ('\u{0619}', '\u{0619}', 31), ('\u{061A}', '\u{061A}', 32), ('\u{064B}', '\u{064B}', 27),
+    ('\u{064C}', '\u{064C}', 28), ('\u{064D}', '\u{064D}', 29), ('\u{064E}', '\u{064E}', 30),
+    ('\u{064F}', '\u{064F}', 31), ('\u{0650}', '\u{0650}', 32), ('\u{0651}', '\u{0651}', 33),
+    ('\u{0652}', '\u{0652}', 34), ('\u{0653}', '\u{0654}', 230), ('\u{0655}', '\u{0656}', 220),
+    ('\u{0657}', '\u{065B}', 230), ('\u{065C}', '\u{065C}', 220), ('\u{065D}', '\u{065E}', 230),
+    ('\u{065F}', '\u{065F}', 220), ('\u{0670}', '\u{0670}', 35), ('\u{06D6}', '\u{06DC}', 230),
+    ('\u{06DF}', '\u{06E2}', 230), ('\u{06E3}', '\u{06E3}', 220), ('\u{06E4}', '\u{06E4}', 230),
+    ('\u{06E7}', '\u{06E8}', 230), ('\u{06EA}', '\u{06EA}', 220), ('\u{06EB}', '\u{06EC}', 230),
+    ('\u{06ED}', '\u{06ED}', 220), ('\u{0711}', '\u{0711}', 36), ('\u{0730}', '\u{0730}', 230),
+    ('\u{0731}', '\u{0731}', 220), ('\u{0732}', '\u{0733}', 230), ('\u{0734}', '\u{0734}', 220),
+    ('\u{0735}', '\u{0736}', 230), ('\u{0737}', '\u{0739}', 220), ('\u{073A}', '\u{073A}', 230),
+    ('\u{073B}', '\u{073C}', 220), ('\u{073D}', '\u{073D}', 230), ('\u{073E}', '\u{073E}', 220),
+    ('\u{073F}', '\u{0741}', 230), ('\u{0742}', '\u{0742}', 220), ('\u{0743}', '\u{0743}', 230),
+    ('\u{0744}', '\u{0744}', 220), ('\u{0745}', '\u{0745}', 230), ('\u{0746}', '\u{0746}', 220),
+    ('\u{0747}', '\u{0747}', 230), ('\u{0748}', '\u{0748}', 220), ('\u{0749}', '\u{074A}', 230),
+    ('\u{07EB}', '\u{07F1}', 230), ('\u{07F2}', '\u{07F2}', 220), ('\u{07F3}', '\u{07F3}', 230),
+    ('\u{07FD}', '\u{07FD}', 220), ('\u{0816}', '\u{0819}', 230), ('\u{081B}', '\u{0823}', 230),
+    ('\u{0825}', '\u{0827}', 230), ('\u{0829}', '\u{082D}', 230), ('\u{0859}', '\u{085B}', 220),
+    ('\u{0898}', '\u{0898}', 230), ('\u{0899}', '\u{089B}', 220), ('\u{089C}', '\u{089F}', 230),
+    ('\u{08CA}', '\u{08CE}', 230), ('\u{08CF}', '\u{08D3}', 220), ('\u{08D4}', '\u{08E1}', 230),
+    ('\u{08E3}', '\u{08E3}', 220), ('\u{08E4}', '\u{08E5}', 230), ('\u{08E6}', '\u{08E6}', 220),
+    ('\u{08E7}', '\u{08E8}', 230), ('\u{08E9}', '\u{08E9}', 220), ('\u{08EA}', '\u{08EC}', 230),
+    ('\u{08ED}', '\u{08EF}', 220), ('\u{08F0}', '\u{08F0}', 27), ('\u{08F1}', '\u{08F1}', 28),
+    ('\u{08F2}', '\u{08F2}', 29), ('\u{08F3}', '\u{08F5}', 230), ('\u{08F6}', '\u{08F6}', 220),
+    ('\u{08F7}', '\u{08F8}', 230), ('\u{08F9}', '\u{08FA}', 220), ('\u{08FB}', '\u{08FF}', 230),
+    ('\u{093C}', '\u{093C}', 7), ('\u{094D}', '\u{094D}', 9), ('\u{0951}', '\u{0951}', 230),
+    ('\u{0952}', '\u{0952}', 220), ('\u{0953}', '\u{0954}', 230), ('\u{09BC}', '\u{09BC}', 7),
+    ('\u{09CD}', '\u{09CD}', 9), ('\u{09FE}', '\u{09FE}', 230), ('\u{0A3C}', '\u{0A3C}', 7),
+    ('\u{0A4D}', '\u{0A4D}', 9), ('\u{0ABC}', '\u{0ABC}', 7), ('\u{0ACD}', '\u{0ACD}', 9),
+    ('\u{0B3C}', '\u{0B3C}', 7), ('\u{0B4D}', '\u{0B4D}', 9), ('\u{0BCD}', '\u{0BCD}', 9),
+    ('\u{0C3C}', '\u{0C3C}', 7), ('\u{0C4D}', '\u{0C4D}', 9), ('\u{0C55}', '\u{0C55}', 84),
+    ('\u{0C56}', '\u{0C56}', 91), ('\u{0CBC}', '\u{0CBC}', 7), ('\u{0CCD}', '\u{0CCD}', 9),
+    ('\u{0D3B}', '\u{0D3C}', 9), ('\u{0D4D}', '\u{0D4D}', 9), ('\u{0DCA}', '\u{0DCA}', 9),
+    ('\u{0E38}', '\u{0E39}', 103), ('\u{0E3A}', '\u{0E3A}', 9), ('\u{0E48}', '\u{0E4B}', 107),
+    ('\u{0EB8}', '\u{0EB9}', 118), ('\u{0EBA}', '\u{0EBA}', 9), ('\u{0EC8}', '\u{0ECB}', 122),
+    ('\u{0F18}', '\u{0F19}', 220), ('\u{0F35}', '\u{0F35}', 220), ('\u{0F37}', '\u{0F37}', 220),
+    ('\u{0F39}', '\u{0F39}', 216), ('\u{0F71}', '\u{0F71}', 129), ('\u{0F72}', '\u{0F72}', 130),
+    ('\u{0F74}', '\u{0F74}', 132), ('\u{0F7A}', '\u{0F7D}', 130), ('\u{0F80}', '\u{0F80}', 130),
+    ('\u{0F82}', '\u{0F83}', 230), ('\u{0F84}', '\u{0F84}', 9), ('\u{0F86}', '\u{0F87}', 230),
+    ('\u{0FC6}', '\u{0FC6}', 220), ('\u{1037}', '\u{1037}', 7), ('\u{1039}', '\u{103A}', 9),
+    ('\u{108D}', '\u{108D}', 220), ('\u{135D}', '\u{135F}', 230), ('\u{1714}', '\u{1715}', 9),
+    ('\u{1734}', '\u{1734}', 9), ('\u{17D2}', '\u{17D2}', 9), ('\u{17DD}', '\u{17DD}', 230),
+    ('\u{18A9}', '\u{18A9}', 228), ('\u{1939}', '\u{1939}', 222), ('\u{193A}', '\u{193A}', 230),
+    ('\u{193B}', '\u{193B}', 220), ('\u{1A17}', '\u{1A17}', 230), ('\u{1A18}', '\u{1A18}', 220),
+    ('\u{1A60}', '\u{1A60}', 9), ('\u{1A75}', '\u{1A7C}', 230), ('\u{1A7F}', '\u{1A7F}', 220),
+    ('\u{1AB0}', '\u{1AB4}', 230), ('\u{1AB5}', '\u{1ABA}', 220), ('\u{1ABB}', '\u{1ABC}', 230),
+    ('\u{1ABD}', '\u{1ABD}', 220), ('\u{1ABF}', '\u{1AC0}', 220), ('\u{1AC1}', '\u{1AC2}', 230),
+    ('\u{1AC3}', '\u{1AC4}', 220), ('\u{1AC5}', '\u{1AC9}', 230), ('\u{1ACA}', '\u{1ACA}', 220),
+    ('\u{1ACB}', '\u{1ACE}', 230), ('\u{1B34}', '\u{1B34}', 7), ('\u{1B44}', '\u{1B44}', 9),
+    ('\u{1B6B}', '\u{1B6B}', 230), ('\u{1B6C}', '\u{1B6C}', 220), ('\u{1B6D}', '\u{1B73}', 230),
+    ('\u{1BAA}', '\u{1BAB}', 9), ('\u{1BE6}', '\u{1BE6}', 7), ('\u{1BF2}', '\u{1BF3}', 9),
+    ('\u{1C37}', '\u{1C37}', 7), ('\u{1CD0}', '\u{1CD2}', 230), ('\u{1CD4}', '\u{1CD4}', 1),
+    ('\u{1CD5}', '\u{1CD9}', 220), ('\u{1CDA}', '\u{1CDB}', 230), ('\u{1CDC}', '\u{1CDF}', 220),
+    ('\u{1CE0}', '\u{1CE0}', 230), ('\u{1CE2}', '\u{1CE8}', 1), ('\u{1CED}', '\u{1CED}', 220),
+    ('\u{1CF4}', '\u{1CF4}', 230), ('\u{1CF8}', '\u{1CF9}', 230), ('\u{1DC0}', '\u{1DC1}', 230),
+    ('\u{1DC2}', '\u{1DC2}', 220), ('\u{1DC3}', '\u{1DC9}', 230), ('\u{1DCA}', '\u{1DCA}', 220),
+    ('\u{1DCB}', '\u{1DCC}', 230), ('\u{1DCD}', '\u{1DCD}', 234), ('\u{1DCE}', '\u{1DCE}', 214),
+    ('\u{1DCF}', '\u{1DCF}', 220), ('\u{1DD0}', '\u{1DD0}', 202), ('\u{1DD1}', '\u{1DF5}', 230),
+    ('\u{1DF6}', '\u{1DF6}', 232), ('\u{1DF7}', '\u{1DF8}', 228), ('\u{1DF9}', '\u{1DF9}', 220),
+    ('\u{1DFA}', '\u{1DFA}', 218), ('\u{1DFB}', '\u{1DFB}', 230), ('\u{1DFC}', '\u{1DFC}', 233),
+    ('\u{1DFD}', '\u{1DFD}', 220), ('\u{1DFE}', '\u{1DFE}', 230), ('\u{1DFF}', '\u{1DFF}', 220),
+    ('\u{20D0}', '\u{20D1}', 230), ('\u{20D2}', '\u{20D3}', 1), ('\u{20D4}', '\u{20D7}', 230),
+    ('\u{20D8}', '\u{20DA}', 1), ('\u{20DB}', '\u{20DC}', 230), ('\u{20E1}', '\u{20E1}', 230),
+    ('\u{20E5}', '\u{20E6}', 1), ('\u{20E7}', '\u{20E7}', 230), ('\u{20E8}', '\u{20E8}', 220),
+    ('\u{20E9}', '\u{20E9}', 230), ('\u{20EA}', '\u{20EB}', 1), ('\u{20EC}', '\u{20EF}', 220),
+    ('\u{20F0}', '\u{20F0}', 230), ('\u{2CEF}', '\u{2CF1}', 230), ('\u{2D7F}', '\u{2D7F}', 9),
+    ('\u{2DE0}', '\u{2DFF}', 230), ('\u{302A}', '\u{302A}', 218), ('\u{302B}', '\u{302B}', 228),
+    ('\u{302C}', '\u{302C}', 232), ('\u{302D}', '\u{302D}', 222), ('\u{302E}', '\u{302F}', 224),
+    ('\u{3099}', '\u{309A}', 8), ('\u{A66F}', '\u{A66F}', 230), ('\u{A674}', '\u{A67D}', 230),
+    ('\u{A69E}', '\u{A69F}', 230), ('\u{A6F0}', '\u{A6F1}', 230), ('\u{A806}', '\u{A806}', 9),
+    ('\u{A82C}', '\u{A82C}', 9), ('\u{A8C4}', '\u{A8C4}', 9), ('\u{A8E0}', '\u{A8F1}', 230),
+    ('\u{A92B}', '\u{A92D}', 220), ('\u{A953}', '\u{A953}', 9), ('\u{A9B3}', '\u{A9B3}', 7),
+    ('\u{A9C0}', '\u{A9C0}', 9), ('\u{AAB0}', '\u{AAB0}', 230), ('\u{AAB2}', '\u{AAB3}', 230),
+    ('\u{AAB4}', '\u{AAB4}', 220), ('\u{AAB7}', '\u{AAB8}', 230), ('\u{AABE}', '\u{AABF}', 230),
+    ('\u{AAC1}', '\u{AAC1}', 230), ('\u{AAF6}', '\u{AAF6}', 9), ('\u{ABED}', '\u{ABED}', 9),
+    ('\u{FB1E}', '\u{FB1E}', 26), ('\u{FE20}', '\u{FE26}', 230), ('\u{FE27}', '\u{FE2D}', 220),
+    ('\u{FE2E}', '\u{FE2F}', 230), ('\u{101FD}', '\u{101FD}', 220), ('\u{102E0}', '\u{102E0}', 220),
+    ('\u{10376}', '\u{1037A}', 230), ('\u{10A0D}', '\u{10A0D}', 220),
+    ('\u{10A0F}', '\u{10A0F}', 230), ('\u{10A38}', '\u{10A38}', 230), ('\u{10A39}', '\u{10A39}', 1),
+    ('\u{10A3A}', '\u{10A3A}', 220), ('\u{10A3F}', '\u{10A3F}', 9), ('\u{10AE5}', '\u{10AE5}', 230),
+    ('\u{10AE6}', '\u{10AE6}', 220), ('\u{10D24}', '\u{10D27}', 230),
+    ('\u{10EAB}', '\u{10EAC}', 230), ('\u{10F46}', '\u{10F47}', 220),
+    ('\u{10F48}', '\u{10F4A}', 230), ('\u{10F4B}', '\u{10F4B}', 220),
+    ('\u{10F4C}', '\u{10F4C}', 230), ('\u{10F4D}', '\u{10F50}', 220),
+    ('\u{10F82}', '\u{10F82}', 230), ('\u{10F83}', '\u{10F83}', 220),
+    ('\u{10F84}', '\u{10F84}', 230), ('\u{10F85}', '\u{10F85}', 220), ('\u{11046}', '\u{11046}', 9),
+    ('\u{11070}', '\u{11070}', 9), ('\u{1107F}', '\u{1107F}', 9), ('\u{110B9}', '\u{110B9}', 9),
+    ('\u{110BA}', '\u{110BA}', 7), ('\u{11100}', '\u{11102}', 230), ('\u{11133}', '\u{11134}', 9),
+    ('\u{11173}', '\u{11173}', 7), ('\u{111C0}', '\u{111C0}', 9), ('\u{111CA}', '\u{111CA}', 7),
+    ('\u{11235}', '\u{11235}', 9), ('\u{11236}', '\u{11236}', 7), ('\u{112E9}', '\u{112E9}', 7),
+    ('\u{112EA}', '\u{112EA}', 9), ('\u{1133B}', '\u{1133C}', 7), ('\u{1134D}', '\u{1134D}', 9),
+    ('\u{11366}', '\u{1136C}', 230), ('\u{11370}', '\u{11374}', 230), ('\u{11442}', '\u{11442}', 9),
+    ('\u{11446}', '\u{11446}', 7), ('\u{1145E}', '\u{1145E}', 230), ('\u{114C2}', '\u{114C2}', 9),
+    ('\u{114C3}', '\u{114C3}', 7), ('\u{115BF}', '\u{115BF}', 9), ('\u{115C0}', '\u{115C0}', 7),
+    ('\u{1163F}', '\u{1163F}', 9), ('\u{116B6}', '\u{116B6}', 9), ('\u{116B7}', '\u{116B7}', 7),
+    ('\u{1172B}', '\u{1172B}', 9), ('\u{11839}', '\u{11839}', 9), ('\u{1183A}', '\u{1183A}', 7),
+    ('\u{1193D}', '\u{1193E}', 9), ('\u{11943}', '\u{11943}', 7), ('\u{119E0}', '\u{119E0}', 9),
+    ('\u{11A34}', '\u{11A34}', 9), ('\u{11A47}', '\u{11A47}', 9), ('\u{11A99}', '\u{11A99}', 9),
+    ('\u{11C3F}', '\u{11C3F}', 9), ('\u{11D42}', '\u{11D42}', 7), ('\u{11D44}', '\u{11D45}', 9),
+    ('\u{11D97}', '\u{11D97}', 9), ('\u{16AF0}', '\u{16AF4}', 1), ('\u{16B30}', '\u{16B36}', 230),
+    ('\u{16FF0}', '\u{16FF1}', 6), ('\u{1BC9E}', '\u{1BC9E}', 1), ('\u{1D165}', '\u{1D166}', 216),
+    ('\u{1D167}', '\u{1D169}', 1), ('\u{1D16D}', '\u{1D16D}', 226), ('\u{1D16E}', '\u{1D172}', 216),
+    ('\u{1D17B}', '\u{1D182}', 220), ('\u{1D185}', '\u{1D189}', 230),
+    ('\u{1D18A}', '\u{1D18B}', 220), ('\u{1D1AA}', '\u{1D1AD}', 230),
+    ('\u{1D242}', '\u{1D244}', 230), ('\u{1E000}', '\u{1E006}', 230),
+    ('\u{1E008}', '\u{1E018}', 230), ('\u{1E01B}', '\u{1E021}', 230),
+    ('\u{1E023}', '\u{1E024}', 230), ('\u{1E026}', '\u{1E02A}', 230),
+    ('\u{1E130}', '\u{1E136}', 230), ('\u{1E2AE}', '\u{1E2AE}', 230),
+    ('\u{1E2EC}', '\u{1E2EF}', 230), ('\u{1E8D0}', '\u{1E8D6}', 220),
+    ('\u{1E944}', '\u{1E949}', 230), ('\u{1E94A}', '\u{1E94A}', 7),
+];
+
+/// Compatibility decompositions (NFKD) of characters whose compatibility
+/// form differs from their canonical one
+const COMPATIBILITY: &[(char, &str)] = &[
+    ('\u{00A0}', "\u{0020}"), ('¨', "\u{0020}\u{0308}"), ('ª', "a"), ('¯', "\u{0020}\u{0304}"),
+    ('²', "2"), ('³', "3"), ('´', "\u{0020}\u{0301}"), ('µ', "μ"), ('¸', "\u{0020}\u{0327}"),
+    ('¹', "1"), ('º', "o"), ('¼', "1⁄4"), ('½', "1⁄2"), ('¾', "3⁄4"), ('Ĳ', "IJ"), ('ĳ', "ij"),
+    ('Ŀ', "L·"), ('ŀ', "l·"), ('ŉ', "ʼn"), ('ſ', "s"), ('Ǆ', "DZ\u{030C}"), ('ǅ', "Dz\u{030C}"),
+    ('ǆ', "dz\u{030C}"), ('Ǉ', "LJ"), ('ǈ', "Lj"), ('ǉ', "lj"), ('Ǌ', "NJ"), ('ǋ', "Nj"),
+    ('ǌ', "nj"), ('Ǳ', "DZ"), ('ǲ', "Dz"), ('ǳ', "dz"), ('ʰ', "h"), ('ʱ', "ɦ"), ('ʲ', "j"),
+    ('ʳ', "r"), ('ʴ', "ɹ"), ('ʵ', "ɻ"), ('ʶ', "ʁ"), ('ʷ', "w"), ('ʸ', "y"),
+    ('˘', "\u{0020}\u{0306}"), ('˙', "\u{0020}\u{0307}"), ('˚', "\u{0020}\u{030A}"),
+    ('˛', "\u{0020}\u{0328}"), ('˜', "\u{0020}\u{0303}"), ('˝', "\u{0020}\u{030B}"), ('ˠ', "ɣ"),
+    ('ˡ', "l"), ('ˢ', "s"), ('ˣ', "x"), ('ˤ', "ʕ"), ('ͺ', "\u{0020}\u{0345}"),
+    ('΄', "\u{0020}\u{0301}"), ('΅', "\u{0020}\u{0308}\u{0301}"), ('ϐ', "β"), ('ϑ', "θ"),
+    ('ϒ', "Υ"), ('ϓ', "Υ\u{0301}"), ('ϔ', "Υ\u{0308}"), ('ϕ', "φ"), ('ϖ', "π"), ('ϰ', "κ"),
+    ('ϱ', "ρ"), ('ϲ', "ς"), ('ϴ', "Θ"), ('ϵ', "ε"), ('Ϲ', "Σ"), ('և', "եւ"), ('ٵ', "اٴ"),
+    ('ٶ', "وٴ"), ('ٷ', "ۇٴ"), ('ٸ', "يٴ"), ('ำ', "\u{0E4D}า"), ('ຳ', "\u{0ECD}າ"), ('ໜ', "ຫນ"),
+    ('ໝ', "ຫມ"), ('༌', "་"), ('\u{0F77}', "\u{0FB2}\u{0F71}\u{0F80}"),
+    ('\u{0F79}', "\u{0FB3}\u{0F71}\u{0F80}"), ('ჼ', "ნ"), ('ᴬ', "A"), ('ᴭ', "Æ"), ('ᴮ', "B"),
+    ('ᴰ', "D"), ('ᴱ', "E"), ('ᴲ', "Ǝ"), ('ᴳ', "G"), ('ᴴ', "H"), ('ᴵ', "I"), ('ᴶ', "J"), ('ᴷ', "K"),
+    ('ᴸ', "L"), ('ᴹ', "M"), ('ᴺ', "N"), ('ᴼ', "O"), ('ᴽ', "Ȣ"), ('ᴾ', "P"), ('ᴿ', "R"), ('ᵀ', "T"),
+    ('ᵁ', "U"), ('ᵂ', "W"), ('ᵃ', "a"), ('ᵄ', "ɐ"), ('ᵅ', "ɑ"), ('ᵆ', "ᴂ"), ('ᵇ', "b"), ('ᵈ', "d"),
+    ('ᵉ', "e"), ('ᵊ', "ə"), ('ᵋ', "ɛ"), ('ᵌ', "ɜ"), ('ᵍ', "g"), ('ᵏ', "k"), ('ᵐ', "m"), ('ᵑ', "ŋ"),
+    ('ᵒ', "o"), ('ᵓ', "ɔ"), ('ᵔ', "ᴖ"), ('ᵕ', "ᴗ"), ('ᵖ', "p"), ('ᵗ', "t"), ('ᵘ', "u"), ('ᵙ', "ᴝ"),
+    ('ᵚ', "ɯ"), ('ᵛ', "v"), ('ᵜ', "ᴥ"), ('ᵝ', "β"), ('ᵞ', "γ"), ('ᵟ', "δ"), ('ᵠ', "φ"), ('ᵡ', "χ"),
+    ('ᵢ', "i"), ('ᵣ', "r"), ('ᵤ', "u"), ('ᵥ', "v"), ('ᵦ', "β"), ('ᵧ', "γ"), ('ᵨ', "ρ"), ('ᵩ', "φ"),
+    ('ᵪ', "χ"), ('ᵸ', "н"), ('ᶛ', "ɒ"), ('ᶜ', "c"), ('ᶝ', "ɕ"), ('ᶞ', "ð"), ('ᶟ', "ɜ"), ('ᶠ', "f"),
+    ('ᶡ', "ɟ"), ('ᶢ', "ɡ"), ('ᶣ', "ɥ"), ('ᶤ', "ɨ"), ('ᶥ', "ɩ"), ('ᶦ', "ɪ"), ('ᶧ', "ᵻ"), ('ᶨ', "ʝ"),
+    ('ᶩ', "ɭ"), ('ᶪ', "ᶅ"), ('ᶫ', "ʟ"), ('ᶬ', "ɱ"), ('ᶭ', "ɰ"), ('ᶮ', "ɲ"), ('ᶯ', "ɳ"), ('ᶰ', "ɴ"),
+    ('ᶱ', "ɵ"), ('ᶲ', "ɸ"), ('ᶳ', "ʂ"), ('ᶴ', "ʃ"), ('ᶵ', "ƫ"), ('ᶶ', "ʉ"), ('ᶷ', "ʊ"), ('ᶸ', "ᴜ"),
+    ('ᶹ', "ʋ"), ('ᶺ', "ʌ"), ('ᶻ', "z"), ('ᶼ', "ʐ"), ('ᶽ', "ʑ"), ('ᶾ', "ʒ"), ('ᶿ', "θ"), ('ẚ', "aʾ"),
+    ('ẛ', "s\u{0307}"), ('᾽', "\u{0020}\u{0313}"), ('᾿', "\u{0020}\u{0313}"),
+    ('῀', "\u{0020}\u{0342}"), ('῁', "\u{0020}\u{0308}\u{0342}"), ('῍', "\u{0020}\u{0313}\u{0300}"),
+    ('῎', "\u{0020}\u{0313}\u{0301}"), ('῏', "\u{0020}\u{0313}\u{0342}"),
+    ('῝', "\u{0020}\u{0314}\u{0300}"), ('῞', "\u{0020}\u{0314}\u{0301}"),
+    ('῟', "\u{0020}\u{0314}\u{0342}"), ('῭', "\u{0020}\u{0308}\u{0300}"),
+    ('΅', "\u{0020}\u{0308}\u{0301}"), ('´', "\u{0020}\u{0301}"), ('῾', "\u{0020}\u{0314}"),
+    ('\u{2000}', "\u{0020}"), ('\u{2001}', "\u{0020}"), ('\u{2002}', "\u{0020}"),
+    ('\u{2003}', "\u{0020}"), ('\u{2004}', "\u{0020}"), ('\u{2005}', "\u{0020}"),
+    ('\u{2006}', "\u{0020}"), ('\u{2007}', "\u{0020}"), ('\u{2008}', "\u{0020}"),
+    ('\u{2009}', "\u{0020}"), ('\u{200A}', "\u{0020}"), ('‑', "‐"), ('‗', "\u{0020}\u{0333}"),
+    ('․', "."), ('‥', ".."), ('…', "..."), ('\u{202F}', "\u{0020}"), ('″', "′′"), ('‴', "′′′"),
+    ('‶', "‵‵"), ('‷', "‵‵‵"), ('‼', "!!"), ('‾', "\u{0020}\u{0305}"), ('⁇', "??"), ('⁈', "?!"),
+    ('⁉', "!?"), ('⁗', "′′′′"), ('\u{205F}', "\u{0020}"), ('⁰', "0"), ('ⁱ', "i"), ('⁴', "4"),
+    ('⁵', "5"), ('⁶', "6"), ('⁷', "7"), ('⁸', "8"), ('⁹', "9"), ('⁺', "+"), ('⁻', "−"), ('⁼', "="),
+    ('⁽', "("), ('⁾', ")"), ('ⁿ', "n"), ('₀', "0"), ('₁', "1"), ('₂', "2"), ('₃', "3"), ('₄', "4"),
+    ('₅', "5"), ('₆', "6"), ('₇', "7"), ('₈', "8"), ('₉', "9"), ('₊', "+"), ('₋', "−"), ('₌', "="),
+    ('₍', "("), ('₎', ")"), ('ₐ', "a"), ('ₑ', "e"), ('ₒ', "o"), ('ₓ', "x"), ('ₔ', "ə"), ('ₕ', "h"),
+    ('ₖ', "k"), ('ₗ', "l"), ('ₘ', "m"), ('ₙ', "n"), ('ₚ', "p"), ('ₛ', "s"), ('ₜ', "t"), ('₨', "Rs"),
+    ('℀', "a/c"), ('℁', "a/s"), ('ℂ', "C"), ('℃', "°C"), ('℅', "c/o"), ('℆', "c/u"), ('ℇ', "Ɛ"),
+    ('℉', "°F"), ('ℊ', "g"), ('ℋ', "H"), ('ℌ', "H"), ('ℍ', "H"), ('ℎ', "h"), ('ℏ', "ħ"), ('ℐ', "I"),
+    ('ℑ', "I"), ('ℒ', "L"), ('ℓ', "l"), ('ℕ', "N"), ('№', "No"), ('ℙ', "P"), ('ℚ', "Q"), ('ℛ', "R"),
+    ('ℜ', "R"), ('ℝ', "R"), ('℠', "SM"), ('℡', "TEL"), ('™', "TM"), ('ℤ', "Z"), ('ℨ', "Z"),
+    ('ℬ', "B"), ('ℭ', "C"), ('ℯ', "e"), ('ℰ', "E"), ('ℱ', "F"), ('ℳ', "M"), ('ℴ', "o"), ('ℵ', "א"),
+    ('ℶ', "ב"), ('ℷ', "ג"), ('ℸ', "ד"), ('ℹ', "i"), ('℻', "FAX"), ('ℼ', "π"), ('ℽ', "γ"),
+    ('ℾ', "Γ"), ('ℿ', "Π"), ('⅀', "∑"), ('ⅅ', "D"), ('ⅆ', "d"), ('ⅇ', "e"), ('ⅈ', "i"), ('ⅉ', "j"),
+    ('⅐', "1⁄7"), ('⅑', "1⁄9"), ('⅒', "1⁄10"), ('⅓', "1⁄3"), ('⅔', "2⁄3"), ('⅕', "1⁄5"),
+    ('⅖', "2⁄5"), ('⅗', "3⁄5"), ('⅘', "4⁄5"), ('⅙', "1⁄6"), ('⅚', "5⁄6"), ('⅛', "1⁄8"),
+    ('⅜', "3⁄8"), ('⅝', "5⁄8"), ('⅞', "7⁄8"), ('⅟', "1⁄"), ('Ⅰ', "I"), ('Ⅱ', "II"), ('Ⅲ', "III"),
+    ('Ⅳ', "IV"), ('Ⅴ', "V"), ('Ⅵ', "VI"), ('Ⅶ', "VII"), ('Ⅷ', "VIII"), ('Ⅸ', "IX"), ('Ⅹ', "X"),
+    ('Ⅺ', "XI"), ('Ⅻ', "XII"), ('Ⅼ', "L"), ('Ⅽ', "C"), ('Ⅾ', "D"), ('Ⅿ', "M"), ('ⅰ', "i"),
+    ('ⅱ', "ii"), ('ⅲ', "iii"), ('ⅳ', "iv"), ('ⅴ', "v"), ('ⅵ', "vi"), ('ⅶ', "vii"), ('ⅷ', "viii"),
+    ('ⅸ', "ix"), ('ⅹ', "x"), ('ⅺ', "xi"), ('ⅻ', "xii"), ('ⅼ', "l"), ('ⅽ', "c"), ('ⅾ', "d"),
+    ('ⅿ', "m"), ('↉', "0⁄3"), ('∬', "∫∫"), ('∭', "∫∫∫"), ('∯', "∮∮"), ('∰', "∮∮∮"), ('①', "1"),
+    ('②', "2"), ('③', "3"), ('④', "4"), ('⑤', "5"), ('⑥', "6"), ('⑦', "7"), ('⑧', "8"), ('⑨', "9"),
+    ('⑩', "10"), ('⑪', "11"), ('⑫', "12"), ('⑬', "13"), ('⑭', "14"), ('⑮', "15"), ('⑯', "16"),
+    ('⑰', "17"), ('⑱', "18"), ('⑲', "19"), ('⑳', "20"), ('⑴', "(1)"), ('⑵', "(2)"), ('⑶', "(3)"),
+    ('⑷', "(4)"), ('⑸', "(5)"), ('⑹', "(6)"), ('⑺', "(7)"), ('⑻', "(8)"), ('⑼', "(9)"),
+    ('⑽', "(10)"), ('⑾', "(11)"), ('⑿', "(12)"), ('⒀', "(13)"), ('⒁', "(14)"), ('⒂', "(15)"),
+    ('⒃', "(16)"), ('⒄', "(17)"), ('⒅', "(18)"), ('⒆', "(19)"), ('⒇', "(20)"), ('⒈', "1."),
+    ('⒉', "2."), ('⒊', "3."), ('⒋', "4."), ('⒌', "5."), ('⒍', "6."), ('⒎', "7."), ('⒏', "8."),
+    ('⒐', "9."), ('⒑', "10."), ('⒒', "11."), ('⒓', "12."), ('⒔', "13."), ('⒕', "14."), ('⒖', "15."),
+    ('⒗', "16."), ('⒘', "17."), ('⒙', "18."), ('⒚', "19."), ('⒛', "20."), ('⒜', "(a)"),
+    ('⒝', "(b)"), ('⒞', "(c)"), ('⒟', "(d)"), ('⒠', "(e)"), ('⒡', "(f)"), ('⒢', "(g)"),
+    ('⒣', "(h)"), ('⒤', "(i)"), ('⒥', "(j)"), ('⒦', "(k)"), ('⒧', "(l)"), ('⒨', "(m)"),
+    ('⒩', "(n)"), ('⒪', "(o)"), ('⒫', "(p)"), ('⒬', "(q)"), ('⒭', "(r)"), ('⒮', "(s)"),
+    ('⒯', "(t)"), ('⒰', "(u)"), ('⒱', "(v)"), ('⒲', "(w)"), ('⒳', "(x)"), ('⒴', "(y)"),
+    ('⒵', "(z)"), ('Ⓐ', "A"), ('Ⓑ', "B"), ('Ⓒ', "C"), ('Ⓓ', "D"), ('Ⓔ', "E"), ('Ⓕ', "F"),
+    ('Ⓖ', "G"), ('Ⓗ', "H"), ('Ⓘ', "I"), ('Ⓙ', "J"), ('Ⓚ', "K"), ('Ⓛ', "L"), ('Ⓜ', "M"), ('Ⓝ', "N"),
+    ('Ⓞ', "O"), ('Ⓟ', "P"), ('Ⓠ', "Q"), ('Ⓡ', "R"), ('Ⓢ', "S"), ('Ⓣ', "T"), ('Ⓤ', "U"), ('Ⓥ', "V"),
+    ('Ⓦ', "W"), ('Ⓧ', "X"), ('Ⓨ', "Y"), ('Ⓩ', "Z"), ('ⓐ', "a"), ('ⓑ', "b"), ('ⓒ', "c"), ('ⓓ', "d"),
+    ('ⓔ', "e"), ('ⓕ', "f"), ('ⓖ', "g"), ('ⓗ', "h"), ('ⓘ', "i"), ('ⓙ', "j"), ('ⓚ', "k"), ('ⓛ', "l"),
+    ('ⓜ', "m"), ('ⓝ', "n"), ('ⓞ', "o"), ('ⓟ', "p"), ('ⓠ', "q"), ('ⓡ', "r"), ('ⓢ', "s"), ('ⓣ', "t"),
+    ('ⓤ', "u"), ('ⓥ', "v"), ('ⓦ', "w"), ('ⓧ', "x"), ('ⓨ', "y"), ('ⓩ', "z"), ('⓪', "0"),
+    ('⨌', "∫∫∫∫"), ('⩴', "::="), ('⩵', "=="), ('⩶', "==="), ('ⱼ', "j"), ('ⱽ', "V"), ('ⵯ', "ⵡ"),
+    ('⺟', "母"), ('⻳', "龟"), ('⼀', "一"), ('⼁', "丨"), ('⼂', "丶"), ('⼃', "丿"), ('⼄', "乙"), ('⼅', "亅"),
+    ('⼆', "二"), ('⼇', "亠"), ('⼈', "人"), ('⼉', "儿"), ('⼊', "入"), ('⼋', "八"), ('⼌', "冂"), ('⼍', "冖"),
+    ('⼎', "冫"), ('⼏', "几"), ('⼐', "凵"), ('⼑', "刀"), ('⼒', "力"), ('⼓', "勹"), ('⼔', "匕"), ('⼕', "匚"),
+    ('⼖', "匸"), ('⼗', "十"), ('⼘', "卜"), ('⼙', "卩"), ('⼚', "厂"), ('⼛', "厶"), ('⼜', "又"), ('⼝', "口"),
+    ('⼞', "囗"), ('⼟', "土"), ('⼠', "士"), ('⼡', "夂"), ('⼢', "夊"), ('⼣', "夕"), ('⼤', "大"), ('⼥', "女"),
+    ('⼦', "子"), ('⼧', "宀"), ('⼨', "寸"), ('⼩', "小"), ('⼪', "尢"), ('⼫', "尸"), ('⼬', "屮"), ('⼭', "山"),
+    ('⼮', "巛"), ('⼯', "工"), ('⼰', "己"), ('⼱', "巾"), ('⼲', "干"), ('⼳', "幺"), ('⼴', "广"), ('⼵', "廴"),
+    ('⼶', "廾"), ('⼷', "弋"), ('⼸', "弓"), ('⼹', "彐"), ('⼺', "彡"), ('⼻', "彳"), ('⼼', "心"), ('⼽', "戈"),
+    ('⼾', "戶"), ('⼿', "手"), ('⽀', "支"), ('⽁', "攴"), ('⽂', "文"), ('⽃', "斗"), ('⽄', "斤"), ('⽅', "方"),
+    ('⽆', "无"), ('⽇', "日"), ('⽈', "曰"), ('⽉', "月"), ('⽊', "木"), ('⽋', "欠"), ('⽌', "止"), ('⽍', "歹"),
+    ('⽎', "殳"), ('⽏', "毋"), ('⽐', "比"), ('⽑', "毛"), ('⽒', "氏"), ('⽓', "气"), ('⽔', "水"), ('⽕', "火"),
+    ('⽖', "爪"), ('⽗', "父"), ('⽘', "爻"), ('⽙', "爿"), ('⽚', "片"), ('⽛', "牙"), ('⽜', "牛"), ('⽝', "犬"),
+    ('⽞', "玄"), ('⽟', "玉"), ('⽠', "瓜"), ('⽡', "瓦"), ('⽢', "甘"), ('⽣', "生"), ('⽤', "用"), ('⽥', "田"),
+    ('⽦', "疋"), ('⽧', "疒"), ('⽨', "癶"), ('⽩', "白"), ('⽪', "皮"), ('⽫', "皿"), ('⽬', "目"), ('⽭', "矛"),
+    ('⽮', "矢"), ('⽯', "石"), ('⽰', "示"), ('⽱', "禸"), ('⽲', "禾"), ('⽳', "穴"), ('⽴', "立"), ('⽵', "竹"),
+    ('⽶', "米"), ('⽷', "糸"), ('⽸', "缶"), ('⽹', "网"), ('⽺', "羊"), ('⽻', "羽"), ('⽼', "老"), ('⽽', "而"),
+    ('⽾', "耒"), ('⽿', "耳"), ('⾀', "聿"), ('⾁', "肉"), ('⾂', "臣"), ('⾃', "自"), ('⾄', "至"), ('⾅', "臼"),
+    ('⾆', "舌"), ('⾇', "舛"), ('⾈', "舟"), ('⾉', "艮"), ('⾊', "色"), ('⾋', "艸"), ('⾌', "虍"), ('⾍', "虫"),
+    ('⾎', "血"), ('⾏', "行"), ('⾐', "衣"), ('⾑', "襾"), ('⾒', "見"), ('⾓', "角"), ('⾔', "言"), ('⾕', "谷"),
+    ('⾖', "豆"), ('⾗', "豕"), ('⾘', "豸"), ('⾙', "貝"), ('⾚', "赤"), ('⾛', "走"), ('⾜', "足"), ('⾝', "身"),
+    ('⾞', "車"), ('⾟', "辛"), ('⾠', "辰"), ('⾡', "辵"), ('⾢', "邑"), ('⾣', "酉"), ('⾤', "釆"), ('⾥', "里"),
+    ('⾦', "金"), ('⾧', "長"), ('⾨', "門"), ('⾩', "阜"), ('⾪', "隶"), ('⾫', "隹"), ('⾬', "雨"), ('⾭', "靑"),
+    ('⾮', "非"), ('⾯', "面"), ('⾰', "革"), ('⾱', "韋"), ('⾲', "韭"), ('⾳', "音"), ('⾴', "頁"), ('⾵', "風"),
+    ('⾶', "飛"), ('⾷', "食"), ('⾸', "首"), ('⾹', "香"), ('⾺', "馬"), ('⾻', "骨"), ('⾼', "高"), ('⾽', "髟"),
+    ('⾾', "鬥"), ('⾿', "鬯"), ('⿀', "鬲"), ('⿁', "鬼"), ('⿂', "魚"), ('⿃', "鳥"), ('⿄', "鹵"), ('⿅', "鹿"),
+    ('⿆', "麥"), ('⿇', "麻"), ('⿈', "黃"), ('⿉', "黍"), ('⿊', "黑"), ('⿋', "黹"), ('⿌', "黽"), ('⿍', "鼎"),
+    ('⿎', "鼓"), ('⿏', "鼠"), ('⿐', "鼻"), ('⿑', "齊"), ('⿒', "齒"), ('⿓', "龍"), ('⿔', "龜"), ('⿕', "龠"),
+    ('\u{3000}', "\u{0020}"), ('〶', "〒"), ('〸', "十"), ('〹', "卄"), ('〺', "卅"),
+    ('゛', "\u{0020}\u{3099}"), ('゜', "\u{0020}\u{309A}"), ('ゟ', "より"), ('ヿ', "コト"), ('ㄱ', "ᄀ"),
+    ('ㄲ', "ᄁ"), ('ㄳ', "ᆪ"), ('ㄴ', "ᄂ"), ('ㄵ', "ᆬ"), ('ㄶ', "ᆭ"), ('ㄷ', "ᄃ"), ('ㄸ', "ᄄ"), ('ㄹ', "ᄅ"),
+    ('ㄺ', "ᆰ"), ('ㄻ', "ᆱ"), ('ㄼ', "ᆲ"), ('ㄽ', "ᆳ"), ('ㄾ', "ᆴ"), ('ㄿ', "ᆵ"), ('ㅀ', "ᄚ"), ('ㅁ', "ᄆ"),
+    ('ㅂ', "ᄇ"), ('ㅃ', "ᄈ"), ('ㅄ', "ᄡ"), ('ㅅ', "ᄉ"), ('ㅆ', "ᄊ"), ('ㅇ', "ᄋ"), ('ㅈ', "ᄌ"), ('ㅉ', "ᄍ"),
+    ('ㅊ', "ᄎ"), ('ㅋ', "ᄏ"), ('ㅌ', "ᄐ"), ('ㅍ', "ᄑ"), ('ㅎ', "ᄒ"), ('ㅏ', "ᅡ"), ('ㅐ', "ᅢ"), ('ㅑ', "ᅣ"),
+    ('ㅒ', "ᅤ"), ('ㅓ', "ᅥ"), ('ㅔ', "ᅦ"), ('ㅕ', "ᅧ"), ('ㅖ', "ᅨ"), ('ㅗ', "ᅩ"), ('ㅘ', "ᅪ"), ('ㅙ', "ᅫ"),
+    ('ㅚ', "ᅬ"), ('ㅛ', "ᅭ"), ('ㅜ', "ᅮ"), ('ㅝ', "ᅯ"), ('ㅞ', "ᅰ"), ('ㅟ', "ᅱ"), ('ㅠ', "ᅲ"), ('ㅡ', "ᅳ"),
+    ('ㅢ', "ᅴ"), ('ㅣ', "ᅵ"), ('ㅤ', "ᅠ"), ('ㅥ', "ᄔ"), ('ㅦ', "ᄕ"), ('ㅧ', "ᇇ"), ('ㅨ', "ᇈ"), ('ㅩ', "ᇌ"),
+    ('ㅪ', "ᇎ"), ('ㅫ', "ᇓ"), ('ㅬ', "ᇗ"), ('ㅭ', "ᇙ"), ('ㅮ', "ᄜ"), ('ㅯ', "ᇝ"), ('ㅰ', "ᇟ"), ('ㅱ', "ᄝ"),
+    ('ㅲ', "ᄞ"), ('ㅳ', "ᄠ"), ('ㅴ', "ᄢ"), ('ㅵ', "ᄣ"), ('ㅶ', "ᄧ"), ('ㅷ', "ᄩ"), ('ㅸ', "ᄫ"), ('ㅹ', "ᄬ"),
+    ('ㅺ', "ᄭ"), ('ㅻ', "ᄮ"), ('ㅼ', "ᄯ"), ('ㅽ', "ᄲ"), ('ㅾ', "ᄶ"), ('ㅿ', "ᅀ"), ('ㆀ', "ᅇ"), ('ㆁ', "ᅌ"),
+    ('ㆂ', "ᇱ"), ('ㆃ', "ᇲ"), ('ㆄ', "ᅗ"), ('ㆅ', "ᅘ"), ('ㆆ', "ᅙ"), ('ㆇ', "ᆄ"), ('ㆈ', "ᆅ"), ('ㆉ', "ᆈ"),
+    ('ㆊ', "ᆑ"), ('ㆋ', "ᆒ"), ('ㆌ', "ᆔ"), ('ㆍ', "ᆞ"), ('ㆎ', "ᆡ"), ('㆒', "一"), ('㆓', "二"), ('㆔', "三"),
+    ('㆕', "四"), ('㆖', "上"), ('㆗', "中"), ('㆘', "下"), ('㆙', "甲"), ('㆚', "乙"), ('㆛', "丙"), ('㆜', "丁"),
+    ('㆝', "天"), ('㆞', "地"), ('㆟', "人"), ('㈀', "(ᄀ)"), ('㈁', "(ᄂ)"), ('㈂', "(ᄃ)"), ('㈃', "(ᄅ)"),
+    ('㈄', "(ᄆ)"), ('㈅', "(ᄇ)"), ('㈆', "(ᄉ)"), ('㈇', "(ᄋ)"), ('㈈', "(ᄌ)"), ('㈉', "(ᄎ)"),
+    ('㈊', "(ᄏ)"), ('㈋', "(ᄐ)"), ('㈌', "(ᄑ)"), ('㈍', "(ᄒ)"), ('㈎', "(가)"), ('㈏', "(나)"),
+    ('㈐', "(다)"), ('㈑', "(라)"), ('㈒', "(마)"), ('㈓', "(바)"), ('㈔', "(사)"), ('㈕', "(아)"),
+    ('㈖', "(자)"), ('㈗', "(차)"), ('㈘', "(카)"), ('㈙', "(타)"), ('㈚', "(파)"), ('㈛', "(하)"),
+    ('㈜', "(주)"), ('㈝', "(오전)"), ('㈞', "(오후)"), ('㈠', "(一)"), ('㈡', "(二)"), ('㈢', "(三)"),
+    ('㈣', "(四)"), ('㈤', "(五)"), ('㈥', "(六)"), ('㈦', "(七)"), ('㈧', "(八)"), ('㈨', "(九)"),
+    ('㈩', "(十)"), ('㈪', "(月)"), ('㈫', "(火)"), ('㈬', "(水)"), ('㈭', "(木)"), ('㈮', "(金)"),
+    ('㈯', "(土)"), ('㈰', "(日)"), ('㈱', "(株)"), ('㈲', "(有)"), ('㈳', "(社)"), ('㈴', "(名)"),
+    ('㈵', "(特)"), ('㈶', "(財)"), ('㈷', "(祝)"), ('㈸', "(労)"), ('㈹', "(代)"), ('㈺', "(呼)"),
+    ('㈻', "(学)"), ('㈼', "(監)"), ('㈽', "(企)"), ('㈾', "(資)"), ('㈿', "(協)"), ('㉀', "(祭)"),
+    ('㉁', "(休)"), ('㉂', "(自)"), ('㉃', "(至)"), ('㉄', "問"), ('㉅', "幼"), ('㉆', "文"), ('㉇', "箏"),
+    ('㉐', "PTE"), ('㉑', "21"), ('㉒', "22"), ('㉓', "23"), ('㉔', "24"), ('㉕', "25"), ('㉖', "26"),
+    ('㉗', "27"), ('㉘', "28"), ('㉙', "29"), ('㉚', "30"), ('㉛', "31"), ('㉜', "32"), ('㉝', "33"),
+    ('㉞', "34"), ('㉟', "35"), ('㉠', "ᄀ"), ('㉡', "ᄂ"), ('㉢', "ᄃ"), ('㉣', "ᄅ"), ('㉤', "ᄆ"),
+    ('㉥', "ᄇ"), ('㉦', "ᄉ"), ('㉧', "ᄋ"), ('㉨', "ᄌ"), ('㉩', "ᄎ"), ('㉪', "ᄏ"), ('㉫', "ᄐ"), ('㉬', "ᄑ"),
+    ('㉭', "ᄒ"), ('㉮', "가"), ('㉯', "나"), ('㉰', "다"), ('㉱', "라"), ('㉲', "마"), ('㉳', "바"),
+    ('㉴', "사"), ('㉵', "아"), ('㉶', "자"), ('㉷', "차"), ('㉸', "카"), ('㉹', "타"), ('㉺', "파"),
+    ('㉻', "하"), ('㉼', "참고"), ('㉽', "주의"), ('㉾', "우"), ('㊀', "一"), ('㊁', "二"), ('㊂', "三"),
+    ('㊃', "四"), ('㊄', "五"), ('㊅', "六"), ('㊆', "七"), ('㊇', "八"), ('㊈', "九"), ('㊉', "十"), ('㊊', "月"),
+    ('㊋', "火"), ('㊌', "水"), ('㊍', "木"), ('㊎', "金"), ('㊏', "土"), ('㊐', "日"), ('㊑', "株"), ('㊒', "有"),
+    ('㊓', "社"), ('㊔', "名"), ('㊕', "特"), ('㊖', "財"), ('㊗', "祝"), ('㊘', "労"), ('㊙', "秘"), ('㊚', "男"),
+    ('㊛', "女"), ('㊜', "適"), ('㊝', "優"), ('㊞', "印"), ('㊟', "注"), ('㊠', "項"), ('㊡', "休"), ('㊢', "写"),
+    ('㊣', "正"), ('㊤', "上"), ('㊥', "中"), ('㊦', "下"), ('㊧', "左"), ('㊨', "右"), ('㊩', "医"), ('㊪', "宗"),
+    ('㊫', "学"), ('㊬', "監"), ('㊭', "企"), ('㊮', "資"), ('㊯', "協"), ('㊰', "夜"), ('㊱', "36"),
+    ('㊲', "37"), ('㊳', "38"), ('㊴', "39"), ('㊵', "40"), ('㊶', "41"), ('㊷', "42"), ('㊸', "43"),
+    ('㊹', "44"), ('㊺', "45"), ('㊻', "46"), ('㊼', "47"), ('㊽', "48"), ('㊾', "49"), ('㊿', "50"),
+    ('㋀', "1月"), ('㋁', "2月"), ('㋂', "3月"), ('㋃', "4月"), ('㋄', "5月"), ('㋅', "6月"), ('㋆', "7月"),
+    ('㋇', "8月"), ('㋈', "9月"), ('㋉', "10月"), ('㋊', "11月"), ('㋋', "12月"), ('㋌', "Hg"), ('㋍', "erg"),
+    ('㋎', "eV"), ('㋏', "LTD"), ('㋐', "ア"), ('㋑', "イ"), ('㋒', "ウ"), ('㋓', "エ"), ('㋔', "オ"),
+    ('㋕', "カ"), ('㋖', "キ"), ('㋗', "ク"), ('㋘', "ケ"), ('㋙', "コ"), ('㋚', "サ"), ('㋛', "シ"), ('㋜', "ス"),
+    ('㋝', "セ"), ('㋞', "ソ"), ('㋟', "タ"), ('㋠', "チ"), ('㋡', "ツ"), ('㋢', "テ"), ('㋣', "ト"), ('㋤', "ナ"),
+    ('㋥', "ニ"), ('㋦', "ヌ"), ('㋧', "ネ"), ('㋨', "ノ"), ('㋩', "ハ"), ('㋪', "ヒ"), ('㋫', "フ"), ('㋬', "ヘ"),
+    ('㋭', "ホ"), ('㋮', "マ"), ('㋯', "ミ"), ('㋰', "ム"), ('㋱', "メ"), ('㋲', "モ"), ('㋳', "ヤ"), ('㋴', "ユ"),
+    ('㋵', "ヨ"), ('㋶', "ラ"), ('㋷', "リ"), ('㋸', "ル"), ('㋹', "レ"), ('㋺', "ロ"), ('㋻', "ワ"), ('㋼', "ヰ"),
+    ('㋽', "ヱ"), ('㋾', "ヲ"), ('㋿', "令和"), ('㌀', "アハ\u{309A}ート"), ('㌁', "アルファ"),
+    ('㌂', "アンヘ\u{309A}ア"), ('㌃', "アール"), ('㌄', "イニンク\u{3099}"), ('㌅', "インチ"), ('㌆', "ウォン"),
+    ('㌇', "エスクート\u{3099}"), ('㌈', "エーカー"), ('㌉', "オンス"), ('㌊', "オーム"), ('㌋', "カイリ"), ('㌌', "カラット"),
+    ('㌍', "カロリー"), ('㌎', "カ\u{3099}ロン"), ('㌏', "カ\u{3099}ンマ"), ('㌐', "キ\u{3099}カ\u{3099}"),
+    ('㌑', "キ\u{3099}ニー"), ('㌒', "キュリー"), ('㌓', "キ\u{3099}ルタ\u{3099}ー"), ('㌔', "キロ"),
+    ('㌕', "キロク\u{3099}ラム"), ('㌖', "キロメートル"), ('㌗', "キロワット"), ('㌘', "ク\u{3099}ラム"),
+    ('㌙', "ク\u{3099}ラムトン"), ('㌚', "クルセ\u{3099}イロ"), ('㌛', "クローネ"), ('㌜', "ケース"), ('㌝', "コルナ"),
+    ('㌞', "コーホ\u{309A}"), ('㌟', "サイクル"), ('㌠', "サンチーム"), ('㌡', "シリンク\u{3099}"), ('㌢', "センチ"),
+    ('㌣', "セント"), ('㌤', "タ\u{3099}ース"), ('㌥', "テ\u{3099}シ"), ('㌦', "ト\u{3099}ル"), ('㌧', "トン"),
+    ('㌨', "ナノ"), ('㌩', "ノット"), ('㌪', "ハイツ"), ('㌫', "ハ\u{309A}ーセント"), ('㌬', "ハ\u{309A}ーツ"),
+    ('㌭', "ハ\u{3099}ーレル"), ('㌮', "ヒ\u{309A}アストル"), ('㌯', "ヒ\u{309A}クル"), ('㌰', "ヒ\u{309A}コ"),
+    ('㌱', "ヒ\u{3099}ル"), ('㌲', "ファラット\u{3099}"), ('㌳', "フィート"), ('㌴', "フ\u{3099}ッシェル"),
+    ('㌵', "フラン"), ('㌶', "ヘクタール"), ('㌷', "ヘ\u{309A}ソ"), ('㌸', "ヘ\u{309A}ニヒ"), ('㌹', "ヘルツ"),
+    ('㌺', "ヘ\u{309A}ンス"), ('㌻', "ヘ\u{309A}ーシ\u{3099}"), ('㌼', "ヘ\u{3099}ータ"), ('㌽', "ホ\u{309A}イント"),
+    ('㌾', "ホ\u{3099}ルト"), ('㌿', "ホン"), ('㍀', "ホ\u{309A}ント\u{3099}"), ('㍁', "ホール"), ('㍂', "ホーン"),
+    ('㍃', "マイクロ"), ('㍄', "マイル"), ('㍅', "マッハ"), ('㍆', "マルク"), ('㍇', "マンション"), ('㍈', "ミクロン"),
+    ('㍉', "ミリ"), ('㍊', "ミリハ\u{3099}ール"), ('㍋', "メカ\u{3099}"), ('㍌', "メカ\u{3099}トン"), ('㍍', "メートル"),
+    ('㍎', "ヤート\u{3099}"), ('㍏', "ヤール"), ('㍐', "ユアン"), ('㍑', "リットル"), ('㍒', "リラ"),
+    ('㍓', "ルヒ\u{309A}ー"), ('㍔', "ルーフ\u{3099}ル"), ('㍕', "レム"), ('㍖', "レントケ\u{3099}ン"), ('㍗', "ワット"),
+    ('㍘', "0点"), ('㍙', "1点"), ('㍚', "2点"), ('㍛', "3点"), ('㍜', "4点"), ('㍝', "5点"), ('㍞', "6点"),
+    ('㍟', "7点"), ('㍠', "8点"), ('㍡', "9点"), ('㍢', "10点"), ('㍣', "11点"), ('㍤', "12点"), ('㍥', "13点"),
+    ('㍦', "14点"), ('㍧', "15点"), ('㍨', "16点"), ('㍩', "17点"), ('㍪', "18点"), ('㍫', "19点"),
+    ('㍬', "20点"), ('㍭', "21点"), ('㍮', "22点"), ('㍯', "23点"), ('㍰', "24点"), ('㍱', "hPa"), ('㍲', "da"),
+    ('㍳', "AU"), ('㍴', "bar"), ('㍵', "oV"), ('㍶', "pc"), ('㍷', "dm"), ('㍸', "dm2"), ('㍹', "dm3"),
+    ('㍺', "IU"), ('㍻', "平成"), ('㍼', "昭和"), ('㍽', "大正"), ('㍾', "明治"), ('㍿', "株式会社"), ('㎀', "pA"),
+    ('㎁', "nA"), ('㎂', "μA"), ('㎃', "mA"), ('㎄', "kA"), ('㎅', "KB"), ('㎆', "MB"), ('㎇', "GB"),
+    ('㎈', "cal"), ('㎉', "kcal"), ('㎊', "pF"), ('㎋', "nF"), ('㎌', "μF"), ('㎍', "μg"), ('㎎', "mg"),
+    ('㎏', "kg"), ('㎐', "Hz"), ('㎑', "kHz"), ('㎒', "MHz"), ('㎓', "GHz"), ('㎔', "THz"), ('㎕', "μl"),
+    ('㎖', "ml"), ('㎗', "dl"), ('㎘', "kl"), ('㎙', "fm"), ('㎚', "nm"), ('㎛', "μm"), ('㎜', "mm"),
+    ('㎝', "cm"), ('㎞', "km"), ('㎟', "mm2"), ('㎠', "cm2"), ('㎡', "m2"), ('㎢', "km2"), ('㎣', "mm3"),
+    ('㎤', "cm3"), ('㎥', "m3"), ('㎦', "km3"), ('㎧', "m∕s"), ('㎨', "m∕s2"), ('㎩', "Pa"), ('㎪', "kPa"),
+    ('㎫', "MPa"), ('㎬', "GPa"), ('㎭', "rad"), ('㎮', "rad∕s"), ('㎯', "rad∕s2"), ('㎰', "ps"),
+    ('㎱', "ns"), ('㎲', "μs"), ('㎳', "ms"), ('㎴', "pV"), ('㎵', "nV"), ('㎶', "μV"), ('㎷', "mV"),
+    ('㎸', "kV"), ('㎹', "MV"), ('㎺', "pW"), ('㎻', "nW"), ('㎼', "μW"), ('㎽', "mW"), ('㎾', "kW"),
+    ('㎿', "MW"), ('㏀', "kΩ"), ('㏁', "MΩ"), ('㏂', "a.m."), ('㏃', "Bq"), ('㏄', "cc"), ('㏅', "cd"),
+    ('㏆', "C∕kg"), ('㏇', "Co."), ('㏈', "dB"), ('㏉', "Gy"), ('㏊', "ha"), ('㏋', "HP"), ('㏌', "in"),
+    ('㏍', "KK"), ('㏎', "KM"), ('㏏', "kt"), ('㏐', "lm"), ('㏑', "ln"), ('㏒', "log"), ('㏓', "lx"),
+    ('㏔', "mb"), ('㏕', "mil"), ('㏖', "mol"), ('㏗', "PH"), ('㏘', "p.m."), ('㏙', "PPM"), ('㏚', "PR"),
+    ('㏛', "sr"), ('㏜', "Sv"), ('㏝', "Wb"), ('㏞', "V∕m"), ('㏟', "A∕m"), ('㏠', "1日"), ('㏡', "2日"),
+    ('㏢', "3日"), ('㏣', "4日"), ('㏤', "5日"), ('㏥', "6日"), ('㏦', "7日"), ('㏧', "8日"), ('㏨', "9日"),
+    ('㏩', "10日"), ('㏪', "11日"), ('㏫', "12日"), ('㏬', "13日"), ('㏭', "14日"), ('㏮', "15日"),
+    ('㏯', "16日"), ('㏰', "17日"), ('㏱', "18日"), ('㏲', "19日"), ('㏳', "20日"), ('㏴', "21日"),
+    ('㏵', "22日"), ('㏶', "23日"), ('㏷', "24日"), ('㏸', "25日"), ('㏹', "26日"), ('㏺', "27日"),
+    ('㏻', "28日"), ('㏼', "29日"), ('㏽', "30日"), ('㏾', "31日"), ('㏿', "gal"), ('ꚜ', "ъ"), ('ꚝ', "ь"),
+    ('ꝰ', "ꝯ"), ('ꟲ', "C"), ('ꟳ', "F"), ('ꟴ', "Q"), ('ꟸ', "Ħ"), ('ꟹ', "œ"), ('ꭜ', "ꜧ"), ('ꭝ', "ꬷ"),
+    ('ꭞ', "ɫ"), ('ꭟ', "ꭒ"), ('ꭩ', "ʍ"), ('ﬀ', "ff"), ('ﬁ', "fi"), ('ﬂ', "fl"), ('ﬃ', "ffi"),
+    ('ﬄ', "ffl"), ('ﬅ', "st"), ('ﬆ', "st"), ('ﬓ', "մն"), ('ﬔ', "մե"), ('ﬕ', "մի"), ('ﬖ', "վն"),
+    ('ﬗ', "մխ"), ('ﬠ', "ע"), ('ﬡ', "א"), ('ﬢ', "ד"), ('ﬣ', "ה"), ('ﬤ', "כ"), ('ﬥ', "ל"), ('ﬦ', "ם"),
+    ('ﬧ', "ר"), ('ﬨ', "ת"), ('﬩', "+"), ('ﭏ', "אל"), ('ﭐ', "ٱ"), ('ﭑ', "ٱ"), ('ﭒ', "ٻ"), ('ﭓ', "ٻ"),
+    ('ﭔ', "ٻ"), ('ﭕ', "ٻ"), ('ﭖ', "پ"), ('ﭗ', "پ"), ('ﭘ', "پ"), ('ﭙ', "پ"), ('ﭚ', "ڀ"), ('ﭛ', "ڀ"),
+    ('ﭜ', "ڀ"), ('ﭝ', "ڀ"), ('ﭞ', "ٺ"), ('ﭟ', "ٺ"), ('ﭠ', "ٺ"), ('ﭡ', "ٺ"), ('ﭢ', "ٿ"), ('ﭣ', "ٿ"),
+    ('ﭤ', "ٿ"), ('ﭥ', "ٿ"), ('ﭦ', "ٹ"), ('ﭧ', "ٹ"), ('ﭨ', "ٹ"), ('ﭩ', "ٹ"), ('ﭪ', "ڤ"), ('ﭫ', "ڤ"),
+    ('ﭬ', "ڤ"), ('ﭭ', "ڤ"), ('ﭮ', "ڦ"), ('ﭯ', "ڦ"), ('ﭰ', "ڦ"), ('ﭱ', "ڦ"), ('ﭲ', "ڄ"), ('ﭳ', "ڄ"),
+    ('ﭴ', "ڄ"), ('ﭵ', "ڄ"), ('ﭶ', "ڃ"), ('ﭷ', "ڃ"), ('ﭸ', "ڃ"), ('ﭹ', "ڃ"), ('ﭺ', "چ"), ('ﭻ', "چ"),
+    ('ﭼ', "چ"), ('ﭽ', "چ"), ('ﭾ', "ڇ"), ('ﭿ', "ڇ"), ('ﮀ', "ڇ"), ('ﮁ', "ڇ"), ('ﮂ', "ڍ"), ('ﮃ', "ڍ"),
+    ('ﮄ', "ڌ"), ('ﮅ', "ڌ"), ('ﮆ', "ڎ"), ('ﮇ', "ڎ"), ('ﮈ', "ڈ"), ('ﮉ', "ڈ"), ('ﮊ', "ژ"), ('ﮋ', "ژ"),
+    ('ﮌ', "ڑ"), ('ﮍ', "ڑ"), ('ﮎ', "ک"), ('ﮏ', "ک"), ('ﮐ', "ک"), ('ﮑ', "ک"), ('ﮒ', "گ"), ('ﮓ', "گ"),
+    ('ﮔ', "گ"), ('ﮕ', "گ"), ('ﮖ', "ڳ"), ('ﮗ', "ڳ"), ('ﮘ', "ڳ"), ('ﮙ', "ڳ"), ('ﮚ', "ڱ"), ('ﮛ', "ڱ"),
+    ('ﮜ', "ڱ"), ('ﮝ', "ڱ"), ('ﮞ', "ں"), ('ﮟ', "ں"), ('ﮠ', "ڻ"), ('ﮡ', "ڻ"), ('ﮢ', "ڻ"), ('ﮣ', "ڻ"),
+    ('ﮤ', "ە\u{0654}"), ('ﮥ', "ە\u{0654}"), ('ﮦ', "ہ"), ('ﮧ', "ہ"), ('ﮨ', "ہ"), ('ﮩ', "ہ"),
+    ('ﮪ', "ھ"), ('ﮫ', "ھ"), ('ﮬ', "ھ"), ('ﮭ', "ھ"), ('ﮮ', "ے"), ('ﮯ', "ے"), ('ﮰ', "ے\u{0654}"),
+    ('ﮱ', "ے\u{0654}"), ('ﯓ', "ڭ"), ('ﯔ', "ڭ"), ('ﯕ', "ڭ"), ('ﯖ', "ڭ"), ('ﯗ', "ۇ"), ('ﯘ', "ۇ"),
+    ('ﯙ', "ۆ"), ('ﯚ', "ۆ"), ('ﯛ', "ۈ"), ('ﯜ', "ۈ"), ('ﯝ', "ۇٴ"), ('ﯞ', "ۋ"), ('ﯟ', "ۋ"), ('ﯠ', "ۅ"),
+    ('ﯡ', "ۅ"), ('ﯢ', "ۉ"), ('ﯣ', "ۉ"), ('ﯤ', "ې"), ('ﯥ', "ې"), ('ﯦ', "ې"), ('ﯧ', "ې"), ('ﯨ', "ى"),
+    ('ﯩ', "ى"), ('ﯪ', "ي\u{0654}ا"), ('ﯫ', "ي\u{0654}ا"), ('ﯬ', "ي\u{0654}ە"), ('ﯭ', "ي\u{0654}ە"),
+    ('ﯮ', "ي\u{0654}و"), ('ﯯ', "ي\u{0654}و"), ('ﯰ', "ي\u{0654}ۇ"), ('ﯱ', "ي\u{0654}ۇ"),
+    ('ﯲ', "ي\u{0654}ۆ"), ('ﯳ', "ي\u{0654}ۆ"), ('ﯴ', "ي\u{0654}ۈ"), ('ﯵ', "ي\u{0654}ۈ"),
+    ('ﯶ', "ي\u{0654}ې"), ('ﯷ', "ي\u{0654}ې"), ('ﯸ', "ي\u{0654}ې"), ('ﯹ', "ي\u{0654}ى"),
+    ('ﯺ', "ي\u{0654}ى"), ('ﯻ', "ي\u{0654}ى"), ('ﯼ', "ی"), ('ﯽ', "ی"), ('ﯾ', "ی"), ('ﯿ', "ی"),
+    ('ﰀ', "ي\u{0654}ج"), ('ﰁ', "ي\u{0654}ح"), ('ﰂ', "ي\u{0654}م"), ('ﰃ', "ي\u{0654}ى"),
+    ('ﰄ', "ي\u{0654}ي"), ('ﰅ', "بج"), ('ﰆ', "بح"), ('ﰇ', "بخ"), ('ﰈ', "بم"), ('ﰉ', "بى"),
+    ('ﰊ', "بي"), ('ﰋ', "تج"), ('ﰌ', "تح"), ('ﰍ', "تخ"), ('ﰎ', "تم"), ('ﰏ', "تى"), ('ﰐ', "تي"),
+    ('ﰑ', "ثج"), ('ﰒ', "ثم"), ('ﰓ', "ثى"), ('ﰔ', "ثي"), ('ﰕ', "جح"), ('ﰖ', "جم"), ('ﰗ', "حج"),
+    ('ﰘ', "حم"), ('ﰙ', "خج"), ('ﰚ', "خح"), ('ﰛ', "خم"), ('ﰜ', "سج"), ('ﰝ', "سح"), ('ﰞ', "سخ"),
+    ('ﰟ', "سم"), ('ﰠ', "صح"), ('ﰡ', "صم"), ('ﰢ', "ضج"), ('ﰣ', "ضح"), ('ﰤ', "ضخ"), ('ﰥ', "ضم"),
+    ('ﰦ', "طح"), ('ﰧ', "طم"), ('ﰨ', "ظم"), ('ﰩ', "عج"), ('ﰪ', "عم"), ('ﰫ', "غج"), ('ﰬ', "غم"),
+    ('ﰭ', "فج"), ('ﰮ', "فح"), ('ﰯ', "فخ"), ('ﰰ', "فم"), ('ﰱ', "فى"), ('ﰲ', "في"), ('ﰳ', "قح"),
+    ('ﰴ', "قم"), ('ﰵ', "قى"), ('ﰶ', "قي"), ('ﰷ', "كا"), ('ﰸ', "كج"), ('ﰹ', "كح"), ('ﰺ', "كخ"),
+    ('ﰻ', "كل"), ('ﰼ', "كم"), ('ﰽ', "كى"), ('ﰾ', "كي"), ('ﰿ', "لج"), ('ﱀ', "لح"), ('ﱁ', "لخ"),
+    ('ﱂ', "لم"), ('ﱃ', "لى"), ('ﱄ', "لي"), ('ﱅ', "مج"), ('ﱆ', "مح"), ('ﱇ', "مخ"), ('ﱈ', "مم"),
+    ('ﱉ', "مى"), ('ﱊ', "مي"), ('ﱋ', "نج"), ('ﱌ', "نح"), ('ﱍ', "نخ"), ('ﱎ', "نم"), ('ﱏ', "نى"),
+    ('ﱐ', "ني"), ('ﱑ', "هج"), ('ﱒ', "هم"), ('ﱓ', "هى"), ('ﱔ', "هي"), ('ﱕ', "يج"), ('ﱖ', "يح"),
+    ('ﱗ', "يخ"), ('ﱘ', "يم"), ('ﱙ', "يى"), ('ﱚ', "يي"), ('ﱛ', "ذ\u{0670}"), ('ﱜ', "ر\u{0670}"),
+    ('ﱝ', "ى\u{0670}"), ('ﱞ', "\u{0020}\u{064C}\u{0651}"), ('ﱟ', "\u{0020}\u{064D}\u{0651}"),
+    ('ﱠ', "\u{0020}\u{064E}\u{0651}"), ('ﱡ', "\u{0020}\u{064F}\u{0651}"),
+    ('ﱢ', "\u{0020}\u{0650}\u{0651}"), ('ﱣ', "\u{0020}\u{0651}\u{0670}"), ('ﱤ', "ي\u{0654}ر"),
+    ('ﱥ', "ي\u{0654}ز"), ('ﱦ', "ي\u{0654}م"), ('ﱧ', "ي\u{0654}ن"), ('ﱨ', "ي\u{0654}ى"),
+    ('ﱩ', "ي\u{0654}ي"), ('ﱪ', "بر"), ('ﱫ', "بز"), ('ﱬ', "بم"), ('ﱭ', "بن"), ('ﱮ', "بى"),
+    ('ﱯ', "بي"), ('ﱰ', "تر"), ('ﱱ', "تز"), ('ﱲ', "تم"), ('ﱳ', "تن"), ('ﱴ', "تى"), ('ﱵ', "تي"),
+    ('ﱶ', "ثر"), ('ﱷ', "ثز"), ('ﱸ', "ثم"), ('ﱹ', "ثن"), ('ﱺ', "ثى"), ('ﱻ', "ثي"), ('ﱼ', "فى"),
+    ('ﱽ', "في"), ('ﱾ', "قى"), ('ﱿ', "قي"), ('ﲀ', "كا"), ('ﲁ', "كل"), ('ﲂ', "كم"), ('ﲃ', "كى"),
+    ('ﲄ', "كي"), ('ﲅ', "لم"), ('ﲆ', "لى"), ('ﲇ', "لي"), ('ﲈ', "ما"), ('ﲉ', "مم"), ('ﲊ', "نر"),
+    ('ﲋ', "نز"), ('ﲌ', "نم"), ('ﲍ', "نن"), ('ﲎ', "نى"), ('ﲏ', "ني"), ('ﲐ', "ى\u{0670}"),
+    ('ﲑ', "ير"), ('ﲒ', "يز"), ('ﲓ', "يم"), ('ﲔ', "ين"), ('ﲕ', "يى"), ('ﲖ', "يي"),
+    ('ﲗ', "ي\u{0654}ج"), ('ﲘ', "ي\u{0654}ح"), ('ﲙ', "ي\u{0654}خ"), ('ﲚ', "ي\u{0654}م"),
+    ('ﲛ', "ي\u{0654}ه"), ('ﲜ', "بج"), ('ﲝ', "بح"), ('ﲞ', "بخ"), ('ﲟ', "بم"), ('ﲠ', "به"),
+    ('ﲡ', "تج"), ('ﲢ', "تح"), ('ﲣ', "تخ"), ('ﲤ', "تم"), ('ﲥ', "ته"), ('ﲦ', "ثم"), ('ﲧ', "جح"),
+    ('ﲨ', "جم"), ('ﲩ', "حج"), ('ﲪ', "حم"), ('ﲫ', "خج"), ('ﲬ', "خم"), ('ﲭ', "سج"), ('ﲮ', "سح"),
+    ('ﲯ', "سخ"), ('ﲰ', "سم"), ('ﲱ', "صح"), ('ﲲ', "صخ"), ('ﲳ', "صم"), ('ﲴ', "ضج"), ('ﲵ', "ضح"),
+    ('ﲶ', "ضخ"), ('ﲷ', "ضم"), ('ﲸ', "طح"), ('ﲹ', "ظم"), ('ﲺ', "عج"), ('ﲻ', "عم"), ('ﲼ', "غج"),
+    ('ﲽ', "غم"), ('ﲾ', "فج"), ('ﲿ', "فح"), ('ﳀ', "فخ"), ('ﳁ', "فم"), ('ﳂ', "قح"), ('ﳃ', "قم"),
+    ('ﳄ', "كج"), ('ﳅ', "كح"), ('ﳆ', "كخ"), ('ﳇ', "كل"), ('ﳈ', "كم"), ('ﳉ', "لج"), ('ﳊ', "لح"),
+    ('ﳋ', "لخ"), ('ﳌ', "لم"), ('ﳍ', "له"), ('ﳎ', "مج"), ('ﳏ', "مح"), ('ﳐ', "مخ"), ('ﳑ', "مم"),
+    ('ﳒ', "نج"), ('ﳓ', "نح"), ('ﳔ', "نخ"), ('ﳕ', "نم"), ('ﳖ', "نه"), ('ﳗ', "هج"), ('ﳘ', "هم"),
+    ('ﳙ', "ه\u{0670}"), ('ﳚ', "يج"), ('ﳛ', "يح"), ('ﳜ', "يخ"), ('ﳝ', "يم"), ('ﳞ', "يه"),
+    ('ﳟ', "ي\u{0654}م"), ('ﳠ', "ي\u{0654}ه"), ('ﳡ', "بم"), ('ﳢ', "به"), ('ﳣ', "تم"), ('ﳤ', "ته"),
+    ('ﳥ', "ثم"), ('ﳦ', "ثه"), ('ﳧ', "سم"), ('ﳨ', "سه"), ('ﳩ', "شم"), ('ﳪ', "شه"), ('ﳫ', "كل"),
+    ('ﳬ', "كم"), ('ﳭ', "لم"), ('ﳮ', "نم"), ('ﳯ', "نه"), ('ﳰ', "يم"), ('ﳱ', "يه"),
+    ('ﳲ', "ـ\u{064E}\u{0651}"), ('ﳳ', "ـ\u{064F}\u{0651}"), ('ﳴ', "ـ\u{0650}\u{0651}"), ('ﳵ', "طى"),
+    ('ﳶ', "طي"), ('ﳷ', "عى"), ('ﳸ', "عي"), ('ﳹ', "غى"), ('ﳺ', "غي"), ('ﳻ', "سى"), ('ﳼ', "سي"),
+    ('ﳽ', "شى"), ('ﳾ', "شي"), ('ﳿ', "حى"), ('ﴀ', "حي"), ('ﴁ', "جى"), ('ﴂ', "جي"), ('ﴃ', "خى"),
+    ('ﴄ', "خي"), ('ﴅ', "صى"), ('ﴆ', "صي"), ('ﴇ', "ضى"), ('ﴈ', "ضي"), ('ﴉ', "شج"), ('ﴊ', "شح"),
+    ('ﴋ', "شخ"), ('ﴌ', "شم"), ('ﴍ', "شر"), ('ﴎ', "سر"), ('ﴏ', "صر"), ('ﴐ', "ضر"), ('ﴑ', "طى"),
+    ('ﴒ', "طي"), ('ﴓ', "عى"), ('ﴔ', "عي"), ('ﴕ', "غى"), ('ﴖ', "غي"), ('ﴗ', "سى"), ('ﴘ', "سي"),
+    ('ﴙ', "شى"), ('ﴚ', "شي"), ('ﴛ', "حى"), ('ﴜ', "حي"), ('ﴝ', "جى"), ('ﴞ', "جي"), ('ﴟ', "خى"),
+    ('ﴠ', "خي"), ('ﴡ', "صى"), ('ﴢ', "صي"), ('ﴣ', "ضى"), ('ﴤ', "ضي"), ('ﴥ', "شج"), ('ﴦ', "شح"),
+    ('ﴧ', "شخ"), ('ﴨ', "شم"), ('ﴩ', "شر"), ('ﴪ', "سر"), ('ﴫ', "صر"), ('ﴬ', "ضر"), ('ﴭ', "شج"),
+    ('ﴮ', "شح"), ('ﴯ', "شخ"), ('ﴰ', "شم"), ('ﴱ', "سه"), ('ﴲ', "شه"), ('ﴳ', "طم"), ('ﴴ', "سج"),
+    ('ﴵ', "سح"), ('ﴶ', "سخ"), ('ﴷ', "شج"), ('ﴸ', "شح"), ('ﴹ', "شخ"), ('ﴺ', "طم"), ('ﴻ', "ظم"),
+    ('ﴼ', "ا\u{064B}"), ('ﴽ', "ا\u{064B}"), ('ﵐ', "تجم"), ('ﵑ', "تحج"), ('ﵒ', "تحج"), ('ﵓ', "تحم"),
+    ('ﵔ', "تخم"), ('ﵕ', "تمج"), ('ﵖ', "تمح"), ('ﵗ', "تمخ"), ('ﵘ', "جمح"), ('ﵙ', "جمح"),
+    ('ﵚ', "حمي"), ('ﵛ', "حمى"), ('ﵜ', "سحج"), ('ﵝ', "سجح"), ('ﵞ', "سجى"), ('ﵟ', "سمح"),
+    ('ﵠ', "سمح"), ('ﵡ', "سمج"), ('ﵢ', "سمم"), ('ﵣ', "سمم"), ('ﵤ', "صحح"), ('ﵥ', "صحح"),
+    ('ﵦ', "صمم"), ('ﵧ', "شحم"), ('ﵨ', "شحم"), ('ﵩ', "شجي"), ('ﵪ', "شمخ"), ('ﵫ', "شمخ"),
+    ('ﵬ', "شمم"), ('ﵭ', "شمم"), ('ﵮ', "ضحى"), ('ﵯ', "ضخم"), ('ﵰ', "ضخم"), ('ﵱ', "طمح"),
+    ('ﵲ', "طمح"), ('ﵳ', "طمم"), ('ﵴ', "طمي"), ('ﵵ', "عجم"), ('ﵶ', "عمم"), ('ﵷ', "عمم"),
+    ('ﵸ', "عمى"), ('ﵹ', "غمم"), ('ﵺ', "غمي"), ('ﵻ', "غمى"), ('ﵼ', "فخم"), ('ﵽ', "فخم"),
+    ('ﵾ', "قمح"), ('ﵿ', "قمم"), ('ﶀ', "لحم"), ('ﶁ', "لحي"), ('ﶂ', "لحى"), ('ﶃ', "لجج"),
+    ('ﶄ', "لجج"), ('ﶅ', "لخم"), ('ﶆ', "لخم"), ('ﶇ', "لمح"), ('ﶈ', "لمح"), ('ﶉ', "محج"),
+    ('ﶊ', "محم"), ('ﶋ', "محي"), ('ﶌ', "مجح"), ('ﶍ', "مجم"), ('ﶎ', "مخج"), ('ﶏ', "مخم"),
+    ('ﶒ', "مجخ"), ('ﶓ', "همج"), ('ﶔ', "همم"), ('ﶕ', "نحم"), ('ﶖ', "نحى"), ('ﶗ', "نجم"),
+    ('ﶘ', "نجم"), ('ﶙ', "نجى"), ('ﶚ', "نمي"), ('ﶛ', "نمى"), ('ﶜ', "يمم"), ('ﶝ', "يمم"),
+    ('ﶞ', "بخي"), ('ﶟ', "تجي"), ('ﶠ', "تجى"), ('ﶡ', "تخي"), ('ﶢ', "تخى"), ('ﶣ', "تمي"),
+    ('ﶤ', "تمى"), ('ﶥ', "جمي"), ('ﶦ', "جحى"), ('ﶧ', "جمى"), ('ﶨ', "سخى"), ('ﶩ', "صحي"),
+    ('ﶪ', "شحي"), ('ﶫ', "ضحي"), ('ﶬ', "لجي"), ('ﶭ', "لمي"), ('ﶮ', "يحي"), ('ﶯ', "يجي"),
+    ('ﶰ', "يمي"), ('ﶱ', "ممي"), ('ﶲ', "قمي"), ('ﶳ', "نحي"), ('ﶴ', "قمح"), ('ﶵ', "لحم"),
+    ('ﶶ', "عمي"), ('ﶷ', "كمي"), ('ﶸ', "نجح"), ('ﶹ', "مخي"), ('ﶺ', "لجم"), ('ﶻ', "كمم"),
+    ('ﶼ', "لجم"), ('ﶽ', "نجح"), ('ﶾ', "جحي"), ('ﶿ', "حجي"), ('ﷀ', "مجي"), ('ﷁ', "فمي"),
+    ('ﷂ', "بحي"), ('ﷃ', "كمم"), ('ﷄ', "عجم"), ('ﷅ', "صمم"), ('ﷆ', "سخي"), ('ﷇ', "نجي"),
+    ('ﷰ', "صلے"), ('ﷱ', "قلے"), ('ﷲ', "الله"), ('ﷳ', "اكبر"), ('ﷴ', "محمد"), ('ﷵ', "صلعم"),
+    ('ﷶ', "رسول"), ('ﷷ', "عليه"), ('ﷸ', "وسلم"), ('ﷹ', "صلى"),
+    ('ﷺ', "صلى\u{0020}الله\u{0020}عليه\u{0020}وسلم"), ('ﷻ', "جل\u{0020}جلاله"), ('﷼', "ریال"),
+    ('︐', ","), ('︑', "、"), ('︒', "。"), ('︓', ":"), ('︔', ";"), ('︕', "!"), ('︖', "?"), ('︗', "〖"),
+    ('︘', "〗"), ('︙', "..."), ('︰', ".."), ('︱', "—"), ('︲', "–"), ('︳', "_"), ('︴', "_"),
+    ('︵', "("), ('︶', ")"), ('︷', "{"), ('︸', "}"), ('︹', "〔"), ('︺', "〕"), ('︻', "【"), ('︼', "】"),
+    ('︽', "《"), ('︾', "》"), ('︿', "〈"), ('﹀', "〉"), ('﹁', "「"), ('﹂', "」"), ('﹃', "『"), ('﹄', "』"),
+    ('﹇', "["), ('﹈', "]"), ('﹉', "\u{0020}\u{0305}"), ('﹊', "\u{0020}\u{0305}"),
+    ('﹋', "\u{0020}\u{0305}"), ('﹌', "\u{0020}\u{0305}"), ('﹍', "_"), ('﹎', "_"), ('﹏', "_"),
+    ('﹐', ","), ('﹑', "、"), ('﹒', "."), ('﹔', ";"), ('﹕', ":"), ('﹖', "?"), ('﹗', "!"), ('﹘', "—"),
+    ('﹙', "("), ('﹚', ")"), ('﹛', "{"), ('﹜', "}"), ('﹝', "〔"), ('﹞', "〕"), ('﹟', "#"), ('﹠', "&"),
+    ('﹡', "*"), ('﹢', "+"), ('﹣', "-"), ('﹤', "<"), ('﹥', ">"), ('﹦', "="), ('﹨', "\u{005C}"),
+    ('﹩', "$"), ('﹪', "%"), ('﹫', "@"), ('ﹰ', "\u{0020}\u{064B}"), ('ﹱ', "ـ\u{064B}"),
+    ('ﹲ', "\u{0020}\u{064C}"), ('ﹴ', "\u{0020}\u{064D}"), ('ﹶ', "\u{0020}\u{064E}"),
+    ('ﹷ', "ـ\u{064E}"), ('ﹸ', "\u{0020}\u{064F}"), ('ﹹ', "ـ\u{064F}"), ('ﹺ', "\u{0020}\u{0650}"),
+    ('ﹻ', "ـ\u{0650}"), ('ﹼ', "\u{0020}\u{0651}"), ('ﹽ', "ـ\u{0651}"), ('ﹾ', "\u{0020}\u{0652}"),
+    ('ﹿ', "ـ\u{0652}"), ('ﺀ', "ء"), ('ﺁ', "ا\u{0653}"), ('ﺂ', "ا\u{0653}"), ('ﺃ', "ا\u{0654}"),
+    ('ﺄ', "ا\u{0654}"), ('ﺅ', "و\u{0654}"), ('ﺆ', "و\u{0654}"), ('ﺇ', "ا\u{0655}"),
+    ('ﺈ', "ا\u{0655}"), ('ﺉ', "ي\u{0654}"), ('ﺊ', "ي\u{0654}"), ('ﺋ', "ي\u{0654}"),
+    ('ﺌ', "ي\u{0654}"), ('ﺍ', "ا"), ('ﺎ', "ا"), ('ﺏ', "ب"), ('ﺐ', "ب"), ('ﺑ', "ب"), ('ﺒ', "ب"),
+    ('ﺓ', "ة"), ('ﺔ', "ة"), ('ﺕ', "ت"), ('ﺖ', "ت"), ('ﺗ', "ت"), ('ﺘ', "ت"), ('ﺙ', "ث"), ('ﺚ', "ث"),
+    ('ﺛ', "ث"), ('ﺜ', "ث"), ('ﺝ', "ج"), ('ﺞ', "ج"), ('ﺟ', "ج"), ('ﺠ', "ج"), ('ﺡ', "ح"), ('ﺢ', "ح"),
+    ('ﺣ', "ح"), ('ﺤ', "ح"), ('ﺥ', "خ"), ('ﺦ', "خ"), ('ﺧ', "خ"), ('ﺨ', "خ"), ('ﺩ', "د"), ('ﺪ', "د"),
+    ('ﺫ', "ذ"), ('ﺬ', "ذ"), ('ﺭ', "ر"), ('ﺮ', "ر"), ('ﺯ', "ز"), ('ﺰ', "ز"), ('ﺱ', "س"), ('ﺲ', "س"),
+    ('ﺳ', "س"), ('ﺴ', "س"), ('ﺵ', "ش"), ('ﺶ', "ش"), ('ﺷ', "ش"), ('ﺸ', "ش"), ('ﺹ', "ص"), ('ﺺ', "ص"),
+    ('ﺻ', "ص"), ('ﺼ', "ص"), ('ﺽ', "ض"), ('ﺾ', "ض"), ('ﺿ', "ض"), ('ﻀ', "ض"), ('ﻁ', "ط"), ('ﻂ', "ط"),
+    ('ﻃ', "ط"), ('ﻄ', "ط"), ('ﻅ', "ظ"), ('ﻆ', "ظ"), ('ﻇ', "ظ"), ('ﻈ', "ظ"), ('ﻉ', "ع"), ('ﻊ', "ع"),
+    ('ﻋ', "ع"), ('ﻌ', "ع"), ('ﻍ', "غ"), ('ﻎ', "غ"), ('ﻏ', "غ"), ('ﻐ', "غ"), ('ﻑ', "ف"), ('ﻒ', "ف"),
+    ('ﻓ', "ف"), ('ﻔ', "ف"), ('ﻕ', "ق"), ('ﻖ', "ق"), ('ﻗ', "ق"), ('ﻘ', "ق"), ('ﻙ', "ك"), ('ﻚ', "ك"),
+    ('ﻛ', "ك"), ('ﻜ', "ك"), ('ﻝ', "ل"), ('ﻞ', "ل"), ('ﻟ', "ل"), ('ﻠ', "ل"), ('ﻡ', "م"), ('ﻢ', "م"),
+    ('ﻣ', "م"), ('ﻤ', "م"), ('ﻥ', "ن"), ('ﻦ', "ن"), ('ﻧ', "ن"), ('ﻨ', "ن"), ('ﻩ', "ه"), ('ﻪ', "ه"),
+    ('ﻫ', "ه"), ('ﻬ', "ه"), ('ﻭ', "و"), ('ﻮ', "و"), ('ﻯ', "ى"), ('ﻰ', "ى"), ('ﻱ', "ي"), ('ﻲ', "ي"),
+    ('ﻳ', "ي"), ('ﻴ', "ي"), ('ﻵ', "لا\u{0653}"), ('ﻶ', "لا\u{0653}"), ('ﻷ', "لا\u{0654}"),
+    ('ﻸ', "لا\u{0654}"), ('ﻹ', "لا\u{0655}"), ('ﻺ', "لا\u{0655}"), ('ﻻ', "لا"), ('ﻼ', "لا"),
+    ('！', "!"), ('＂', "\u{0022}"), ('＃', "#"), ('＄', "$"), ('％', "%"), ('＆', "&"),
+    ('＇', "\u{0027}"), ('（', "("), ('）', ")"), ('＊', "*"), ('＋', "+"), ('，', ","), ('－', "-"),
+    ('．', "."), ('／', "/"), ('０', "0"), ('１', "1"), ('２', "2"), ('３', "3"), ('４', "4"), ('５', "5"),
+    ('６', "6"), ('７', "7"), ('８', "8"), ('９', "9"), ('：', ":"), ('；', ";"), ('＜', "<"), ('＝', "="),
+    ('＞', ">"), ('？', "?"), ('＠', "@"), ('Ａ', "A"), ('Ｂ', "B"), ('Ｃ', "C"), ('Ｄ', "D"), ('Ｅ', "E"),
+    ('Ｆ', "F"), ('Ｇ', "G"), ('Ｈ', "H"), ('Ｉ', "I"), ('Ｊ', "J"), ('Ｋ', "K"), ('Ｌ', "L"), ('Ｍ', "M"),
+    ('Ｎ', "N"), ('Ｏ', "O"), ('Ｐ', "P"), ('Ｑ', "Q"), ('Ｒ', "R"), ('Ｓ', "S"), ('Ｔ', "T"), ('Ｕ', "U"),
+    ('Ｖ', "V"), ('Ｗ', "W"), ('Ｘ', "X"), ('Ｙ', "Y"), ('Ｚ', "Z"), ('［', "["), ('＼', "\u{005C}"),
+    ('］', "]"), ('＾', "^"), ('＿', "_"), ('｀', "`"), ('ａ', "a"), ('ｂ', "b"), ('ｃ', "c"), ('ｄ', "d"),
+    ('ｅ', "e"), ('ｆ', "f"), ('ｇ', "g"), ('ｈ', "h"), ('ｉ', "i"), ('ｊ', "j"), ('ｋ', "k"), ('ｌ', "l"),
+    ('ｍ', "m"), ('ｎ', "n"), ('ｏ', "o"), ('ｐ', "p"), ('ｑ', "q"), ('ｒ', "r"), ('ｓ', "s"), ('ｔ', "t"),
+    ('ｕ', "u"), ('ｖ', "v"), ('ｗ', "w"), ('ｘ', "x"), ('ｙ', "y"), ('ｚ', "z"), ('｛', "{"), ('｜', "|"),
+    ('｝', "}"), ('～', "~"), ('｟', "⦅"), ('｠', "⦆"), ('｡', "。"), ('｢', "「"), ('｣', "」"), ('､', "、"),
+    ('･', "・"), ('ｦ', "ヲ"), ('ｧ', "ァ"), ('ｨ', "ィ"), ('ｩ', "ゥ"), ('ｪ', "ェ"), ('ｫ', "ォ"), ('ｬ', "ャ"),
+    ('ｭ', "ュ"), ('ｮ', "ョ"), ('ｯ', "ッ"), ('ｰ', "ー"), ('ｱ', "ア"), ('ｲ', "イ"), ('ｳ', "ウ"), ('ｴ', "エ"),
+    ('ｵ', "オ"), ('ｶ', "カ"), ('ｷ', "キ"), ('ｸ', "ク"), ('ｹ', "ケ"), ('ｺ', "コ"), ('ｻ', "サ"), ('ｼ', "シ"),
+    ('ｽ', "ス"), ('ｾ', "セ"), ('ｿ', "ソ"), ('ﾀ', "タ"), ('ﾁ', "チ"), ('ﾂ', "ツ"), ('ﾃ', "テ"), ('ﾄ', "ト"),
+    ('ﾅ', "ナ"), ('ﾆ', "ニ"), ('ﾇ', "ヌ"), ('ﾈ', "ネ"), ('ﾉ', "ノ"), ('ﾊ', "ハ"), ('ﾋ', "ヒ"), ('ﾌ', "フ"),
+    ('ﾍ', "ヘ"), ('ﾎ', "ホ"), ('ﾏ', "マ"), ('ﾐ', "ミ"), ('ﾑ', "ム"), ('ﾒ', "メ"), ('ﾓ', "モ"), ('ﾔ', "ヤ"),
+    ('ﾕ', "ユ"), ('ﾖ', "ヨ"), ('ﾗ', "ラ"), ('ﾘ', "リ"), ('ﾙ', "ル"), ('ﾚ', "レ"), ('ﾛ', "ロ"), ('ﾜ', "ワ"),
+    ('ﾝ', "ン"), ('ﾞ', "\u{3099}"), ('ﾟ', "\u{309A}"), ('ﾠ', "ᅠ"), ('ﾡ', "ᄀ"), ('ﾢ', "ᄁ"),
+    ('ﾣ', "ᆪ"), ('ﾤ', "ᄂ"), ('ﾥ', "ᆬ"), ('ﾦ', "ᆭ"), ('ﾧ', "ᄃ"), ('ﾨ', "ᄄ"), ('ﾩ', "ᄅ"), ('ﾪ', "ᆰ"),
+    ('ﾫ', "ᆱ"), ('ﾬ', "ᆲ"), ('ﾭ', "ᆳ"), ('ﾮ', "ᆴ"), ('ﾯ', "ᆵ"), ('ﾰ', "ᄚ"), ('ﾱ', "ᄆ"), ('ﾲ', "ᄇ"),
+    ('ﾳ', "ᄈ"), ('ﾴ', "ᄡ"), ('ﾵ', "ᄉ"), ('ﾶ', "ᄊ"), ('ﾷ', "ᄋ"), ('ﾸ', "ᄌ"), ('ﾹ', "ᄍ"), ('ﾺ', "ᄎ"),
+    ('ﾻ', "ᄏ"), ('ﾼ', "ᄐ"), ('ﾽ', "ᄑ"), ('ﾾ', "ᄒ"), ('ￂ', "ᅡ"), ('ￃ', "ᅢ"), ('ￄ', "ᅣ"), ('ￅ', "ᅤ"),
+    ('ￆ', "ᅥ"), ('ￇ', "ᅦ"), ('ￊ', "ᅧ"), ('ￋ', "ᅨ"), ('ￌ', "ᅩ"), ('ￍ', "ᅪ"), ('ￎ', "ᅫ"), ('ￏ', "ᅬ"),
+    ('ￒ', "ᅭ"), ('ￓ', "ᅮ"), ('ￔ', "ᅯ"), ('ￕ', "ᅰ"), ('ￖ', "ᅱ"), ('ￗ', "ᅲ"), ('ￚ', "ᅳ"), ('ￛ', "ᅴ"),
+    ('ￜ', "ᅵ"), ('￠', "¢"), ('￡', "£"), ('￢', "¬"), ('￣', "\u{0020}\u{0304}"), ('￤', "¦"),
+    ('￥', "¥"), ('￦', "₩"), ('￨', "│"), ('￩', "←"), ('￪', "↑"), ('￫', "→"), ('￬', "↓"), ('￭', "■"),
+    ('￮', "○"), ('𐞁', "ː"), ('𐞂', "ˑ"), ('𐞃', "æ"), ('𐞄', "ʙ"), ('𐞅', "ɓ"), ('𐞇', "ʣ"), ('𐞈', "ꭦ"),
+    ('𐞉', "ʥ"), ('𐞊', "ʤ"), ('𐞋', "ɖ"), ('𐞌', "ɗ"), ('𐞍', "ᶑ"), ('𐞎', "ɘ"), ('𐞏', "ɞ"), ('𐞐', "ʩ"),
+    ('𐞑', "ɤ"), ('𐞒', "ɢ"), ('𐞓', "ɠ"), ('𐞔', "ʛ"), ('𐞕', "ħ"), ('𐞖', "ʜ"), ('𐞗', "ɧ"), ('𐞘', "ʄ"),
+    ('𐞙', "ʪ"), ('𐞚', "ʫ"), ('𐞛', "ɬ"), ('𐞜', "𝼄"), ('𐞝', "ꞎ"), ('𐞞', "ɮ"), ('𐞟', "𝼅"), ('𐞠', "ʎ"),
+    ('𐞡', "𝼆"), ('𐞢', "ø"), ('𐞣', "ɶ"), ('𐞤', "ɷ"), ('𐞥', "q"), ('𐞦', "ɺ"), ('𐞧', "𝼈"), ('𐞨', "ɽ"),
+    ('𐞩', "ɾ"), ('𐞪', "ʀ"), ('𐞫', "ʨ"), ('𐞬', "ʦ"), ('𐞭', "ꭧ"), ('𐞮', "ʧ"), ('𐞯', "ʈ"), ('𐞰', "ⱱ"),
+    ('𐞲', "ʏ"), ('𐞳', "ʡ"), ('𐞴', "ʢ"), ('𐞵', "ʘ"), ('𐞶', "ǀ"), ('𐞷', "ǁ"), ('𐞸', "ǂ"), ('𐞹', "𝼊"),
+    ('𐞺', "𝼞"), ('𝐀', "A"), ('𝐁', "B"), ('𝐂', "C"), ('𝐃', "D"), ('𝐄', "E"), ('𝐅', "F"), ('𝐆', "G"),
+    ('𝐇', "H"), ('𝐈', "I"), ('𝐉', "J"), ('𝐊', "K"), ('𝐋', "L"), ('𝐌', "M"), ('𝐍', "N"), ('𝐎', "O"),
+    ('𝐏', "P"), ('𝐐', "Q"), ('𝐑', "R"), ('𝐒', "S"), ('𝐓', "T"), ('𝐔', "U"), ('𝐕', "V"), ('𝐖', "W"),
+    ('𝐗', "X"), ('𝐘', "Y"), ('𝐙', "Z"), ('𝐚', "a"), ('𝐛', "b"), ('𝐜', "c"), ('𝐝', "d"), ('𝐞', "e"),
+    ('𝐟', "f"), ('𝐠', "g"), ('𝐡', "h"), ('𝐢', "i"), ('𝐣', "j"), ('𝐤', "k"), ('𝐥', "l"), ('𝐦', "m"),
+    ('𝐧', "n"), ('𝐨', "o"), ('𝐩', "p"), ('𝐪', "q"), ('𝐫', "r"), ('𝐬', "s"), ('𝐭', "t"), ('𝐮', "u"),
+    ('𝐯', "v"), ('𝐰', "w"), ('𝐱', "x"), ('𝐲', "y"), ('𝐳', "z"), ('𝐴', "A"), ('𝐵', "B"), ('𝐶', "C"),
+    ('𝐷', "D"), ('𝐸', "E"), ('𝐹', "F"), ('𝐺', "G"), ('𝐻', "H"), ('𝐼', "I"), ('𝐽', "J"), ('𝐾', "K"),
+    ('𝐿', "L"), ('𝑀', "M"), ('𝑁', "N"), ('𝑂', "O"), ('𝑃', "P"), ('𝑄', "Q"), ('𝑅', "R"), ('𝑆', "S"),
+    ('𝑇', "T"), ('𝑈', "U"), ('𝑉', "V"), ('𝑊', "W"), ('𝑋', "X"), ('𝑌', "Y"), ('𝑍', "Z"), ('𝑎', "a"),
+    ('𝑏', "b"), ('𝑐', "c"), ('𝑑', "d"), ('𝑒', "e"), ('𝑓', "f"), ('𝑔', "g"), ('𝑖', "i"), ('𝑗', "j"),
+    ('𝑘', "k"), ('𝑙', "l"), ('𝑚', "m"), ('𝑛', "n"), ('𝑜', "o"), ('𝑝', "p"), ('𝑞', "q"), ('𝑟', "r"),
+    ('𝑠', "s"), ('𝑡', "t"), ('𝑢', "u"), ('𝑣', "v"), ('𝑤', "w"), ('𝑥', "x"), ('𝑦', "y"), ('𝑧', "z"),
+    ('𝑨', "A"), ('𝑩', "B"), ('𝑪', "C"), ('𝑫', "D"), ('𝑬', "E"), ('𝑭', "F"), ('𝑮', "G"), ('𝑯', "H"),
+    ('𝑰', "I"), ('𝑱', "J"), ('𝑲', "K"), ('𝑳', "L"), ('𝑴', "M"), ('𝑵', "N"), ('𝑶', "O"), ('𝑷', "P"),
+    ('𝑸', "Q"), ('𝑹', "R"), ('𝑺', "S"), ('𝑻', "T"), ('𝑼', "U"), ('𝑽', "V"), ('𝑾', "W"), ('𝑿', "X"),
+    ('𝒀', "Y"), ('𝒁', "Z"), ('𝒂', "a"), ('𝒃', "b"), ('𝒄', "c"), ('𝒅', "d"), ('𝒆', "e"), ('𝒇', "f"),
+    ('𝒈', "g"), ('𝒉', "h"), ('𝒊', "i"), ('𝒋', "j"), ('𝒌', "k"), ('𝒍', "l"), ('𝒎', "m"), ('𝒏', "n"),
+    ('𝒐', "o"), ('𝒑', "p"), ('𝒒', "q"), ('𝒓', "r"), ('𝒔', "s"), ('𝒕', "t"), ('𝒖', "u"), ('𝒗', "v"),
+    ('𝒘', "w"), ('𝒙', "x"), ('𝒚', "y"), ('𝒛', "z"), ('𝒜', "A"), ('𝒞', "C"), ('𝒟', "D"), ('𝒢', "G"),
+    ('𝒥', "J"), ('𝒦', "K"), ('𝒩', "N"), ('𝒪', "O"), ('𝒫', "P"), ('𝒬', "Q"), ('𝒮', "S"), ('𝒯', "T"),
+    ('𝒰', "U"), ('𝒱', "V"), ('𝒲', "W"), ('𝒳', "X"), ('𝒴', "Y"), ('𝒵', "Z"), ('𝒶', "a"), ('𝒷', "b"),
+    ('𝒸', "c"), ('𝒹', "d"), ('𝒻', "f"), ('𝒽', "h"), ('𝒾', "i"), ('𝒿', "j"), ('𝓀', "k"), ('𝓁', "l"),
+    ('𝓂', "m"), ('𝓃', "n"), ('𝓅', "p"), ('𝓆', "q"), ('𝓇', "r"), ('𝓈', "s"), ('𝓉', "t"), ('𝓊', "u"),
+    ('𝓋', "v"), ('𝓌', "w"), ('𝓍', "x"), ('𝓎', "y"), ('𝓏', "z"), ('𝓐', "A"), ('𝓑', "B"), ('𝓒', "C"),
+    ('𝓓', "D"), ('𝓔', "E"), ('𝓕', "F"), ('𝓖', "G"), ('𝓗', "H"), ('𝓘', "I"), ('𝓙', "J"), ('𝓚', "K"),
+    ('𝓛', "L"), ('𝓜', "M"), ('𝓝', "N"), ('𝓞', "O"), ('𝓟', "P"), ('𝓠', "Q"), ('𝓡', "R"), ('𝓢', "S"),
+    ('𝓣', "T"), ('𝓤', "U"), ('𝓥', "V"), ('𝓦', "W"), ('𝓧', "X"), ('𝓨', "Y"), ('𝓩', "Z"), ('𝓪', "a"),
+    ('𝓫', "b"), ('𝓬', "c"), ('𝓭', "d"), ('𝓮', "e"), ('𝓯', "f"), ('𝓰', "g"), ('𝓱', "h"), ('𝓲', "i"),
+    ('𝓳', "j"), ('𝓴', "k"), ('𝓵', "l"), ('𝓶', "m"), ('𝓷', "n"), ('𝓸', "o"), ('𝓹', "p"), ('𝓺', "q"),
+    ('𝓻', "r"), ('𝓼', "s"), ('𝓽', "t"), ('𝓾', "u"), ('𝓿', "v"), ('𝔀', "w"), ('𝔁', "x"), ('𝔂', "y"),
+    ('𝔃', "z"), ('𝔄', "A"), ('𝔅', "B"), ('𝔇', "D"), ('𝔈', "E"), ('𝔉', "F"), ('𝔊', "G"), ('𝔍', "J"),
+    ('𝔎', "K"), ('𝔏', "L"), ('𝔐', "M"), ('𝔑', "N"), ('𝔒', "O"), ('𝔓', "P"), ('𝔔', "Q"), ('𝔖', "S"),
+    ('𝔗', "T"), ('𝔘', "U"), ('𝔙', "V"), ('𝔚', "W"), ('𝔛', "X"), ('𝔜', "Y"), ('𝔞', "a"), ('𝔟', "b"),
+    ('𝔠', "c"), ('𝔡', "d"), ('𝔢', "e"), ('𝔣', "f"), ('𝔤', "g"), ('𝔥', "h"), ('𝔦', "i"), ('𝔧', "j"),
+    ('𝔨', "k"), ('𝔩', "l"), ('𝔪', "m"), ('𝔫', "n"), ('𝔬', "o"), ('𝔭', "p"), ('𝔮', "q"), ('𝔯', "r"),
+    ('𝔰', "s"), ('𝔱', "t"), ('𝔲', "u"), ('𝔳', "v"), ('𝔴', "w"), ('𝔵', "x"), ('𝔶', "y"), ('𝔷', "z"),
+    ('𝔸', "A"), ('𝔹', "B"), ('𝔻', "D"), ('𝔼', "E"), ('𝔽', "F"), ('𝔾', "G"), ('𝕀', "I"), ('𝕁', "J"),
+    ('𝕂', "K"), ('𝕃', "L"), ('𝕄', "M"), ('𝕆', "O"), ('𝕊', "S"), ('𝕋', "T"), ('𝕌', "U"), ('𝕍', "V"),
+    ('𝕎', "W"), ('𝕏', "X"), ('𝕐', "Y"), ('𝕒', "a"), ('𝕓', "b"), ('𝕔', "c"), ('𝕕', "d"), ('𝕖', "e"),
+    ('𝕗', "f"), ('𝕘', "g"), ('𝕙', "h"), ('𝕚', "i"), ('𝕛', "j"), ('𝕜', "k"), ('𝕝', "l"), ('𝕞', "m"),
+    ('𝕟', "n"), ('𝕠', "o"), ('𝕡', "p"), ('𝕢', "q"), ('𝕣', "r"), ('𝕤', "s"), ('𝕥', "t"), ('𝕦', "u"),
+    ('𝕧', "v"), ('𝕨', "w"), ('𝕩', "x"), ('𝕪', "y"), ('𝕫', "z"), ('𝕬', "A"), ('𝕭', "B"), ('𝕮', "C"),
+    ('𝕯', "D"), ('𝕰', "E"), ('𝕱', "F"), ('𝕲', "G"), ('𝕳', "H"), ('𝕴', "I"), ('𝕵', "J"), ('𝕶', "K"),
+    ('𝕷', "L"), ('𝕸', "M"), ('𝕹', "N"), ('𝕺', "O"), ('𝕻', "P"), ('𝕼', "Q"), ('𝕽', "R"), ('𝕾', "S"),
+    ('𝕿', "T"), ('𝖀', "U"), ('𝖁', "V"), ('𝖂', "W"), ('𝖃', "X"), ('𝖄', "Y"), ('𝖅', "Z"), ('𝖆', "a"),
+    ('𝖇', "b"), ('𝖈', "c"), ('𝖉', "d"), ('𝖊', "e"), ('𝖋', "f"), ('𝖌', "g"), ('𝖍', "h"), ('𝖎', "i"),
+    ('𝖏', "j"), ('𝖐', "k"), ('𝖑', "l"), ('𝖒', "m"), ('𝖓', "n"), ('𝖔', "o"), ('𝖕', "p"), ('𝖖', "q"),
+    ('𝖗', "r"), ('𝖘', "s"), ('𝖙', "t"), ('𝖚', "u"), ('𝖛', "v"), ('𝖜', "w"), ('𝖝', "x"), ('𝖞', "y"),
+    ('𝖟', "z"), ('𝖠', "A"), ('𝖡', "B"), ('𝖢', "C"), ('𝖣', "D"), ('𝖤', "E"), ('𝖥', "F"), ('𝖦', "G"),
+    ('𝖧', "H"), ('𝖨', "I"), ('𝖩', "J"), ('𝖪', "K"), ('𝖫', "L"), ('𝖬', "M"), ('𝖭', "N"), ('𝖮', "O"),
+    ('𝖯', "P"), ('𝖰', "Q"), ('𝖱', "R"), ('𝖲', "S"), ('𝖳', "T"), ('𝖴', "U"), ('𝖵', "V"), ('𝖶', "W"),
+    ('𝖷', "X"), ('𝖸', "Y"), ('𝖹', "Z"), ('𝖺', "a"), ('𝖻', "b"), ('𝖼', "c"), ('𝖽', "d"), ('𝖾', "e"),
+    ('𝖿', "f"), ('𝗀', "g"), ('𝗁', "h"), ('𝗂', "i"), ('𝗃', "j"), ('𝗄', "k"), ('𝗅', "l"), ('𝗆', "m"),
+    ('𝗇', "n"), ('𝗈', "o"), ('𝗉', "p"), ('𝗊', "q"), ('𝗋', "r"), ('𝗌', "s"), ('𝗍', "t"), ('𝗎', "u"),
+    ('𝗏', "v"), ('𝗐', "w"), ('𝗑', "x"), ('𝗒', "y"), ('𝗓', "z"), ('𝗔', "A"), ('𝗕', "B"), ('𝗖', "C"),
+    ('𝗗', "D"), ('𝗘', "E"), ('𝗙', "F"), ('𝗚', "G"), ('𝗛', "H"), ('𝗜', "I"), ('𝗝', "J"), ('𝗞', "K"),
+    ('𝗟', "L"), ('𝗠', "M"), ('𝗡', "N"), ('𝗢', "O"), ('𝗣', "P"), ('𝗤', "Q"), ('𝗥', "R"), ('𝗦', "S"),
+    ('𝗧', "T"), ('𝗨', "U"), ('𝗩', "V"), ('𝗪', "W"), ('𝗫', "X"), ('𝗬', "Y"), ('𝗭', "Z"), ('𝗮', "a"),
+    ('𝗯', "b"), ('𝗰', "c"), ('𝗱', "d"), ('𝗲', "e"), ('𝗳', "f"), ('𝗴', "g"), ('𝗵', "h"), ('𝗶', "i"),
+    ('𝗷', "j"), ('𝗸', "k"), ('𝗹', "l"), ('𝗺', "m"), ('𝗻', "n"), ('𝗼', "o"), ('𝗽', "p"), ('𝗾', "q"),
+    ('𝗿', "r"), ('𝘀', "s"), ('𝘁', "t"), ('𝘂', "u"), ('𝘃', "v"), ('𝘄', "w"), ('𝘅', "x"), ('𝘆', "y"),
+    ('𝘇', "z"), ('𝘈', "A"), ('𝘉', "B"), ('𝘊', "C"), ('𝘋', "D"), ('𝘌', "E"), ('𝘍', "F"), ('𝘎', "G"),
+    ('𝘏', "H"), ('𝘐', "I"), ('𝘑', "J"), ('𝘒', "K"), ('𝘓', "L"), ('𝘔', "M"), ('𝘕', "N"), ('𝘖', "O"),
+    ('𝘗', "P"), ('𝘘', "Q"), ('𝘙', "R"), ('𝘚', "S"), ('𝘛', "T"), ('𝘜', "U"), ('𝘝', "V"), ('𝘞', "W"),
+    ('𝘟', "X"), ('𝘠', "Y"), ('𝘡', "Z"), ('𝘢', "a"), ('𝘣', "b"), ('𝘤', "c"), ('𝘥', "d"), ('𝘦', "e"),
+    ('𝘧', "f"), ('𝘨', "g"), ('𝘩', "h"), ('𝘪', "i"), ('𝘫', "j"), ('𝘬', "k"), ('𝘭', "l"), ('𝘮', "m"),
+    ('𝘯', "n"), ('𝘰', "o"), ('𝘱', "p"), ('𝘲', "q"), ('𝘳', "r"), ('𝘴', "s"), ('𝘵', "t"), ('𝘶', "u"),
+    ('𝘷', "v"), ('𝘸', "w"), ('𝘹', "x"), ('𝘺', "y"), ('𝘻', "z"), ('𝘼', "A"), ('𝘽', "B"), ('𝘾', "C"),
+    ('𝘿', "D"), ('𝙀', "E"), ('𝙁', "F"), ('𝙂', "G"), ('𝙃', "H"), ('𝙄', "I"), ('𝙅', "J"), ('𝙆', "K"),
+    ('𝙇', "L"), ('𝙈', "M"), ('𝙉', "N"), ('𝙊', "O"), ('𝙋', "P"), ('𝙌', "Q"), ('𝙍', "R"), ('𝙎', "S"),
+    ('𝙏', "T"), ('𝙐', "U"), ('𝙑', "V"), ('𝙒', "W"), ('𝙓', "X"), ('𝙔', "Y"), ('𝙕', "Z"), ('𝙖', "a"),
+    ('𝙗', "b"), ('𝙘', "c"), ('𝙙', "d"), ('𝙚', "e"), ('𝙛', "f"), ('𝙜', "g"), ('𝙝', "h"), ('𝙞', "i"),
+    ('𝙟', "j"), ('𝙠', "k"), ('𝙡', "l"), ('𝙢', "m"), ('𝙣', "n"), ('𝙤', "o"), ('𝙥', "p"), ('𝙦', "q"),
+    ('𝙧', "r"), ('𝙨', "s"), ('𝙩', "t"), ('𝙪', "u"), ('𝙫', "v"), ('𝙬', "w"), ('𝙭', "x"), ('𝙮', "y"),
+    ('𝙯', "z"), ('𝙰', "A"), ('𝙱', "B"), ('𝙲', "C"), ('𝙳', "D"), ('𝙴', "E"), ('𝙵', "F"), ('𝙶', "G"),
+    ('𝙷', "H"), ('𝙸', "I"), ('𝙹', "J"), ('𝙺', "K"), ('𝙻', "L"), ('𝙼', "M"), ('𝙽', "N"), ('𝙾', "O"),
+    ('𝙿', "P"), ('𝚀', "Q"), ('𝚁', "R"), ('𝚂', "S"), ('𝚃', "T"), ('𝚄', "U"), ('𝚅', "V"), ('𝚆', "W"),
+    ('𝚇', "X"), ('𝚈', "Y"), ('𝚉', "Z"), ('𝚊', "a"), ('𝚋', "b"), ('𝚌', "c"), ('𝚍', "d"), ('𝚎', "e"),
+    ('𝚏', "f"), ('𝚐', "g"), ('𝚑', "h"), ('𝚒', "i"), ('𝚓', "j"), ('𝚔', "k"), ('𝚕', "l"), ('𝚖', "m"),
+    ('𝚗', "n"), ('𝚘', "o"), ('𝚙', "p"), ('𝚚', "q"), ('𝚛', "r"), ('𝚜', "s"), ('𝚝', "t"), ('𝚞', "u"),
+    ('𝚟', "v"), ('𝚠', "w"), ('𝚡', "x"), ('𝚢', "y"), ('𝚣', "z"), ('𝚤', "ı"), ('𝚥', "ȷ"), ('𝚨', "Α"),
+    ('𝚩', "Β"), ('𝚪', "Γ"), ('𝚫', "Δ"), ('𝚬', "Ε"), ('𝚭', "Ζ"), ('𝚮', "Η"), ('𝚯', "Θ"), ('𝚰', "Ι"),
+    ('𝚱', "Κ"), ('𝚲', "Λ"), ('𝚳', "Μ"), ('𝚴', "Ν"), ('𝚵', "Ξ"), ('𝚶', "Ο"), ('𝚷', "Π"), ('𝚸', "Ρ"),
+    ('𝚹', "Θ"), ('𝚺', "Σ"), ('𝚻', "Τ"), ('𝚼', "Υ"), ('𝚽', "Φ"), ('𝚾', "Χ"), ('𝚿', "Ψ"), ('𝛀', "Ω"),
+    ('𝛁', "∇"), ('𝛂', "α"), ('𝛃', "β"), ('𝛄', "γ"), ('𝛅', "δ"), ('𝛆', "ε"), ('𝛇', "ζ"), ('𝛈', "η"),
+    ('𝛉', "θ"), ('𝛊', "ι"), ('𝛋', "κ"), ('𝛌', "λ"), ('𝛍', "μ"), ('𝛎', "ν"), ('𝛏', "ξ"), ('𝛐', "ο"),
+    ('𝛑', "π"), ('𝛒', "ρ"), ('𝛓', "ς"), ('𝛔', "σ"), ('𝛕', "τ"), ('𝛖', "υ"), ('𝛗', "φ"), ('𝛘', "χ"),
+    ('𝛙', "ψ"), ('𝛚', "ω"), ('𝛛', "∂"), ('𝛜', "ε"), ('𝛝', "θ"), ('𝛞', "κ"), ('𝛟', "φ"), ('𝛠', "ρ"),
+    ('𝛡', "π"), ('𝛢', "Α"), ('𝛣', "Β"), ('𝛤', "Γ"), ('𝛥', "Δ"), ('𝛦', "Ε"), ('𝛧', "Ζ"), ('𝛨', "Η"),
+    ('𝛩', "Θ"), ('𝛪', "Ι"), ('𝛫', "Κ"), ('𝛬', "Λ"), ('𝛭', "Μ"), ('𝛮', "Ν"), ('𝛯', "Ξ"), ('𝛰', "Ο"),
+    ('𝛱', "Π"), ('𝛲', "Ρ"), ('𝛳', "Θ"), ('𝛴', "Σ"), ('𝛵', "Τ"), ('𝛶', "Υ"), ('𝛷', "Φ"), ('𝛸', "Χ"),
+    ('𝛹', "Ψ"), ('𝛺', "Ω"), ('𝛻', "∇"), ('𝛼', "α"), ('𝛽', "β"), ('𝛾', "γ"), ('𝛿', "δ"), ('𝜀', "ε"),
+    ('𝜁', "ζ"), ('𝜂', "η"), ('𝜃', "θ"), ('𝜄', "ι"), ('𝜅', "κ"), ('𝜆', "λ"), ('𝜇', "μ"), ('𝜈', "ν"),
+    ('𝜉', "ξ"), ('𝜊', "ο"), ('𝜋', "π"), ('𝜌', "ρ"), ('𝜍', "ς"), ('𝜎', "σ"), ('𝜏', "τ"), ('𝜐', "υ"),
+    ('𝜑', "φ"), ('𝜒', "χ"), ('𝜓', "ψ"), ('𝜔', "ω"), ('𝜕', "∂"), ('𝜖', "ε"), ('𝜗', "θ"), ('𝜘', "κ"),
+    ('𝜙', "φ"), ('𝜚', "ρ"), ('𝜛', "π"), ('𝜜', "Α"), ('𝜝', "Β"), ('𝜞', "Γ"), ('𝜟', "Δ"), ('𝜠', "Ε"),
+    ('𝜡', "Ζ"), ('𝜢', "Η"), ('𝜣', "Θ"), ('𝜤', "Ι"), ('𝜥', "Κ"), ('𝜦', "Λ"), ('𝜧', "Μ"), ('𝜨', "Ν"),
+    ('𝜩', "Ξ"), ('𝜪', "Ο"), ('𝜫', "Π"), ('𝜬', "Ρ"), ('𝜭', "Θ"), ('𝜮', "Σ"), ('𝜯', "Τ"), ('𝜰', "Υ"),
+    ('𝜱', "Φ"), ('𝜲', "Χ"), ('𝜳', "Ψ"), ('𝜴', "Ω"), ('𝜵', "∇"), ('𝜶', "α"), ('𝜷', "β"), ('𝜸', "γ"),
+    ('𝜹', "δ"), ('𝜺', "ε"), ('𝜻', "ζ"), ('𝜼', "η"), ('𝜽', "θ"), ('𝜾', "ι"), ('𝜿', "κ"), ('𝝀', "λ"),
+    ('𝝁', "μ"), ('𝝂', "ν"), ('𝝃', "ξ"), ('𝝄', "ο"), ('𝝅', "π"), ('𝝆', "ρ"), ('𝝇', "ς"), ('𝝈', "σ"),
+    ('𝝉', "τ"), ('𝝊', "υ"), ('𝝋', "φ"), ('𝝌', "χ"), ('𝝍', "ψ"), ('𝝎', "ω"), ('𝝏', "∂"), ('𝝐', "ε"),
+    ('𝝑', "θ"), ('𝝒', "κ"), ('𝝓', "φ"), ('𝝔', "ρ"), ('𝝕', "π"), ('𝝖', "Α"), ('𝝗', "Β"), ('𝝘', "Γ"),
+    ('𝝙', "Δ"), ('𝝚', "Ε"), ('𝝛', "Ζ"), ('𝝜', "Η"), ('𝝝', "Θ"), ('𝝞', "Ι"), ('𝝟', "Κ"), ('𝝠', "Λ"),
+    ('𝝡', "Μ"), ('𝝢', "Ν"), ('𝝣', "Ξ"), ('𝝤', "Ο"), ('𝝥', "Π"), ('𝝦', "Ρ"), ('𝝧', "Θ"), ('𝝨', "Σ"),
+    ('𝝩', "Τ"), ('𝝪', "Υ"), ('𝝫', "Φ"), ('𝝬', "Χ"), ('𝝭', "Ψ"), ('𝝮', "Ω"), ('𝝯', "∇"), ('𝝰', "α"),
+    ('𝝱', "β"), ('𝝲', "γ"), ('𝝳', "δ"), ('𝝴', "ε"), ('𝝵', "ζ"), ('𝝶', "η"), ('𝝷', "θ"), ('𝝸', "ι"),
+    ('𝝹', "κ"), ('𝝺', "λ"), ('𝝻', "μ"), ('𝝼', "ν"), ('𝝽', "ξ"), ('𝝾', "ο"), ('𝝿', "π"), ('𝞀', "ρ"),
+    ('𝞁', "ς"), ('𝞂', "σ"), ('𝞃', "τ"), ('𝞄', "υ"), ('𝞅', "φ"), ('𝞆', "χ"), ('𝞇', "ψ"), ('𝞈', "ω"),
+    ('𝞉', "∂"), ('𝞊', "ε"), ('𝞋', "θ"), ('𝞌', "κ"), ('𝞍', "φ"), ('𝞎', "ρ"), ('𝞏', "π"), ('𝞐', "Α"),
+    ('𝞑', "Β"), ('𝞒', "Γ"), ('𝞓', "Δ"), ('𝞔', "Ε"), ('𝞕', "Ζ"), ('𝞖', "Η"), ('𝞗', "Θ"), ('𝞘', "Ι"),
+    ('𝞙', "Κ"), ('𝞚', "Λ"), ('𝞛', "Μ"), ('𝞜', "Ν"), ('𝞝', "Ξ"), ('𝞞', "Ο"), ('𝞟', "Π"), ('𝞠', "Ρ"),
+    ('𝞡', "Θ"), ('𝞢', "Σ"), ('𝞣', "Τ"), ('𝞤', "Υ"), ('𝞥', "Φ"), ('𝞦', "Χ"), ('𝞧', "Ψ"), ('𝞨', "Ω"),
+    ('𝞩', "∇"), ('𝞪', "α"), ('𝞫', "β"), ('𝞬', "γ"), ('𝞭', "δ"), ('𝞮', "ε"), ('𝞯', "ζ"), ('𝞰', "η"),
+    ('𝞱', "θ"), ('𝞲', "ι"), ('𝞳', "κ"), ('𝞴', "λ"), ('𝞵', "μ"), ('𝞶', "ν"), ('𝞷', "ξ"), ('𝞸', "ο"),
+    ('𝞹', "π"), ('𝞺', "ρ"), ('𝞻', "ς"), ('𝞼', "σ"), ('𝞽', "τ"), ('𝞾', "υ"), ('𝞿', "φ"), ('𝟀', "χ"),
+    ('𝟁', "ψ"), ('𝟂', "ω"), ('𝟃', "∂"), ('𝟄', "ε"), ('𝟅', "θ"), ('𝟆', "κ"), ('𝟇', "φ"), ('𝟈', "ρ"),
+    ('𝟉', "π"), ('𝟊', "Ϝ"), ('𝟋', "ϝ"), ('𝟎', "0"), ('𝟏', "1"), ('𝟐', "2"), ('𝟑', "3"), ('𝟒', "4"),
+    ('𝟓', "5"), ('𝟔', "6"), ('𝟕', "7"), ('𝟖', "8"), ('𝟗', "9"), ('𝟘', "0"), ('𝟙', "1"), ('𝟚', "2"),
+    ('𝟛', "3"), ('𝟜', "4"), ('𝟝', "5"), ('𝟞', "6"), ('𝟟', "7"), ('𝟠', "8"), ('𝟡', "9"), ('𝟢', "0"),
+    ('𝟣', "1"), ('𝟤', "2"), ('𝟥', "3"), ('𝟦', "4"), ('𝟧', "5"), ('𝟨', "6"), ('𝟩', "7"), ('𝟪', "8"),
+    ('𝟫', "9"), ('𝟬', "0"), ('𝟭', "1"), ('𝟮', "2"), ('𝟯', "3"), ('𝟰', "4"), ('𝟱', "5"), ('𝟲', "6"),
+    ('𝟳', "7"), ('𝟴', "8"), ('𝟵', "9"), ('𝟶', "0"), ('𝟷', "1"), ('𝟸', "2"), ('𝟹', "3"), ('𝟺', "4"),
+    ('𝟻', "5"), ('𝟼', "6"), ('𝟽', "7"), ('𝟾', "8"), ('𝟿', "9"), ('𞸀', "ا"), ('𞸁', "ب"), ('𞸂', "ج"),
+    ('𞸃', "د"), ('𞸅', "و"), ('𞸆', "ز"), ('𞸇', "ح"), ('𞸈', "ط"), ('𞸉', "ي"), ('𞸊', "ك"), ('𞸋', "ل"),
+    ('𞸌', "م"), ('𞸍', "ن"), ('𞸎', "س"), ('𞸏', "ع"), ('𞸐', "ف"), ('𞸑', "ص"), ('𞸒', "ق"), ('𞸓', "ر"),
+    ('𞸔', "ش"), ('𞸕', "ت"), ('𞸖', "ث"), ('𞸗', "خ"), ('𞸘', "ذ"), ('𞸙', "ض"), ('𞸚', "ظ"), ('𞸛', "غ"),
+    ('𞸜', "ٮ"), ('𞸝', "ں"), ('𞸞', "ڡ"), ('𞸟', "ٯ"), ('𞸡', "ب"), ('𞸢', "ج"), ('𞸤', "ه"), ('𞸧', "ح"),
+    ('𞸩', "ي"), ('𞸪', "ك"), ('𞸫', "ل"), ('𞸬', "م"), ('𞸭', "ن"), ('𞸮', "س"), ('𞸯', "ع"), ('𞸰', "ف"),
+    ('𞸱', "ص"), ('𞸲', "ق"), ('𞸴', "ش"), ('𞸵', "ت"), ('𞸶', "ث"), ('𞸷', "خ"), ('𞸹', "ض"), ('𞸻', "غ"),
+    ('𞹂', "ج"), ('𞹇', "ح"), ('𞹉', "ي"), ('𞹋', "ل"), ('𞹍', "ن"), ('𞹎', "س"), ('𞹏', "ع"), ('𞹑', "ص"),
+    ('𞹒', "ق"), ('𞹔', "ش"), ('𞹗', "خ"), ('𞹙', "ض"), ('𞹛', "غ"), ('𞹝', "ں"), ('𞹟', "ٯ"), ('𞹡', "ب"),
+    ('𞹢', "ج"), ('𞹤', "ه"), ('𞹧', "ح"), ('𞹨', "ط"), ('𞹩', "ي"), ('𞹪', "ك"), ('𞹬', "م"), ('𞹭', "ن"),
+    ('𞹮', "س"), ('𞹯', "ع"), ('𞹰', "ف"), ('𞹱', "ص"), ('𞹲', "ق"), ('𞹴', "ش"), ('𞹵', "ت"), ('𞹶', "ث"),
+    ('𞹷', "خ"), ('𞹹', "ض"), ('𞹺', "ظ"), ('𞹻', "غ"), ('𞹼', "ٮ"), ('𞹾', "ڡ"), ('𞺀', "ا"), ('𞺁', "ب"),
+    ('𞺂', "ج"), ('𞺃', "د"), ('𞺄', "ه"), ('𞺅', "و"), ('𞺆', "ز"), ('𞺇', "ح"), ('𞺈', "ط"), ('𞺉', "ي"),
+    ('𞺋', "ل"), ('𞺌', "م"), ('𞺍', "ن"), ('𞺎', "س"), ('𞺏', "ع"), ('𞺐', "ف"), ('𞺑', "ص"), ('𞺒', "ق"),
+    ('𞺓', "ر"), ('𞺔', "ش"), ('𞺕', "ت"), ('𞺖', "ث"), ('𞺗', "خ"), ('𞺘', "ذ"), ('𞺙', "ض"), ('𞺚', "ظ"),
+    ('𞺛', "غ"), ('𞺡', "ب"), ('𞺢', "ج"), ('𞺣', "د"), ('𞺥', "و"), ('𞺦', "ز"), ('𞺧', "ح"), ('𞺨', "ط"),
+    ('𞺩', "ي"), ('𞺫', "ل"), ('𞺬', "م"), ('𞺭', "ن"), ('𞺮', "س"), ('𞺯', "ع"), ('𞺰', "ف"), ('𞺱', "ص"),
+    ('𞺲', "ق"), ('𞺳', "ر"), ('𞺴', "ش"), ('𞺵', "ت"), ('𞺶', "ث"), ('𞺷', "خ"), ('𞺸', "ذ"), ('𞺹', "ض"),
+    ('𞺺', "ظ"), ('𞺻', "غ"), ('🄀', "0."), ('🄁', "0,"), ('🄂', "1,"), ('🄃', "2,"), ('🄄', "3,"),
+    ('🄅', "4,"), ('🄆', "5,"), ('🄇', "6,"), ('🄈', "7,"), ('🄉', "8,"), ('🄊', "9,"), ('🄐', "(A)"),
+    ('🄑', "(B)"), ('🄒', "(C)"), ('🄓', "(D)"), ('🄔', "(E)"), ('🄕', "(F)"), ('🄖', "(G)"),
+    ('🄗', "(H)"), ('🄘', "(I)"), ('🄙', "(J)"), ('🄚', "(K)"), ('🄛', "(L)"), ('🄜', "(M)"),
+    ('🄝', "(N)"), ('🄞', "(O)"), ('🄟', "(P)"), ('🄠', "(Q)"), ('🄡', "(R)"), ('🄢', "(S)"),
+    ('🄣', "(T)"), ('🄤', "(U)"), ('🄥', "(V)"), ('🄦', "(W)"), ('🄧', "(X)"), ('🄨', "(Y)"),
+    ('🄩', "(Z)"), ('🄪', "〔S〕"), ('🄫', "C"), ('🄬', "R"), ('🄭', "CD"), ('🄮', "WZ"), ('🄰', "A"),
+    ('🄱', "B"), ('🄲', "C"), ('🄳', "D"), ('🄴', "E"), ('🄵', "F"), ('🄶', "G"), ('🄷', "H"), ('🄸', "I"),
+    ('🄹', "J"), ('🄺', "K"), ('🄻', "L"), ('🄼', "M"), ('🄽', "N"), ('🄾', "O"), ('🄿', "P"), ('🅀', "Q"),
+    ('🅁', "R"), ('🅂', "S"), ('🅃', "T"), ('🅄', "U"), ('🅅', "V"), ('🅆', "W"), ('🅇', "X"), ('🅈', "Y"),
+    ('🅉', "Z"), ('🅊', "HV"), ('🅋', "MV"), ('🅌', "SD"), ('🅍', "SS"), ('🅎', "PPV"), ('🅏', "WC"),
+    ('🅪', "MC"), ('🅫', "MD"), ('🅬', "MR"), ('🆐', "DJ"), ('🈀', "ほか"), ('🈁', "ココ"), ('🈂', "サ"),
+    ('🈐', "手"), ('🈑', "字"), ('🈒', "双"), ('🈓', "テ\u{3099}"), ('🈔', "二"), ('🈕', "多"), ('🈖', "解"),
+    ('🈗', "天"), ('🈘', "交"), ('🈙', "映"), ('🈚', "無"), ('🈛', "料"), ('🈜', "前"), ('🈝', "後"), ('🈞', "再"),
+    ('🈟', "新"), ('🈠', "初"), ('🈡', "終"), ('🈢', "生"), ('🈣', "販"), ('🈤', "声"), ('🈥', "吹"), ('🈦', "演"),
+    ('🈧', "投"), ('🈨', "捕"), ('🈩', "一"), ('🈪', "三"), ('🈫', "遊"), ('🈬', "左"), ('🈭', "中"), ('🈮', "右"),
+    ('🈯', "指"), ('🈰', "走"), ('🈱', "打"), ('🈲', "禁"), ('🈳', "空"), ('🈴', "合"), ('🈵', "満"), ('🈶', "有"),
+    ('🈷', "月"), ('🈸', "申"), ('🈹', "割"), ('🈺', "営"), ('🈻', "配"), ('🉀', "〔本〕"), ('🉁', "〔三〕"),
+    ('🉂', "〔二〕"), ('🉃', "〔安〕"), ('🉄', "〔点〕"), ('🉅', "〔打〕"), ('🉆', "〔盗〕"), ('🉇', "〔勝〕"),
+    ('🉈', "〔敗〕"), ('🉐', "得"), ('🉑', "可"), ('🯰', "0"), ('🯱', "1"), ('🯲', "2"), ('🯳', "3"),
+    ('🯴', "4"), ('🯵', "5"), ('🯶', "6"), ('🯷', "7"), ('🯸', "8"), ('🯹', "9"),
+];
+
+/// Precomposed characters that composition never produces, although their
+/// decomposition starts with a starter: script-specific exclusions such as
+/// Devanagari nukta letters and Hebrew presentation forms, and letters
+/// added after Unicode 3.0
+const COMPOSITION_EXCLUSIONS: &[char] = &[
+    '\u{0958}', '\u{0959}', '\u{095A}', '\u{095B}', '\u{095C}', '\u{095D}', '\u{095E}', '\u{095F}',
+    '\u{09DC}', '\u{09DD}', '\u{09DF}', '\u{0A33}', '\u{0A36}', '\u{0A59}', '\u{0A5A}', '\u{0A5B}',
+    '\u{0A5E}', '\u{0B5C}', '\u{0B5D}', '\u{0F43}', '\u{0F4D}', '\u{0F52}', '\u{0F57}', '\u{0F5C}',
+    '\u{0F69}', '\u{0F76}', '\u{0F78}', '\u{0F93}', '\u{0F9D}', '\u{0FA2}', '\u{0FA7}', '\u{0FAC}',
+    '\u{0FB9}', '\u{1F71}', '\u{1F73}', '\u{1F75}', '\u{1F77}', '\u{1F79}', '\u{1F7B}', '\u{1F7D}',
+    '\u{1FBB}', '\u{1FC9}', '\u{1FCB}', '\u{1FD3}', '\u{1FDB}', '\u{1FE3}', '\u{1FEB}', '\u{1FEE}',
+    '\u{1FF9}', '\u{1FFB}', '\u{212B}', '\u{2ADC}', '\u{FB1D}', '\u{FB1F}', '\u{FB2A}', '\u{FB2B}',
+    '\u{FB2C}', '\u{FB2D}', '\u{FB2E}', '\u{FB2F}', '\u{FB30}', '\u{FB31}', '\u{FB32}', '\u{FB33}',
+    '\u{FB34}', '\u{FB35}', '\u{FB36}', '\u{FB38}', '\u{FB39}', '\u{FB3A}', '\u{FB3B}', '\u{FB3C}',
+    '\u{FB3E}', '\u{FB40}', '\u{FB41}', '\u{FB43}', '\u{FB44}', '\u{FB46}', '\u{FB47}', '\u{FB48}',
+    '\u{FB49}', '\u{FB4A}', '\u{FB4B}', '\u{FB4C}', '\u{FB4D}', '\u{FB4E}', '\u{1D15E}',
+    '\u{1D15F}', '\u{1D160}', '\u{1D161}', '\u{1D162}', '\u{1D163}', '\u{1D164}', '\u{1D1BB}',
+    '\u{1D1BC}', '\u{1D1BD}', '\u{1D1BE}', '\u{1D1BF}', '\u{1D1C0}',
+];
+
+/// Marks that extend a grapheme cluster, as inclusive ranges: combining
+/// marks (General_Category Mn, Me and Mc) and the Thai and Lao SARA AM,
+/// less the Mc letters UAX #29 leaves as clusters of their own (Myanmar
+/// tone marks and a few others)
+const MARKS: &[(char, char)] = &[
+    ('\u{0300}', '\u{036F}'), ('\u{0483}', '\u{0489}'), ('\u{0591}', '\u{05BD}'),
+    ('\u{05BF}', '\u{05BF}'), ('\u{05C1}', '\u{05C2}'), ('\u{05C4}', '\u{05C5}'),
+    ('\u{05C7}', '\u{05C7}'), ('\u{0610}', '\u{061A}'), ('\u{064B}', '\u{065F}'),
+    ('\u{0670}', '\u{0670}'), ('\u{06D6}', '\u{06DC}'), ('\u{06DF}', '\u{06E4}'),
+    ('\u{06E7}', '\u{06E8}'), ('\u{06EA}', '\u{06ED}'), ('\u{0711}', '\u{0711}'),
+    ('\u{0730}', '\u{074A}'), ('\u{07A6}', '\u{07B0}'), ('\u{07EB}', '\u{07F3}'),
+    ('\u{07FD}', '\u{07FD}'), ('\u{0816}', '\u{0819}'), ('\u{081B}', '\u{0823}'),
+    ('\u{0825}', '\u{0827}'), ('\u{0829}', '\u{082D}'), ('\u{0859}', '\u{085B}'),
+    ('\u{0898}', '\u{089F}'), ('\u{08CA}', '\u{08E1}'), ('\u{08E3}', '\u{0903}'),
+    ('\u{093A}', '\u{093C}'), ('\u{093E}', '\u{094F}'), ('\u{0951}', '\u{0957}'),
+    ('\u{0962}', '\u{0963}'), ('\u{0981}', '\u{0983}'), ('\u{09BC}', '\u{09BC}'),
+    ('\u{09BE}', '\u{09C4}'), ('\u{09C7}', '\u{09C8}'), ('\u{09CB}', '\u{09CD}'),
+    ('\u{09D7}', '\u{09D7}'), ('\u{09E2}', '\u{09E3}'), ('\u{09FE}', '\u{09FE}'),
+    ('\u{0A01}', '\u{0A03}'), ('\u{0A3C}', '\u{0A3C}'), ('\u{0A3E}', '\u{0A42}'),
+    ('\u{0A47}', '\u{0A48}'), ('\u{0A4B}', '\u{0A4D}'), ('\u{0A51}', '\u{0A51}'),
+    ('\u{0A70}', '\u{0A71}'), ('\u{0A75}', '\u{0A75}'), ('\u{0A81}', '\u{0A83}'),
+    ('\u{0ABC}', '\u{0ABC}'), ('\u{0ABE}', '\u{0AC5}'), ('\u{0AC7}', '\u{0AC9}'),
+    ('\u{0ACB}', '\u{0ACD}'), ('\u{0AE2}', '\u{0AE3}'), ('\u{0AFA}', '\u{0AFF}'),
+    ('\u{0B01}', '\u{0B03}'), ('\u{0B3C}', '\u{0B3C}'), ('\u{0B3E}', '\u{0B44}'),
+    ('\u{0B47}', '\u{0B48}'), ('\u{0B4B}', '\u{0B4D}'), ('\u{0B55}', '\u{0B57}'),
+    ('\u{0B62}', '\u{0B63}'), ('\u{0B82}', '\u{0B82}'), ('\u{0BBE}', '\u{0BC2}'),
+    ('\u{0BC6}', '\u{0BC8}'), ('\u{0BCA}', '\u{0BCD}'), ('\u{0BD7}', '\u{0BD7}'),
+    ('\u{0C00}', '\u{0C04}'), ('\u{0C3C}', '\u{0C3C}'), ('\u{0C3E}', '\u{0C44}'),
+    ('\u{0C46}', '\u{0C48}'), ('\u{0C4A}', '\u{0C4D}'), ('\u{0C55}', '\u{0C56}'),
+    ('\u{0C62}', '\u{0C63}'), ('\u{0C81}', '\u{0C83}'), ('\u{0CBC}', '\u{0CBC}'),
+    ('\u{0CBE}', '\u{0CC4}'), ('\u{0CC6}', '\u{0CC8}'), ('\u{0CCA}', '\u{0CCD}'),
+    ('\u{0CD5}', '\u{0CD6}'), ('\u{0CE2}', '\u{0CE3}'), ('\u{0D00}', '\u{0D03}'),
+    ('\u{0D3B}', '\u{0D3C}'), ('\u{0D3E}', '\u{0D44}'), ('\u{0D46}', '\u{0D48}'),
+    ('\u{0D4A}', '\u{0D4D}'), ('\u{0D57}', '\u{0D57}'), ('\u{0D62}', '\u{0D63}'),
+    ('\u{0D81}', '\u{0D83}'), ('\u{0DCA}', '\u{0DCA}'), ('\u{0DCF}', '\u{0DD4}'),
+    ('\u{0DD6}', '\u{0DD6}'), ('\u{0DD8}', '\u{0DDF}'), ('\u{0DF2}', '\u{0DF3}'),
+    ('\u{0E31}', '\u{0E31}'), ('\u{0E33}', '\u{0E3A}'), ('\u{0E47}', '\u{0E4E}'),
+    ('\u{0EB1}', '\u{0EB1}'), ('\u{0EB3}', '\u{0EBC}'), ('\u{0EC8}', '\u{0ECD}'),
+    ('\u{0F18}', '\u{0F19}'), ('\u{0F35}', '\u{0F35}'), ('\u{0F37}', '\u{0F37}'),
+    ('\u{0F39}', '\u{0F39}'), ('\u{0F3E}', '\u{0F3F}'), ('\u{0F71}', '\u{0F84}'),
+    ('\u{0F86}', '\u{0F87}'), ('\u{0F8D}', '\u{0F97}'), ('\u{0F99}', '\u{0FBC}'),
+    ('\u{0FC6}', '\u{0FC6}'), ('\u{102D}', '\u{1037}'), ('\u{1039}', '\u{103E}'),
+    ('\u{1056}', '\u{1059}'), ('\u{105E}', '\u{1060}'), ('\u{1071}', '\u{1074}'),
+    ('\u{1082}', '\u{1082}'), ('\u{1084}', '\u{1086}'), ('\u{108D}', '\u{108D}'),
+    ('\u{109D}', '\u{109D}'), ('\u{135D}', '\u{135F}'), ('\u{1712}', '\u{1715}'),
+    ('\u{1732}', '\u{1734}'), ('\u{1752}', '\u{1753}'), ('\u{1772}', '\u{1773}'),
+    ('\u{17B4}', '\u{17D3}'), ('\u{17DD}', '\u{17DD}'), ('\u{180B}', '\u{180D}'),
+    ('\u{180F}', '\u{180F}'), ('\u{1885}', '\u{1886}'), ('\u{18A9}', '\u{18A9}'),
+    ('\u{1920}', '\u{192B}'), ('\u{1930}', '\u{193B}'), ('\u{1A17}', '\u{1A1B}'),
+    ('\u{1A55}', '\u{1A5E}'), ('\u{1A60}', '\u{1A60}'), ('\u{1A62}', '\u{1A62}'),
+    ('\u{1A65}', '\u{1A7C}'), ('\u{1A7F}', '\u{1A7F}'), ('\u{1AB0}', '\u{1ACE}'),
+    ('\u{1B00}', '\u{1B04}'), ('\u{1B34}', '\u{1B44}'), ('\u{1B6B}', '\u{1B73}'),
+    ('\u{1B80}', '\u{1B82}'), ('\u{1BA1}', '\u{1BAD}'), ('\u{1BE6}', '\u{1BF3}'),
+    ('\u{1C24}', '\u{1C37}'), ('\u{1CD0}', '\u{1CD2}'), ('\u{1CD4}', '\u{1CE8}'),
+    ('\u{1CED}', '\u{1CED}'), ('\u{1CF4}', '\u{1CF4}'), ('\u{1CF7}', '\u{1CF9}'),
+    ('\u{1DC0}', '\u{1DFF}'), ('\u{20D0}', '\u{20F0}'), ('\u{2CEF}', '\u{2CF1}'),
+    ('\u{2D7F}', '\u{2D7F}'), ('\u{2DE0}', '\u{2DFF}'), ('\u{302A}', '\u{302F}'),
+    ('\u{3099}', '\u{309A}'), ('\u{A66F}', '\u{A672}'), ('\u{A674}', '\u{A67D}'),
+    ('\u{A69E}', '\u{A69F}'), ('\u{A6F0}', '\u{A6F1}'), ('\u{A802}', '\u{A802}'),
+    ('\u{A806}', '\u{A806}'), ('\u{A80B}', '\u{A80B}'), ('\u{A823}', '\u{A827}'),
+    ('\u{A82C}', '\u{A82C}'), ('\u{A880}', '\u{A881}'), ('\u{A8B4}', '\u{A8C5}'),
+    ('\u{A8E0}', '\u{A8F1}'), ('\u{A8FF}', '\u{A8FF}'), ('\u{A926}', '\u{A92D}'),
+    ('\u{A947}', '\u{A953}'), ('\u{A980}', '\u{A983}'), ('\u{A9B3}', '\u{A9C0}'),
+    ('\u{A9E5}', '\u{A9E5}'), ('\u{AA29}', '\u{AA36}'), ('\u{AA43}', '\u{AA43}'),
+    ('\u{AA4C}', '\u{AA4D}'), ('\u{AA7C}', '\u{AA7C}'), ('\u{AAB0}', '\u{AAB0}'),
+    ('\u{AAB2}', '\u{AAB4}'), ('\u{AAB7}', '\u{AAB8}'), ('\u{AABE}', '\u{AABF}'),
+    ('\u{AAC1}', '\u{AAC1}'), ('\u{AAEB}', '\u{AAEF}'), ('\u{AAF5}', '\u{AAF6}'),
+    ('\u{ABE3}', '\u{ABEA}'), ('\u{ABEC}', '\u{ABED}'), ('\u{FB1E}', '\u{FB1E}'),
+    ('\u{FE00}', '\u{FE0F}'), ('\u{FE20}', '\u{FE2F}'), ('\u{101FD}', '\u{101FD}'),
+    ('\u{102E0}', '\u{102E0}'), ('\u{10376}', '\u{1037A}'), ('\u{10A01}', '\u{10A03}'),
+    ('\u{10A05}', '\u{10A06}'), ('\u{10A0C}', '\u{10A0F}'), ('\u{10A38}', '\u{10A3A}'),
+    ('\u{10A3F}', '\u{10A3F}'), ('\u{10AE5}', '\u{10AE6}'), ('\u{10D24}', '\u{10D27}'),
+    ('\u{10EAB}', '\u{10EAC}'), ('\u{10F46}', '\u{10F50}'), ('\u{10F82}', '\u{10F85}'),
+    ('\u{11000}', '\u{11002}'), ('\u{11038}', '\u{11046}'), ('\u{11070}', '\u{11070}'),
+    ('\u{11073}', '\u{11074}'), ('\u{1107F}', '\u{11082}'), ('\u{110B0}', '\u{110BA}'),
+    ('\u{110C2}', '\u{110C2}'), ('\u{11100}', '\u{11102}'), ('\u{11127}', '\u{11134}'),
+    ('\u{11145}', '\u{11146}'), ('\u{11173}', '\u{11173}'), ('\u{11180}', '\u{11182}'),
+    ('\u{111B3}', '\u{111C0}'), ('\u{111C9}', '\u{111CC}'), ('\u{111CE}', '\u{111CF}'),
+    ('\u{1122C}', '\u{11237}'), ('\u{1123E}', '\u{1123E}'), ('\u{112DF}', '\u{112EA}'),
+    ('\u{11300}', '\u{11303}'), ('\u{1133B}', '\u{1133C}'), ('\u{1133E}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'), ('\u{1134B}', '\u{1134D}'), ('\u{11357}', '\u{11357}'),
+    ('\u{11362}', '\u{11363}'), ('\u{11366}', '\u{1136C}'), ('\u{11370}', '\u{11374}'),
+    ('\u{11435}', '\u{11446}'), ('\u{1145E}', '\u{1145E}'), ('\u{114B0}', '\u{114C3}'),
+    ('\u{115AF}', '\u{115B5}'), ('\u{115B8}', '\u{115C0}'), ('\u{115DC}', '\u{115DD}'),
+    ('\u{11630}', '\u{11640}'), ('\u{116AB}', '\u{116B7}'), ('\u{1171D}', '\u{1171F}'),
+    ('\u{11722}', '\u{1172B}'), ('\u{1182C}', '\u{1183A}'), ('\u{11930}', '\u{11935}'),
+    ('\u{11937}', '\u{11938}'), ('\u{1193B}', '\u{1193E}'), ('\u{11940}', '\u{11940}'),
+    ('\u{11942}', '\u{11943}'), ('\u{119D1}', '\u{119D7}'), ('\u{119DA}', '\u{119E0}'),
+    ('\u{119E4}', '\u{119E4}'), ('\u{11A01}', '\u{11A0A}'), ('\u{11A33}', '\u{11A39}'),
+    ('\u{11A3B}', '\u{11A3E}'), ('\u{11A47}', '\u{11A47}'), ('\u{11A51}', '\u{11A5B}'),
+    ('\u{11A8A}', '\u{11A99}'), ('\u{11C2F}', '\u{11C36}'), ('\u{11C38}', '\u{11C3F}'),
+    ('\u{11C92}', '\u{11CA7}'), ('\u{11CA9}', '\u{11CB6}'), ('\u{11D31}', '\u{11D36}'),
+    ('\u{11D3A}', '\u{11D3A}'), ('\u{11D3C}', '\u{11D3D}'), ('\u{11D3F}', '\u{11D45}'),
+    ('\u{11D47}', '\u{11D47}'), ('\u{11D8A}', '\u{11D8E}'), ('\u{11D90}', '\u{11D91}'),
+    ('\u{11D93}', '\u{11D97}'), ('\u{11EF3}', '\u{11EF6}'), ('\u{16AF0}', '\u{16AF4}'),
+    ('\u{16B30}', '\u{16B36}'), ('\u{16F4F}', '\u{16F4F}'), ('\u{16F51}', '\u{16F87}'),
+    ('\u{16F8F}', '\u{16F92}'), ('\u{16FE4}', '\u{16FE4}'), ('\u{16FF0}', '\u{16FF1}'),
+    ('\u{1BC9D}', '\u{1BC9E}'), ('\u{1CF00}', '\u{1CF2D}'), ('\u{1CF30}', '\u{1CF46}'),
+    ('\u{1D165}', '\u{1D169}'), ('\u{1D16D}', '\u{1D172}'), ('\u{1D17B}', '\u{1D182}'),
+    ('\u{1D185}', '\u{1D18B}'), ('\u{1D1AA}', '\u{1D1AD}'), ('\u{1D242}', '\u{1D244}'),
+    ('\u{1DA00}', '\u{1DA36}'), ('\u{1DA3B}', '\u{1DA6C}'), ('\u{1DA75}', '\u{1DA75}'),
+    ('\u{1DA84}', '\u{1DA84}'), ('\u{1DA9B}', '\u{1DA9F}'), ('\u{1DAA1}', '\u{1DAAF}'),
+    ('\u{1E000}', '\u{1E006}'), ('\u{1E008}', '\u{1E018}'), ('\u{1E01B}', '\u{1E021}'),
+    ('\u{1E023}', '\u{1E024}'), ('\u{1E026}', '\u{1E02A}'), ('\u{1E130}', '\u{1E136}'),
+    ('\u{1E2AE}', '\u{1E2AE}'), ('\u{1E2EC}', '\u{1E2EF}'), ('\u{1E8D0}', '\u{1E8D6}'),
+    ('\u{1E944}', '\u{1E94A}'), ('\u{E0100}', '\u{E01EF}'),
+];
+
+/// Hangul syllable arithmetic (Unicode chapter 3.12)
+const HANGUL_BASE: u32 = 0xAC00;
+const LEADING_BASE: u32 = 0x1100;
+const VOWEL_BASE: u32 = 0x1161;
+const TRAILING_BASE: u32 = 0x11A7;
+const VOWEL_COUNT: u32 = 21;
+const TRAILING_COUNT: u32 = 28;
+const SYLLABLE_COUNT: u32 = 11172;
+
+/// Canonical decomposition of a single character, if it has one
+pub fn decompose_char(c: char) -> Option<&'static str> {
+    DECOMPOSITIONS
+        .binary_search_by_key(&c, |(precomposed, _)| *precomposed)
+        .ok()
+        .map(|index| DECOMPOSITIONS[index].1)
+}
+
+/// Base character of a possibly precomposed character ('é' → 'e')
+pub fn base_char(c: char) -> char {
+    decompose_char(c)
+        .and_then(|decomposed| decomposed.chars().next())
+        .unwrap_or(c)
+}
+
+/// Canonical combining class of a character (0 for starters)
+pub fn combining_class(c: char) -> u8 {
+    COMBINING_CLASSES
+        .binary_search_by(|&(start, end, _)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .map(|index| COMBINING_CLASSES[index].2)
+        .unwrap_or(0)
+}
+
+/// Whether a character is a mark that joins the preceding grapheme
+/// cluster: a combining mark, or a spacing vowel sign such as Thai 'ำ'
+/// or Bengali 'া' (UAX #29 rules GB9 and GB9a)
+pub fn is_mark(c: char) -> bool {
+    MARKS
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+fn compatibility_decomposition(c: char) -> Option<&'static str> {
+    COMPATIBILITY
+        .binary_search_by_key(&c, |(character, _)| *character)
+        .ok()
+        .map(|index| COMPATIBILITY[index].1)
+}
+
+/// A Unicode normalization form
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl Form {
+    /// Parse "NFC", "NFD", "NFKC" or "NFKD", in any case
+    pub fn from_name(name: &str) -> Option<Form> {
+        match name.to_ascii_uppercase().as_str() {
+            "NFC" => Some(Form::Nfc),
+            "NFD" => Some(Form::Nfd),
+            "NFKC" => Some(Form::Nfkc),
+            "NFKD" => Some(Form::Nfkd),
+            _ => None,
+        }
+    }
+}
+
+/// Fully decompose text, canonically or for compatibility, and put
+/// combining marks in canonical order
+fn decompose(text: &str, compatibility: bool) -> Vec<char> {
+    let mut chars: Vec<char> = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        let index = (c as u32).wrapping_sub(HANGUL_BASE);
+        if index < SYLLABLE_COUNT {
+            let per_leading = VOWEL_COUNT * TRAILING_COUNT;
+            chars.extend(char::from_u32(LEADING_BASE + index / per_leading));
+            chars.extend(char::from_u32(VOWEL_BASE + index % per_leading / TRAILING_COUNT));
+            if !index.is_multiple_of(TRAILING_COUNT) {
+                chars.extend(char::from_u32(TRAILING_BASE + index % TRAILING_COUNT));
+            }
+            continue;
+        }
+        match compatibility.then(|| compatibility_decomposition(c)).flatten().or_else(|| decompose_char(c)) {
+            Some(decomposed) => chars.extend(decomposed.chars()),
+            None => chars.push(c),
+        }
+    }
+
+    // Stable sort of each run of marks by class
+    let mut start = 0;
+    while start < chars.len() {
+        if combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let mut end = start;
+        while end < chars.len() && combining_class(chars[end]) != 0 {
+            end += 1;
+        }
+        chars[start..end].sort_by_key(|c| combining_class(*c));
+        start = end;
+    }
+    chars
+}
+
+/// Pairs that canonical composition joins: (starter, mark) → precomposed
+/// Built from `DECOMPOSITIONS`, composing longer decompositions one mark at
+/// a time ("ệ" from "ẹ" and U+0302).
+static COMPOSITIONS: LazyLock<HashMap<(char, char), char>> = LazyLock::new(|| {
+    let composable = DECOMPOSITIONS.iter().filter(|(precomposed, decomposed)| {
+        decomposed.chars().count() > 1
+            && COMPOSITION_EXCLUSIONS.binary_search(precomposed).is_err()
+            && decomposed.chars().next().is_some_and(|first| combining_class(first) == 0)
+    });
+
+    let mut by_decomposition: HashMap<&str, char> = HashMap::new();
+    for &(precomposed, decomposed) in composable.clone() {
+        by_decomposition.entry(decomposed).or_insert(precomposed);
+    }
+
+    let mut pairs = HashMap::new();
+    for &(precomposed, decomposed) in composable {
+        let Some((split, last)) = decomposed.char_indices().next_back() else {
+            continue;
+        };
+        let prefix = &decomposed[..split];
+        let first = match prefix.chars().count() {
+            1 => prefix.chars().next(),
+            _ => by_decomposition.get(prefix).copied(),
+        };
+        if let Some(first) = first {
+            pairs.entry((first, last)).or_insert(precomposed);
+        }
+    }
+    pairs
+});
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first_code, second_code) = (first as u32, second as u32);
+    let leading = first_code.wrapping_sub(LEADING_BASE);
+    let vowel = second_code.wrapping_sub(VOWEL_BASE);
+    if leading < 19 && vowel < VOWEL_COUNT {
+        return char::from_u32(HANGUL_BASE + (leading * VOWEL_COUNT + vowel) * TRAILING_COUNT);
+    }
+    let syllable = first_code.wrapping_sub(HANGUL_BASE);
+    let trailing = second_code.wrapping_sub(TRAILING_BASE);
+    if syllable < SYLLABLE_COUNT && syllable.is_multiple_of(TRAILING_COUNT) && trailing > 0 && trailing < TRAILING_COUNT {
+        return char::from_u32(first_code + trailing);
+    }
+    COMPOSITIONS.get(&(first, second)).copied()
+}
+
+/// Canonically compose decomposed characters
+fn compose(chars: Vec<char>) -> String {
+    let mut result: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter: Option<usize> = None;
+
+    for c in chars {
+        let class = combining_class(c);
+        if let Some(index) = starter {
+            let last_class = result.last().map_or(0, |last| combining_class(*last));
+            let adjacent = index == result.len() - 1;
+            if adjacent || (last_class != 0 && last_class < class) {
+                if let Some(composed) = compose_pair(result[index], c) {
+                    result[index] = composed;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(result.len());
+        }
+        result.push(c);
+    }
+    result.into_iter().collect()
+}
+
+/// Normalize text to a Unicode normalization form
+/// Characters unassigned in Unicode 14.0 pass through unchanged. ASCII
+/// text is returned as is.
+pub fn normalize_form(text: &str, form: Form) -> String {
+    if text.is_ascii() {
+        return text.to_string();
+    }
+    match form {
+        Form::Nfd => decompose(text, false).into_iter().collect(),
+        Form::Nfkd => decompose(text, true).into_iter().collect(),
+        Form::Nfc => compose(decompose(text, false)),
+        Form::Nfkc => compose(decompose(text, true)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lines in the format of NormalizationTest.txt: source; NFC; NFD; NFKC; NFKD
+    const CONFORMANCE: &[&str] = &[
+        "212B;00C5;0041 030A;00C5;0041 030A;",
+        "2126;03A9;03A9;03A9;03A9;",
+        "212A;004B;004B;004B;004B;",
+        "0340;0300;0300;0300;0300;",
+        "0343;0313;0313;0313;0313;",
+        "0374;02B9;02B9;02B9;02B9;",
+        "037E;003B;003B;003B;003B;",
+        "0387;00B7;00B7;00B7;00B7;",
+        "1F71;03AC;03B1 0301;03AC;03B1 0301;",
+        "2000;2002;2002;0020;0020;",
+        "F900;8C48;8C48;8C48;8C48;",
+        "2F800;4E3D;4E3D;4E3D;4E3D;",
+        "1E0A;1E0A;0044 0307;1E0A;0044 0307;",
+        "1E0C 0307;1E0C 0307;0044 0323 0307;1E0C 0307;0044 0323 0307;",
+        "0044 0307 0323;1E0C 0307;0044 0323 0307;1E0C 0307;0044 0323 0307;",
+        "1E9B 0323;1E9B 0323;017F 0323 0307;1E69;0073 0323 0307;",
+        "1EC7;1EC7;0065 0323 0302;1EC7;0065 0323 0302;",
+        "0061 0315 0300 05AE 0300 0062;00E0 05AE 0300 0315 0062;0061 05AE 0300 0300 0315 0062;00E0 05AE 0300 0315 0062;0061 05AE 0300 0300 0315 0062;",
+        "0061 0316 0300;00E0 0316;0061 0316 0300;00E0 0316;0061 0316 0300;",
+        "0958;0915 093C;0915 093C;0915 093C;0915 093C;",
+        "FB2C;05E9 05BC 05C1;05E9 05BC 05C1;05E9 05BC 05C1;05E9 05BC 05C1;",
+        "0B47 0B3E;0B4B;0B47 0B3E;0B4B;0B47 0B3E;",
+        "0F73;0F71 0F72;0F71 0F72;0F71 0F72;0F71 0F72;",
+        "0344;0308 0301;0308 0301;0308 0301;0308 0301;",
+        "AC00;AC00;1100 1161;AC00;1100 1161;",
+        "D4DB;D4DB;1111 1171 11B6;D4DB;1111 1171 11B6;",
+        "1100 1161 11A8;AC01;1100 1161 11A8;AC01;1100 1161 11A8;",
+        "FB01;FB01;FB01;0066 0069;0066 0069;",
+        "2460;2460;2460;0031;0031;",
+        "00B2;00B2;00B2;0032;0032;",
+        "FF76 FF9E;FF76 FF9E;FF76 FF9E;30AC;30AB 3099;",
+        "3300;3300;3300;30A2 30D1 30FC 30C8;30A2 30CF 309A 30FC 30C8;",
+        "01C4;01C4;01C4;0044 017D;0044 005A 030C;",
+        "1F80;1F80;03B1 0313 0345;1F80;03B1 0313 0345;",
+        "0049 0307;0130;0049 0307;0130;0049 0307;",
+        "00C5 0301;01FA;0041 030A 0301;01FA;0041 030A 0301;",
+    ];
+
+    fn parse(field: &str) -> String {
+        field.split(' ').map(|code| char::from_u32(u32::from_str_radix(code, 16).unwrap()).unwrap()).collect()
+    }
+
+    #[test]
+    fn conformance_invariants() {
+        for line in CONFORMANCE {
+            let columns: Vec<String> = line.split(';').take(5).map(parse).collect();
+            let [c1, c2, c3, c4, c5] = &columns[..] else { panic!("{line}") };
+            for source in [c1, c2, c3] {
+                assert_eq!(normalize_form(source, Form::Nfc), *c2, "NFC {line}");
+                assert_eq!(normalize_form(source, Form::Nfd), *c3, "NFD {line}");
+            }
+            for source in [c4, c5] {
+                assert_eq!(normalize_form(source, Form::Nfc), *c4, "NFC {line}");
+                assert_eq!(normalize_form(source, Form::Nfd), *c5, "NFD {line}");
+            }
+            for source in [c1, c2, c3, c4, c5] {
+                assert_eq!(normalize_form(source, Form::Nfkc), *c4, "NFKC {line}");
+                assert_eq!(normalize_form(source, Form::Nfkd), *c5, "NFKD {line}");
+            }
+        }
+    }
+
+    #[test]
+    fn tables_are_sorted_and_stable() {
+        assert!(DECOMPOSITIONS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(COMPATIBILITY.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(COMBINING_CLASSES.windows(2).all(|pair| pair[0].1 < pair[1].0));
+        assert!(COMPOSITION_EXCLUSIONS.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let listed = DECOMPOSITIONS.iter().chain(COMPATIBILITY).map(|(c, _)| c.to_string());
+        for text in listed {
+            let nfd = normalize_form(&text, Form::Nfd);
+            let nfkd = normalize_form(&text, Form::Nfkd);
+            assert_eq!(normalize_form(&nfd, Form::Nfd), nfd, "{text}");
+            assert_eq!(normalize_form(&nfd, Form::Nfc), normalize_form(&text, Form::Nfc), "{text}");
+            assert_eq!(normalize_form(&nfkd, Form::Nfkd), nfkd, "{text}");
+            assert_eq!(normalize_form(&nfkd, Form::Nfkc), normalize_form(&text, Form::Nfkc), "{text}");
+        }
+    }
+
+    #[test]
+    fn singletons_decompose() {
+        assert_eq!(normalize_form("\u{212B}", Form::Nfc), "\u{00C5}");
+        assert_eq!(normalize_form("\u{2126}", Form::Nfc), "\u{03A9}");
+        assert_eq!(normalize_form("\u{212A}", Form::Nfkc), "K");
+        assert_eq!(base_char('\u{212B}'), 'A');
+    }
+}
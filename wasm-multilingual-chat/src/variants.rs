@@ -0,0 +1,171 @@
+//! Regional variants of detected languages
+
+use wasm_bindgen::prelude::*;
+use crate::detection::best_language;
+use crate::hanzi::variant_counts;
+use crate::segmentation::words;
+
+// Spelling and vocabulary cues, lowercase; multi-word cues are matched as
+// consecutive words, so "pequeno-almoço" is listed as "pequeno almoço"
+
+/// British spellings and words
+/// Words British English merely uses more ("holiday", "football", "mate")
+/// are left out, since American messages have them too.
+const EN_GB_CUES: &[&str] = &[
+    "colour", "colours", "coloured", "favour", "favours", "favourite", "favourites", "honour", "neighbour",
+    "neighbours", "behaviour", "labour", "flavour", "humour", "rumour", "centre", "centres", "theatre", "metre",
+    "metres", "litre", "litres", "organise", "organised", "organising", "realise", "realised", "recognise",
+    "recognised", "apologise", "apologised", "analyse", "analysed", "defence", "licence", "travelled",
+    "travelling", "cancelled", "cancelling", "grey", "tyre", "tyres", "aluminium", "whilst", "amongst", "mum",
+    "lorry", "flat", "crisps", "petrol", "motorway", "nappy", "postcode", "fortnight", "loo", "maths", "cheque",
+    "programme",
+];
+
+/// American spellings and words
+const EN_US_CUES: &[&str] = &[
+    "color", "colors", "colored", "favor", "favors", "favorite", "favorites", "honor", "neighbor", "neighbors",
+    "behavior", "labor", "flavor", "humor", "rumor", "center", "centers", "theater", "liter", "liters",
+    "organize", "organized", "organizing", "realize", "realized", "recognize", "recognized", "apologize",
+    "apologized", "analyze", "analyzed", "defense", "traveled", "traveling", "canceled", "canceling", "gray",
+    "aluminum", "mom", "mommy", "gotten", "apartment", "truck", "vacation", "soccer", "cookie", "cookies",
+    "sneakers", "trash", "garbage", "sidewalk", "gas station", "freeway", "diaper", "zip code", "y'all",
+    "candy", "faucet", "math", "cell phone", "parking lot",
+];
+
+/// Brazilian spellings and words
+const PT_BR_CUES: &[&str] = &[
+    "você", "vocês", "ônibus", "trem", "celular", "café da manhã", "banheiro", "a gente", "tá", "né",
+    "garoto", "garota", "moça", "geladeira", "sorvete", "xícara", "time", "equipe", "usuário", "usuários",
+    "arquivo", "arquivos", "tela", "suco", "registro", "contato", "ótimo", "ótima", "conosco",
+    "econômico", "acadêmico", "anônimo", "polêmica", "prêmio", "fenômeno", "gênero", "tênis", "bebê",
+    "dezesseis", "dezessete", "dezenove", "planejamento", "esporte",
+];
+
+/// European Portuguese spellings and words
+const PT_PT_CUES: &[&str] = &[
+    "autocarro", "comboio", "telemóvel", "pequeno almoço", "casa de banho", "fixe", "miúdo", "miúda",
+    "rapariga", "frigorífico", "gelado", "chávena", "equipa", "utilizador", "utilizadores", "ficheiro",
+    "ficheiros", "ecrã", "sumo", "facto", "registo", "contacto", "óptimo", "óptima", "connosco", "económico",
+    "académico", "anónimo", "polémica", "prémio", "fenómeno", "género", "ténis", "bebé", "dezasseis",
+    "dezassete", "dezanove", "planeamento", "desporto", "percebes",
+];
+
+/// Present forms of "estar", which Brazil follows with a gerund ("estou
+/// fazendo") and Portugal with "a" and an infinitive ("estou a fazer")
+const ESTAR_FORMS: [&str; 8] = ["estou", "estás", "está", "estamos", "estão", "tás", "tou", "estava"];
+
+/// Number of cues found in a list of lowercased words
+fn cue_count(words: &[String], cues: &[&str]) -> usize {
+    cues.iter()
+        .map(|cue| {
+            let cue: Vec<&str> = cue.split(' ').collect();
+            words.windows(cue.len()).filter(|window| window.iter().zip(&cue).all(|(word, part)| word == part)).count()
+        })
+        .sum()
+}
+
+/// Brazilian and European progressive forms ("estou fazendo", "estou a fazer")
+fn progressive_counts(words: &[String]) -> (usize, usize) {
+    let mut brazilian = 0;
+    let mut european = 0;
+    for (i, word) in words.iter().enumerate() {
+        if !ESTAR_FORMS.contains(&word.as_str()) {
+            continue;
+        }
+        match (words.get(i + 1), words.get(i + 2)) {
+            (Some(next), _) if next.ends_with("ndo") => brazilian += 1,
+            (Some(next), Some(verb)) if next == "a" && verb.ends_with('r') => european += 1,
+            _ => {}
+        }
+    }
+    (brazilian, european)
+}
+
+/// Region-qualified code for text in a language, or `None` without cues
+/// English ("en-GB", "en-US") and Portuguese ("pt-BR", "pt-PT") are told
+/// apart by spelling and vocabulary cues, Chinese ("zh-Hans", "zh-Hant") by
+/// characters that exist in only one script; the variant with more cues
+/// wins, and a tie gives `None`.
+pub fn regional_variant(text: &str, language: &str) -> Option<&'static str> {
+    let lowered: Vec<String> = words(text).iter().map(|word| word.text.to_lowercase().replace('\u{2019}', "'")).collect();
+    let (first, second, first_count, second_count) = match language {
+        "en" => ("en-GB", "en-US", cue_count(&lowered, EN_GB_CUES), cue_count(&lowered, EN_US_CUES)),
+        "zh" => {
+            let (simplified, traditional) = variant_counts(text);
+            ("zh-Hans", "zh-Hant", simplified, traditional)
+        }
+        "pt" => {
+            let (brazilian, european) = progressive_counts(&lowered);
+            (
+                "pt-BR",
+                "pt-PT",
+                cue_count(&lowered, PT_BR_CUES) + brazilian,
+                cue_count(&lowered, PT_PT_CUES) + european,
+            )
+        }
+        _ => return None,
+    };
+
+    match first_count.cmp(&second_count) {
+        std::cmp::Ordering::Greater => Some(first),
+        std::cmp::Ordering::Less => Some(second),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+/// Detect language with its regional variant
+/// Returns BCP 47 code: en-GB, en-US, pt-BR, pt-PT, zh-Hans or zh-Hant when
+/// the text has regional cues, otherwise the plain code from `detect_language`
+///
+/// Cues are spellings ("colour"/"color", "registo"/"registro", "prémio"/"prêmio"),
+/// everyday vocabulary ("lorry"/"truck", "autocarro"/"ônibus") and, for
+/// Portuguese, the progressive ("estou a fazer"/"estou fazendo"). Chinese
+/// is Simplified (zh-Hans) or Traditional (zh-Hant) by which script's
+/// characters it uses ("这个"/"這個"). Text without cues, or with as many
+/// for each variant, keeps the plain "en", "pt" or "zh" so callers can
+/// apply their own default.
+///
+/// @param text - Message text
+#[wasm_bindgen]
+pub fn detect_language_variant(text: &str) -> String {
+    if text.trim().is_empty() {
+        return String::from("en");
+    }
+
+    let language = best_language(text).0;
+    String::from(regional_variant(text, language).unwrap_or(language))
+}
+
+/// Refine an already detected language to its regional variant
+/// Returns the region-qualified code, or `language` unchanged when the text
+/// has no regional cues or the language has no variants
+///
+/// Use this after `detect_language_with_options` or `LanguageDetector` to
+/// avoid detecting twice.
+///
+/// @param text - Message text
+/// @param language - Detected language code ("en", "pt" and "zh" have variants)
+#[wasm_bindgen]
+pub fn regional_language_variant(text: &str, language: &str) -> String {
+    regional_variant(text, language).map(String::from).unwrap_or_else(|| language.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_spelling_and_vocabulary() {
+        assert_eq!(regional_variant("My favourite colour is grey", "en"), Some("en-GB"));
+        assert_eq!(regional_variant("We rented a flat near the centre", "en"), Some("en-GB"));
+        assert_eq!(regional_variant("My favorite color is gray", "en"), Some("en-US"));
+        assert_eq!(regional_variant("Nice weather today", "en"), None);
+    }
+
+    #[test]
+    fn words_shared_by_both_variants_are_not_british_cues() {
+        assert_eq!(regional_variant("We watched football all through the holidays", "en"), None);
+        assert_eq!(regional_variant("Happy holidays! Watching football with my mom", "en"), Some("en-US"));
+        assert_eq!(regional_variant("Hey mate, back at uni after the holiday", "en"), None);
+    }
+}
@@ -0,0 +1,370 @@
+//! Word segmentation for Thai, Chinese and Japanese
+//!
+//! **Learning Point**: These languages are written without spaces between
+//! words. Runs of their script are split by maximal matching: of all ways to
+//! cover the run with dictionary words, the one leaving the fewest characters
+//! unmatched and using the fewest words wins. Unmatched characters are then
+//! grouped by rules (a Thai run stays one word, Chinese runs split in twos,
+//! Japanese runs split where the script changes).
+
+use wasm_bindgen::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock, RwLock};
+use crate::hanzi::to_simplified;
+use crate::js::to_js;
+use crate::segmentation::{is_extend, with_char_offsets, words, Token};
+
+/// Common Thai words, sorted
+const THAI_WORDS: &[&str] = &[
+    "กรุงเทพ", "กรุณา", "กลับ", "กลัว", "กลางคืน", "กว่า", "กัน", "กับ", "การ", "กาแฟ", "กำลัง", "กิน", "กี่",
+    "กุ้ง", "กู", "ก็", "ก็ได้", "ก่อน", "ขวด", "ขอ", "ของ", "ขอบคุณ", "ขอโทษ", "ขับ", "ขาย", "ขึ้น", "ข่าว",
+    "ข้อความ", "ข้อมูล", "ข้าง", "ข้าว", "คง", "คน", "คนไทย", "ครอบครัว", "ครับ", "ครั้ง", "ครู", "ควร",
+    "ความ", "คอมพิวเตอร์", "คะ", "คำตอบ", "คำถาม", "คิด", "คืน", "คือ", "คุณ", "คุย", "ค่อย", "ค่ะ", "ค่า",
+    "งาน", "ง่วง", "ง่าย", "จน", "จบ", "จริง", "จะ", "จาก", "จาน", "จำ", "จึง", "จ่าย", "จ้ะ", "จ้า", "จ๊ะ",
+    "ฉัน", "ชวน", "ชอบ", "ชั่วโมง", "ชา", "ชิ้น", "ชื่อ", "ช่วย", "ช้า", "ซิ", "ซึ่ง", "ซื้อ", "ดัง", "ดี",
+    "ดีใจ", "ดื่ม", "ดู", "ด้วย", "ตรวจ", "ตลาด", "ตอน", "ตอนนี้", "ตอบ", "ตัดสินใจ", "ตัว", "ตั้งแต่", "ตา",
+    "ตาม", "ตำรวจ", "ติดต่อ", "ตื่น", "ต่ำ", "ต้อง", "ต้องการ", "ต้อนรับ", "ถนน", "ถาม", "ถึง", "ถือ", "ถูก",
+    "ถูกต้อง", "ถ้า", "ทะเล", "ทั้ง", "ทาง", "ทำ", "ทำงาน", "ทำให้", "ทำไม", "ทีม", "ที่", "ที่นั่น",
+    "ที่นี่", "ที่สุด", "ที่อยู่", "ทุก", "ท่าน", "นม", "นอก", "นอน", "นะ", "นักเรียน", "นั่ง", "นั่น",
+    "นั้น", "นาที", "นี่", "นี้", "น่ะ", "น่ารัก", "น้อง", "น้อย", "น้ำ", "บน", "บริษัท", "บอก", "บาง", "บาท",
+    "บ้าน", "ประชุม", "ประเทศ", "ประเทศไทย", "ปลา", "ปัญหา", "ปิด", "ปี", "ผม", "ผลไม้", "ผัก", "ผิด",
+    "ผู้ชาย", "ผู้หญิง", "ฝน", "พบ", "พรุ่งนี้", "พร้อม", "พวก", "พวกเขา", "พวกเรา", "พัน", "พี่", "พูด",
+    "พ่อ", "ฟัง", "ภาษา", "ภาษาไทย", "ภูเขา", "มัน", "มั้ย", "มา", "มาก", "มี", "มึง", "มืด", "มือ", "มือถือ",
+    "ยัง", "ยังไง", "ยาก", "ยาว", "ยินดี", "ยี่สิบ", "ยืน", "ยุ่ง", "ย้าย", "รถ", "รถไฟ", "รอ", "รองเท้า",
+    "ระบบ", "ระหว่าง", "รัก", "รับ", "ราคา", "รูป", "รู้", "รู้จัก", "ร้อน", "ร้อย", "ร้าน", "ลง", "ลอง",
+    "ลาก่อน", "ลืม", "ลูก", "ลูกค้า", "ล่ะ", "ล้าน", "วัน", "วันนี้", "วิดีโอ", "วิ่ง", "ว่า", "ว่าง",
+    "สนามบิน", "สนุก", "สบาย", "สบายดี", "สร้าง", "สวย", "สวัสดี", "สว่าง", "สอง", "สอน", "สัปดาห์", "สั่ง",
+    "สั้น", "สาม", "สำคัญ", "สำหรับ", "สิ", "สิบ", "สิ่ง", "สี่", "สูง", "ส่ง", "หก", "หนัง", "หนังสือ",
+    "หนาว", "หนึ่ง", "หน้า", "หมอ", "หมื่น", "หมู", "หรอ", "หรอก", "หรือ", "หรือไม่", "หลัง", "หลาย", "หวัง",
+    "หัว", "หา", "หาก", "หิว", "ห้อง", "ห้องน้ำ", "ห้า", "อธิบาย", "อยาก", "อยู่", "อย่าง", "อย่างไร",
+    "อร่อย", "ออก", "อะไร", "อังกฤษ", "อัน", "อัปเดต", "อากาศ", "อาจ", "อาทิตย์", "อาบน้ำ", "อาหาร", "อีก",
+    "อ่าน", "เกิน", "เกี่ยวกับ", "เก่ง", "เก่า", "เก้า", "เขา", "เขียน", "เข้า", "เข้าใจ", "เคย",
+    "เครื่องบิน", "เงิน", "เงียบ", "เจอ", "เจ็ด", "เชื่อ", "เช้า", "เดิน", "เดือน", "เด็ก", "เตรียม", "เถอะ",
+    "เท่านั้น", "เท่าไร", "เท่าไหร่", "เท้า", "เธอ", "เปลี่ยน", "เปิด", "เป็น", "เพราะ", "เพลง", "เพียง",
+    "เพื่อน", "เมือง", "เมื่อ", "เมื่อวาน", "เมื่อไร", "เมื่อไหร่", "เย็น", "เรา", "เริ่ม", "เรียน", "เรื่อง",
+    "เร็ว", "เลย", "เลือก", "เล็ก", "เล่น", "เวลา", "เว็บไซต์", "เสร็จ", "เสียใจ", "เสื้อ", "เหนื่อย",
+    "เหมือน", "เหรอ", "เห็น", "เอกสาร", "เอง", "เอา", "แก้", "แก้ว", "แค่", "แต่", "แนะนำ", "แบบ", "แปด",
+    "แผนที่", "แพง", "แม่", "แม้", "และ", "แล้ว", "แสน", "โครงการ", "โชคดี", "โดย", "โทร", "โทรศัพท์", "โน้น",
+    "โรงพยาบาล", "โรงเรียน", "โรงแรม", "ใกล้", "ใคร", "ใจ", "ใช่", "ใช้", "ใต้", "ใน", "ใหญ่", "ใหม่", "ให้",
+    "ไกล", "ไก่", "ไข่", "ได้", "ได้แก่", "ไทย", "ไป", "ไม่", "ไม่เป็นไร", "ไม่ใช่", "ไม่ได้", "ไว้", "ไหน",
+    "ไหม",
+];
+
+/// Common Simplified Chinese words, sorted; Traditional text is looked up
+/// through its Simplified characters
+const CHINESE_WORDS: &[&str] = &[
+    "一下", "一个", "一些", "一会儿", "一定", "一样", "一点", "一直", "一起", "上", "上午", "上来", "上海", "下", "下午", "下来", "下雨", "不",
+    "不会", "不客气", "不是", "不用", "不能", "不要", "不过", "世界", "东西", "两个", "个", "中", "中午", "中国", "中国人", "中学", "中文",
+    "为什么", "主要", "之前", "之后", "也", "买", "了", "了解", "事情", "产品", "人", "人民币", "什么", "今", "今天", "今年", "今晚", "从",
+    "从来", "他", "他们", "以前", "以后", "价格", "会", "会议", "但是", "你", "你们", "你好", "使用", "信息", "做", "健康", "公司", "关系",
+    "其实", "再", "再见", "决定", "准备", "几个", "出去", "出来", "分钟", "刚才", "到", "前", "办公室", "北京", "医生", "医院", "历史", "去",
+    "去年", "参加", "又", "发展", "发现", "只是", "可以", "可是", "可能", "吃", "吃饭", "同事", "同学", "名字", "后", "吗", "吧", "听到",
+    "告诉", "呢", "周末", "和", "咖啡", "咱们", "哥哥", "哪个", "哪里", "商店", "啊", "喜欢", "喝", "喝水", "回去", "回家", "回来", "因为",
+    "团队", "困难", "国家", "图书馆", "图片", "在", "地址", "地方", "地铁", "城市", "外国人", "多", "多少", "大", "大学", "大家", "天", "天气",
+    "太", "女朋友", "她", "她们", "好", "如果", "妈妈", "妹妹", "姐姐", "学习", "学校", "学生", "孩子", "它", "它们", "完成", "客户", "家",
+    "家里", "容易", "对", "对不起", "小", "小学", "小时", "少", "就", "就是", "工作", "工资", "已经", "市场", "希望", "帮助", "帮忙", "年",
+    "应该", "开始", "弟弟", "当然", "很", "很多", "快乐", "怎么", "怎样", "总是", "情况", "想", "意思", "感觉", "我", "我们", "或者", "房子",
+    "所以", "手机", "才", "技术", "把", "提供", "支持", "数据", "文件", "文化", "方面", "日", "日本", "早上", "时候", "时间", "明天", "明年",
+    "星期", "昨天", "是", "晚上", "更", "最", "最后", "月", "有", "有意思", "有时", "朋友", "服务", "机场", "来", "欢迎", "正在", "水果",
+    "汉语", "汽车", "没", "没关系", "没有", "没问题", "注意", "消息", "漂亮", "火车", "点", "然后", "爸爸", "特别", "现在", "生日", "生活", "用",
+    "用户", "电影", "电脑", "电话", "男朋友", "的", "看", "看到", "真", "真的", "睡觉", "知道", "研究", "确定", "社会", "第一", "等", "简单",
+    "管理", "米饭", "系统", "经常", "经济", "结束", "给", "网站", "网络", "美国", "老师", "老板", "而且", "能", "能够", "自己", "英语", "虽然",
+    "衣服", "被", "要", "见面", "视频", "觉得", "解决", "计算机", "认为", "讨论", "让", "说", "请问", "谢谢", "走", "起来", "超市", "跟",
+    "身体", "过来", "还", "还是", "还有", "这", "这个", "这些", "这样", "这里", "进来", "进行", "那", "那个", "那些", "那样", "那里", "都",
+    "酒店", "里", "重要", "钱包", "银行", "问题", "需要", "非常", "音乐", "项目", "颜色", "飞机", "饭店", "马上", "高兴",
+];
+
+/// Japanese particles, auxiliaries and common words, sorted
+const JAPANESE_WORDS: &[&str] = &[
+    "あそこ", "あった", "あなた", "あの", "ありがとう", "ある", "あれ", "いた", "いつ", "いて", "いました", "います", "いる", "おはよう", "お願い", "か",
+    "から", "が", "けど", "ここ", "こと", "この", "これ", "こんにちは", "こんばんは", "ございます", "さ", "さようなら", "した", "して", "しない",
+    "します", "すごい", "すみません", "する", "そこ", "そして", "その", "それ", "ぞ", "たい", "だ", "だから", "だった", "ちょっと", "で", "でした",
+    "でしょう", "です", "でも", "と", "とても", "どう", "どこ", "どの", "どれ", "な", "ない", "なぜ", "なった", "なる", "に", "ね", "の", "ので",
+    "のに", "は", "へ", "ました", "ます", "ません", "まだ", "まで", "も", "もう", "もの", "や", "よ", "より", "よろしく", "わ", "を", "一",
+    "三", "上", "下", "中", "二", "人", "今", "今日", "仕事", "会社", "何", "俺", "僕", "先生", "円", "前", "友達", "大丈夫", "学校",
+    "年", "彼", "彼女", "後", "思う", "方", "日", "日本", "日本語", "明日", "昨日", "時間", "月", "本当", "来る", "私", "英語", "行く",
+    "見る", "言う", "誰", "電話", "食べる", "飲む",
+];
+
+/// Characters in the longest dictionary word
+const MAX_WORD_CHARS: usize = 12;
+
+/// Larger word lists, one word per line with '#' comments
+#[cfg(feature = "cjk-dictionary")]
+const BUNDLED_LISTS: [(&str, &str); 2] = [("zh", include_str!("../data/zh_words.txt")), ("ja", include_str!("../data/ja_words.txt"))];
+
+#[cfg(feature = "cjk-dictionary")]
+static BUNDLED_WORDS: LazyLock<HashMap<&'static str, HashSet<&'static str>>> = LazyLock::new(|| {
+    BUNDLED_LISTS
+        .iter()
+        .map(|(language, list)| {
+            let words = list.lines().map(str::trim).filter(|word| !word.is_empty() && !word.starts_with('#')).collect();
+            (*language, words)
+        })
+        .collect()
+});
+
+/// The larger word list of a language, when built with `cjk-dictionary`
+#[cfg(feature = "cjk-dictionary")]
+fn bundled_words(language: &str) -> Option<&'static HashSet<&'static str>> {
+    BUNDLED_WORDS.get(language)
+}
+
+#[cfg(not(feature = "cjk-dictionary"))]
+fn bundled_words(_language: &str) -> Option<&'static HashSet<&'static str>> {
+    None
+}
+
+/// Words added to a built-in dictionary at runtime
+#[derive(Default)]
+struct ExtraWords {
+    words: HashSet<String>,
+    /// Characters in the longest added word
+    longest: usize,
+}
+
+/// Added words per language
+static EXTRA_WORDS: LazyLock<RwLock<HashMap<String, Arc<ExtraWords>>>> = LazyLock::new(|| RwLock::new(HashMap::new()));
+
+fn extra_words(language: &str) -> Option<Arc<ExtraWords>> {
+    EXTRA_WORDS.read().ok()?.get(language).cloned()
+}
+
+/// The built-in dictionary of a language with the words added to it
+struct Lexicon {
+    builtin: &'static [&'static str],
+    bundled: Option<&'static HashSet<&'static str>>,
+    extra: Option<Arc<ExtraWords>>,
+}
+
+impl Lexicon {
+    fn contains(&self, word: &str) -> bool {
+        self.builtin.binary_search(&word).is_ok()
+            || self.bundled.is_some_and(|bundled| bundled.contains(word))
+            || self.extra.as_ref().is_some_and(|extra| extra.words.contains(word))
+    }
+
+    fn longest(&self) -> usize {
+        self.extra.as_ref().map_or(MAX_WORD_CHARS, |extra| extra.longest.max(MAX_WORD_CHARS))
+    }
+}
+
+/// Script of a character, for grouping characters outside the dictionary
+#[derive(Clone, Copy, PartialEq)]
+enum Class {
+    Thai,
+    Han,
+    Hiragana,
+    Katakana,
+    Other,
+}
+
+fn class_of(c: char) -> Class {
+    match c {
+        '\u{0E00}'..='\u{0E7F}' => Class::Thai,
+        '\u{3005}' | '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' => Class::Han,
+        '\u{3041}'..='\u{309F}' => Class::Hiragana,
+        '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' => Class::Katakana,
+        _ => Class::Other,
+    }
+}
+
+/// Characters that cannot start a word: combining marks, Thai following
+/// vowels and the repetition mark ๆ, small kana, the prolonged sound mark
+/// and the kanji repetition mark 々
+fn attaches_to_previous(c: char) -> bool {
+    is_extend(c)
+        || matches!(c,
+            '\u{0E30}' | '\u{0E32}' | '\u{0E33}' | '\u{0E45}' | '\u{0E46}'
+            | 'ぁ' | 'ぃ' | 'ぅ' | 'ぇ' | 'ぉ' | 'っ' | 'ゃ' | 'ゅ' | 'ょ' | 'ゎ'
+            | 'ァ' | 'ィ' | 'ゥ' | 'ェ' | 'ォ' | 'ッ' | 'ャ' | 'ュ' | 'ョ' | 'ヮ'
+            | 'ー' | '々')
+}
+
+/// Thai leading vowels, written before the consonant they follow in speech
+fn attaches_to_next(c: char) -> bool {
+    matches!(c, '\u{0E40}'..='\u{0E44}')
+}
+
+fn dictionary(language: &str) -> Option<&'static [&'static str]> {
+    match language {
+        "th" => Some(THAI_WORDS),
+        "zh" => Some(CHINESE_WORDS),
+        "ja" => Some(JAPANESE_WORDS),
+        _ => None,
+    }
+}
+
+/// A piece of a run: char range and whether it is a dictionary word
+struct Piece {
+    start: usize,
+    end: usize,
+    known: bool,
+}
+
+/// Split one word token of a scriptio continua language
+fn segment_run<'a>(token: Token<'a>, language: &str, lexicon: &Lexicon) -> Vec<Token<'a>> {
+    let chars: Vec<(usize, char)> = token.text.char_indices().collect();
+    let n = chars.len();
+    let key: Vec<char> = chars
+        .iter()
+        .map(|(_, c)| if language == "zh" { to_simplified(*c).unwrap_or(*c) } else { *c })
+        .collect();
+    let boundary = |i: usize| i == 0 || i == n || (!attaches_to_previous(chars[i].1) && !attaches_to_next(chars[i - 1].1));
+
+    // best[i]: (unmatched chars, words, previous position, known) covering chars[..i]
+    let mut best: Vec<Option<(usize, usize, usize, bool)>> = vec![None; n + 1];
+    best[0] = Some((0, 0, 0, true));
+    for i in 0..n {
+        let Some((unmatched, count, _, _)) = best[i] else { continue };
+        if !boundary(i) {
+            continue;
+        }
+        let mut relax = |j: usize, candidate: (usize, usize, usize, bool)| {
+            if best[j].is_none_or(|current| (candidate.0, candidate.1) < (current.0, current.1)) {
+                best[j] = Some(candidate);
+            }
+        };
+        for j in (i + 1)..=n.min(i + lexicon.longest()) {
+            let word: String = key[i..j].iter().collect();
+            if boundary(j) && lexicon.contains(&word) {
+                relax(j, (unmatched, count + 1, i, true));
+            }
+        }
+        let next = ((i + 1)..=n).find(|j| boundary(*j)).unwrap_or(n);
+        relax(next, (unmatched + next - i, count + 1, i, false));
+    }
+
+    let mut pieces = Vec::new();
+    let mut end = n;
+    while end > 0 {
+        let (_, _, start, known) = best[end].expect("every boundary is reachable");
+        pieces.push(Piece { start, end, known });
+        end = start;
+    }
+    pieces.reverse();
+
+    let class = |i: usize| class_of(chars[i].1);
+    let mut merged: Vec<Piece> = Vec::new();
+    for piece in pieces {
+        if let Some(last) = merged.last_mut() {
+            let okurigana = language == "ja" && class(last.start) == Class::Han && class(piece.start) == Class::Hiragana;
+            if !last.known && !piece.known && (class(last.start) == class(piece.start) || okurigana) {
+                last.end = piece.end;
+                continue;
+            }
+        }
+        merged.push(piece);
+    }
+
+    let offset = |i: usize| token.start + chars.get(i).map_or(token.text.len(), |(offset, _)| *offset);
+    let mut tokens = Vec::new();
+    for piece in merged {
+        let mut start = piece.start;
+        if language == "zh" && !piece.known && class(start) == Class::Han {
+            // Most Chinese words have two characters; an odd one joins the last
+            while piece.end - start >= 4 {
+                tokens.push((start, start + 2));
+                start += 2;
+            }
+        }
+        tokens.push((start, piece.end));
+    }
+    tokens
+        .into_iter()
+        .map(|(start, end)| {
+            let (start, end) = (offset(start), offset(end));
+            Token { text: &token.text[start - token.start..end - token.start], start, end }
+        })
+        .collect()
+}
+
+/// Split text into words, segmenting Thai, Chinese and Japanese runs
+/// Other languages, and words without characters of the language's script,
+/// are tokenized as by `segmentation::words`.
+pub fn segment<'a>(text: &'a str, language: &str) -> Vec<Token<'a>> {
+    let Some(builtin) = dictionary(language) else {
+        return words(text);
+    };
+    let lexicon = Lexicon { builtin, bundled: bundled_words(language), extra: extra_words(language) };
+    let in_script = |c: char| match language {
+        "th" => class_of(c) == Class::Thai,
+        _ => class_of(c) != Class::Other && class_of(c) != Class::Thai,
+    };
+
+    let mut tokens = Vec::new();
+    for word in words(text) {
+        if word.text.chars().any(in_script) {
+            tokens.extend(segment_run(word, language, &lexicon));
+        } else {
+            tokens.push(word);
+        }
+    }
+    tokens
+}
+
+/// Split text into words, including Thai, Chinese and Japanese
+/// Returns array: [{"text":"สวัสดี","start":0,"end":18,"charStart":0,"charEnd":6},
+/// {"text":"ครับ","start":18,"end":30,"charStart":6,"charEnd":10}]
+///
+/// Thai ("th"), Chinese ("zh") and Japanese ("ja") are written without
+/// spaces; their runs are split by maximal matching against a built-in
+/// dictionary of common words, extended with `add_dictionary_words`.
+/// Building with the `cjk-dictionary` feature adds about 900 Chinese and
+/// 300 Japanese words, at the cost of about 10 KB of wasm.
+/// Words the dictionary does not know stay whole between known ones, so
+/// a fuller word list gives finer splits. Text in other languages, and
+/// Latin words inside Thai, split at spaces and punctuation as everywhere.
+/// Offsets are in bytes and in characters, as in `segment_text`.
+///
+/// @param text - Text to split
+/// @param language - Language code selecting the dictionary
+#[wasm_bindgen]
+pub fn segment_words(text: &str, language: &str) -> JsValue {
+    to_js(&with_char_offsets(text, &segment(text, language)))
+}
+
+/// Add words to the segmentation dictionary of Thai, Chinese or Japanese
+/// Returns the number of words that were new, or 0 for other languages.
+///
+/// `words` holds one word per line, so a word list can be fetched as a
+/// text asset and passed in whole; blank lines and lines starting with
+/// '#' are skipped. Chinese words are matched through their Simplified
+/// characters, so add them in Simplified. Added words are used by
+/// `segment_words` and by everything counting words in these languages
+/// (statistics, keywords) until the module is reloaded.
+///
+/// @param language - "th", "zh" or "ja"
+/// @param words - Newline-separated words
+#[wasm_bindgen]
+pub fn add_dictionary_words(language: &str, words: &str) -> u32 {
+    if dictionary(language).is_none() {
+        return 0;
+    }
+    let mut dictionaries = EXTRA_WORDS.write().unwrap();
+    let current = dictionaries.get(language);
+    let mut extra = ExtraWords {
+        words: current.map(|current| current.words.clone()).unwrap_or_default(),
+        longest: current.map_or(0, |current| current.longest),
+    };
+
+    let mut added = 0;
+    for word in words.lines().map(str::trim).filter(|word| !word.is_empty() && !word.starts_with('#')) {
+        let word: String = match language {
+            "zh" => word.chars().map(|c| to_simplified(c).unwrap_or(c)).collect(),
+            _ => word.to_string(),
+        };
+        extra.longest = extra.longest.max(word.chars().count());
+        if extra.words.insert(word) {
+            added += 1;
+        }
+    }
+    dictionaries.insert(language.to_string(), Arc::new(extra));
+    added
+}
+
+/// Forget the words added to a language's segmentation dictionary
+#[wasm_bindgen]
+pub fn clear_dictionary_words(language: &str) {
+    EXTRA_WORDS.write().unwrap().remove(language);
+}
@@ -0,0 +1,453 @@
+//! Writing-assist checks
+
+use wasm_bindgen::prelude::*;
+use crate::js::{to_js, to_json};
+use serde::{Deserialize, Serialize};
+use crate::detection::detect_language;
+use crate::entities::Span;
+use crate::keywords::is_content_word;
+use crate::segmentation::words;
+use crate::unicode::base_char;
+
+/// Words that are legitimately repeated back to back
+/// ("I had had enough", "he said that that was fine", "nous nous sommes vus")
+fn repeat_allowlist(language: &str) -> &'static [&'static str] {
+    match language {
+        "de" => &["die", "das", "der", "sie"],
+        "fr" => &["nous", "vous"],
+        "it" => &["che"],
+        "es" | "pt" => &["que"],
+        "hi" => &["धीरे", "अलग", "कभी", "बार", "साथ", "जल्दी"],
+        _ => &["had", "that", "is", "do", "bye", "no", "ha"],
+    }
+}
+
+/// An accidental repetition of a word
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DoubledWord {
+    word: String,
+    first: Span,
+    /// The repeat to underline
+    second: Span,
+    /// Range to delete to keep a single occurrence (separator plus repeat)
+    remove: Span,
+}
+
+fn find_doubled_words_report(text: &str, language: &str) -> Vec<DoubledWord> {
+    let tokens = words(text);
+    let allowlist = repeat_allowlist(language);
+    let mut doubled = Vec::new();
+
+    for pair in tokens.windows(2) {
+        let (first, second) = (pair[0], pair[1]);
+        if !text[first.end..second.start].chars().all(char::is_whitespace) {
+            continue;
+        }
+
+        let word = first.text.to_lowercase();
+        if word != second.text.to_lowercase()
+            || word.chars().all(char::is_numeric)
+            || allowlist.contains(&word.as_str())
+        {
+            continue;
+        }
+
+        doubled.push(DoubledWord {
+            word,
+            first: Span { start: first.start, end: first.end },
+            second: Span { start: second.start, end: second.end },
+            remove: Span { start: first.end, end: second.end },
+        });
+    }
+
+    doubled
+}
+
+/// Find words accidentally typed twice in a row ("the the cat")
+/// Returns array: [{"word":"the","first":{"start":0,"end":3},"second":{"start":4,"end":7},"remove":{"start":3,"end":7}}]
+///
+/// Words are compared case-insensitively and only count as doubled when
+/// separated by whitespace alone, so "Yes, yes" or "well. Well" are not
+/// flagged. Numbers and the language's allowlisted repeats are skipped.
+#[wasm_bindgen]
+pub fn find_doubled_words(text: &str, language: &str) -> JsValue {
+    to_js(&find_doubled_words_report(text, language))
+}
+
+/// `find_doubled_words` as a JSON string
+/// @deprecated Use `find_doubled_words`, which returns an object instead of JSON text.
+#[deprecated(note = "use `find_doubled_words`, which returns an object")]
+#[wasm_bindgen]
+pub fn find_doubled_words_json(text: &str, language: &str) -> String {
+    to_json(&find_doubled_words_report(text, language), "[]")
+}
+
+/// Forms of "to be" that start a passive construction
+const BE_FORMS: [&str; 8] = ["am", "is", "are", "was", "were", "be", "been", "being"];
+
+/// Words that may sit between the "to be" form and the participle
+/// ("was quickly fixed", "is not being used")
+const PASSIVE_FILLERS: [&str; 12] = [
+    "not", "never", "also", "already", "just", "still", "often", "always", "being", "been", "all", "then",
+];
+
+/// Words allowed between the "to be" form and the participle, besides -ly adverbs
+const PASSIVE_WINDOW: usize = 2;
+
+/// Irregular past participles
+const IRREGULAR_PARTICIPLES: &[&str] = &[
+    "awoken", "beaten", "become", "begun", "bent", "bitten", "blown", "born", "borne", "bought", "bound",
+    "broken", "brought", "built", "burnt", "caught", "chosen", "come", "cut", "done", "drawn", "driven",
+    "drunk", "eaten", "fallen", "fed", "felt", "fought", "found", "forbidden", "forgiven", "forgotten",
+    "frozen", "given", "gone", "grown", "held", "hidden", "hit", "hung", "hurt", "kept", "known", "laid",
+    "led", "left", "lent", "lost", "made", "meant", "met", "paid", "put", "read", "rewritten", "ridden",
+    "run", "said", "seen", "sent", "set", "shaken", "shot", "shown", "shut", "sold", "spent", "split",
+    "spoken", "spread", "stolen", "struck", "sung", "swept", "sworn", "taken", "taught", "thrown", "told",
+    "torn", "thought", "understood", "undone", "upset", "won", "worn", "woven", "written",
+];
+
+/// Words ending in -ed that are not participles, or -ed adjectives that
+/// describe a state of mind rather than an action ("I am tired")
+const NON_PARTICIPLES_ED: &[&str] = &[
+    "bed", "red", "shed", "need", "feed", "seed", "speed", "weed", "breed", "greed", "indeed", "hundred",
+    "naked", "sacred", "wicked", "tired", "bored", "excited", "interested", "worried", "scared", "pleased",
+    "surprised", "confused", "married", "ashamed", "annoyed",
+];
+
+/// Participles that form a fixed phrase with a following "to" ("is used to", "was supposed to")
+const PARTICIPLE_PHRASES: [&str; 2] = ["used", "supposed"];
+
+fn is_past_participle(word: &str) -> bool {
+    IRREGULAR_PARTICIPLES.contains(&word)
+        || (word.len() > 3 && word.ends_with("ed") && !NON_PARTICIPLES_ED.contains(&word))
+}
+
+/// A likely passive construction
+#[derive(Serialize)]
+struct PassiveVoice<'a> {
+    text: &'a str,
+    start: usize,
+    end: usize,
+    auxiliary: String,
+    participle: String,
+}
+
+fn find_passive_voice_report<'a>(text: &'a str) -> Vec<PassiveVoice<'a>> {
+    if detect_language(text) != "en" {
+        return Vec::new();
+    }
+
+    let tokens = words(text);
+    let lowered: Vec<String> = tokens.iter().map(|token| token.text.to_lowercase()).collect();
+    let mut found = Vec::new();
+    let mut index = 0;
+
+    while index < tokens.len() {
+        if !BE_FORMS.contains(&lowered[index].as_str()) {
+            index += 1;
+            continue;
+        }
+
+        let mut next = index + 1;
+        let mut skipped = 0;
+        while next < tokens.len()
+            && skipped < PASSIVE_WINDOW
+            && !is_past_participle(&lowered[next])
+            && (PASSIVE_FILLERS.contains(&lowered[next].as_str()) || lowered[next].ends_with("ly"))
+        {
+            next += 1;
+            skipped += 1;
+        }
+
+        // Stop at sentence punctuation between the words
+        let participle = tokens
+            .get(next)
+            .filter(|_| is_past_participle(&lowered[next]))
+            .filter(|token| !text[tokens[index].end..token.start].contains(['.', '!', '?', ';', ',']))
+            .filter(|_| !(PARTICIPLE_PHRASES.contains(&lowered[next].as_str()) && lowered.get(next + 1).is_some_and(|w| w == "to")));
+
+        match participle {
+            Some(token) => {
+                let start = tokens[index].start;
+                found.push(PassiveVoice {
+                    text: &text[start..token.end],
+                    start,
+                    end: token.end,
+                    auxiliary: lowered[index].clone(),
+                    participle: lowered[next].clone(),
+                });
+                index = next + 1;
+            }
+            None => index += 1,
+        }
+    }
+
+    found
+}
+
+/// Find likely passive constructions in English text
+/// Returns array: [{"text":"was fixed","start":8,"end":17,"auxiliary":"was","participle":"fixed"}]
+///
+/// A passive is a form of "to be" followed by a past participle (an
+/// irregular form like "written" or a word ending in -ed), with at most two
+/// adverbs or negations in between ("was quickly fixed", "is not being
+/// used"). Emotion adjectives like "tired" or "interested" and the fixed
+/// phrases "used to" / "supposed to" are excluded.
+///
+/// This is a heuristic. Known false positives are adjectival participles
+/// describing a state ("the shop is closed", "she is qualified") and
+/// -ed adjectives missing from the exclusion list ("he is talented").
+/// Passives with "get" ("got fired") are not detected. Text not detected
+/// as English returns an empty array.
+#[wasm_bindgen]
+pub fn find_passive_voice(text: &str) -> JsValue {
+    to_js(&find_passive_voice_report(text))
+}
+
+/// `find_passive_voice` as a JSON string
+/// @deprecated Use `find_passive_voice`, which returns an object instead of JSON text.
+#[deprecated(note = "use `find_passive_voice`, which returns an object")]
+#[wasm_bindgen]
+pub fn find_passive_voice_json(text: &str) -> String {
+    to_json(&find_passive_voice_report(text), "[]")
+}
+
+/// Edit distance with adjacent transpositions (optimal string alignment)
+/// Counts insertions, deletions, substitutions and swaps of neighbouring
+/// characters, the four most common typing mistakes.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1).min(rows[i][j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+
+    rows[a.len()][b.len()]
+}
+
+/// Spelling with accents removed, plus the language's usual ASCII spellings
+/// (German "ä" → "ae"), so "cafe" or "Strasse" match their accented forms
+fn fold_spelling(word: &str, language: &str) -> String {
+    word.chars()
+        .map(|c| match (language, c) {
+            ("de", 'ä') => String::from("ae"),
+            ("de", 'ö') => String::from("oe"),
+            ("de", 'ü') => String::from("ue"),
+            ("de", 'ß') => String::from("ss"),
+            _ => base_char(c).to_string(),
+        })
+        .collect()
+}
+
+/// Distance between two lowercased words, where differing only in accents
+/// costs half an edit
+fn spelling_distance(word: &str, candidate: &str, language: &str) -> f64 {
+    let raw = edit_distance(word, candidate) as f64;
+    let folded = edit_distance(&fold_spelling(word, language), &fold_spelling(candidate, language)) as f64;
+    raw.min(folded + 0.5)
+}
+
+/// Largest distance a correction may have for a word of this length
+fn max_correction_distance(length: usize) -> f64 {
+    match length {
+        0..=4 => 1.0,
+        5..=8 => 2.0,
+        _ => 3.0,
+    }
+}
+
+/// A dictionary entry: a plain word, or a word with its corpus frequency
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DictionaryEntry {
+    Word(String),
+    Counted { word: String, frequency: f64 },
+}
+
+/// Suggested spelling correction
+#[derive(Serialize)]
+struct Correction {
+    suggestion: Option<String>,
+    distance: Option<f64>,
+    confidence: f64,
+}
+
+/// Copy the capitalization of `typed` onto a dictionary word
+fn match_case(typed: &str, word: &str) -> String {
+    let letters = || typed.chars().filter(|c| c.is_alphabetic());
+    if letters().count() > 1 && letters().all(char::is_uppercase) {
+        return word.to_uppercase();
+    }
+    let mut chars = word.chars();
+    match (typed.chars().next(), chars.next()) {
+        (Some(first), Some(initial)) if first.is_uppercase() => initial.to_uppercase().chain(chars).collect(),
+        _ => word.to_string(),
+    }
+}
+
+fn suggest_correction_report(word: &str, language: &str, dictionary: JsValue) -> Correction {
+    let entries: Vec<DictionaryEntry> = serde_wasm_bindgen::from_value(dictionary).unwrap_or_default();
+    let entries: Vec<(String, f64)> = entries
+        .into_iter()
+        .map(|entry| match entry {
+            DictionaryEntry::Word(word) => (word, 0.0),
+            DictionaryEntry::Counted { word, frequency } => (word, frequency.max(0.0)),
+        })
+        .collect();
+
+    best_correction(word, language, &entries)
+}
+
+/// Suggest the most likely intended word for a misspelling
+/// Returns object: {"suggestion":"hello","distance":1.0,"confidence":0.8}
+///
+/// Candidates are dictionary words within an edit distance of 1 (words up
+/// to 4 letters), 2 (up to 8) or 3 (longer), counting swapped neighbours as
+/// one edit and accent-only differences as half an edit. Each candidate is
+/// weighted e^(−2 × distance) × (1 + ln(1 + frequency)); the best one is
+/// suggested, with its share of the total weight, scaled down by its
+/// distance, as the confidence.
+///
+/// A word found in the dictionary (ignoring case) returns itself with
+/// confidence 1.0; without candidates, suggestion and distance are null.
+/// The suggestion copies the capitalization of the typed word.
+///
+/// @param word - Typed word
+/// @param language - Language code, used for accent spellings like "ae" for "ä"
+/// @param dictionary - Array of words, or of {word, frequency} objects
+#[wasm_bindgen]
+pub fn suggest_correction(word: &str, language: &str, dictionary: JsValue) -> JsValue {
+    to_js(&suggest_correction_report(word, language, dictionary))
+}
+
+/// `suggest_correction` as a JSON string
+/// @deprecated Use `suggest_correction`, which returns an object instead of JSON text.
+#[deprecated(note = "use `suggest_correction`, which returns an object")]
+#[wasm_bindgen]
+pub fn suggest_correction_json(word: &str, language: &str, dictionary: JsValue) -> String {
+    to_json(&suggest_correction_report(word, language, dictionary), "{}")
+}
+
+fn best_correction(word: &str, language: &str, entries: &[(String, f64)]) -> Correction {
+    let typed = word.trim().to_lowercase();
+    let none = Correction { suggestion: None, distance: None, confidence: 0.0 };
+    if typed.is_empty() {
+        return none;
+    }
+
+    if entries.iter().any(|(entry, _)| entry.to_lowercase() == typed) {
+        return Correction { suggestion: Some(word.trim().to_string()), distance: Some(0.0), confidence: 1.0 };
+    }
+
+    let limit = max_correction_distance(typed.chars().count());
+    let candidates: Vec<(&String, f64, f64)> = entries
+        .iter()
+        .filter_map(|(entry, frequency)| {
+            let distance = spelling_distance(&typed, &entry.to_lowercase(), language);
+            (distance <= limit).then(|| (entry, distance, (-2.0 * distance).exp() * (1.0 + frequency.ln_1p())))
+        })
+        .collect();
+
+    let total: f64 = candidates.iter().map(|(_, _, weight)| weight).sum();
+    let Some(&(best, distance, weight)) = candidates
+        .iter()
+        .max_by(|a, b| a.2.total_cmp(&b.2).then_with(|| b.0.cmp(a.0)))
+    else {
+        return none;
+    };
+
+    Correction {
+        suggestion: Some(match_case(word.trim(), best)),
+        distance: Some(distance),
+        confidence: weight / total * (1.0 - distance / (limit + 1.0)),
+    }
+}
+
+/// Share of a text's content words that even its main topic word
+/// typically takes up
+const EXPECTED_TOP_WORD_RATE: f64 = 0.02;
+
+/// Threshold used when the caller passes 0 or less
+const DEFAULT_OVERUSE_THRESHOLD: f64 = 2.0;
+
+/// Repeats below this count are never flagged, whatever the threshold
+const MIN_OVERUSE_COUNT: usize = 3;
+
+/// A content word used more often than expected
+#[derive(Serialize)]
+struct OverusedWord {
+    word: String,
+    count: usize,
+    expected: f64,
+    spans: Vec<Span>,
+}
+
+fn overused_words_report(text: &str, language: &str, threshold: f64) -> Vec<OverusedWord> {
+    let threshold = if threshold > 0.0 { threshold } else { DEFAULT_OVERUSE_THRESHOLD };
+
+    let mut occurrences: Vec<(String, Vec<Span>)> = Vec::new();
+    let mut total = 0;
+    for token in words(text) {
+        let word = token.text.to_lowercase();
+        if !is_content_word(&word, language) {
+            continue;
+        }
+        total += 1;
+        let span = Span { start: token.start, end: token.end };
+        match occurrences.iter_mut().find(|(seen, _)| *seen == word) {
+            Some((_, spans)) => spans.push(span),
+            None => occurrences.push((word, vec![span])),
+        }
+    }
+
+    let expected = (total as f64 * EXPECTED_TOP_WORD_RATE).max(1.0);
+    let mut overused: Vec<OverusedWord> = occurrences
+        .into_iter()
+        .filter(|(_, spans)| spans.len() >= MIN_OVERUSE_COUNT && spans.len() as f64 > threshold * expected)
+        .map(|(word, spans)| OverusedWord { word, count: spans.len(), expected, spans })
+        .collect();
+    overused.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+
+    overused
+}
+
+/// Find content words repeated more than expected for the message length
+/// Returns array, most repeated first: [{"word":"great","count":4,"expected":1.0,"spans":[{"start":0,"end":5}]}]
+///
+/// The expected count of any single content word is the number of content
+/// words times 2% (what a text's main topic word usually takes), and at
+/// least 1. A word is overused when it occurs more than `threshold` times
+/// its expected count and at least three times, so long messages are not
+/// flagged for their natural repetition and short ones rarely are at all.
+/// Words are compared case-insensitively; stop words, numbers and single
+/// letters are skipped. Spans cover every occurrence.
+///
+/// @param text - Message text
+/// @param language - Language code selecting the stop words
+/// @param threshold - Multiple of the expected count (0 or less uses 2.0)
+#[wasm_bindgen]
+pub fn overused_words(text: &str, language: &str, threshold: f64) -> JsValue {
+    to_js(&overused_words_report(text, language, threshold))
+}
+
+/// `overused_words` as a JSON string
+/// @deprecated Use `overused_words`, which returns an object instead of JSON text.
+#[deprecated(note = "use `overused_words`, which returns an object")]
+#[wasm_bindgen]
+pub fn overused_words_json(text: &str, language: &str, threshold: f64) -> String {
+    to_json(&overused_words_report(text, language, threshold), "[]")
+}